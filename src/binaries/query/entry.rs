@@ -39,6 +39,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::task::TaskScheduler;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -231,6 +232,11 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Task scheduler, the tasks are run by cloud control if it is configured.
+    if conf.query.cloud_control_grpc_server_address.is_none() {
+        TaskScheduler::instance().start();
+    }
+
     // Print information to users.
     println!("Databend Query");
     println!();
@@ -75,6 +75,8 @@ pub struct Task {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_suspended_at: Option<DateTime<Utc>>,
+    pub after: Vec<String>,
+    pub when_condition: Option<String>,
 }
 
 pub fn format_schedule_options(s: &ScheduleOptions) -> Result<String> {
@@ -200,6 +202,8 @@ impl TryFrom<crate::pb::Task> for Task {
             status,
             created_at,
             updated_at,
+            after: value.after,
+            when_condition: value.when_condition,
         };
         Ok(t)
     }
@@ -149,6 +149,7 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    UnknownRowAccessPolicy(1122),
 
     // Data Related Errors
 
@@ -230,6 +231,10 @@ build_exceptions! {
     IllegalPasswordPolicy(2213),
     PasswordPolicyIsUsedByUser(2214),
     InvalidPassword(2215),
+    UnknownWorkloadGroup(2216),
+    WorkloadGroupAlreadyExists(2217),
+    IllegalWorkloadGroup(2218),
+    WorkloadGroupQueueTimeout(2219),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -259,6 +264,8 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    /// row access policy error codes
+    RowAccessPolicyAlreadyExists(2322),
 
 
     // Cluster error codes.
@@ -328,6 +335,16 @@ build_exceptions! {
     IllegalStream(2733),
     StreamVersionMismatched(2734),
 
+    // Pipe error codes.
+    UnknownPipe(2740),
+    PipeAlreadyExists(2741),
+    PipeVersionMismatched(2742),
+
+    // Task error codes.
+    UnknownTask(2750),
+    TaskAlreadyExists(2751),
+    IllegalTask(2752),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -338,6 +355,11 @@ build_exceptions! {
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
 
+    // Transaction error codes.
+    CurrentTransactionIsAborted(2911),
+    DDLInTransaction(2912),
+    TableNotSupportTransaction(2913),
+
 }
 
 // Storage errors [3001, 4000].
@@ -30,6 +30,7 @@ pub enum Feature {
     ComputedColumn,
     StorageEncryption,
     Stream,
+    RowAccessPolicy,
 }
 
 impl Display for Feature {
@@ -65,6 +66,9 @@ impl Display for Feature {
             Feature::Stream => {
                 write!(f, "stream")
             }
+            Feature::RowAccessPolicy => {
+                write!(f, "row_access_policy")
+            }
         }
     }
 }
@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use apache_avro::Reader;
+use apache_avro::Schema as AvroSchema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use opendal::Operator;
+
+/// The number of bytes read at the start of the avro file on first read,
+/// large enough for the header of most files.
+const DEFAULT_HEADER_READ_SIZE: u64 = 64 * 1024;
+
+/// Read the writer schema in the header of an avro file.
+#[async_backtrace::framed]
+pub async fn read_avro_schema_async(
+    operator: &Operator,
+    path: &str,
+    file_size: Option<u64>,
+) -> Result<AvroSchema> {
+    let file_size = match file_size {
+        None => operator.stat(path).await?.content_length(),
+        Some(n) => n,
+    };
+    let header_size = DEFAULT_HEADER_READ_SIZE.min(file_size);
+    let buf = operator.read_with(path).range(0..header_size).await?;
+    match Reader::new(buf.as_slice()) {
+        Ok(reader) => Ok(reader.writer_schema().clone()),
+        // the header does not fit in the first read
+        Err(_) if header_size < file_size => {
+            let buf = operator.read(path).await?;
+            let reader = Reader::new(buf.as_slice()).map_err(|e| avro_error(path, e))?;
+            Ok(reader.writer_schema().clone())
+        }
+        Err(e) => Err(avro_error(path, e)),
+    }
+}
+
+/// Convert the schema of avro records to table schema.
+///
+/// Records are mapped to Tuple, arrays to Array, maps to Map with string keys,
+/// a union with null to Nullable and other unions (or recursive types) to Variant.
+pub fn infer_avro_schema(schema: &AvroSchema) -> Result<TableSchema> {
+    match schema {
+        AvroSchema::Record(record) => {
+            let fields = record
+                .fields
+                .iter()
+                .map(|f| Ok(TableField::new(&f.name, avro_to_table_type(&f.schema)?)))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(TableSchema::new(fields))
+        }
+        _ => Err(ErrorCode::BadBytes(format!(
+            "the top level schema of avro file must be a record, got {}",
+            schema.canonical_form()
+        ))),
+    }
+}
+
+fn avro_to_table_type(schema: &AvroSchema) -> Result<TableDataType> {
+    let data_type = match schema {
+        AvroSchema::Null => TableDataType::Null,
+        AvroSchema::Boolean => TableDataType::Boolean,
+        AvroSchema::Int | AvroSchema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        AvroSchema::Long | AvroSchema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        AvroSchema::Float => TableDataType::Number(NumberDataType::Float32),
+        AvroSchema::Double => TableDataType::Number(NumberDataType::Float64),
+        AvroSchema::Bytes
+        | AvroSchema::Fixed(_)
+        | AvroSchema::String
+        | AvroSchema::Enum(_)
+        | AvroSchema::Uuid => TableDataType::String,
+        AvroSchema::Date => TableDataType::Date,
+        AvroSchema::TimestampMillis
+        | AvroSchema::TimestampMicros
+        | AvroSchema::LocalTimestampMillis
+        | AvroSchema::LocalTimestampMicros => TableDataType::Timestamp,
+        AvroSchema::Decimal(decimal) => {
+            if decimal.precision > 76 || decimal.scale > decimal.precision {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unsupported avro decimal({}, {})",
+                    decimal.precision, decimal.scale
+                )));
+            }
+            TableDataType::Decimal(DecimalDataType::from_size(DecimalSize {
+                precision: decimal.precision as u8,
+                scale: decimal.scale as u8,
+            })?)
+        }
+        AvroSchema::Array(item) => TableDataType::Array(Box::new(avro_to_table_type(item)?)),
+        AvroSchema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_to_table_type(value)?],
+        })),
+        AvroSchema::Record(record) => {
+            let mut fields_name = Vec::with_capacity(record.fields.len());
+            let mut fields_type = Vec::with_capacity(record.fields.len());
+            for field in record.fields.iter() {
+                fields_name.push(field.name.clone());
+                fields_type.push(avro_to_table_type(&field.schema)?);
+            }
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        AvroSchema::Union(union) => {
+            let variants = union
+                .variants()
+                .iter()
+                .filter(|s| !matches!(s, AvroSchema::Null))
+                .collect::<Vec<_>>();
+            let data_type = match variants.as_slice() {
+                [] => TableDataType::Null,
+                [s] => avro_to_table_type(s)?,
+                _ => TableDataType::Variant,
+            };
+            if union.is_nullable() && data_type != TableDataType::Null {
+                data_type.wrap_nullable()
+            } else {
+                data_type
+            }
+        }
+        AvroSchema::Ref { .. } | AvroSchema::Duration => TableDataType::Variant,
+    };
+    Ok(data_type)
+}
+
+fn avro_error(path: &str, e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::BadBytes(format!("invalid avro file {}: {}", path, e))
+}
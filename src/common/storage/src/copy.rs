@@ -20,6 +20,9 @@ use serde::Deserialize;
 use serde::Serialize;
 use thiserror::Error;
 
+/// The max number of rejected rows kept for each file, the earliest ones are kept.
+pub const MAX_REJECTED_ROWS_PER_FILE: usize = 1000;
+
 #[derive(Default, Clone, Serialize, Deserialize)]
 pub struct CopyStatus {
     /// Key is file path.
@@ -55,15 +58,21 @@ impl FileStatus {
     pub fn add_error(&mut self, error: FileParseError, line: usize) {
         match &mut self.error {
             None => {
+                let first_error = FileErrorInfo { error, line };
                 self.error = Some(FileErrorsInfo {
                     num_errors: 1,
-                    first_error: FileErrorInfo { error, line },
+                    rejected_rows: vec![first_error.clone()],
+                    first_error,
                 });
             }
             Some(info) => {
                 info.num_errors += 1;
+                let error = FileErrorInfo { error, line };
+                if info.rejected_rows.len() < MAX_REJECTED_ROWS_PER_FILE {
+                    info.rejected_rows.push(error.clone());
+                }
                 if info.first_error.line > line {
-                    info.first_error = FileErrorInfo { error, line };
+                    info.first_error = error;
                 }
             }
         };
@@ -83,6 +92,9 @@ impl FileStatus {
 pub struct FileErrorsInfo {
     pub num_errors: usize,
     pub first_error: FileErrorInfo,
+    /// The rejected rows of the file, at most [`MAX_REJECTED_ROWS_PER_FILE`] are kept.
+    #[serde(default)]
+    pub rejected_rows: Vec<FileErrorInfo>,
 }
 
 impl FileErrorsInfo {
@@ -91,6 +103,11 @@ impl FileErrorsInfo {
         if self.first_error.line > other.first_error.line {
             self.first_error = other.first_error;
         }
+        self.rejected_rows.extend(other.rejected_rows);
+        if self.rejected_rows.len() > MAX_REJECTED_ROWS_PER_FILE {
+            self.rejected_rows.sort_by_key(|row| row.line);
+            self.rejected_rows.truncate(MAX_REJECTED_ROWS_PER_FILE);
+        }
     }
 }
 
@@ -139,6 +156,17 @@ pub enum FileParseError {
 }
 
 impl FileParseError {
+    /// Returns the name of the column the error occurred in, if the error is about a column.
+    pub fn column_name(&self) -> Option<&str> {
+        match self {
+            FileParseError::ColumnDecodeError { column_name, .. }
+            | FileParseError::ColumnMissingError { column_name, .. }
+            | FileParseError::ColumnDataNotDrained { column_name, .. } => Some(column_name),
+            FileParseError::NumberOfColumnsMismatch { .. }
+            | FileParseError::InvalidNDJsonRow { .. } => None,
+        }
+    }
+
     pub fn to_error_code(&self, mode: &OnErrorMode, file_path: &str, line: usize) -> ErrorCode {
         let pos: String = format!("at file '{}', line {}", file_path, line);
         let message = match mode {
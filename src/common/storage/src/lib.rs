@@ -57,6 +57,17 @@ pub mod parquet_rs;
 pub use parquet_rs::read_metadata_async;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
+mod avro;
+pub use avro::infer_avro_schema;
+pub use avro::read_avro_schema_async;
+
+mod orc;
+pub use orc::infer_orc_field;
+pub use orc::infer_orc_schema;
+pub use orc::orc_column_names;
+pub use orc::read_orc_metadata_async;
+pub use orc::read_orc_schema_async;
+
 mod stage;
 pub use stage::init_stage_operator;
 pub use stage::StageFileInfo;
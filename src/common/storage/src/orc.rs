@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use opendal::Operator;
+use orc_rust::proto::r#type::Kind;
+use orc_rust::reader::schema::create_field;
+use orc_rust::reader::Reader;
+
+/// Open an orc file and read its footer.
+///
+/// The footer is located by seeking from the end of the file, only the
+/// footer and the stripe footers are read, with range requests.
+#[async_backtrace::framed]
+pub async fn read_orc_metadata_async(
+    operator: &Operator,
+    path: &str,
+) -> Result<Reader<opendal::Reader>> {
+    let reader = operator.reader(path).await?;
+    Reader::new_async(reader)
+        .await
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid orc file {}: {}", path, e)))
+}
+
+/// Read the schema of an orc file.
+#[async_backtrace::framed]
+pub async fn read_orc_schema_async(operator: &Operator, path: &str) -> Result<TableSchema> {
+    let reader = read_orc_metadata_async(operator, path).await?;
+    infer_orc_schema(&reader)
+}
+
+/// Convert the types in the footer of an orc file to table schema.
+///
+/// The columns of orc files are always nullable.
+pub fn infer_orc_schema<R>(reader: &Reader<R>) -> Result<TableSchema> {
+    let fields = orc_column_names(reader)
+        .iter()
+        .map(|name| infer_orc_field(reader, name))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(TableSchema::new(fields))
+}
+
+/// The names of the top level columns of an orc file.
+pub fn orc_column_names<R>(reader: &Reader<R>) -> Vec<String> {
+    match reader.metadata().footer.types.first() {
+        Some(root) => root.field_names.clone(),
+        None => vec![],
+    }
+}
+
+/// Convert a top level column of an orc file to table field.
+pub fn infer_orc_field<R>(reader: &Reader<R>, name: &str) -> Result<TableField> {
+    let typ = reader
+        .schema()
+        .field(name)
+        .ok_or_else(|| ErrorCode::BadBytes(format!("orc file has no column named {}", name)))?;
+    match typ.kind() {
+        // the orc reader can only decode primitive columns for now.
+        Kind::List | Kind::Map | Kind::Struct | Kind::Union => {
+            Err(ErrorCode::Unimplemented(format!(
+                "column {} of orc file is {:?}, nested types of orc are not supported yet",
+                name,
+                typ.kind()
+            )))
+        }
+        _ => {
+            let field = create_field((name, &typ));
+            let data_type = TableDataType::try_from(&field)?;
+            Ok(TableField::new(name, data_type))
+        }
+    }
+}
@@ -0,0 +1,321 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+const IVF_FORMAT_VERSION: u8 = 1;
+const MAX_LISTS: usize = 256;
+const TRAINING_SAMPLES_PER_LIST: usize = 64;
+const KMEANS_ITERATIONS: usize = 10;
+
+/// The distance functions an [`IvfIndex`] can be searched with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distance {
+    Cosine,
+    L2,
+}
+
+impl Distance {
+    /// Returns the distance of the scalar function `name`, if there is one.
+    pub fn from_func_name(name: &str) -> Option<Distance> {
+        match name {
+            "cosine_distance" => Some(Distance::Cosine),
+            "l2_distance" => Some(Distance::L2),
+            _ => None,
+        }
+    }
+
+    // The lengths are checked by the callers.
+    fn eval(&self, from: &[f32], to: &[f32]) -> f32 {
+        match self {
+            Distance::Cosine => {
+                let (mut ab, mut aa, mut bb) = (0.0, 0.0, 0.0);
+                for (a, b) in from.iter().zip(to.iter()) {
+                    ab += a * b;
+                    aa += a * a;
+                    bb += b * b;
+                }
+                1.0 - ab / (aa.sqrt() * bb.sqrt())
+            }
+            Distance::L2 => squared_l2(from, to).sqrt(),
+        }
+    }
+}
+
+fn squared_l2(from: &[f32], to: &[f32]) -> f32 {
+    from.iter()
+        .zip(to.iter())
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum()
+}
+
+/// An inverted file index over vectors of the same dimension.
+///
+/// The vectors are clustered by k-means, each cluster forms a list.
+/// A search only scans the lists whose centroids are nearest to the query,
+/// so its result is approximate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IvfIndex {
+    dimension: usize,
+    /// `num_lists * dimension` values.
+    centroids: Vec<f32>,
+    /// The start of every list in `vectors`, ends with the number of vectors.
+    offsets: Vec<u32>,
+    /// The indexed vectors, grouped by list.
+    vectors: Vec<f32>,
+}
+
+impl IvfIndex {
+    /// Build the index from `vectors`, which holds the vectors one after another.
+    pub fn build(dimension: usize, vectors: &[f32]) -> Result<IvfIndex> {
+        if dimension == 0 || vectors.len() % dimension != 0 {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Invalid vectors of dimension {}, number of values: {}",
+                dimension,
+                vectors.len()
+            )));
+        }
+
+        let num_vectors = vectors.len() / dimension;
+        if num_vectors == 0 {
+            return Ok(IvfIndex {
+                dimension,
+                centroids: vec![],
+                offsets: vec![0],
+                vectors: vec![],
+            });
+        }
+
+        let num_lists = ((num_vectors as f64).sqrt() as usize).clamp(1, MAX_LISTS);
+        let centroids = train_centroids(dimension, vectors, num_lists);
+
+        let assignments = vectors
+            .chunks_exact(dimension)
+            .map(|v| nearest_centroid(dimension, &centroids, v))
+            .collect::<Vec<_>>();
+
+        let mut offsets = vec![0u32; num_lists + 1];
+        for list in assignments.iter() {
+            offsets[list + 1] += 1;
+        }
+        for i in 0..num_lists {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let mut positions = offsets[..num_lists].to_vec();
+        let mut grouped = vec![0.0; vectors.len()];
+        for (vector, list) in vectors.chunks_exact(dimension).zip(assignments.iter()) {
+            let start = positions[*list] as usize * dimension;
+            grouped[start..start + dimension].copy_from_slice(vector);
+            positions[*list] += 1;
+        }
+
+        Ok(IvfIndex {
+            dimension,
+            centroids,
+            offsets,
+            vectors: grouped,
+        })
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn num_lists(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn num_vectors(&self) -> usize {
+        *self.offsets.last().unwrap() as usize
+    }
+
+    /// Search the `nprobe` lists nearest to `query`,
+    /// returns the smallest `limit` distances in ascending order.
+    pub fn search(
+        &self,
+        distance: Distance,
+        query: &[f32],
+        nprobe: usize,
+        limit: usize,
+    ) -> Result<Vec<f32>> {
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dimension,
+            )));
+        }
+
+        let mut lists = self
+            .centroids
+            .chunks_exact(self.dimension)
+            .map(|c| distance.eval(query, c))
+            .enumerate()
+            .collect::<Vec<_>>();
+        lists.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let mut distances = vec![];
+        for (list, _) in lists.into_iter().take(nprobe.max(1)) {
+            let start = self.offsets[list] as usize * self.dimension;
+            let end = self.offsets[list + 1] as usize * self.dimension;
+            distances.extend(
+                self.vectors[start..end]
+                    .chunks_exact(self.dimension)
+                    .map(|v| distance.eval(query, v))
+                    .filter(|d| !d.is_nan()),
+            );
+        }
+        distances.sort_by(|a, b| a.total_cmp(b));
+        distances.truncate(limit);
+        Ok(distances)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(
+            13 + (self.centroids.len() + self.offsets.len() + self.vectors.len()) * 4,
+        );
+        buf.push(IVF_FORMAT_VERSION);
+        buf.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.num_lists() as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.num_vectors() as u32).to_le_bytes());
+        for v in self.centroids.iter() {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in self.offsets.iter() {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in self.vectors.iter() {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<IvfIndex> {
+        let invalid = || ErrorCode::StorageOther("Invalid vector index data");
+        if bytes.len() < 13 {
+            return Err(invalid());
+        }
+        if bytes[0] != IVF_FORMAT_VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "Unsupported vector index version: {}",
+                bytes[0]
+            )));
+        }
+
+        let read_u32 = |pos: usize| u32::from_le_bytes(bytes[pos..pos + 4].try_into().unwrap());
+        let dimension = read_u32(1) as usize;
+        let num_lists = read_u32(5) as usize;
+        let num_vectors = read_u32(9) as usize;
+
+        let num_centroid_values = num_lists * dimension;
+        let num_vector_values = num_vectors * dimension;
+        let expected = 13 + (num_centroid_values + num_lists + 1 + num_vector_values) * 4;
+        if dimension == 0 || bytes.len() != expected {
+            return Err(invalid());
+        }
+
+        let mut values = bytes[13..]
+            .chunks_exact(4)
+            .map(|b| <[u8; 4]>::try_from(b).unwrap());
+        let centroids = values
+            .by_ref()
+            .take(num_centroid_values)
+            .map(f32::from_le_bytes)
+            .collect::<Vec<_>>();
+        let offsets = values
+            .by_ref()
+            .take(num_lists + 1)
+            .map(u32::from_le_bytes)
+            .collect::<Vec<_>>();
+        let vectors = values.map(f32::from_le_bytes).collect::<Vec<_>>();
+
+        if offsets.windows(2).any(|w| w[0] > w[1]) || offsets[num_lists] as usize != num_vectors {
+            return Err(invalid());
+        }
+
+        Ok(IvfIndex {
+            dimension,
+            centroids,
+            offsets,
+            vectors,
+        })
+    }
+}
+
+fn nearest_centroid(dimension: usize, centroids: &[f32], vector: &[f32]) -> usize {
+    centroids
+        .chunks_exact(dimension)
+        .map(|c| squared_l2(vector, c))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+        .unwrap()
+}
+
+/// Lloyd's k-means over an evenly strided sample of the vectors.
+/// The initial centroids are picked evenly from the sample, so the result is deterministic.
+fn train_centroids(dimension: usize, vectors: &[f32], num_lists: usize) -> Vec<f32> {
+    let num_vectors = vectors.len() / dimension;
+    let num_samples = num_vectors.min(num_lists * TRAINING_SAMPLES_PER_LIST);
+    let sample_step = num_vectors / num_samples;
+    let samples = vectors
+        .chunks_exact(dimension)
+        .step_by(sample_step)
+        .take(num_samples)
+        .collect::<Vec<_>>();
+
+    let init_step = num_samples / num_lists;
+    let mut centroids = samples
+        .iter()
+        .step_by(init_step)
+        .take(num_lists)
+        .flat_map(|v| v.iter().copied())
+        .collect::<Vec<_>>();
+
+    let mut sums = vec![0.0f32; centroids.len()];
+    let mut counts = vec![0usize; num_lists];
+    for _ in 0..KMEANS_ITERATIONS {
+        sums.iter_mut().for_each(|v| *v = 0.0);
+        counts.iter_mut().for_each(|v| *v = 0);
+
+        for sample in samples.iter() {
+            let list = nearest_centroid(dimension, &centroids, sample);
+            counts[list] += 1;
+            let sum = &mut sums[list * dimension..(list + 1) * dimension];
+            for (s, v) in sum.iter_mut().zip(sample.iter()) {
+                *s += v;
+            }
+        }
+
+        let mut changed = false;
+        for (list, count) in counts.iter().enumerate() {
+            // An empty list keeps its centroid.
+            if *count == 0 {
+                continue;
+            }
+            let range = list * dimension..(list + 1) * dimension;
+            for (c, s) in centroids[range.clone()].iter_mut().zip(sums[range].iter()) {
+                let mean = s / *count as f32;
+                changed |= *c != mean;
+                *c = mean;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    centroids
+}
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 mod distance;
+mod ivf;
 
 pub use distance::cosine_distance;
 pub use distance::l2_distance;
+pub use ivf::Distance;
+pub use ivf::IvfIndex;
@@ -0,0 +1,80 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_vector::l2_distance;
+use databend_common_vector::Distance;
+use databend_common_vector::IvfIndex;
+
+#[test]
+fn test_ivf_index() {
+    // 4 well separated clusters of 2-dimension vectors.
+    let mut vectors = vec![];
+    for (x, y) in [(0.0, 0.0), (100.0, 0.0), (0.0, 100.0), (100.0, 100.0)] {
+        for i in 0..25 {
+            vectors.push(x + (i % 5) as f32);
+            vectors.push(y + (i / 5) as f32);
+        }
+    }
+
+    let index = IvfIndex::build(2, &vectors).unwrap();
+    assert_eq!(index.dimension(), 2);
+    assert_eq!(index.num_lists(), 10);
+    assert_eq!(index.num_vectors(), 100);
+
+    // Probing all the lists gives the exact result.
+    let query = [101.5, 2.5];
+    let mut expected = vectors
+        .chunks_exact(2)
+        .map(|v| l2_distance(&query, v).unwrap())
+        .collect::<Vec<_>>();
+    expected.sort_by(|a, b| a.total_cmp(b));
+    let distances = index.search(Distance::L2, &query, 10, 5).unwrap();
+    assert_eq!(distances.len(), 5);
+    for (d, e) in distances.iter().zip(expected.iter()) {
+        approx::assert_relative_eq!(d, e);
+    }
+
+    // Probing fewer lists scans fewer vectors, the distances can only be larger.
+    let distances = index.search(Distance::L2, &query, 1, 5).unwrap();
+    for (d, e) in distances.iter().zip(expected.iter()) {
+        assert!(*d >= *e - f32::EPSILON);
+    }
+
+    let distances = index.search(Distance::Cosine, &[1.0, 1.0], 10, 3).unwrap();
+    assert_eq!(distances.len(), 3);
+    assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+
+    assert!(index.search(Distance::L2, &[1.0, 2.0, 3.0], 1, 1).is_err());
+}
+
+#[test]
+fn test_ivf_index_serialization() {
+    let vectors = (0..300).map(|v| v as f32).collect::<Vec<_>>();
+    let index = IvfIndex::build(3, &vectors).unwrap();
+    let bytes = index.to_bytes();
+    assert_eq!(IvfIndex::from_bytes(&bytes).unwrap(), index);
+    assert!(IvfIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    let empty = IvfIndex::build(3, &[]).unwrap();
+    assert_eq!(empty.num_vectors(), 0);
+    assert_eq!(IvfIndex::from_bytes(&empty.to_bytes()).unwrap(), empty);
+    assert!(
+        empty
+            .search(Distance::L2, &[0.0; 3], 1, 1)
+            .unwrap()
+            .is_empty()
+    );
+
+    assert!(IvfIndex::build(3, &[1.0, 2.0]).is_err());
+}
@@ -16,9 +16,11 @@ use databend_common_meta_kvapi::kvapi;
 
 use crate::background_api_keys::ID_GEN_BACKGROUND_JOB;
 use crate::data_mask_api_keys::ID_GEN_DATA_MASK;
+use crate::row_access_policy_api_keys::ID_GEN_ROW_ACCESS_POLICY;
 use crate::schema_api_keys::ID_GEN_CATALOG;
 use crate::schema_api_keys::ID_GEN_DATABASE;
 use crate::schema_api_keys::ID_GEN_INDEX;
+use crate::schema_api_keys::ID_GEN_PIPE;
 use crate::schema_api_keys::ID_GEN_TABLE;
 use crate::schema_api_keys::ID_GEN_TABLE_LOCK;
 use crate::share_api_keys::ID_GEN_SHARE;
@@ -76,6 +78,12 @@ impl IdGenerator {
         }
     }
 
+    pub fn row_access_policy_id() -> Self {
+        Self {
+            resource: ID_GEN_ROW_ACCESS_POLICY.to_string(),
+        }
+    }
+
     pub fn table_lock_id() -> Self {
         Self {
             resource: ID_GEN_TABLE_LOCK.to_string(),
@@ -94,6 +102,13 @@ impl IdGenerator {
             resource: ID_GEN_CATALOG.to_string(),
         }
     }
+
+    /// Create a key for generating pipe id with kvapi::KVApi
+    pub fn pipe_id() -> Self {
+        Self {
+            resource: ID_GEN_PIPE.to_string(),
+        }
+    }
 }
 
 impl kvapi::Key for IdGenerator {
@@ -185,6 +200,16 @@ mod t {
             assert_eq!(g1, g2);
         }
 
+        // Row access policy id generator
+        {
+            let g1 = IdGenerator::row_access_policy_id();
+            let k = g1.to_string_key();
+            assert_eq!("__fd_id_gen/row_access_policy", k);
+
+            let g2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g1, g2);
+        }
+
         {
             let g1 = IdGenerator::table_lock_id();
             let k = g1.to_string_key();
@@ -194,6 +219,16 @@ mod t {
             assert_eq!(g1, g2);
         }
 
+        // Pipe id generator
+        {
+            let g1 = IdGenerator::pipe_id();
+            let k = g1.to_string_key();
+            assert_eq!("__fd_id_gen/pipe_id", k);
+
+            let g2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g1, g2);
+        }
+
         Ok(())
     }
 
@@ -33,6 +33,8 @@ use databend_common_meta_app::app_error::DropTableWithDropTime;
 use databend_common_meta_app::app_error::DuplicatedUpsertFiles;
 use databend_common_meta_app::app_error::GetIndexWithDropTime;
 use databend_common_meta_app::app_error::IndexAlreadyExists;
+use databend_common_meta_app::app_error::PipeAlreadyExists;
+use databend_common_meta_app::app_error::PipeVersionMismatched;
 use databend_common_meta_app::app_error::ShareHasNoGrantedPrivilege;
 use databend_common_meta_app::app_error::StreamAlreadyExists;
 use databend_common_meta_app::app_error::StreamVersionMismatched;
@@ -48,6 +50,7 @@ use databend_common_meta_app::app_error::UndropTableWithNoDropTime;
 use databend_common_meta_app::app_error::UnknownCatalog;
 use databend_common_meta_app::app_error::UnknownDatabaseId;
 use databend_common_meta_app::app_error::UnknownIndex;
+use databend_common_meta_app::app_error::UnknownPipe;
 use databend_common_meta_app::app_error::UnknownStreamId;
 use databend_common_meta_app::app_error::UnknownTable;
 use databend_common_meta_app::app_error::UnknownTableId;
@@ -57,6 +60,8 @@ use databend_common_meta_app::app_error::WrongShare;
 use databend_common_meta_app::app_error::WrongShareObject;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdList;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdListKey;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
 use databend_common_meta_app::schema::CatalogId;
 use databend_common_meta_app::schema::CatalogIdToName;
 use databend_common_meta_app::schema::CatalogInfo;
@@ -73,6 +78,8 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreatePipeReply;
+use databend_common_meta_app::schema::CreatePipeReq;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReply;
@@ -95,6 +102,8 @@ use databend_common_meta_app::schema::DropDatabaseReply;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReply;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropPipeReply;
+use databend_common_meta_app::schema::DropPipeReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableReply;
 use databend_common_meta_app::schema::DropVirtualColumnReply;
@@ -109,6 +118,9 @@ use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::GetLVTReply;
 use databend_common_meta_app::schema::GetLVTReq;
+use databend_common_meta_app::schema::GetPipeLoadedFileReply;
+use databend_common_meta_app::schema::GetPipeLoadedFileReq;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::GetTableReq;
@@ -126,10 +138,18 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListPipesReq;
 use databend_common_meta_app::schema::ListTableReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
+use databend_common_meta_app::schema::PipeId;
+use databend_common_meta_app::schema::PipeIdToName;
+use databend_common_meta_app::schema::PipeIdent;
+use databend_common_meta_app::schema::PipeInfo;
+use databend_common_meta_app::schema::PipeLoadedFileNameIdent;
+use databend_common_meta_app::schema::PipeMeta;
+use databend_common_meta_app::schema::PipeNameIdent;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
@@ -139,6 +159,9 @@ use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
@@ -158,6 +181,10 @@ use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReply;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdatePipeMetaReply;
+use databend_common_meta_app::schema::UpdatePipeMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -3103,6 +3130,150 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut tb_meta_seqs = Vec::with_capacity(req.update_table_metas.len());
+
+            for req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+                tb_meta_seqs.push(tb_meta_seq);
+
+                // table is not changed
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?));
+
+                if let Some(req) = &req.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            req,
+                            tb_meta_seq,
+                            req.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                for req in &req.update_stream_meta {
+                    let stream_id = TableId {
+                        table_id: req.stream_id,
+                    };
+                    let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
+                        get_pb_value(self, &stream_id).await?;
+
+                    if stream_meta_seq == 0 || stream_meta.is_none() {
+                        return Err(KVAppError::AppError(AppError::UnknownStreamId(
+                            UnknownStreamId::new(req.stream_id, "update_multi_table_meta"),
+                        )));
+                    }
+
+                    if req.seq.match_seq(stream_meta_seq).is_err() {
+                        return Err(KVAppError::AppError(AppError::from(
+                            StreamVersionMismatched::new(
+                                req.stream_id,
+                                req.seq,
+                                stream_meta_seq,
+                                "update_multi_table_meta",
+                            ),
+                        )));
+                    }
+
+                    let mut new_stream_meta = stream_meta.unwrap();
+                    new_stream_meta.options = req.options.clone();
+                    new_stream_meta.updated_on = Utc::now();
+
+                    txn_req
+                        .condition
+                        .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
+                    txn_req
+                        .if_then
+                        .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
+                }
+
+                if let Some(deduplicated_label) = req.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label))
+                }
+            }
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                succ = succ;
+                "update_multi_table_meta"
+            );
+
+            if succ {
+                return Ok(UpdateMultiTableMetaReply {});
+            }
+
+            // The txn failed, find out which table has been changed.
+            for (req, tb_meta_seq) in req.update_table_metas.iter().zip(tb_meta_seqs) {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (curr_seq, _): (_, Option<TableMeta>) = get_pb_value(self, &tbid).await?;
+                if curr_seq != tb_meta_seq {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            curr_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+                let fail_if_duplicated = req
+                    .copied_files
+                    .as_ref()
+                    .map(|v| v.fail_if_duplicated)
+                    .unwrap_or(false);
+                if fail_if_duplicated {
+                    // the table version does match, the txn failed because of the copied files
+                    return Err(KVAppError::AppError(AppError::from(
+                        DuplicatedUpsertFiles::new(req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+            }
+            // continue and try update the "table copied files"
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
@@ -3205,6 +3376,111 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         )))
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+        let req_seq = req.seq;
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            debug!(ident = as_display!(&tbid); "set_table_row_access_policy");
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "set_table_row_access_policy"),
+                )));
+            }
+            if req_seq.match_seq(tb_meta_seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        tb_meta_seq,
+                        "set_table_row_access_policy",
+                    ),
+                )));
+            }
+
+            let table_meta = table_meta.unwrap();
+            let old_policy = table_meta
+                .row_access_policy
+                .as_ref()
+                .map(|p| p.policy.clone());
+
+            let mut new_table_meta = table_meta.clone();
+            new_table_meta.row_access_policy = match &req.action {
+                SetTableRowAccessPolicyAction::Set(policy) => Some(policy.clone()),
+                SetTableRowAccessPolicyAction::Unset(_) => None,
+            };
+
+            let mut txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&tbid, serialize_struct(&new_table_meta)?), // tb_id -> tb_meta
+                ],
+                else_then: vec![],
+            };
+
+            // The table id lists of the policies are kept in sync with the table,
+            // so that dropping a policy can detach it from the tables.
+            if let Some(old_policy) = old_policy {
+                update_row_access_policy_table_id_list(
+                    self,
+                    &mut txn_req.condition,
+                    &mut txn_req.if_then,
+                    req.tenant.clone(),
+                    old_policy,
+                    req.table_id,
+                    false,
+                )
+                .await?;
+            }
+            if let SetTableRowAccessPolicyAction::Set(policy) = &req.action {
+                update_row_access_policy_table_id_list(
+                    self,
+                    &mut txn_req.condition,
+                    &mut txn_req.if_then,
+                    req.tenant.clone(),
+                    policy.policy.clone(),
+                    req.table_id,
+                    true,
+                )
+                .await?;
+            }
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id = as_debug!(&tbid),
+                succ = succ;
+                "set_table_row_access_policy"
+            );
+
+            if succ {
+                return Ok(SetTableRowAccessPolicyReply {
+                    share_table_info: get_share_table_info_map(self, &new_table_meta).await?,
+                });
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("set_table_row_access_policy", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_drop_table_infos(
@@ -3938,6 +4214,351 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         Ok(catalog_infos)
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn create_pipe(&self, req: CreatePipeReq) -> Result<CreatePipeReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+
+        let ctx = &func_name!();
+
+        let mut trials = txn_trials(None, ctx);
+
+        let pipe_id = loop {
+            trials.next().unwrap()?;
+
+            // Get pipe by name to ensure absence
+            let (pipe_id_seq, pipe_id) = get_u64_value(self, name_key).await?;
+            debug!(
+                pipe_id_seq = pipe_id_seq,
+                pipe_id = pipe_id,
+                name_key = as_debug!(name_key);
+                "get_pipe"
+            );
+
+            if pipe_id_seq > 0 {
+                return if req.if_not_exists {
+                    Ok(CreatePipeReply { pipe_id })
+                } else {
+                    Err(KVAppError::AppError(AppError::PipeAlreadyExists(
+                        PipeAlreadyExists::new(
+                            &name_key.pipe_name,
+                            format!("create pipe: tenant: {}", name_key.tenant),
+                        ),
+                    )))
+                };
+            }
+
+            // Create pipe by inserting these record:
+            // (tenant, pipe_name) -> pipe_id
+            // (pipe_id) -> pipe_meta
+            // (pipe_id) -> (tenant, pipe_name)
+            let pipe_id = fetch_id(self, IdGenerator::pipe_id()).await?;
+            let id_key = PipeId { pipe_id };
+            let id_to_name_key = PipeIdToName { pipe_id };
+
+            debug!(pipe_id = pipe_id, name_key = as_debug!(name_key); "new pipe id");
+
+            {
+                let condition = vec![
+                    txn_cond_seq(name_key, Eq, 0),
+                    txn_cond_seq(&id_to_name_key, Eq, 0),
+                ];
+                let if_then = vec![
+                    txn_op_put(name_key, serialize_u64(pipe_id)?), /* (tenant, pipe_name) -> pipe_id */
+                    txn_op_put(&id_key, serialize_struct(&req.meta)?), // (pipe_id) -> pipe_meta
+                    txn_op_put(&id_to_name_key, serialize_struct(name_key)?), /* __fd_pipe_id_to_name/<pipe_id> -> (tenant,pipe_name) */
+                ];
+
+                let txn_req = TxnRequest {
+                    condition,
+                    if_then,
+                    else_then: vec![],
+                };
+
+                let (succ, _) = send_txn(self, txn_req).await?;
+
+                debug!(
+                    name = as_debug!(name_key),
+                    id = as_debug!(&id_key),
+                    succ = succ;
+                    "create_pipe"
+                );
+
+                if succ {
+                    break pipe_id;
+                }
+            }
+        };
+
+        Ok(CreatePipeReply { pipe_id })
+    }
+
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn drop_pipe(&self, req: DropPipeReq) -> Result<DropPipeReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+
+        let ctx = &func_name!();
+
+        let mut trials = txn_trials(None, ctx);
+
+        let pipe_id = loop {
+            trials.next().unwrap()?;
+
+            let res = get_pipe_or_err(self, name_key, format!("drop_pipe: {}", &name_key)).await;
+
+            let (pipe_id_seq, pipe_id, pipe_meta_seq, _) = match res {
+                Ok(x) => x,
+                Err(e) => {
+                    if let KVAppError::AppError(AppError::UnknownPipe(_)) = e {
+                        if req.if_exists {
+                            return Ok(DropPipeReply {});
+                        }
+                    }
+
+                    return Err(e);
+                }
+            };
+
+            // Delete pipe by deleting these record:
+            // (tenant, pipe_name) -> pipe_id
+            // (pipe_id) -> pipe_meta
+            // (pipe_id) -> (tenant, pipe_name)
+            let id_key = PipeId { pipe_id };
+            let id_to_name_key = PipeIdToName { pipe_id };
+
+            debug!(
+                pipe_id = pipe_id,
+                name_key = as_debug!(&name_key);
+                "pipe keys to delete"
+            );
+
+            {
+                let condition = vec![
+                    txn_cond_seq(name_key, Eq, pipe_id_seq),
+                    txn_cond_seq(&id_key, Eq, pipe_meta_seq),
+                ];
+                let if_then = vec![
+                    txn_op_del(name_key),        // (tenant, pipe_name) -> pipe_id
+                    txn_op_del(&id_key),         // (pipe_id) -> pipe_meta
+                    txn_op_del(&id_to_name_key), /* __fd_pipe_id_to_name/<pipe_id> -> (tenant,pipe_name) */
+                ];
+
+                let txn_req = TxnRequest {
+                    condition,
+                    if_then,
+                    else_then: vec![],
+                };
+
+                let (succ, _) = send_txn(self, txn_req).await?;
+
+                debug!(
+                    name = as_debug!(&name_key),
+                    id = as_debug!(&id_key),
+                    succ = succ;
+                    "drop_pipe"
+                );
+
+                if succ {
+                    break pipe_id;
+                }
+            }
+        };
+
+        // The pipe is gone, no one can load files through it anymore.
+        // Clean up the loaded file records in batches, they are not needed by a new pipe with the same name.
+        remove_pipe_loaded_files(self, pipe_id).await?;
+
+        Ok(DropPipeReply {})
+    }
+
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn get_pipe(&self, req: GetPipeReq) -> Result<Arc<PipeInfo>, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+
+        let (_, pipe_id, pipe_meta_seq, pipe_meta) =
+            get_pipe_or_err(self, name_key, "get_pipe").await?;
+
+        let pipe = PipeInfo {
+            ident: PipeIdent::new(pipe_id, pipe_meta_seq),
+            name_ident: name_key.clone(),
+            meta: pipe_meta,
+        };
+
+        Ok(Arc::new(pipe))
+    }
+
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_pipe_meta(
+        &self,
+        req: UpdatePipeMetaReq,
+    ) -> Result<UpdatePipeMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let id_key = PipeId {
+            pipe_id: req.pipe_id,
+        };
+
+        let ctx = &func_name!();
+
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (pipe_meta_seq, pipe_meta): (_, Option<PipeMeta>) =
+                get_pb_value(self, &id_key).await?;
+
+            if pipe_meta_seq == 0 || pipe_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownPipe(
+                    UnknownPipe::new(&req.pipe_name, "update_pipe_meta"),
+                )));
+            }
+
+            if req.seq.match_seq(pipe_meta_seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    PipeVersionMismatched::new(
+                        req.pipe_id,
+                        req.seq,
+                        pipe_meta_seq,
+                        "update_pipe_meta",
+                    ),
+                )));
+            }
+
+            let condition = vec![txn_cond_seq(&id_key, Eq, pipe_meta_seq)];
+            let mut if_then = vec![txn_op_put(&id_key, serialize_struct(&req.new_meta)?)];
+
+            for (file, file_info) in req.loaded_files.iter() {
+                let key = PipeLoadedFileNameIdent {
+                    pipe_id: req.pipe_id,
+                    file: file.clone(),
+                };
+                if_then.push(txn_op_put(&key, serialize_struct(file_info)?));
+            }
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id = as_debug!(&id_key),
+                succ = succ;
+                "update_pipe_meta"
+            );
+
+            if succ {
+                return Ok(UpdatePipeMetaReply {});
+            }
+        }
+    }
+
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn list_pipes(&self, req: ListPipesReq) -> Result<Vec<Arc<PipeInfo>>, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let name_key = PipeNameIdent {
+            tenant: req.tenant,
+            // Using a empty pipe to to list all
+            pipe_name: "".to_string(),
+        };
+
+        // Pairs of pipe-name and pipe_id with seq
+        let (tenant_pipe_names, pipe_ids) = list_u64_value(self, &name_key).await?;
+
+        // Keys for fetching serialized PipeMeta from kvapi::KVApi
+        let mut kv_keys = Vec::with_capacity(pipe_ids.len());
+
+        for pipe_id in pipe_ids.iter() {
+            let k = PipeId { pipe_id: *pipe_id }.to_string_key();
+            kv_keys.push(k);
+        }
+
+        // Batch get all pipe-metas.
+        // - A pipe-meta may be already deleted. It is Ok. Just ignore it.
+
+        let seq_metas = self.mget_kv(&kv_keys).await?;
+        let mut pipe_infos = Vec::with_capacity(kv_keys.len());
+
+        for (i, seq_meta_opt) in seq_metas.iter().enumerate() {
+            if let Some(seq_meta) = seq_meta_opt {
+                let pipe_meta: PipeMeta = deserialize_struct(&seq_meta.data)?;
+
+                let pipe_info = PipeInfo {
+                    ident: PipeIdent::new(pipe_ids[i], seq_meta.seq),
+                    name_ident: PipeNameIdent {
+                        tenant: name_key.tenant.clone(),
+                        pipe_name: tenant_pipe_names[i].pipe_name.clone(),
+                    },
+                    meta: pipe_meta,
+                };
+                pipe_infos.push(Arc::new(pipe_info));
+            } else {
+                debug!(
+                    k = as_display!(&kv_keys[i]);
+                    "pipe_meta not found, maybe just deleted after listing names and before listing meta"
+                );
+            }
+        }
+
+        Ok(pipe_infos)
+    }
+
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn get_pipe_loaded_file_info(
+        &self,
+        req: GetPipeLoadedFileReq,
+    ) -> Result<GetPipeLoadedFileReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let pipe_id = req.pipe_id;
+
+        let mut file_infos = BTreeMap::new();
+
+        let mut keys = Vec::with_capacity(req.files.len());
+
+        for file in req.files.iter() {
+            let ident = PipeLoadedFileNameIdent {
+                pipe_id,
+                file: file.clone(),
+            };
+            keys.push(ident.to_string_key());
+        }
+
+        let mut file_names = req.files.into_iter();
+
+        for c in keys.chunks(DEFAULT_MGET_SIZE) {
+            let seq_infos: Vec<(u64, Option<TableCopiedFileInfo>)> =
+                mget_pb_values(self, c).await?;
+
+            for (_seq, file_info) in seq_infos {
+                let f_name = file_names.next().unwrap();
+
+                if let Some(f_info) = file_info {
+                    file_infos.insert(f_name, f_info);
+                }
+            }
+        }
+
+        Ok(GetPipeLoadedFileReply {
+            file_info: file_infos,
+        })
+    }
+
     #[minitrace::trace]
     async fn set_table_lvt(&self, req: SetLVTReq) -> Result<SetLVTReply, KVAppError> {
         debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
@@ -4885,6 +5506,78 @@ pub fn catalog_has_to_exist(
     }
 }
 
+/// Returns (pipe_id_seq, pipe_id, pipe_meta_seq, pipe_meta)
+pub(crate) async fn get_pipe_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &PipeNameIdent,
+    msg: impl Display,
+) -> Result<(u64, u64, u64, PipeMeta), KVAppError> {
+    let (pipe_id_seq, pipe_id) = get_u64_value(kv_api, name_key).await?;
+    pipe_has_to_exist(pipe_id_seq, name_key, &msg)?;
+
+    let id_key = PipeId { pipe_id };
+
+    let (pipe_meta_seq, pipe_meta) = get_pb_value(kv_api, &id_key).await?;
+    pipe_has_to_exist(pipe_meta_seq, name_key, msg)?;
+
+    Ok((
+        pipe_id_seq,
+        pipe_id,
+        pipe_meta_seq,
+        // Safe unwrap(): pipe_meta_seq > 0 implies pipe_meta is not None.
+        pipe_meta.unwrap(),
+    ))
+}
+
+/// Return OK if a pipe_id or pipe_meta exists by checking the seq.
+///
+/// Otherwise returns UnknownPipe error
+fn pipe_has_to_exist(
+    seq: u64,
+    pipe_name_ident: &PipeNameIdent,
+    msg: impl Display,
+) -> Result<(), KVAppError> {
+    if seq == 0 {
+        debug!(seq = seq, pipe_name_ident = as_debug!(pipe_name_ident); "pipe does not exist");
+
+        Err(KVAppError::AppError(AppError::UnknownPipe(
+            UnknownPipe::new(
+                &pipe_name_ident.pipe_name,
+                format!("{}: {}", msg, pipe_name_ident),
+            ),
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Remove the loaded file records of a dropped pipe.
+///
+/// The records are deleted chunk by chunk, each chunk in its own transaction.
+async fn remove_pipe_loaded_files(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    pipe_id: u64,
+) -> Result<(), KVAppError> {
+    let loaded_file_ident = PipeLoadedFileNameIdent {
+        pipe_id,
+        file: "".to_string(),
+    };
+
+    let loaded_files = list_keys(kv_api, &loaded_file_ident).await?;
+
+    for chunk in loaded_files.chunks(DEFAULT_MGET_SIZE) {
+        let txn_req = TxnRequest {
+            condition: vec![],
+            if_then: chunk.iter().map(|k| txn_op_del(k)).collect(),
+            else_then: vec![],
+        };
+
+        send_txn(kv_api, txn_req).await?;
+    }
+
+    Ok(())
+}
+
 async fn update_mask_policy_table_id_list(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     condition: &mut Vec<TxnCondition>,
@@ -4962,6 +5655,33 @@ async fn update_mask_policy(
     Ok(())
 }
 
+async fn update_row_access_policy_table_id_list(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+    tenant: String,
+    name: String,
+    table_id: u64,
+    add: bool,
+) -> Result<(), KVAppError> {
+    let id_list_key = RowAccessPolicyTableIdListKey { tenant, name };
+
+    let (id_list_seq, id_list_opt): (_, Option<RowAccessPolicyTableIdList>) =
+        get_pb_value(kv_api, &id_list_key).await?;
+    if let Some(mut id_list) = id_list_opt {
+        if add {
+            id_list.id_list.insert(table_id);
+        } else {
+            id_list.id_list.remove(&table_id);
+        }
+
+        condition.push(txn_cond_seq(&id_list_key, Eq, id_list_seq));
+        if_then.push(txn_op_put(&id_list_key, serialize_struct(&id_list)?));
+    }
+
+    Ok(())
+}
+
 /// Return OK if a table lock exists by checking the seq.
 ///
 /// Otherwise returns TableLockExpired error
@@ -20,3 +20,5 @@ pub(crate) const ID_GEN_TABLE_LOCK: &str = "table_lock_id";
 pub(crate) const ID_GEN_INDEX: &str = "index_id";
 
 pub(crate) const ID_GEN_CATALOG: &str = "catalog_id";
+
+pub(crate) const ID_GEN_PIPE: &str = "pipe_id";
@@ -32,6 +32,11 @@ use databend_common_meta_app::data_mask::DatamaskNameIdent;
 use databend_common_meta_app::data_mask::DropDatamaskReq;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdList;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdListKey;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
 use databend_common_meta_app::schema::CatalogMeta;
 use databend_common_meta_app::schema::CatalogNameIdent;
 use databend_common_meta_app::schema::CatalogOption;
@@ -41,6 +46,7 @@ use databend_common_meta_app::schema::CreateDatabaseReply;
 use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreatePipeReq;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReq;
 use databend_common_meta_app::schema::DBIdTableName;
@@ -56,6 +62,7 @@ use databend_common_meta_app::schema::DeleteLockRevReq;
 use databend_common_meta_app::schema::DropCatalogReq;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropPipeReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropVirtualColumnReq;
 use databend_common_meta_app::schema::DroppedId;
@@ -64,6 +71,8 @@ use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::GetCatalogReq;
 use databend_common_meta_app::schema::GetDatabaseReq;
 use databend_common_meta_app::schema::GetLVTReq;
+use databend_common_meta_app::schema::GetPipeLoadedFileReq;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::GetTableReq;
 use databend_common_meta_app::schema::IcebergCatalogOption;
@@ -78,14 +87,19 @@ use databend_common_meta_app::schema::ListDroppedTableReq;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
+use databend_common_meta_app::schema::ListPipesReq;
 use databend_common_meta_app::schema::ListTableReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockKey;
+use databend_common_meta_app::schema::PipeMeta;
+use databend_common_meta_app::schema::PipeNameIdent;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
@@ -97,10 +111,13 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdatePipeMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -130,6 +147,7 @@ use crate::kv_app_error::KVAppError;
 use crate::serialize_struct;
 use crate::testing::get_kv_data;
 use crate::DatamaskApi;
+use crate::RowAccessPolicyApi;
 use crate::SchemaApi;
 use crate::ShareApi;
 use crate::DEFAULT_MGET_SIZE;
@@ -261,7 +279,11 @@ impl SchemaApiTestSuite {
     pub async fn test_single_node<B, MT>(b: B) -> anyhow::Result<()>
     where
         B: kvapi::ApiBuilder<MT>,
-        MT: ShareApi + kvapi::AsKVApi<Error = MetaError> + SchemaApi + DatamaskApi,
+        MT: ShareApi
+            + kvapi::AsKVApi<Error = MetaError>
+            + SchemaApi
+            + DatamaskApi
+            + RowAccessPolicyApi,
     {
         let suite = SchemaApiTestSuite {};
 
@@ -293,7 +315,11 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_update_multi_meta(&b.build().await).await?;
         suite.table_update_mask_policy(&b.build().await).await?;
+        suite
+            .table_update_row_access_policy(&b.build().await)
+            .await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_many(&b.build().await).await?;
@@ -330,6 +356,7 @@ impl SchemaApiTestSuite {
             .virtual_column_create_list_drop(&b.build().await)
             .await?;
         suite.catalog_create_get_list_drop(&b.build().await).await?;
+        suite.pipe_create_get_update_drop(&b.build().await).await?;
         suite.table_least_visible_time(&b.build().await).await?;
         suite
             .drop_table_without_tableid_to_name(&b.build().await)
@@ -1413,6 +1440,120 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn pipe_create_get_update_drop<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let pipe_name = "pipe1";
+        let name_ident = PipeNameIdent::new(tenant, pipe_name);
+
+        info!("--- create pipe1");
+        let req = CreatePipeReq {
+            if_not_exists: false,
+            name_ident: name_ident.clone(),
+            meta: PipeMeta {
+                copy_stmt: "COPY INTO t1 FROM @s1".to_string(),
+                ..Default::default()
+            },
+        };
+
+        let res = mt.create_pipe(req.clone()).await?;
+        info!("create pipe res: {:?}", res);
+
+        info!("--- create pipe1 again");
+        let err = mt.create_pipe(req).await.unwrap_err();
+        assert_eq!(
+            ErrorCode::PipeAlreadyExists("").code(),
+            ErrorCode::from(err).code()
+        );
+
+        let got = mt.get_pipe(GetPipeReq::new(tenant, pipe_name)).await?;
+        assert_eq!(got.ident.pipe_id, res.pipe_id);
+        assert_eq!(got.pipe_name(), "pipe1");
+        assert_eq!(got.meta.copy_stmt, "COPY INTO t1 FROM @s1");
+
+        info!("--- update pipe1 with loaded files");
+        {
+            let mut new_meta = got.meta.clone();
+            new_meta.execution_paused = true;
+
+            let mut loaded_files = BTreeMap::new();
+            loaded_files.insert("a.csv".to_string(), TableCopiedFileInfo {
+                etag: Some("tag".to_string()),
+                content_length: 1,
+                last_modified: None,
+            });
+
+            mt.update_pipe_meta(UpdatePipeMetaReq {
+                pipe_id: res.pipe_id,
+                pipe_name: pipe_name.to_string(),
+                seq: MatchSeq::Exact(got.ident.seq),
+                new_meta: new_meta.clone(),
+                loaded_files,
+            })
+            .await?;
+
+            let updated = mt.get_pipe(GetPipeReq::new(tenant, pipe_name)).await?;
+            assert!(updated.meta.execution_paused);
+            assert!(updated.ident.seq > got.ident.seq);
+
+            info!("--- update pipe1 with a stale seq");
+            let err = mt
+                .update_pipe_meta(UpdatePipeMetaReq {
+                    pipe_id: res.pipe_id,
+                    pipe_name: pipe_name.to_string(),
+                    seq: MatchSeq::Exact(got.ident.seq),
+                    new_meta,
+                    loaded_files: BTreeMap::new(),
+                })
+                .await
+                .unwrap_err();
+            assert_eq!(
+                ErrorCode::PipeVersionMismatched("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let got = mt
+                .get_pipe_loaded_file_info(GetPipeLoadedFileReq {
+                    pipe_id: res.pipe_id,
+                    files: vec!["a.csv".to_string(), "b.csv".to_string()],
+                })
+                .await?;
+            assert_eq!(got.file_info.len(), 1);
+            assert!(got.file_info.contains_key("a.csv"));
+        }
+
+        let got = mt.list_pipes(ListPipesReq::new(tenant)).await?;
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].pipe_name(), "pipe1");
+
+        info!("--- drop pipe1");
+        mt.drop_pipe(DropPipeReq {
+            if_exists: false,
+            name_ident: name_ident.clone(),
+        })
+        .await?;
+
+        let got = mt.list_pipes(ListPipesReq::new(tenant)).await?;
+        assert_eq!(got.len(), 0);
+
+        let got = mt
+            .get_pipe_loaded_file_info(GetPipeLoadedFileReq {
+                pipe_id: res.pipe_id,
+                files: vec!["a.csv".to_string()],
+            })
+            .await?;
+        assert!(got.file_info.is_empty());
+
+        info!("--- drop pipe1 if exists");
+        mt.drop_pipe(DropPipeReq {
+            if_exists: true,
+            name_ident,
+        })
+        .await?;
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn drop_table_without_tableid_to_name<
         MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>,
@@ -2531,6 +2672,114 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]))
+        };
+
+        let table_meta = |created_on| TableMeta {
+            schema: schema(),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on,
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(Utc::now()),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, data_bytes: u64, seq: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            }
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let mut update_table_metas = vec![];
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                update_table_metas.push(update_req(&table, 1, table.ident.seq));
+            }
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: update_table_metas.clone(),
+            })
+            .await?;
+
+            for (tbl_name, req) in tbl_names.iter().zip(update_table_metas) {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                assert_eq!(table.meta, req.new_table_meta);
+            }
+        }
+
+        info!("--- update multi table meta, version mismatch of one table updates nothing");
+        {
+            let tb1 = mt.get_table((tenant, db_name, tbl_names[0]).into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, tbl_names[1]).into()).await?;
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(&tb1, 2, tb1.ident.seq),
+                        update_req(&tb2, 2, tb2.ident.seq + 1),
+                    ],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            let got = mt.get_table((tenant, db_name, tbl_names[0]).into()).await?;
+            assert_eq!(got.ident.seq, tb1.ident.seq);
+            assert_eq!(got.meta.statistics.data_bytes, 1);
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_mask_policy<
         MT: SchemaApi + DatamaskApi + kvapi::AsKVApi<Error = MetaError>,
@@ -2859,6 +3108,173 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_row_access_policy<
+        MT: SchemaApi + RowAccessPolicyApi + kvapi::AsKVApi<Error = MetaError>,
+    >(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "tb1";
+        let policy_name_1 = "policy1";
+        let policy_name_2 = "policy2";
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]))
+        };
+
+        let created_on = Utc::now();
+        let id_list_key = |name: &str| RowAccessPolicyTableIdListKey {
+            tenant: tenant.to_string(),
+            name: name.to_string(),
+        };
+
+        info!("--- prepare db and table");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent::new(tenant, db_name, tbl_name),
+                table_meta: TableMeta {
+                    schema: schema(),
+                    engine: "JSON".to_string(),
+                    created_on,
+                    ..TableMeta::default()
+                },
+            };
+            mt.create_table(req).await?;
+        }
+
+        info!("--- create row access policies");
+        for name in [policy_name_1, policy_name_2] {
+            let req = CreateRowAccessPolicyReq {
+                if_not_exists: false,
+                name: RowAccessPolicyNameIdent {
+                    tenant: tenant.to_string(),
+                    name: name.to_string(),
+                },
+                args: vec![("n".to_string(), "UInt64".to_string())],
+                body: "n > 1".to_string(),
+                comment: None,
+                create_on: created_on,
+            };
+            mt.create_row_access_policy(req).await?;
+        }
+
+        let policy = |name: &str| TableRowAccessPolicy {
+            policy: name.to_string(),
+            columns: vec!["number".to_string()],
+        };
+
+        info!("--- set policy1 and check");
+        let table_id = {
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.to_string(),
+                table_id: res.ident.table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Set(policy(policy_name_1)),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(res.meta.row_access_policy, Some(policy(policy_name_1)));
+
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_key(policy_name_1)).await?;
+            assert_eq!(id_list.id_list, BTreeSet::from([res.ident.table_id]));
+            res.ident.table_id
+        };
+
+        info!("--- replace policy1 with policy2 and check");
+        {
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.to_string(),
+                table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Set(policy(policy_name_2)),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(res.meta.row_access_policy, Some(policy(policy_name_2)));
+
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_key(policy_name_1)).await?;
+            assert!(id_list.id_list.is_empty());
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_key(policy_name_2)).await?;
+            assert_eq!(id_list.id_list, BTreeSet::from([table_id]));
+        }
+
+        info!("--- drop policy2 detaches it from the table");
+        {
+            let req = DropRowAccessPolicyReq {
+                if_exists: false,
+                name: RowAccessPolicyNameIdent {
+                    tenant: tenant.to_string(),
+                    name: policy_name_2.to_string(),
+                },
+            };
+            mt.drop_row_access_policy(req).await?;
+
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(res.meta.row_access_policy, None);
+
+            let id_list: Result<RowAccessPolicyTableIdList, KVAppError> =
+                get_kv_data(mt.as_kv_api(), &id_list_key(policy_name_2)).await;
+            assert!(id_list.is_err());
+        }
+
+        info!("--- unset policy and check");
+        {
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.to_string(),
+                table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Set(policy(policy_name_1)),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.to_string(),
+                table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Unset(policy_name_1.to_string()),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(res.meta.row_access_policy, None);
+
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_key(policy_name_1)).await?;
+            assert!(id_list.id_list.is_empty());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
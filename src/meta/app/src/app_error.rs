@@ -74,6 +74,22 @@ impl DatamaskAlreadyExists {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("RowAccessPolicyAlreadyExists: `{name}` while `{context}`")]
+pub struct RowAccessPolicyAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl RowAccessPolicyAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("BackgroundJobAlreadyExists: `{name}` while `{context}`")]
 pub struct BackgroundJobAlreadyExists {
@@ -384,6 +400,22 @@ impl UnknownDatamask {
     }
 }
 
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("UnknownRowAccessPolicy: `{name}` while `{context}`")]
+pub struct UnknownRowAccessPolicy {
+    name: String,
+    context: String,
+}
+
+impl UnknownRowAccessPolicy {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownBackgroundJob: `{name}` while `{context}`")]
 pub struct UnknownBackgroundJob {
@@ -839,6 +871,58 @@ impl GetIndexWithDropTime {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("PipeAlreadyExists: `{pipe_name}` while `{context}`")]
+pub struct PipeAlreadyExists {
+    pipe_name: String,
+    context: String,
+}
+
+impl PipeAlreadyExists {
+    pub fn new(pipe_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            pipe_name: pipe_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UnknownPipe: `{pipe_name}` while `{context}`")]
+pub struct UnknownPipe {
+    pipe_name: String,
+    context: String,
+}
+
+impl UnknownPipe {
+    pub fn new(pipe_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            pipe_name: pipe_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("PipeVersionMismatched: {pipe_id} expect `{expect}` but `{curr}`  while `{context}`")]
+pub struct PipeVersionMismatched {
+    pipe_id: u64,
+    expect: MatchSeq,
+    curr: u64,
+    context: String,
+}
+
+impl PipeVersionMismatched {
+    pub fn new(pipe_id: u64, expect: MatchSeq, curr: u64, context: impl Into<String>) -> Self {
+        Self {
+            pipe_id,
+            expect,
+            curr,
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("VirtualColumnAlreadyExists: `{table_id}` while `{context}`")]
 pub struct VirtualColumnAlreadyExists {
@@ -1000,12 +1084,27 @@ pub enum AppError {
     #[error(transparent)]
     GetIndexWithDropTIme(#[from] GetIndexWithDropTime),
 
+    #[error(transparent)]
+    PipeAlreadyExists(#[from] PipeAlreadyExists),
+
+    #[error(transparent)]
+    UnknownPipe(#[from] UnknownPipe),
+
+    #[error(transparent)]
+    PipeVersionMismatched(#[from] PipeVersionMismatched),
+
     #[error(transparent)]
     DatamaskAlreadyExists(#[from] DatamaskAlreadyExists),
 
     #[error(transparent)]
     UnknownDatamask(#[from] UnknownDatamask),
 
+    #[error(transparent)]
+    RowAccessPolicyAlreadyExists(#[from] RowAccessPolicyAlreadyExists),
+
+    #[error(transparent)]
+    UnknownRowAccessPolicy(#[from] UnknownRowAccessPolicy),
+
     #[error(transparent)]
     BackgroundJobAlreadyExists(#[from] BackgroundJobAlreadyExists),
 
@@ -1313,6 +1412,20 @@ impl AppErrorMessage for GetIndexWithDropTime {
     }
 }
 
+impl AppErrorMessage for PipeAlreadyExists {
+    fn message(&self) -> String {
+        format!("Pipe '{}' already exists", self.pipe_name)
+    }
+}
+
+impl AppErrorMessage for UnknownPipe {
+    fn message(&self) -> String {
+        format!("Unknown pipe '{}'", self.pipe_name)
+    }
+}
+
+impl AppErrorMessage for PipeVersionMismatched {}
+
 impl AppErrorMessage for DatamaskAlreadyExists {
     fn message(&self) -> String {
         format!("Datamask '{}' already exists", self.name)
@@ -1325,6 +1438,18 @@ impl AppErrorMessage for UnknownDatamask {
     }
 }
 
+impl AppErrorMessage for RowAccessPolicyAlreadyExists {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownRowAccessPolicy {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' does not exist", self.name)
+    }
+}
+
 impl AppErrorMessage for UnmatchColumnDataType {
     fn message(&self) -> String {
         format!(
@@ -1435,8 +1560,17 @@ impl From<AppError> for ErrorCode {
             AppError::UnknownIndex(err) => ErrorCode::UnknownIndex(err.message()),
             AppError::DropIndexWithDropTime(err) => ErrorCode::DropIndexWithDropTime(err.message()),
             AppError::GetIndexWithDropTIme(err) => ErrorCode::GetIndexWithDropTime(err.message()),
+            AppError::PipeAlreadyExists(err) => ErrorCode::PipeAlreadyExists(err.message()),
+            AppError::UnknownPipe(err) => ErrorCode::UnknownPipe(err.message()),
+            AppError::PipeVersionMismatched(err) => ErrorCode::PipeVersionMismatched(err.message()),
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDatamask(err) => ErrorCode::UnknownDatamask(err.message()),
+            AppError::RowAccessPolicyAlreadyExists(err) => {
+                ErrorCode::RowAccessPolicyAlreadyExists(err.message())
+            }
+            AppError::UnknownRowAccessPolicy(err) => {
+                ErrorCode::UnknownRowAccessPolicy(err.message())
+            }
 
             AppError::BackgroundJobAlreadyExists(err) => {
                 ErrorCode::BackgroundJobAlreadyExists(err.message())
@@ -110,6 +110,8 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -121,6 +123,8 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
         }
     }
 
@@ -138,6 +142,10 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -153,6 +161,8 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Avro(_) => StageFileCompression::None,
+            FileFormatParams::Orc(_) => StageFileCompression::None,
         }
     }
 
@@ -184,6 +194,8 @@ impl FileFormatParams {
                 )?)
             }
             StageFileFormatType::Parquet => FileFormatParams::Parquet(ParquetFileFormatParams {}),
+            StageFileFormatType::Avro => FileFormatParams::Avro(AvroFileFormatParams {}),
+            StageFileFormatType::Orc => FileFormatParams::Orc(OrcFileFormatParams {}),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -510,6 +522,14 @@ impl NdJsonFileFormatParams {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {}
 
+/// Avro files carry their own schema and block codec, so there is nothing to configure.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {}
+
+/// ORC files carry their own schema and stripe compression, so there is nothing to configure.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -555,6 +575,12 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(_) => {
                 write!(f, "TYPE = PARQUET")
             }
+            FileFormatParams::Avro(_) => {
+                write!(f, "TYPE = AVRO")
+            }
+            FileFormatParams::Orc(_) => {
+                write!(f, "TYPE = ORC")
+            }
         }
     }
 }
@@ -21,6 +21,7 @@ mod ownership_info;
 mod password_policy;
 mod principal_identity;
 mod role_info;
+mod task;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -31,6 +32,7 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod workload_group;
 
 pub use connection::*;
 pub use file_format::*;
@@ -40,6 +42,12 @@ pub use password_policy::PasswordPolicy;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use task::task_dag_root;
+pub use task::Task;
+pub use task::TaskRun;
+pub use task::TaskRunState;
+pub use task::TaskSchedule;
+pub use task::TaskStatus;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
@@ -62,3 +70,4 @@ pub use user_quota::UserQuota;
 pub use user_setting::UserSetting;
 pub use user_setting::UserSettingValue;
 pub use user_stage::*;
+pub use workload_group::WorkloadGroup;
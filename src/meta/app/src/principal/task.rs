@@ -0,0 +1,241 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use cron::Schedule;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TaskSchedule {
+    IntervalMinutes(u64),
+    /// A cron expression and the optional time zone it is evaluated in.
+    Cron(String, Option<String>),
+}
+
+impl Default for TaskSchedule {
+    fn default() -> Self {
+        TaskSchedule::IntervalMinutes(1)
+    }
+}
+
+impl TaskSchedule {
+    /// Returns the first time the task is due strictly after `after`.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        match self {
+            TaskSchedule::IntervalMinutes(minutes) => {
+                Ok(after + chrono::Duration::minutes(*minutes as i64))
+            }
+            TaskSchedule::Cron(expr, time_zone) => {
+                let schedule = Schedule::from_str(expr)
+                    .map_err(|e| format!("invalid cron expression {}: {}", expr, e))?;
+                let next = match time_zone.as_deref().filter(|tz| !tz.is_empty()) {
+                    Some(tz) => {
+                        let tz = chrono_tz::Tz::from_str(tz)
+                            .map_err(|e| format!("invalid time zone {}: {}", tz, e))?;
+                        schedule
+                            .after(&after.with_timezone(&tz))
+                            .next()
+                            .map(|t| t.with_timezone(&Utc))
+                    }
+                    None => schedule.after(&after).next(),
+                };
+                next.ok_or_else(|| format!("cron expression {} has no upcoming time", expr))
+            }
+        }
+    }
+}
+
+impl Display for TaskSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskSchedule::IntervalMinutes(minutes) => write!(f, "INTERVAL {} MINUTE", minutes),
+            TaskSchedule::Cron(expr, Some(tz)) if !tz.is_empty() => {
+                write!(f, "CRON {} TIMEZONE {}", expr, tz)
+            }
+            TaskSchedule::Cron(expr, _) => write!(f, "CRON {}", expr),
+        }
+    }
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TaskStatus {
+    #[default]
+    Suspended = 0,
+    Started = 1,
+}
+
+impl Display for TaskStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A task stored in the meta service, scheduled by the query nodes themselves
+/// when cloud control is not configured.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Task {
+    pub task_id: u64,
+    pub task_name: String,
+    pub query_text: String,
+    pub comment: String,
+    /// The role the task runs as.
+    pub owner: String,
+    /// Only the root of a task DAG is scheduled, the other tasks run after their predecessors.
+    pub schedule: Option<TaskSchedule>,
+    pub warehouse: Option<String>,
+    pub status: TaskStatus,
+    pub suspend_task_after_num_failures: Option<u64>,
+    /// The number of runs failed in a row since the last succeeded one.
+    pub consecutive_failures: u64,
+    pub next_scheduled_at: Option<DateTime<Utc>>,
+    pub last_suspended_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// The names of the tasks this task runs after.
+    pub after: Vec<String>,
+    /// A boolean SQL expression, the run is skipped if it is not true.
+    pub when_condition: Option<String>,
+}
+
+impl Task {
+    /// Returns true if the task should be suspended after `failures` runs failed in a row.
+    pub fn reach_failure_limit(&self, failures: u64) -> bool {
+        match self.suspend_task_after_num_failures {
+            Some(limit) => limit > 0 && failures >= limit,
+            None => false,
+        }
+    }
+}
+
+/// Returns the root of the task DAG `task_name` belongs to, by following the predecessors.
+///
+/// Fails if a predecessor does not exist, the predecessors form a cycle, or they lead to
+/// more than one root.
+pub fn task_dag_root<'a>(
+    tasks: &'a BTreeMap<String, Task>,
+    task_name: &'a str,
+) -> Result<String, String> {
+    let mut roots = BTreeSet::new();
+    visit_predecessors(
+        tasks,
+        task_name,
+        &mut vec![],
+        &mut HashSet::new(),
+        &mut roots,
+    )?;
+    match roots.len() {
+        1 => Ok(roots.into_iter().next().unwrap().to_string()),
+        _ => Err(format!(
+            "task {} has more than one root task: {}",
+            task_name,
+            roots.into_iter().collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+fn visit_predecessors<'a>(
+    tasks: &'a BTreeMap<String, Task>,
+    task_name: &'a str,
+    path: &mut Vec<&'a str>,
+    visited: &mut HashSet<&'a str>,
+    roots: &mut BTreeSet<&'a str>,
+) -> Result<(), String> {
+    if let Some(pos) = path.iter().position(|name| *name == task_name) {
+        let mut cycle = path[pos..].to_vec();
+        cycle.push(task_name);
+        return Err(format!("task dependency cycle: {}", cycle.join(" -> ")));
+    }
+    if !visited.insert(task_name) {
+        return Ok(());
+    }
+    let task = tasks
+        .get(task_name)
+        .ok_or_else(|| format!("predecessor task {} does not exist", task_name))?;
+    if task.after.is_empty() {
+        roots.insert(task_name);
+        return Ok(());
+    }
+    path.push(task_name);
+    for predecessor in &task.after {
+        visit_predecessors(tasks, predecessor, path, visited, roots)?;
+    }
+    path.pop();
+    Ok(())
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TaskRunState {
+    #[default]
+    Scheduled = 0,
+    Executing = 1,
+    Succeeded = 2,
+    Failed = 3,
+    Cancelled = 4,
+}
+
+impl Display for TaskRunState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TaskRunState::Scheduled => "SCHEDULED",
+            TaskRunState::Executing => "EXECUTING",
+            TaskRunState::Succeeded => "SUCCEEDED",
+            TaskRunState::Failed => "FAILED",
+            TaskRunState::Cancelled => "CANCELLED",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// One run of a task, as shown in `system.task_history`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TaskRun {
+    /// The task definition at the time it ran.
+    pub task: Task,
+    pub run_id: u64,
+    pub query_id: String,
+    pub attempt_number: u64,
+    pub state: TaskRunState,
+    pub error_code: i64,
+    pub error_message: Option<String>,
+    pub scheduled_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A workload group limits the queries of its users and roles on each query node.
+///
+/// A limit of 0 means unlimited.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// The max number of queries running at the same time.
+    pub max_concurrency: u64,
+    /// The max memory in bytes used by the running queries, a query fails if its
+    /// allocation exceeds it, and no more query is started while it is exceeded.
+    pub max_memory_usage: u64,
+    /// The max seconds a query waits in the queue.
+    pub queue_timeout: u64,
+    pub users: Vec<String>,
+    pub roles: Vec<String>,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl WorkloadGroup {
+    /// Returns true if the queries of the user or the role are limited by this group.
+    pub fn contains(&self, user: &str, role: Option<&str>) -> bool {
+        self.users.iter().any(|u| u == user)
+            || role.is_some_and(|role| self.roles.iter().any(|r| r == role))
+    }
+
+    /// Find the workload group of the user, a group assigned to the user
+    /// takes precedence over a group assigned to the role.
+    pub fn find_of_user<'a>(
+        workload_groups: &'a [WorkloadGroup],
+        user: &str,
+        role: Option<&str>,
+    ) -> Option<&'a WorkloadGroup> {
+        workload_groups
+            .iter()
+            .find(|g| g.contains(user, None))
+            .or_else(|| workload_groups.iter().find(|g| g.contains(user, role)))
+    }
+}
@@ -20,6 +20,7 @@ mod index;
 mod least_visible_time;
 mod lock;
 mod ownership;
+mod pipe;
 mod table;
 mod virtual_column;
 pub use catalog::*;
@@ -61,6 +62,7 @@ pub use lock::LockMeta;
 pub use lock::LockType;
 pub use lock::TableLockKey;
 pub use ownership::Ownership;
+pub use pipe::*;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -85,6 +87,9 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::SetTableRowAccessPolicyAction;
+pub use table::SetTableRowAccessPolicyReply;
+pub use table::SetTableRowAccessPolicyReq;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileLockKey;
 pub use table::TableCopiedFileNameIdent;
@@ -97,11 +102,14 @@ pub use table::TableInfo;
 pub use table::TableInfoFilter;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateStreamMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
@@ -141,3 +149,7 @@ const PREFIX_INDEX_BY_ID: &str = "__fd_index_by_id";
 const PREFIX_TABLE_LOCK: &str = "__fd_table_lock";
 const PREFIX_TABLE_LVT: &str = "__fd_table_lvt";
 const PREFIX_VIRTUAL_COLUMN: &str = "__fd_virtual_column";
+const PREFIX_PIPE: &str = "__fd_pipe";
+const PREFIX_PIPE_BY_ID: &str = "__fd_pipe_by_id";
+const PREFIX_PIPE_ID_TO_NAME: &str = "__fd_pipe_id_to_name";
+const PREFIX_PIPE_LOADED_FILES: &str = "__fd_pipe_loaded_files";
@@ -0,0 +1,354 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_types::MatchSeq;
+
+use crate::schema::TableCopiedFileInfo;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct PipeNameIdent {
+    pub tenant: String,
+    pub pipe_name: String,
+}
+
+impl PipeNameIdent {
+    pub fn new(tenant: impl Into<String>, pipe_name: impl Into<String>) -> PipeNameIdent {
+        PipeNameIdent {
+            tenant: tenant.into(),
+            pipe_name: pipe_name.into(),
+        }
+    }
+}
+
+impl Display for PipeNameIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'/'{}'", self.tenant, self.pipe_name)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct PipeId {
+    pub pipe_id: u64,
+}
+
+impl Display for PipeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pipe_id)
+    }
+}
+
+/// Identifies a version of a pipe's meta.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PipeIdent {
+    pub pipe_id: u64,
+
+    /// seq of the pipe meta, any change to the meta increments it.
+    pub seq: u64,
+}
+
+impl PipeIdent {
+    pub fn new(pipe_id: u64, seq: u64) -> Self {
+        PipeIdent { pipe_id, seq }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct PipeIdToName {
+    pub pipe_id: u64,
+}
+
+impl Display for PipeIdToName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pipe_id)
+    }
+}
+
+/// Records a stage file that has been loaded by a pipe.
+///
+/// Unlike the copied-files of a table, these records never expire:
+/// they live as long as the pipe and are what guarantees a file is ingested only once.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct PipeLoadedFileNameIdent {
+    pub pipe_id: u64,
+    pub file: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeMeta {
+    /// The `COPY INTO <table> FROM <stage>` statement the pipe runs.
+    pub copy_stmt: String,
+    pub auto_ingest: bool,
+    pub execution_paused: bool,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+    /// The last time the pipe loaded files successfully.
+    pub last_refreshed_on: Option<DateTime<Utc>>,
+}
+
+impl Default for PipeMeta {
+    fn default() -> Self {
+        PipeMeta {
+            copy_stmt: "".to_string(),
+            auto_ingest: false,
+            execution_paused: false,
+            comment: "".to_string(),
+            created_on: Utc::now(),
+            updated_on: Utc::now(),
+            last_refreshed_on: None,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeInfo {
+    pub ident: PipeIdent,
+    pub name_ident: PipeNameIdent,
+    pub meta: PipeMeta,
+}
+
+impl PipeInfo {
+    pub fn pipe_name(&self) -> &str {
+        &self.name_ident.pipe_name
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipeReq {
+    pub if_not_exists: bool,
+    pub name_ident: PipeNameIdent,
+    pub meta: PipeMeta,
+}
+
+impl Display for CreatePipeReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "create_pipe(if_not_exists={}):{}/{}={:?}",
+            self.if_not_exists, self.name_ident.tenant, self.name_ident.pipe_name, self.meta
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CreatePipeReply {
+    pub pipe_id: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropPipeReq {
+    pub if_exists: bool,
+    pub name_ident: PipeNameIdent,
+}
+
+impl Display for DropPipeReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "drop_pipe(if_exists={}):{}/{}",
+            self.if_exists, self.name_ident.tenant, self.name_ident.pipe_name
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropPipeReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetPipeReq {
+    pub name_ident: PipeNameIdent,
+}
+
+impl GetPipeReq {
+    pub fn new(tenant: impl Into<String>, pipe_name: impl Into<String>) -> GetPipeReq {
+        GetPipeReq {
+            name_ident: PipeNameIdent::new(tenant, pipe_name),
+        }
+    }
+}
+
+impl Display for GetPipeReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "get_pipe:{}/{}",
+            self.name_ident.tenant, self.name_ident.pipe_name
+        )
+    }
+}
+
+/// Update the meta of a pipe and record the files it has just loaded, in one transaction.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdatePipeMetaReq {
+    pub pipe_id: u64,
+    pub pipe_name: String,
+    pub seq: MatchSeq,
+    pub new_meta: PipeMeta,
+    pub loaded_files: BTreeMap<String, TableCopiedFileInfo>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdatePipeMetaReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListPipesReq {
+    pub tenant: String,
+}
+
+impl ListPipesReq {
+    pub fn new(tenant: impl Into<String>) -> ListPipesReq {
+        ListPipesReq {
+            tenant: tenant.into(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetPipeLoadedFileReq {
+    pub pipe_id: u64,
+    pub files: Vec<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetPipeLoadedFileReply {
+    pub file_info: BTreeMap<String, TableCopiedFileInfo>,
+}
+
+mod kvapi_key_impl {
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::schema::PipeId;
+    use crate::schema::PipeIdToName;
+    use crate::schema::PipeLoadedFileNameIdent;
+    use crate::schema::PipeNameIdent;
+    use crate::schema::PREFIX_PIPE;
+    use crate::schema::PREFIX_PIPE_BY_ID;
+    use crate::schema::PREFIX_PIPE_ID_TO_NAME;
+    use crate::schema::PREFIX_PIPE_LOADED_FILES;
+
+    /// __fd_pipe/<tenant>/<pipe_name> -> <pipe_id>
+    impl kvapi::Key for PipeNameIdent {
+        const PREFIX: &'static str = PREFIX_PIPE;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.pipe_name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let pipe_name = p.next_str()?;
+            p.done()?;
+
+            Ok(PipeNameIdent { tenant, pipe_name })
+        }
+    }
+
+    /// "__fd_pipe_by_id/<pipe_id>" -> PipeMeta
+    impl kvapi::Key for PipeId {
+        const PREFIX: &'static str = PREFIX_PIPE_BY_ID;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_u64(self.pipe_id)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let pipe_id = p.next_u64()?;
+            p.done()?;
+
+            Ok(PipeId { pipe_id })
+        }
+    }
+
+    /// "__fd_pipe_id_to_name/<pipe_id> -> PipeNameIdent"
+    impl kvapi::Key for PipeIdToName {
+        const PREFIX: &'static str = PREFIX_PIPE_ID_TO_NAME;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_u64(self.pipe_id)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let pipe_id = p.next_u64()?;
+            p.done()?;
+
+            Ok(PipeIdToName { pipe_id })
+        }
+    }
+
+    /// "__fd_pipe_loaded_files/<pipe_id>/<file> -> TableCopiedFileInfo"
+    impl kvapi::Key for PipeLoadedFileNameIdent {
+        const PREFIX: &'static str = PREFIX_PIPE_LOADED_FILES;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_u64(self.pipe_id)
+                .push_str(&self.file)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let pipe_id = p.next_u64()?;
+            let file = p.next_str()?;
+            p.done()?;
+
+            Ok(PipeLoadedFileNameIdent { pipe_id, file })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_kvapi::kvapi;
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use crate::schema::PipeLoadedFileNameIdent;
+
+    #[test]
+    fn test_pipe_loaded_file_name_ident_conversion() -> Result<(), kvapi::KeyError> {
+        let name = PipeLoadedFileNameIdent {
+            pipe_id: 3,
+            file: "/path/to/file.csv".to_owned(),
+        };
+
+        let key = name.to_string_key();
+        assert_eq!(key, "__fd_pipe_loaded_files/3/%2fpath%2fto%2ffile%2ecsv");
+
+        let from = PipeLoadedFileNameIdent::from_str_key(&key)?;
+        assert_eq!(from, name);
+
+        Ok(())
+    }
+}
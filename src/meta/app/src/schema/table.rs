@@ -239,9 +239,18 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    pub row_access_policy: Option<TableRowAccessPolicy>,
     pub owner: Option<Ownership>,
 }
 
+/// A row access policy attached to a table,
+/// the columns are passed to the policy as its arguments in order.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
 impl TableMeta {
     pub fn add_column(
         &mut self,
@@ -354,6 +363,7 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            row_access_policy: None,
             owner: None,
         }
     }
@@ -619,6 +629,14 @@ pub struct UpdateTableMetaReq {
     pub deduplicated_label: Option<String>,
 }
 
+/// Update the meta of several tables in one transaction.
+///
+/// Either all the tables are updated, or none of them is.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
 impl UpsertTableOptionReq {
     pub fn new(
         table_ident: &TableIdent,
@@ -665,6 +683,27 @@ pub struct SetTableColumnMaskPolicyReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum SetTableRowAccessPolicyAction {
+    // new policy
+    Set(TableRowAccessPolicy),
+    // prev policy name
+    Unset(String),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReq {
+    pub tenant: String,
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub action: SetTableRowAccessPolicyAction,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
@@ -675,6 +714,9 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
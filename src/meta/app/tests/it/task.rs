@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::task_dag_root;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskSchedule;
+
+#[test]
+fn test_task_schedule_next_after() -> anyhow::Result<()> {
+    let now = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+
+    let interval = TaskSchedule::IntervalMinutes(5);
+    assert_eq!(
+        interval.next_after(now).unwrap(),
+        Utc.with_ymd_and_hms(2024, 1, 10, 0, 5, 0).unwrap()
+    );
+    assert_eq!(interval.to_string(), "INTERVAL 5 MINUTE");
+
+    let cron = TaskSchedule::Cron("0 0 0 * * *".to_string(), None);
+    assert_eq!(
+        cron.next_after(now).unwrap(),
+        Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap()
+    );
+
+    // Midnight in Shanghai is 16:00 in UTC.
+    let cron = TaskSchedule::Cron("0 0 0 * * *".to_string(), Some("Asia/Shanghai".to_string()));
+    assert_eq!(
+        cron.next_after(now).unwrap(),
+        Utc.with_ymd_and_hms(2024, 1, 10, 16, 0, 0).unwrap()
+    );
+    assert_eq!(cron.to_string(), "CRON 0 0 0 * * * TIMEZONE Asia/Shanghai");
+
+    let invalid = TaskSchedule::Cron("invalid".to_string(), None);
+    assert!(invalid.next_after(now).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_task_reach_failure_limit() {
+    let mut task = Task::default();
+    assert!(!task.reach_failure_limit(100));
+
+    task.suspend_task_after_num_failures = Some(3);
+    assert!(!task.reach_failure_limit(2));
+    assert!(task.reach_failure_limit(3));
+}
+
+#[test]
+fn test_task_dag_root() {
+    let task = |name: &str, after: &[&str]| {
+        (name.to_string(), Task {
+            task_name: name.to_string(),
+            after: after.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        })
+    };
+
+    let mut tasks = BTreeMap::from([
+        task("root", &[]),
+        task("a", &["root"]),
+        task("b", &["root"]),
+        task("c", &["a", "b"]),
+    ]);
+    assert_eq!(task_dag_root(&tasks, "root").unwrap(), "root");
+    assert_eq!(task_dag_root(&tasks, "c").unwrap(), "root");
+
+    // A task can not run after the tasks of two DAGs.
+    tasks.extend([task("root2", &[]), task("d", &["c", "root2"])]);
+    assert!(task_dag_root(&tasks, "d").is_err());
+
+    tasks.extend([task("e", &["missing"])]);
+    assert!(task_dag_root(&tasks, "e").is_err());
+
+    // root -> a -> c -> a
+    tasks.extend([task("a", &["root", "c"])]);
+    let err = task_dag_root(&tasks, "c").unwrap_err();
+    assert!(err.contains("cycle"), "{}", err);
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::schema as mt;
+use databend_common_protos::pb;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::PipeNameIdent {
+    type PB = pb::PipeNameIdent;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            tenant: p.tenant,
+            pipe_name: p.pipe_name,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let p = pb::PipeNameIdent {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            tenant: self.tenant.clone(),
+            pipe_name: self.pipe_name.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::PipeMeta {
+    type PB = pb::PipeMeta;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            copy_stmt: p.copy_stmt,
+            auto_ingest: p.auto_ingest,
+            execution_paused: p.execution_paused,
+            comment: p.comment,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+            updated_on: DateTime::<Utc>::from_pb(p.updated_on)?,
+            last_refreshed_on: match p.last_refreshed_on {
+                Some(refreshed_on) => Some(DateTime::<Utc>::from_pb(refreshed_on)?),
+                None => None,
+            },
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let p = pb::PipeMeta {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            copy_stmt: self.copy_stmt.clone(),
+            auto_ingest: self.auto_ingest,
+            execution_paused: self.execution_paused,
+            comment: self.comment.clone(),
+            created_on: self.created_on.to_pb()?,
+            updated_on: self.updated_on.to_pb()?,
+            last_refreshed_on: match self.last_refreshed_on {
+                Some(refreshed_on) => Some(refreshed_on.to_pb()?),
+                None => None,
+            },
+        };
+        Ok(p)
+    }
+}
@@ -218,6 +218,10 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            row_access_policy: match p.row_access_policy {
+                Some(policy) => Some(mt::TableRowAccessPolicy::from_pb(policy)?),
+                None => None,
+            },
             owner: match p.owner {
                 Some(owner) => Some(mt::Ownership::from_pb(owner)?),
                 None => None,
@@ -258,6 +262,10 @@ impl FromToProto for mt::TableMeta {
             statistics: Some(self.statistics.to_pb()?),
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
+            row_access_policy: match self.row_access_policy.as_ref() {
+                Some(policy) => Some(policy.to_pb()?),
+                None => None,
+            },
             owner: match self.owner.as_ref() {
                 Some(o) => Some(o.to_pb()?),
                 None => None,
@@ -267,6 +275,32 @@ impl FromToProto for mt::TableMeta {
     }
 }
 
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            policy: p.policy,
+            columns: p.columns,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            policy: self.policy.clone(),
+            columns: self.columns.clone(),
+        };
+        Ok(p)
+    }
+}
+
 impl FromToProto for mt::TableStatistics {
     type PB = pb::TableStatistics;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -0,0 +1,206 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_protos::pb;
+use num::FromPrimitive;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::TaskSchedule {
+    type PB = pb::TaskSchedule;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let schedule_type =
+            pb::task_schedule::ScheduleType::try_from(p.schedule_type).map_err(|_| {
+                Incompatible {
+                    reason: format!("invalid ScheduleType: {}", p.schedule_type),
+                }
+            })?;
+        let v = match schedule_type {
+            pb::task_schedule::ScheduleType::Interval => {
+                mt::TaskSchedule::IntervalMinutes(p.interval_minutes)
+            }
+            pb::task_schedule::ScheduleType::Cron => mt::TaskSchedule::Cron(p.cron, p.time_zone),
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let p = match self {
+            mt::TaskSchedule::IntervalMinutes(minutes) => pb::TaskSchedule {
+                ver: VER,
+                min_reader_ver: MIN_READER_VER,
+                schedule_type: pb::task_schedule::ScheduleType::Interval as i32,
+                interval_minutes: *minutes,
+                cron: "".to_string(),
+                time_zone: None,
+            },
+            mt::TaskSchedule::Cron(cron, time_zone) => pb::TaskSchedule {
+                ver: VER,
+                min_reader_ver: MIN_READER_VER,
+                schedule_type: pb::task_schedule::ScheduleType::Cron as i32,
+                interval_minutes: 0,
+                cron: cron.clone(),
+                time_zone: time_zone.clone(),
+            },
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::Task {
+    type PB = pb::Task;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            task_id: p.task_id,
+            task_name: p.task_name,
+            query_text: p.query_text,
+            comment: p.comment,
+            owner: p.owner,
+            schedule: match p.schedule {
+                Some(schedule) => Some(mt::TaskSchedule::from_pb(schedule)?),
+                None => None,
+            },
+            warehouse: p.warehouse,
+            status: FromPrimitive::from_i32(p.status).ok_or_else(|| Incompatible {
+                reason: format!("invalid TaskStatus: {}", p.status),
+            })?,
+            suspend_task_after_num_failures: p.suspend_task_after_num_failures,
+            consecutive_failures: p.consecutive_failures,
+            next_scheduled_at: match p.next_scheduled_at {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            last_suspended_at: match p.last_suspended_at {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            created_at: DateTime::<Utc>::from_pb(p.created_at)?,
+            updated_at: DateTime::<Utc>::from_pb(p.updated_at)?,
+            after: p.after,
+            when_condition: p.when_condition,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let p = pb::Task {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            task_id: self.task_id,
+            task_name: self.task_name.clone(),
+            query_text: self.query_text.clone(),
+            comment: self.comment.clone(),
+            owner: self.owner.clone(),
+            schedule: match &self.schedule {
+                Some(schedule) => Some(schedule.to_pb()?),
+                None => None,
+            },
+            warehouse: self.warehouse.clone(),
+            status: self.status as i32,
+            suspend_task_after_num_failures: self.suspend_task_after_num_failures,
+            consecutive_failures: self.consecutive_failures,
+            next_scheduled_at: match self.next_scheduled_at {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            last_suspended_at: match self.last_suspended_at {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            created_at: self.created_at.to_pb()?,
+            updated_at: self.updated_at.to_pb()?,
+            after: self.after.clone(),
+            when_condition: self.when_condition.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TaskRun {
+    type PB = pb::TaskRun;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let task = p.task.ok_or_else(|| Incompatible {
+            reason: "TaskRun.task can not be None".to_string(),
+        })?;
+
+        let v = Self {
+            task: mt::Task::from_pb(task)?,
+            run_id: p.run_id,
+            query_id: p.query_id,
+            attempt_number: p.attempt_number,
+            state: FromPrimitive::from_i32(p.state).ok_or_else(|| Incompatible {
+                reason: format!("invalid TaskRunState: {}", p.state),
+            })?,
+            error_code: p.error_code,
+            error_message: p.error_message,
+            scheduled_at: DateTime::<Utc>::from_pb(p.scheduled_at)?,
+            completed_at: match p.completed_at {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let p = pb::TaskRun {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            task: Some(self.task.to_pb()?),
+            run_id: self.run_id,
+            query_id: self.query_id.clone(),
+            attempt_number: self.attempt_number,
+            state: self.state as i32,
+            error_code: self.error_code,
+            error_message: self.error_message.clone(),
+            scheduled_at: self.scheduled_at.to_pb()?,
+            completed_at: match self.completed_at {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        };
+        Ok(p)
+    }
+}
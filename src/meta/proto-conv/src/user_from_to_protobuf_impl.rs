@@ -447,3 +447,47 @@ impl FromToProto for mt::principal::PasswordPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::WorkloadGroup {
+    type PB = pb::WorkloadGroup;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::WorkloadGroup) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::WorkloadGroup {
+            name: p.name.clone(),
+            max_concurrency: p.max_concurrency,
+            max_memory_usage: p.max_memory_usage,
+            queue_timeout: p.queue_timeout,
+            users: p.users.clone(),
+            roles: p.roles.clone(),
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WorkloadGroup, Incompatible> {
+        Ok(pb::WorkloadGroup {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            max_concurrency: self.max_concurrency,
+            max_memory_usage: self.max_memory_usage,
+            queue_timeout: self.queue_timeout,
+            users: self.users.clone(),
+            roles: self.roles.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
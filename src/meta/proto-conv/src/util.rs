@@ -98,6 +98,13 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (66, "2023-12-15: Add: stage.proto/StageInfo::created_on", ),
     (67, "2023-12-19: Add: user.proto/PasswordPolicy and UserOption::password_policy", ),
     (68, "2023-12-19: Add: index.proto/IndexMeta add field `original_query` and `user_defined_block_name`"),
+    (69, "2023-12-21: Add: pipe.proto/PipeNameIdent and PipeMeta", ),
+    (70, "2024-01-02: Add: index.proto/IndexMeta::IndexType add VECTOR", ),
+    (71, "2024-01-04: Add: user.proto/WorkloadGroup", ),
+    (72, "2024-01-08: Add: row_access_policy.proto/RowAccessPolicyMeta, table.proto/TableMeta::row_access_policy", ),
+    (73, "2024-01-10: Add: task.proto/Task, TaskRun, TaskSchedule", ),
+    (74, "2024-01-12: Add: task.proto/Task::after, Task::when_condition; Task::schedule is optional", ),
+    (75, "2024-01-15: Add: file_format.proto/FileFormatParams::avro, FileFormatParams::orc", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
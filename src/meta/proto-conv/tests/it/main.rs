@@ -72,3 +72,10 @@ mod v065_least_visible_time;
 mod v066_stage_create_on;
 mod v067_password_policy;
 mod v068_index_meta;
+mod v069_pipe_meta;
+mod v070_vector_index_meta;
+mod v071_workload_group;
+mod v072_row_access_policy;
+mod v073_task;
+mod v074_task_dag;
+mod v075_avro_orc_file_format_params;
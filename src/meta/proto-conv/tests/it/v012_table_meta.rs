@@ -147,6 +147,7 @@ fn test_decode_v12_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
+        row_access_policy: None,
         owner: None,
     };
 
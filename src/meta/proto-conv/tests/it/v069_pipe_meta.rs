@@ -0,0 +1,58 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::schema::PipeMeta;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `proto_conv::test_build_pb_buf()`
+#[test]
+fn test_decode_v69_pipe_meta() -> anyhow::Result<()> {
+    let pipe_meta_v069 = vec![
+        10, 48, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 116, 49, 32, 70, 82, 79, 77, 32, 64, 115,
+        49, 32, 70, 73, 76, 69, 95, 70, 79, 82, 77, 65, 84, 32, 61, 32, 40, 84, 89, 80, 69, 32, 61,
+        32, 67, 83, 86, 41, 16, 1, 34, 7, 108, 111, 97, 100, 32, 116, 49, 42, 23, 50, 48, 50, 51,
+        45, 49, 50, 45, 50, 49, 32, 49, 48, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 50, 23, 50, 48,
+        50, 51, 45, 49, 50, 45, 50, 49, 32, 49, 48, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 58, 23,
+        50, 48, 50, 51, 45, 49, 50, 45, 50, 50, 32, 48, 56, 58, 51, 48, 58, 48, 48, 32, 85, 84, 67,
+        160, 6, 69, 168, 6, 24,
+    ];
+
+    let want = || PipeMeta {
+        copy_stmt: "COPY INTO t1 FROM @s1 FILE_FORMAT = (TYPE = CSV)".to_string(),
+        auto_ingest: true,
+        execution_paused: false,
+        comment: "load t1".to_string(),
+        created_on: Utc.with_ymd_and_hms(2023, 12, 21, 10, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2023, 12, 21, 10, 0, 9).unwrap(),
+        last_refreshed_on: Some(Utc.with_ymd_and_hms(2023, 12, 22, 8, 30, 0).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), pipe_meta_v069.as_slice(), 69, want())?;
+
+    Ok(())
+}
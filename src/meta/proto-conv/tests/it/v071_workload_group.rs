@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v71_workload_group() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 3, 101, 116, 108, 16, 4, 24, 128, 128, 128, 128, 4, 32, 60, 42, 2, 117, 49, 50, 2, 114,
+        49, 58, 11, 101, 116, 108, 32, 113, 117, 101, 114, 105, 101, 115, 66, 23, 50, 48, 49, 52,
+        45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 74, 23, 50, 48,
+        49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6,
+        71, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::principal::WorkloadGroup {
+        name: "etl".to_string(),
+        max_concurrency: 4,
+        max_memory_usage: 1073741824,
+        queue_timeout: 60,
+        users: vec!["u1".to_string()],
+        roles: vec!["r1".to_string()],
+        comment: "etl queries".to_string(),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 71, want())
+}
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v73_task() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        8, 10, 18, 5, 100, 97, 105, 108, 121, 26, 22, 73, 78, 83, 69, 82, 84, 32, 73, 78, 84, 79,
+        32, 116, 32, 83, 69, 76, 69, 67, 84, 32, 49, 34, 1, 99, 42, 13, 97, 99, 99, 111, 117, 110,
+        116, 95, 97, 100, 109, 105, 110, 50, 8, 16, 5, 160, 6, 73, 168, 6, 24, 58, 3, 119, 104, 49,
+        98, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85,
+        84, 67, 106, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48,
+        57, 32, 85, 84, 67, 114, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48,
+        48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 73, 168, 6, 24,
+    ];
+
+    let want = || mt::Task {
+        task_id: 10,
+        task_name: "daily".to_string(),
+        query_text: "INSERT INTO t SELECT 1".to_string(),
+        comment: "c".to_string(),
+        owner: "account_admin".to_string(),
+        schedule: Some(mt::TaskSchedule::IntervalMinutes(5)),
+        warehouse: Some("wh1".to_string()),
+        status: mt::TaskStatus::Suspended,
+        suspend_task_after_num_failures: None,
+        consecutive_failures: 0,
+        next_scheduled_at: None,
+        last_suspended_at: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+        created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+        after: vec![],
+        when_condition: None,
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 73, want())
+}
+
+#[test]
+fn test_decode_v73_task_run() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 176, 1, 8, 10, 18, 5, 100, 97, 105, 108, 121, 26, 22, 73, 78, 83, 69, 82, 84, 32, 73,
+        78, 84, 79, 32, 116, 32, 83, 69, 76, 69, 67, 84, 32, 49, 34, 1, 99, 42, 13, 97, 99, 99,
+        111, 117, 110, 116, 95, 97, 100, 109, 105, 110, 50, 36, 8, 1, 26, 11, 48, 32, 48, 32, 48,
+        32, 42, 32, 42, 32, 42, 34, 13, 65, 115, 105, 97, 47, 83, 104, 97, 110, 103, 104, 97, 105,
+        160, 6, 73, 168, 6, 24, 64, 1, 72, 3, 80, 1, 90, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50,
+        57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 106, 23, 50, 48, 49, 52, 45, 49,
+        49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 114, 23, 50, 48, 49,
+        52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 73,
+        168, 6, 24, 16, 7, 26, 3, 113, 105, 100, 32, 1, 40, 3, 48, 237, 7, 58, 4, 98, 111, 111,
+        109, 66, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57,
+        32, 85, 84, 67, 74, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58,
+        48, 57, 32, 85, 84, 67, 160, 6, 73, 168, 6, 24,
+    ];
+
+    let want = || mt::TaskRun {
+        task: mt::Task {
+            task_id: 10,
+            task_name: "daily".to_string(),
+            query_text: "INSERT INTO t SELECT 1".to_string(),
+            comment: "c".to_string(),
+            owner: "account_admin".to_string(),
+            schedule: Some(mt::TaskSchedule::Cron(
+                "0 0 0 * * *".to_string(),
+                Some("Asia/Shanghai".to_string()),
+            )),
+            warehouse: None,
+            status: mt::TaskStatus::Started,
+            suspend_task_after_num_failures: Some(3),
+            consecutive_failures: 1,
+            next_scheduled_at: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+            last_suspended_at: None,
+            created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+            after: vec![],
+            when_condition: None,
+        },
+        run_id: 7,
+        query_id: "qid".to_string(),
+        attempt_number: 1,
+        state: mt::TaskRunState::Failed,
+        error_code: 1005,
+        error_message: Some("boom".to_string()),
+        scheduled_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        completed_at: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 73, want())
+}
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v74_task() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        8, 11, 18, 4, 108, 111, 97, 100, 26, 30, 73, 78, 83, 69, 82, 84, 32, 73, 78, 84, 79, 32,
+        116, 32, 83, 69, 76, 69, 67, 84, 32, 42, 32, 70, 82, 79, 77, 32, 115, 49, 34, 1, 99, 42,
+        13, 97, 99, 99, 111, 117, 110, 116, 95, 97, 100, 109, 105, 110, 106, 23, 50, 48, 49, 52,
+        45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 114, 23, 50,
+        48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67,
+        122, 5, 100, 97, 105, 108, 121, 122, 7, 101, 120, 116, 114, 97, 99, 116, 130, 1, 21, 115,
+        116, 114, 101, 97, 109, 95, 104, 97, 115, 95, 100, 97, 116, 97, 40, 39, 115, 49, 39, 41,
+        160, 6, 74, 168, 6, 24,
+    ];
+
+    let want = || mt::Task {
+        task_id: 11,
+        task_name: "load".to_string(),
+        query_text: "INSERT INTO t SELECT * FROM s1".to_string(),
+        comment: "c".to_string(),
+        owner: "account_admin".to_string(),
+        schedule: None,
+        warehouse: None,
+        status: mt::TaskStatus::Suspended,
+        suspend_task_after_num_failures: None,
+        consecutive_failures: 0,
+        next_scheduled_at: None,
+        last_suspended_at: None,
+        created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+        after: vec!["daily".to_string(), "extract".to_string()],
+        when_condition: Some("stream_has_data('s1')".to_string()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 74, want())
+}
@@ -253,6 +253,11 @@ pub enum Expr {
         unit: IntervalKind,
         date: Box<Expr>,
     },
+    /// A query parameter, positional `?` or named `:name`
+    Placeholder {
+        span: Span,
+        name: Option<Identifier>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -575,7 +580,8 @@ impl Expr {
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
-            | Expr::DateTrunc { span, .. } => *span,
+            | Expr::DateTrunc { span, .. }
+            | Expr::Placeholder { span, .. } => *span,
         }
     }
 
@@ -1177,6 +1183,10 @@ impl Display for Expr {
             Expr::Literal { lit, .. } => {
                 write!(f, "{lit}")?;
             }
+            Expr::Placeholder { name, .. } => match name {
+                Some(name) => write!(f, ":{name}")?,
+                None => write!(f, "?")?,
+            },
             Expr::CountAll { window, .. } => {
                 write!(f, "COUNT(*)")?;
                 if let Some(window) = window {
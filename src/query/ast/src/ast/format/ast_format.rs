@@ -422,6 +422,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_placeholder(&mut self, _span: Span, name: &'ast Option<Identifier>) {
+        let name = match name {
+            Some(name) => format!("Placeholder :{}", name),
+            None => "Placeholder ?".to_string(),
+        };
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_count_all(&mut self, _span: Span, _window: &'ast Option<Window>) {
         let name = "Function CountAll".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -868,6 +878,15 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         );
         children.push(from_node);
 
+        if let Some(partition_by) = &copy.partition_by {
+            self.visit_expr(partition_by);
+            let partition_by_node = FormatTreeNode::with_children(
+                AstFormatContext::with_children("PartitionBy".to_string(), 1),
+                vec![self.children.pop().unwrap()],
+            );
+            children.push(partition_by_node);
+        }
+
         if !copy.file_format.is_empty() {
             let mut file_formats_children = Vec::with_capacity(copy.file_format.len());
             for (k, v) in copy.file_format.iter() {
@@ -1692,6 +1711,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             self.visit_stream_point(point);
             children.push(self.children.pop().unwrap());
         }
+        if !stmt.append_only {
+            let append_only_format_ctx = AstFormatContext::new("AppendOnly false".to_string());
+            children.push(FormatTreeNode::new(append_only_format_ctx));
+        }
         if let Some(comment) = &stmt.comment {
             let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
             children.push(FormatTreeNode::new(comment_format_ctx));
@@ -1776,6 +1799,15 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_refresh_ngram_index(&mut self, stmt: &'ast RefreshNgramIndexStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let child = self.children.pop().unwrap();
+        let name = "RefreshNgramIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2648,6 +2680,52 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowPasswordPolicies".to_string());
     }
 
+    fn visit_create_workload_group(&mut self, stmt: &'ast CreateWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_workload_group(&mut self, stmt: &'ast AlterWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_workload_group(&mut self, stmt: &'ast DropWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_workload_group(&mut self, stmt: &'ast DescWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_workload_groups(&mut self) {
+        let ctx = AstFormatContext::new("ShowWorkloadGroups".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
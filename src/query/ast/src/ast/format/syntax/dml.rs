@@ -263,6 +263,13 @@ pub(crate) fn pretty_copy_into_location(copy_stmt: CopyIntoLocationStmt) -> RcDo
                 .append(pretty_query(*query))
                 .append(RcDoc::text(")")),
         })
+        .append(if let Some(partition_by) = copy_stmt.partition_by {
+            RcDoc::line()
+                .append(RcDoc::text("PARTITION BY "))
+                .append(pretty_expr(*partition_by))
+        } else {
+            RcDoc::nil()
+        })
         .append(pretty_file_format(&copy_stmt.file_format))
         .append(
             RcDoc::line()
@@ -238,6 +238,10 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(pretty_expr(*expr))
             .append(RcDoc::text(")")),
         Expr::Literal { lit, .. } => RcDoc::text(lit.to_string()),
+        Expr::Placeholder { name, .. } => match name {
+            Some(name) => RcDoc::text(format!(":{name}")),
+            None => RcDoc::text("?"),
+        },
         Expr::CountAll { window, .. } => {
             RcDoc::text("COUNT(*)").append(if let Some(window) = window {
                 RcDoc::text(" OVER (")
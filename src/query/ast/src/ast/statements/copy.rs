@@ -31,6 +31,7 @@ use url::Url;
 
 use crate::ast::write_comma_separated_map;
 use crate::ast::write_comma_separated_quoted_list;
+use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
@@ -183,7 +184,7 @@ impl Display for CopyIntoTableStmt {
         }
 
         if !self.validation_mode.is_empty() {
-            write!(f, "VALIDATION_MODE = {}", self.validation_mode)?;
+            write!(f, " VALIDATION_MODE = {}", self.validation_mode)?;
         }
 
         if self.size_limit != 0 {
@@ -216,6 +217,8 @@ pub struct CopyIntoLocationStmt {
     pub file_format: BTreeMap<String, String>,
     pub single: bool,
     pub max_file_size: usize,
+    /// Unload the rows to the sub path of the location evaluated from the expression.
+    pub partition_by: Option<Box<Expr>>,
 }
 
 impl Display for CopyIntoLocationStmt {
@@ -227,6 +230,9 @@ impl Display for CopyIntoLocationStmt {
         write!(f, " INTO {}", self.dst)?;
         write!(f, " FROM {}", self.src)?;
 
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY {}", partition_by)?;
+        }
         if !self.file_format.is_empty() {
             write!(f, " FILE_FORMAT = (")?;
             write_comma_separated_map(f, &self.file_format)?;
@@ -245,6 +251,7 @@ impl CopyIntoLocationStmt {
             CopyIntoLocationOption::FileFormat(v) => self.file_format = v,
             CopyIntoLocationOption::Single(v) => self.single = v,
             CopyIntoLocationOption::MaxFileSize(v) => self.max_file_size = v,
+            CopyIntoLocationOption::PartitionBy(v) => self.partition_by = Some(v),
         }
     }
 }
@@ -491,4 +498,5 @@ pub enum CopyIntoLocationOption {
     FileFormat(BTreeMap<String, String>),
     MaxFileSize(usize),
     Single(bool),
+    PartitionBy(Box<Expr>),
 }
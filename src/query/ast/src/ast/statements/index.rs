@@ -15,6 +15,7 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use crate::ast::write_dot_separated_list;
 use crate::ast::Identifier;
 use crate::ast::Query;
 
@@ -32,13 +33,23 @@ pub struct CreateIndexStmt {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TableIndexType {
     Aggregating,
+    Vector,
     // Join
 }
 
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableIndexType::Aggregating => write!(f, "AGGREGATING"),
+            TableIndexType::Vector => write!(f, "VECTOR"),
+        }
+    }
+}
+
 impl Display for CreateIndexStmt {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let sync = if self.sync_creation { "SYNC" } else { "ASYNC" };
-        write!(f, "CREATE {} {:?} INDEX", sync, self.index_type)?;
+        write!(f, "CREATE {} {} INDEX", sync, self.index_type)?;
         if self.if_not_exists {
             write!(f, " IF NOT EXISTS")?;
         }
@@ -49,13 +60,14 @@ impl Display for CreateIndexStmt {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DropIndexStmt {
+    pub index_type: TableIndexType,
     pub if_exists: bool,
     pub index: Identifier,
 }
 
 impl Display for DropIndexStmt {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "DROP INDEX")?;
+        write!(f, "DROP {} INDEX", self.index_type)?;
         if self.if_exists {
             write!(f, " IF EXISTS")?;
         }
@@ -67,13 +79,39 @@ impl Display for DropIndexStmt {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RefreshIndexStmt {
+    pub index_type: TableIndexType,
     pub index: Identifier,
     pub limit: Option<u64>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshNgramIndexStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for RefreshNgramIndexStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "REFRESH NGRAM INDEX FOR ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )
+    }
+}
+
 impl Display for RefreshIndexStmt {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "REFRESH INDEX {index}", index = self.index)?;
+        write!(
+            f,
+            "REFRESH {} INDEX {index}",
+            self.index_type,
+            index = self.index
+        )?;
         if let Some(limit) = self.limit {
             write!(f, " LIMIT {limit}")?;
         }
@@ -32,6 +32,7 @@ mod password_policy;
 mod pipe;
 mod presign;
 mod replace;
+mod row_access_policy;
 mod share;
 mod show;
 mod stage;
@@ -45,6 +46,7 @@ mod update;
 mod user;
 mod view;
 mod virtual_column;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -66,6 +68,7 @@ pub use password_policy::*;
 pub use pipe::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
@@ -79,3 +82,4 @@ pub use update::*;
 pub use user::*;
 pub use view::*;
 pub use virtual_column::*;
+pub use workload_group::*;
@@ -151,6 +151,7 @@ pub enum Statement {
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
+    RefreshNgramIndex(RefreshNgramIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -246,6 +247,11 @@ pub enum Statement {
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
 
+    // row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
     // network policy
     CreateNetworkPolicy(CreateNetworkPolicyStmt),
     AlterNetworkPolicy(AlterNetworkPolicyStmt),
@@ -262,6 +268,13 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // workload group
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+    DescWorkloadGroup(DescWorkloadGroupStmt),
+    ShowWorkloadGroups,
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -275,6 +288,11 @@ pub enum Statement {
     DescribePipe(DescribePipeStmt),
     DropPipe(DropPipeStmt),
     AlterPipe(AlterPipeStmt),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -475,6 +493,7 @@ impl Display for Statement {
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshNgramIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -594,6 +613,9 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
@@ -609,6 +631,11 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DescWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWorkloadGroups => write!(f, "SHOW WORKLOAD GROUPS")?,
             Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
@@ -619,6 +646,9 @@ impl Display for Statement {
             Statement::DescribePipe(stmt) => write!(f, "{stmt}")?,
             Statement::DropPipe(stmt) => write!(f, "{stmt}")?,
             Statement::AlterPipe(stmt) => write!(f, "{stmt}")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ABORT")?,
             Statement::CreateConnection(stmt) => write!(f, "{stmt}")?,
             Statement::DropConnection(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeConnection(stmt) => write!(f, "{stmt}")?,
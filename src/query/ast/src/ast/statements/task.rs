@@ -22,9 +22,11 @@ pub struct CreateTaskStmt {
     pub if_not_exists: bool,
     pub name: String,
     pub warehouse_opts: WarehouseOptions,
-    pub schedule_opts: ScheduleOptions,
+    pub schedule_opts: Option<ScheduleOptions>,
     pub suspend_task_after_num_failures: Option<u64>,
     pub comments: String,
+    pub after: Vec<String>,
+    pub when_condition: Option<String>,
     pub sql: String,
 }
 
@@ -38,7 +40,9 @@ impl Display for CreateTaskStmt {
 
         write!(f, "{}", self.warehouse_opts)?;
 
-        write!(f, "{}", self.schedule_opts)?;
+        if let Some(schedule_opts) = &self.schedule_opts {
+            write!(f, "{}", schedule_opts)?;
+        }
 
         if let Some(num) = self.suspend_task_after_num_failures {
             write!(f, " SUSPEND TASK AFTER {} FAILURES", num)?;
@@ -48,6 +52,14 @@ impl Display for CreateTaskStmt {
             write!(f, " COMMENTS = '{}'", self.comments)?;
         }
 
+        if !self.after.is_empty() {
+            write!(f, " AFTER {}", self.after.join(", "))?;
+        }
+
+        if let Some(when_condition) = &self.when_condition {
+            write!(f, " WHEN {}", when_condition)?;
+        }
+
         write!(f, " AS {}", self.sql)?;
         Ok(())
     }
@@ -112,6 +124,10 @@ pub enum AlterTaskOptions {
     },
     // Change SQL
     ModifyAs(String),
+    // Change the condition of running the task
+    ModifyWhen(String),
+    AddAfter(Vec<String>),
+    RemoveAfter(Vec<String>),
 }
 
 impl Display for AlterTaskOptions {
@@ -146,6 +162,13 @@ impl Display for AlterTaskOptions {
                 Ok(())
             }
             AlterTaskOptions::ModifyAs(sql) => write!(f, " AS {}", sql),
+            AlterTaskOptions::ModifyWhen(when_condition) => {
+                write!(f, " MODIFY WHEN {}", when_condition)
+            }
+            AlterTaskOptions::AddAfter(after) => write!(f, " ADD AFTER {}", after.join(", ")),
+            AlterTaskOptions::RemoveAfter(after) => {
+                write!(f, " REMOVE AFTER {}", after.join(", "))
+            }
         }
     }
 }
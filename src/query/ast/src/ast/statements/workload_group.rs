@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateWorkloadGroupStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub set_options: WorkloadGroupSetOptions,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE WORKLOAD GROUP ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.set_options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub set_options: WorkloadGroupSetOptions,
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET", self.name)?;
+        write!(f, "{}", self.set_options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkloadGroupSetOptions {
+    pub max_concurrency: Option<u64>,
+    pub max_memory_usage: Option<u64>,
+    pub queue_timeout: Option<u64>,
+    pub users: Option<Vec<String>>,
+    pub roles: Option<Vec<String>>,
+    pub comment: Option<String>,
+}
+
+impl Display for WorkloadGroupSetOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(max_concurrency) = self.max_concurrency {
+            write!(f, " MAX_CONCURRENCY = {}", max_concurrency)?;
+        }
+        if let Some(max_memory_usage) = self.max_memory_usage {
+            write!(f, " MAX_MEMORY_USAGE = {}", max_memory_usage)?;
+        }
+        if let Some(queue_timeout) = self.queue_timeout {
+            write!(f, " QUEUE_TIMEOUT = {}", queue_timeout)?;
+        }
+        if let Some(users) = &self.users {
+            write!(f, " USERS = (")?;
+            for (i, user) in users.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "'{}'", user)?;
+            }
+            write!(f, ")")?;
+        }
+        if let Some(roles) = &self.roles {
+            write!(f, " ROLES = (")?;
+            for (i, role) in roles.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "'{}'", role)?;
+            }
+            write!(f, ")")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescWorkloadGroupStmt {
+    pub name: String,
+}
+
+impl Display for DescWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE WORKLOAD GROUP {}", self.name)?;
+
+        Ok(())
+    }
+}
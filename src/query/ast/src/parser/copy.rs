@@ -112,6 +112,7 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
                 file_format: Default::default(),
                 single: Default::default(),
                 max_file_size: Default::default(),
+                partition_by: None,
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -125,6 +126,7 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
          #copy_into_location:"`COPY
                 INTO { internalStage | externalStage | externalLocation }
                 FROM { [<database_name>.]<table_name> | ( <query> ) }
+                [ PARTITION BY <expr> ]
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]
                 [ copyOptions ]`"
          | #copy_into_table: "`COPY
@@ -152,7 +154,9 @@ fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
             CopyIntoTableOption::FileFormat(options)
         }),
         map(
-            rule! { VALIDATION_MODE ~ "=" ~ #literal_string },
+            rule! {
+                VALIDATION_MODE ~ "=" ~ ( #literal_string | #map(ident, |ident| ident.name) )
+            },
             |(_, _, validation_mode)| CopyIntoTableOption::ValidationMode(validation_mode),
         ),
         map(
@@ -201,5 +205,8 @@ fn copy_into_location_option(i: Input) -> IResult<CopyIntoLocationOption> {
         map(rule! { #file_format_clause }, |options| {
             CopyIntoLocationOption::FileFormat(options)
         }),
+        map(rule! { PARTITION ~ BY ~ #expr }, |(_, _, expr)| {
+            CopyIntoLocationOption::PartitionBy(Box::new(expr))
+        }),
     ))(i)
 }
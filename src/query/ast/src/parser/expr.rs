@@ -140,6 +140,22 @@ pub fn subexpr(min_precedence: u32) -> impl FnMut(Input) -> IResult<Expr> {
                         },
                     };
                 }
+
+                // and replace `?` json operator and `:name` map access to query parameter.
+                let placeholder = match &expr_elements[curr as usize].elem {
+                    ExprElement::JsonOp {
+                        op: JsonOperator::Question,
+                    } => Some(ExprElement::Placeholder { name: None }),
+                    ExprElement::MapAccess {
+                        accessor: MapAccessor::Colon { key },
+                    } => Some(ExprElement::Placeholder {
+                        name: Some(key.clone()),
+                    }),
+                    _ => None,
+                };
+                if let Some(placeholder) = placeholder {
+                    expr_elements[curr as usize].elem = placeholder;
+                }
             }
         }
 
@@ -200,6 +216,10 @@ pub enum ExprElement {
     JsonOp {
         op: JsonOperator,
     },
+    /// Query parameter, positional `?` or named `:name`
+    Placeholder {
+        name: Option<Identifier>,
+    },
     /// Unary operation
     UnaryOp {
         op: UnaryOperator,
@@ -474,6 +494,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.0),
                 lit,
             },
+            ExprElement::Placeholder { name } => Expr::Placeholder {
+                span: transform_span(elem.span.0),
+                name,
+            },
             ExprElement::CountAll { window } => Expr::CountAll {
                 span: transform_span(elem.span.0),
                 window,
@@ -38,6 +38,7 @@ use crate::parser::data_mask::data_mask_policy;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::query::*;
+use crate::parser::row_access_policy::row_access_policy;
 use crate::parser::share::share_endpoint_uri_location;
 use crate::parser::stage::*;
 use crate::parser::stream::stream_table;
@@ -108,9 +109,11 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         rule! {
             CREATE ~ TASK ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident ~ #task_warehouse_option
-            ~ SCHEDULE ~ "=" ~ #task_schedule_option
+            ~ ( SCHEDULE ~ "=" ~ #task_schedule_option )?
             ~ (SUSPEND_TASK_AFTER_NUM_FAILURES ~ "=" ~ #literal_u64)?
             ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
+            ~ ( AFTER ~ #comma_separated_list1(ident) )?
+            ~ ( WHEN ~ #expr )?
             ~ AS ~ #statement
         },
         |(
@@ -119,11 +122,11 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             opt_if_not_exists,
             task,
             warehouse_opts,
-            _,
-            _,
             schedule_opts,
             suspend_opt,
             comment_opt,
+            after_opt,
+            when_opt,
             _,
             sql,
         )| {
@@ -134,9 +137,13 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 if_not_exists: opt_if_not_exists.is_some(),
                 name: task.to_string(),
                 warehouse_opts,
-                schedule_opts,
+                schedule_opts: schedule_opts.map(|(_, _, opt)| opt),
                 suspend_task_after_num_failures: suspend_opt.map(|(_, _, num)| num),
                 comments: comment_opt.map(|v| v.2).unwrap_or_default(),
+                after: after_opt
+                    .map(|(_, tasks)| tasks.iter().map(|t| t.to_string()).collect())
+                    .unwrap_or_default(),
+                when_condition: when_opt.map(|(_, expr)| expr.to_string()),
                 sql,
             })
         },
@@ -917,13 +924,13 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
 
     let create_index = map(
         rule! {
-            CREATE ~ SYNC? ~ AGGREGATING ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            CREATE ~ SYNC? ~ #table_index_type ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident
             ~ AS ~ #query
         },
-        |(_, opt_sync, _, _, opt_if_not_exists, index_name, _, query)| {
+        |(_, opt_sync, index_type, _, opt_if_not_exists, index_name, _, query)| {
             Statement::CreateIndex(CreateIndexStmt {
-                index_type: TableIndexType::Aggregating,
+                index_type,
                 if_not_exists: opt_if_not_exists.is_some(),
                 index_name,
                 query: Box::new(query),
@@ -934,10 +941,11 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
 
     let drop_index = map(
         rule! {
-            DROP ~ AGGREGATING ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            DROP ~ #table_index_type ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
         },
-        |(_, _, _, opt_if_exists, index)| {
+        |(_, index_type, _, opt_if_exists, index)| {
             Statement::DropIndex(DropIndexStmt {
+                index_type,
                 if_exists: opt_if_exists.is_some(),
                 index,
             })
@@ -946,10 +954,11 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
 
     let refresh_index = map(
         rule! {
-            REFRESH ~ AGGREGATING ~ INDEX ~ #ident ~ ( LIMIT ~ #literal_u64 )?
+            REFRESH ~ #table_index_type ~ INDEX ~ #ident ~ ( LIMIT ~ #literal_u64 )?
         },
-        |(_, _, _, index, opt_limit)| {
+        |(_, index_type, _, index, opt_limit)| {
             Statement::RefreshIndex(RefreshIndexStmt {
+                index_type,
                 index,
                 limit: opt_limit.map(|(_, limit)| limit),
             })
@@ -1000,6 +1009,19 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let refresh_ngram_index = map(
+        rule! {
+            REFRESH ~ NGRAM ~ INDEX ~ FOR ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, _, (catalog, database, table))| {
+            Statement::RefreshNgramIndex(RefreshNgramIndexStmt {
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let refresh_virtual_column = map(
         rule! {
             REFRESH ~ VIRTUAL ~ COLUMN ~ FOR ~ #dot_separated_idents_1_to_3
@@ -1519,6 +1541,43 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    // row access policy
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident ~ #row_access_policy
+        },
+        |(_, _, _, _, opt_if_not_exists, name, policy)| {
+            let stmt = CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                policy,
+            };
+            Statement::CreateRowAccessPolicy(stmt)
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            let stmt = DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropRowAccessPolicy(stmt)
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let create_network_policy = map(
         rule! {
             CREATE ~ NETWORK ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
@@ -1677,6 +1736,61 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_workload_group = map(
+        rule! {
+            CREATE ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ #workload_group_set_options
+        },
+        |(_, _, _, opt_if_not_exists, name, set_options)| {
+            let stmt = CreateWorkloadGroupStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                set_options,
+            };
+            Statement::CreateWorkloadGroup(stmt)
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident ~ ^SET
+             ~ #workload_group_set_options
+        },
+        |(_, _, _, opt_if_exists, name, _, set_options)| {
+            let stmt = AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                set_options,
+            };
+            Statement::AlterWorkloadGroup(stmt)
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropWorkloadGroup(stmt)
+        },
+    );
+    let describe_workload_group = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ WORKLOAD ~ ^GROUP ~ ^#ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescWorkloadGroup(DescWorkloadGroupStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_workload_groups = value(
+        Statement::ShowWorkloadGroups,
+        rule! { SHOW ~ WORKLOAD ~ ^GROUPS },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -1740,6 +1854,10 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ WORK? });
+    let abort = value(Statement::Abort, rule! { (ABORT | ROLLBACK) ~ WORK? });
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1764,7 +1882,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
-        // network policy / password policy
+        // network policy / password policy / workload group
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -1776,6 +1894,11 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
+            | #create_workload_group: "`CREATE WORKLOAD GROUP [IF NOT EXISTS] name [MAX_CONCURRENCY = <u64_literal>] [MAX_MEMORY_USAGE = <u64_literal>] [QUEUE_TIMEOUT = <u64_literal>] [USERS = ('user1' [, 'user2'])] [ROLES = ('role1' [, 'role2'])] [COMMENT = '<string_literal>']`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name SET [MAX_CONCURRENCY = <u64_literal>] ... [COMMENT = '<string_literal>']`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
+            | #describe_workload_group: "`DESC WORKLOAD GROUP name`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS`"
         ),
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
@@ -1815,9 +1938,10 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #stream_table
         ),
         rule!(
-            #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
-            | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
-            | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            #create_index: "`CREATE {AGGREGATING | VECTOR} INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
+            | #drop_index: "`DROP {AGGREGATING | VECTOR} INDEX [IF EXISTS] <index>`"
+            | #refresh_index: "`REFRESH {AGGREGATING | VECTOR} INDEX <index> [LIMIT <limit>]`"
+            | #refresh_ngram_index: "`REFRESH NGRAM INDEX FOR [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1872,6 +1996,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             #create_data_mask_policy: "`CREATE MASKING POLICY [IF NOT EXISTS] mask_name as (val1 val_type1 [, val type]) return type -> case`"
             | #drop_data_mask_policy: "`DROP MASKING POLICY [IF EXISTS] mask_name`"
             | #describe_data_mask_policy: "`DESC MASKING POLICY mask_name`"
+            | #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] policy_name AS (arg1 arg_type1 [, ...]) RETURNS BOOLEAN -> expr`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] policy_name`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY policy_name`"
         ),
         // share
         rule!(
@@ -1899,10 +2026,12 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
   [ SCHEDULE = { <num> MINUTE | USING CRON <expr> <time_zone> } ]
   [ SUSPEND_TASK_AFTER_NUM_FAILURES = <num> ]
   [ COMMENT = '<string_literal>' ]
+  [ AFTER <name> [ , <name> , ... ] ]
+  [ WHEN <boolean_expr> ]
 AS
   <sql>`"
          | #drop_task : "`DROP TASK [ IF EXISTS ] <name>`"
-         | #alter_task : "`ALTER TASK [ IF EXISTS ] <name> SUSPEND | RESUME | SET <option> = <value>` | UNSET <option> | MODIFY AS <sql>`"
+         | #alter_task : "`ALTER TASK [ IF EXISTS ] <name> SUSPEND | RESUME | SET <option> = <value>` | UNSET <option> | MODIFY AS <sql> | MODIFY WHEN <boolean_expr> | { ADD | REMOVE } AFTER <name> [ , <name> , ... ]`"
          | #show_tasks : "`SHOW TASKS [<show_limit>]`"
          | #desc_task : "`DESC | DESCRIBE TASK <name>`"
          | #execute_task: "`EXECUTE TASK <name>`"
@@ -1923,6 +2052,9 @@ AS
         | #drop_connection: "`DROP CONNECTION [IF EXISTS] <connection_name>`"
         | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
         | #show_connections: "`SHOW CONNECTIONS`"
+        | #begin : "`BEGIN [TRANSACTION]`"
+        | #commit : "`COMMIT [WORK]`"
+        | #abort : "`ABORT | ROLLBACK [WORK]`"
         ),
     ));
 
@@ -2652,6 +2784,23 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
         #rename_table
         | #rename_column
@@ -2663,6 +2812,8 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #add_row_access_policy
+        | #drop_row_access_policy
     )(i)
 }
 
@@ -2857,6 +3008,26 @@ pub fn alter_task_option(i: Input) -> IResult<AlterTaskOptions> {
             AlterTaskOptions::ModifyAs(sql)
         },
     );
+    let modify_when = map(
+        rule! {
+             MODIFY ~ WHEN ~ #expr
+        },
+        |(_, _, expr)| AlterTaskOptions::ModifyWhen(expr.to_string()),
+    );
+    let add_after = map(
+        rule! {
+             ADD ~ AFTER ~ #comma_separated_list1(ident)
+        },
+        |(_, _, tasks)| AlterTaskOptions::AddAfter(tasks.iter().map(|t| t.to_string()).collect()),
+    );
+    let remove_after = map(
+        rule! {
+             REMOVE ~ AFTER ~ #comma_separated_list1(ident)
+        },
+        |(_, _, tasks)| {
+            AlterTaskOptions::RemoveAfter(tasks.iter().map(|t| t.to_string()).collect())
+        },
+    );
     let set = map(
         rule! {
              SET
@@ -2882,6 +3053,9 @@ pub fn alter_task_option(i: Input) -> IResult<AlterTaskOptions> {
         #suspend
         | #resume
         | #modify_as
+        | #modify_when
+        | #add_after
+        | #remove_after
         | #set
         | #unset
     )(i)
@@ -3162,6 +3336,13 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
     ))(i)
 }
 
+pub fn table_index_type(i: Input) -> IResult<TableIndexType> {
+    alt((
+        value(TableIndexType::Aggregating, rule! { AGGREGATING }),
+        value(TableIndexType::Vector, rule! { VECTOR }),
+    ))(i)
+}
+
 pub fn presign_action(i: Input) -> IResult<PresignAction> {
     alt((
         value(PresignAction::Download, rule! { DOWNLOAD }),
@@ -3343,6 +3524,36 @@ pub fn password_set_options(i: Input) -> IResult<PasswordSetOptions> {
     )(i)
 }
 
+pub fn workload_group_set_options(i: Input) -> IResult<WorkloadGroupSetOptions> {
+    map(
+        rule! {
+             ( MAX_CONCURRENCY ~ ^Eq ~ ^#literal_u64 ) ?
+             ~ ( MAX_MEMORY_USAGE ~ ^Eq ~ ^#literal_u64 ) ?
+             ~ ( QUEUE_TIMEOUT ~ ^Eq ~ ^#literal_u64 ) ?
+             ~ ( USERS ~ ^Eq ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" ) ?
+             ~ ( ROLES ~ ^Eq ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" ) ?
+             ~ ( COMMENT ~ ^Eq ~ ^#literal_string)?
+        },
+        |(
+            opt_max_concurrency,
+            opt_max_memory_usage,
+            opt_queue_timeout,
+            opt_users,
+            opt_roles,
+            opt_comment,
+        )| {
+            WorkloadGroupSetOptions {
+                max_concurrency: opt_max_concurrency.map(|opt| opt.2),
+                max_memory_usage: opt_max_memory_usage.map(|opt| opt.2),
+                queue_timeout: opt_queue_timeout.map(|opt| opt.2),
+                users: opt_users.map(|opt| opt.3),
+                roles: opt_roles.map(|opt| opt.3),
+                comment: opt_comment.map(|opt| opt.2),
+            }
+        },
+    )(i)
+}
+
 pub fn password_unset_options(i: Input) -> IResult<PasswordUnSetOptions> {
     map(
         rule! {
@@ -314,6 +314,10 @@ pub enum TokenKind {
     //    reserved list.
     #[token("ALL", ignore(ascii_case))]
     ALL,
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
     ALLOWED_IP_LIST,
     #[token("ADD", ignore(ascii_case))]
@@ -322,6 +326,8 @@ pub enum TokenKind {
     AFTER,
     #[token("AGGREGATING", ignore(ascii_case))]
     AGGREGATING,
+    #[token("APPEND_ONLY", ignore(ascii_case))]
+    APPEND_ONLY,
     #[token("ANY", ignore(ascii_case))]
     ANY,
     #[token("ARGS", ignore(ascii_case))]
@@ -352,6 +358,8 @@ pub enum TokenKind {
     ANTI,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -392,6 +400,8 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -582,6 +592,8 @@ pub enum TokenKind {
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
@@ -656,6 +668,8 @@ pub enum TokenKind {
     SECONDARY,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     /// L2DISTANCE op, from https://github.com/pgvector/pgvector
     #[token("<->")]
     L2DISTANCE,
@@ -677,6 +691,10 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
+    #[token("MAX_MEMORY_USAGE", ignore(ascii_case))]
+    MAX_MEMORY_USAGE,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEMO", ignore(ascii_case))]
@@ -707,6 +725,8 @@ pub enum TokenKind {
     NETWORK,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NGRAM", ignore(ascii_case))]
+    NGRAM,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
@@ -791,6 +811,8 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUEUE_TIMEOUT", ignore(ascii_case))]
+    QUEUE_TIMEOUT,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
     #[token("RANGE", ignore(ascii_case))]
@@ -983,6 +1005,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -1037,6 +1061,8 @@ pub enum TokenKind {
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
@@ -1049,6 +1075,10 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WORK", ignore(ascii_case))]
+    WORK,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -222,6 +222,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &'ast Literal) {}
 
+    fn visit_placeholder(&mut self, _span: Span, _name: &'ast Option<Identifier>) {}
+
     fn visit_count_all(&mut self, _span: Span, window: &'ast Option<Window>) {
         if let Some(window) = window {
             self.visit_window(window);
@@ -387,6 +389,9 @@ pub trait Visitor<'ast>: Sized {
         if let CopyIntoLocationSource::Query(query) = &copy.src {
             self.visit_query(query)
         }
+        if let Some(partition_by) = &copy.partition_by {
+            self.visit_expr(partition_by)
+        }
     }
 
     fn visit_call(&mut self, _call: &'ast CallStmt) {}
@@ -569,6 +574,7 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
+    fn visit_refresh_ngram_index(&mut self, _stmt: &'ast RefreshNgramIndexStmt) {}
 
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
@@ -662,6 +668,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &'ast DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &'ast AlterNetworkPolicyStmt) {}
@@ -682,6 +694,16 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_password_policies(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
+    fn visit_create_workload_group(&mut self, _stmt: &'ast CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &'ast AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &'ast DropWorkloadGroupStmt) {}
+
+    fn visit_desc_workload_group(&mut self, _stmt: &'ast DescWorkloadGroupStmt) {}
+
+    fn visit_show_workload_groups(&mut self) {}
+
     fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
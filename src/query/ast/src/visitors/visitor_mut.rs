@@ -220,6 +220,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &mut Literal) {}
 
+    fn visit_placeholder(&mut self, _span: Span, _name: &mut Option<Identifier>) {}
+
     fn visit_count_all(&mut self, _span: Span, window: &mut Option<Window>) {
         if let Some(window) = window {
             match window {
@@ -403,6 +405,9 @@ pub trait VisitorMut: Sized {
         if let CopyIntoLocationSource::Query(query) = &mut copy.src {
             self.visit_query(query)
         }
+        if let Some(partition_by) = &mut copy.partition_by {
+            self.visit_expr(partition_by)
+        }
     }
 
     fn visit_call(&mut self, _call: &mut CallStmt) {}
@@ -583,6 +588,7 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
+    fn visit_refresh_ngram_index(&mut self, _stmt: &mut RefreshNgramIndexStmt) {}
 
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
@@ -676,6 +682,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &mut DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &mut AlterNetworkPolicyStmt) {}
@@ -696,6 +708,16 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_password_policies(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
+    fn visit_create_workload_group(&mut self, _stmt: &mut CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &mut AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &mut DropWorkloadGroupStmt) {}
+
+    fn visit_desc_workload_group(&mut self, _stmt: &mut DescWorkloadGroupStmt) {}
+
+    fn visit_show_workload_groups(&mut self) {}
+
     fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
@@ -131,6 +131,7 @@ fn test_statement() {
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
         r#"create stream if not exists test2.s2 on table test.t at (stream => test1.s1) comment = 'this is a stream';"#,
+        r#"create stream s on table t append_only = false;"#,
         r#"show full streams from default.test2 like 's%';"#,
         r#"describe stream test2.s2;"#,
         r#"drop stream if exists test2.s2;"#,
@@ -216,6 +217,8 @@ fn test_statement() {
         r#"ALTER TABLE t DROP COLUMN b;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask;"#,
         r#"ALTER TABLE t MODIFY COLUMN b UNSET MASKING POLICY;"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY rap ON (region, id);"#,
+        r#"ALTER TABLE t DROP ROW ACCESS POLICY rap;"#,
         r#"ALTER TABLE t MODIFY COLUMN a int DEFAULT 1, COLUMN b float;"#,
         r#"ALTER TABLE t MODIFY COLUMN a int NULL DEFAULT 1, COLUMN b float NOT NULL COMMENT 'column b';"#,
         r#"ALTER TABLE t MODIFY COLUMN a int;"#,
@@ -337,6 +340,7 @@ fn test_statement() {
                     error_on_column_count_mismatch = FALSE
                 )
                 size_limit=10;"#,
+        r#"COPY INTO mytable FROM @my_stage VALIDATION_MODE = RETURN_ERRORS;"#,
         r#"COPY INTO 's3://mybucket/data.csv'
                 FROM mytable
                 FILE_FORMAT = (
@@ -355,6 +359,7 @@ fn test_statement() {
                     record_delimiter = '\n'
                     skip_header = 1
                 );"#,
+        r#"COPY INTO @my_stage FROM mytable PARTITION BY d;"#,
         r#"COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
                 CREDENTIALS = (
@@ -499,12 +504,18 @@ fn test_statement() {
         r#"CREATE MASKING POLICY email_mask AS (val STRING) RETURNS STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
         r#"DESC MASKING POLICY email_mask"#,
         r#"DROP MASKING POLICY IF EXISTS email_mask"#,
+        r#"CREATE ROW ACCESS POLICY IF NOT EXISTS rap AS (region STRING) RETURNS BOOLEAN -> region = 'EU' COMMENT = 'eu only'"#,
+        r#"DESC ROW ACCESS POLICY rap"#,
+        r#"DROP ROW ACCESS POLICY IF EXISTS rap"#,
         r#"CREATE VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"ALTER VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMN FOR t"#,
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
+        r#"REFRESH NGRAM INDEX FOR t"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"CREATE WORKLOAD GROUP IF NOT EXISTS etl MAX_CONCURRENCY=4 MAX_MEMORY_USAGE=1073741824 QUEUE_TIMEOUT=60 USERS=('u1','u2') ROLES=('r1') COMMENT='etl queries'"#,
+        r#"ALTER WORKLOAD GROUP etl SET MAX_CONCURRENCY=8 USERS=()"#,
         // tasks
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' AS SELECT * FROM MyTable1"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 6 * * *' 'America/Los_Angeles' COMMENT = 'serverless + cron' AS insert into t (c1, c2) values (1, 2), (3, 4)"#,
@@ -518,6 +529,10 @@ fn test_statement() {
         r#"SHOW TASKS"#,
         r#"EXECUTE TASK MyTask"#,
         r#"DESC TASK MyTask"#,
+        r#"CREATE TASK IF NOT EXISTS MyTask1 AFTER MyTask0, MyTask2 WHEN stream_has_data('s1') AS VACUUM TABLE t"#,
+        r#"ALTER TASK MyTask1 MODIFY WHEN stream_has_data('s1')"#,
+        r#"ALTER TASK MyTask1 ADD AFTER MyTask0, MyTask2"#,
+        r#"ALTER TASK MyTask1 REMOVE AFTER MyTask0"#,
         r#"CREATE CONNECTION IF NOT EXISTS my_conn STORAGE_TYPE='s3'"#,
         r#"CREATE CONNECTION IF NOT EXISTS my_conn STORAGE_TYPE='s3' any_arg='any_value'"#,
         r#"DROP CONNECTION IF EXISTS my_conn;"#,
@@ -533,6 +548,11 @@ fn test_statement() {
         r#"ALTER PIPE mypipe SET PIPE_EXECUTION_PAUSED = true"#,
         r#"DROP PIPE mypipe"#,
         r#"DESC PIPE mypipe"#,
+        r#"BEGIN"#,
+        r#"BEGIN TRANSACTION"#,
+        r#"COMMIT"#,
+        r#"ROLLBACK WORK"#,
+        r#"ABORT"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- 96477300355	31379974136	3.074486292973661\nselect 34343",
         "-- xxxxx\n  select 34343;",
@@ -809,6 +829,7 @@ fn test_expr() {
         r#"ARRAY_APPLY([1,2,3], x -> x + 1)"#,
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
         r#"(current_timestamp, current_timestamp(), now())"#,
+        r#"(?, :k1)"#,
     ];
 
     for case in cases {
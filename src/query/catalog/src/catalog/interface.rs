@@ -27,6 +27,8 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreatePipeReply;
+use databend_common_meta_app::schema::CreatePipeReq;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReply;
@@ -36,6 +38,8 @@ use databend_common_meta_app::schema::DropDatabaseReply;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReply;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropPipeReply;
+use databend_common_meta_app::schema::DropPipeReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableReply;
 use databend_common_meta_app::schema::DropVirtualColumnReply;
@@ -46,6 +50,9 @@ use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::GcDroppedTableResp;
 use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
+use databend_common_meta_app::schema::GetPipeLoadedFileReply;
+use databend_common_meta_app::schema::GetPipeLoadedFileReq;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::IndexMeta;
@@ -54,15 +61,19 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListPipesReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
+use databend_common_meta_app::schema::PipeInfo;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -74,6 +85,10 @@ use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReply;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdatePipeMetaReply;
+use databend_common_meta_app::schema::UpdatePipeMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -252,11 +267,26 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply>;
 
+    /// Update the meta of several tables atomically, used to commit a transaction.
+    async fn update_multi_table_meta(
+        &self,
+        _req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        Err(ErrorCode::Unimplemented(
+            "'update_multi_table_meta' not implemented",
+        ))
+    }
+
     async fn set_table_column_mask_policy(
         &self,
         req: SetTableColumnMaskPolicyReq,
     ) -> Result<SetTableColumnMaskPolicyReply>;
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply>;
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply>;
 
     async fn get_table_copied_file_info(
@@ -282,6 +312,39 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
 
     async fn list_locks(&self, req: ListLocksReq) -> Result<Vec<LockInfo>>;
 
+    /// Pipe
+
+    async fn create_pipe(&self, _req: CreatePipeReq) -> Result<CreatePipeReply> {
+        Err(ErrorCode::Unimplemented("'create_pipe' not implemented"))
+    }
+
+    async fn drop_pipe(&self, _req: DropPipeReq) -> Result<DropPipeReply> {
+        Err(ErrorCode::Unimplemented("'drop_pipe' not implemented"))
+    }
+
+    async fn get_pipe(&self, _req: GetPipeReq) -> Result<Arc<PipeInfo>> {
+        Err(ErrorCode::Unimplemented("'get_pipe' not implemented"))
+    }
+
+    async fn update_pipe_meta(&self, _req: UpdatePipeMetaReq) -> Result<UpdatePipeMetaReply> {
+        Err(ErrorCode::Unimplemented(
+            "'update_pipe_meta' not implemented",
+        ))
+    }
+
+    async fn list_pipes(&self, _req: ListPipesReq) -> Result<Vec<Arc<PipeInfo>>> {
+        Err(ErrorCode::Unimplemented("'list_pipes' not implemented"))
+    }
+
+    async fn get_pipe_loaded_file_info(
+        &self,
+        _req: GetPipeLoadedFileReq,
+    ) -> Result<GetPipeLoadedFileReply> {
+        Err(ErrorCode::Unimplemented(
+            "'get_pipe_loaded_file_info' not implemented",
+        ))
+    }
+
     /// Table function
 
     // Get function by name.
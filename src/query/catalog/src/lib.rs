@@ -25,5 +25,6 @@ pub mod statistics;
 pub mod table_args;
 pub mod table_context;
 pub mod table_function;
+pub mod txn;
 
 pub mod table;
@@ -20,6 +20,7 @@ use databend_common_exception::Result;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::StageFileInfo;
@@ -33,6 +34,9 @@ pub struct StageTableInfo {
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
     pub is_select: bool,
+    /// Whether the blocks appended to the stage carry a last column of the partition of each
+    /// row, they are unloaded to the directory of the partition then.
+    pub is_partitioned: bool,
 }
 
 impl StageTableInfo {
@@ -40,6 +44,17 @@ impl StageTableInfo {
         self.schema.clone()
     }
 
+    /// The schema of the unloaded files, which excludes the partition column.
+    pub fn file_schema(&self) -> Arc<TableSchema> {
+        if self.is_partitioned {
+            let mut fields = self.schema.fields().clone();
+            fields.pop();
+            TableSchemaRefExt::create(fields)
+        } else {
+            self.schema.clone()
+        }
+    }
+
     pub fn desc(&self) -> String {
         self.stage_info.stage_name.clone()
     }
@@ -21,6 +21,7 @@ mod projection;
 mod pruning_statistics;
 mod pushdown;
 mod stream_column;
+mod vector_index;
 
 pub use agg_index::*;
 pub use datasource::*;
@@ -31,3 +32,4 @@ pub use projection::Projection;
 pub use pruning_statistics::PruningStatistics;
 pub use pushdown::*;
 pub use stream_column::*;
+pub use vector_index::VectorIndexInfo;
@@ -22,6 +22,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 
 use super::AggIndexInfo;
+use super::VectorIndexInfo;
 use crate::plan::Projection;
 
 /// Information of Virtual Columns.
@@ -93,6 +94,8 @@ pub struct PushDownInfo {
     pub lazy_materialization: bool,
     /// Aggregating index information.
     pub agg_index: Option<AggIndexInfo>,
+    /// Vector index information, used to prune the blocks of a top-k distance query.
+    pub vector_index: Option<VectorIndexInfo>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -139,6 +139,15 @@ impl StreamColumnMeta {
     }
 }
 
+/// The side of the changes read by a stream in standard mode.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeType {
+    /// Rows of the blocks that are added to the table since the stream offset.
+    Insert,
+    /// Rows of the blocks that are removed from the table since the stream offset.
+    Delete,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum StreamColumnType {
     OriginVersion,
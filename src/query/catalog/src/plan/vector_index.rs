@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::F32;
+
+/// The top-k distance query that can be answered by a vector index.
+///
+/// It is `ORDER BY <distance_func>(<column>, <target>) LIMIT <limit>`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    pub index_id: u64,
+    /// The name of the indexed column.
+    pub column_name: String,
+    /// `cosine_distance` or `l2_distance`.
+    pub func_name: String,
+    pub target: Vec<F32>,
+    pub limit: usize,
+}
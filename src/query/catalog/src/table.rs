@@ -39,6 +39,7 @@ use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 
 use crate::lock::Lock;
+use crate::plan::ChangeType;
 use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartStatistics;
@@ -289,6 +290,17 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Returns a table that only reads the given side of the changes, see [`ChangeType`].
+    fn with_change_type(&self, change_type: ChangeType) -> Result<Arc<dyn Table>> {
+        let _ = change_type;
+
+        Err(ErrorCode::Unimplemented(format!(
+            "table {}, of engine type {}, does not support reading changes",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     fn get_block_thresholds(&self) -> BlockThresholds {
         BlockThresholds {
             max_rows_per_block: DEFAULT_BLOCK_MAX_ROWS,
@@ -389,6 +401,11 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        // The table mutated in the current transaction is read with the uncommitted changes.
+        let buffered_table_info = ctx.txn_mgr().lock().get_table_from_buffer(tid);
+        if let Some(table_info) = buffered_table_info {
+            return catalog.get_table_by_info(&table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info = TableInfo {
             ident,
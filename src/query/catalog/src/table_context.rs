@@ -29,6 +29,7 @@ use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
@@ -56,6 +57,7 @@ use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::query_kind::QueryKind;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
@@ -83,6 +85,8 @@ pub enum ProcessInfoState {
     Query,
     Aborting,
     Idle,
+    /// Waiting in the queue of a workload group.
+    Queued,
 }
 
 impl Display for ProcessInfoState {
@@ -91,6 +95,7 @@ impl Display for ProcessInfoState {
             ProcessInfoState::Query => write!(f, "Query"),
             ProcessInfoState::Aborting => write!(f, "Aborting"),
             ProcessInfoState::Idle => write!(f, "Idle"),
+            ProcessInfoState::Queued => write!(f, "Queued"),
         }
     }
 }
@@ -102,6 +107,25 @@ pub struct StageAttachment {
     pub copy_options: Option<BTreeMap<String, String>>,
 }
 
+/// The values of the query parameters, `?` are bound by position and `:name` by name.
+#[derive(Debug, Clone, Default)]
+pub struct QueryParams {
+    pub positional: Vec<QueryParamValue>,
+    pub named: BTreeMap<String, QueryParamValue>,
+    /// Plan the statement without values to describe it, the placeholders are bound as `NULL`
+    /// and their types are inferred from the expressions they are compared with.
+    pub describe: bool,
+}
+
+/// The value of a query parameter.
+#[derive(Debug, Clone)]
+pub enum QueryParamValue {
+    /// A JSON value of the HTTP query API.
+    Json(serde_json::Value),
+    /// A typed value, e.g. of the binary protocol of MySQL.
+    Scalar(Scalar),
+}
+
 #[async_trait::async_trait]
 pub trait TableContext: Send + Sync {
     fn as_any(&self) -> &dyn Any;
@@ -176,6 +200,7 @@ pub trait TableContext: Send + Sync {
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
+    fn get_query_params(&self) -> Option<QueryParams>;
     fn get_last_query_id(&self, index: i32) -> String;
     fn get_query_id_history(&self) -> HashSet<String>;
     fn get_result_cache_key(&self, query_id: &str) -> Option<String>;
@@ -242,4 +267,6 @@ pub trait TableContext: Send + Sync {
     fn set_runtime_filter(&self, filters: (usize, Vec<Expr<String>>));
 
     fn get_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
+
+    fn txn_mgr(&self) -> TxnManagerRef;
 }
@@ -0,0 +1,163 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement commits its own changes.
+    #[default]
+    AutoCommit,
+    /// In an explicit transaction started by `BEGIN`.
+    Active,
+    /// A statement failed in the explicit transaction, it can only be rolled back.
+    Fail,
+}
+
+/// The uncommitted changes of a transaction.
+#[derive(Clone, Debug, Default)]
+struct TxnBuffer {
+    // table_id -> (the request to commit, the table info with the uncommitted table meta)
+    mutated_tables: HashMap<u64, (UpdateTableMetaReq, TableInfo)>,
+}
+
+/// Manage the explicit transaction of a session.
+///
+/// In an explicit transaction, the new table metas are kept here instead of being committed
+/// to the meta server, and they are committed together by `COMMIT` in one meta transaction.
+#[derive(Clone, Debug, Default)]
+pub struct TxnManager {
+    state: TxnState,
+    txn_buffer: TxnBuffer,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager::default()))
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    /// Leave the transaction and discard all the uncommitted changes.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.txn_buffer = TxnBuffer::default();
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    /// Buffer the new meta of a table, `table_info` is the table info before the update.
+    ///
+    /// If the table has been mutated in this transaction, the request is merged with the
+    /// buffered one, and the table version to match stays the one seen by the first mutation.
+    pub fn update_table_meta(&mut self, req: UpdateTableMetaReq, table_info: &TableInfo) {
+        let mut new_table_info = table_info.clone();
+        new_table_info.meta = req.new_table_meta.clone();
+
+        match self.txn_buffer.mutated_tables.entry(req.table_id) {
+            Entry::Vacant(v) => {
+                v.insert((req, new_table_info));
+            }
+            Entry::Occupied(mut v) => {
+                let (buffered_req, buffered_table_info) = v.get_mut();
+                buffered_req.new_table_meta = req.new_table_meta;
+                if let Some(copied_files) = req.copied_files {
+                    match &mut buffered_req.copied_files {
+                        Some(buffered) => {
+                            buffered.file_info.extend(copied_files.file_info);
+                            buffered.expire_at = copied_files.expire_at;
+                            buffered.fail_if_duplicated |= copied_files.fail_if_duplicated;
+                        }
+                        None => buffered_req.copied_files = Some(copied_files),
+                    }
+                }
+                for stream_meta in req.update_stream_meta {
+                    match buffered_req
+                        .update_stream_meta
+                        .iter_mut()
+                        .find(|v| v.stream_id == stream_meta.stream_id)
+                    {
+                        Some(buffered) => buffered.options = stream_meta.options,
+                        None => buffered_req.update_stream_meta.push(stream_meta),
+                    }
+                }
+                if req.deduplicated_label.is_some() {
+                    buffered_req.deduplicated_label = req.deduplicated_label;
+                }
+                *buffered_table_info = new_table_info;
+            }
+        }
+    }
+
+    /// Get the table info with the uncommitted table meta, if the table is mutated in this transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.txn_buffer
+            .mutated_tables
+            .get(&table_id)
+            .map(|(_, table_info)| table_info.clone())
+    }
+
+    /// The catalogs of the tables mutated in this transaction.
+    pub fn mutated_catalogs(&self) -> Vec<String> {
+        let mut catalogs = self
+            .txn_buffer
+            .mutated_tables
+            .values()
+            .map(|(_, table_info)| table_info.catalog().to_string())
+            .collect::<Vec<_>>();
+        catalogs.sort();
+        catalogs.dedup();
+        catalogs
+    }
+
+    pub fn req(&self) -> UpdateMultiTableMetaReq {
+        UpdateMultiTableMetaReq {
+            update_table_metas: self
+                .txn_buffer
+                .mutated_tables
+                .values()
+                .map(|(req, _)| req.clone())
+                .collect(),
+        }
+    }
+}
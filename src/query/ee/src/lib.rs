@@ -17,6 +17,7 @@ pub mod background_service;
 pub mod data_mask;
 pub mod enterprise_services;
 pub mod license;
+pub mod row_access_policy;
 pub mod storage_encryption;
 pub mod storages;
 pub mod stream;
@@ -35,6 +35,7 @@ use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::MODE_APPEND_ONLY;
+use databend_common_storages_stream::stream_table::MODE_STANDARD;
 use databend_common_storages_stream::stream_table::OPT_KEY_DATABASE_NAME;
 use databend_common_storages_stream::stream_table::OPT_KEY_MODE;
 use databend_common_storages_stream::stream_table::OPT_KEY_TABLE_ID;
@@ -117,7 +118,6 @@ impl StreamHandler for RealStreamHandler {
                 );
                 options.insert(OPT_KEY_TABLE_ID.to_string(), table_id.to_string());
                 options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
-                options.insert(OPT_KEY_MODE.to_string(), MODE_APPEND_ONLY.to_string());
                 let fuse_table = FuseTable::try_from_table(table.as_ref())?;
                 if let Some(snapshot_loc) = fuse_table.snapshot_loc().await? {
                     options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
@@ -125,6 +125,13 @@ impl StreamHandler for RealStreamHandler {
             }
         }
 
+        let mode = if plan.append_only {
+            MODE_APPEND_ONLY
+        } else {
+            MODE_STANDARD
+        };
+        options.insert(OPT_KEY_MODE.to_string(), mode.to_string());
+
         let req = CreateTableReq {
             if_not_exists: plan.if_not_exists,
             name_ident: TableNameIdent {
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use databend_common_meta_store::MetaStore;
+
+#[async_trait::async_trait]
+pub trait RowAccessPolicyHandler: Sync + Send {
+    async fn create_row_access_policy(
+        &self,
+        meta_api: Arc<MetaStore>,
+        req: CreateRowAccessPolicyReq,
+    ) -> Result<()>;
+
+    async fn drop_row_access_policy(
+        &self,
+        meta_api: Arc<MetaStore>,
+        req: DropRowAccessPolicyReq,
+    ) -> Result<()>;
+
+    async fn get_row_access_policy(
+        &self,
+        meta_api: Arc<MetaStore>,
+        tenant: String,
+        name: String,
+    ) -> Result<RowAccessPolicyMeta>;
+}
+
+pub struct RowAccessPolicyHandlerWrapper {
+    handler: Box<dyn RowAccessPolicyHandler>,
+}
+
+impl RowAccessPolicyHandlerWrapper {
+    pub fn new(handler: Box<dyn RowAccessPolicyHandler>) -> Self {
+        Self { handler }
+    }
+
+    pub async fn create_row_access_policy(
+        &self,
+        meta_api: Arc<MetaStore>,
+        req: CreateRowAccessPolicyReq,
+    ) -> Result<()> {
+        self.handler.create_row_access_policy(meta_api, req).await
+    }
+
+    pub async fn drop_row_access_policy(
+        &self,
+        meta_api: Arc<MetaStore>,
+        req: DropRowAccessPolicyReq,
+    ) -> Result<()> {
+        self.handler.drop_row_access_policy(meta_api, req).await
+    }
+
+    pub async fn get_row_access_policy(
+        &self,
+        meta_api: Arc<MetaStore>,
+        tenant: String,
+        name: String,
+    ) -> Result<RowAccessPolicyMeta> {
+        self.handler
+            .get_row_access_policy(meta_api, tenant, name)
+            .await
+    }
+}
+
+pub fn get_row_access_policy_handler() -> Arc<RowAccessPolicyHandlerWrapper> {
+    GlobalInstance::get()
+}
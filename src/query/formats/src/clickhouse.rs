@@ -16,6 +16,8 @@ use std::str::FromStr;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_meta_app::principal::StageFileFormatType;
 
 const SUFFIX_WITH_NAMES_AND_TYPES: &str = "withnamesandtypes";
@@ -37,10 +39,18 @@ pub struct ClickhouseSuffix {
     pub json: Option<ClickhouseTypeSuffixJson>,
 }
 
+/// Binary formats of ClickHouse, they have no counterpart in `StageFileFormatType`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClickhouseBinaryFormat {
+    RowBinary,
+    Native,
+}
+
 #[derive(Default, Clone)]
 pub struct ClickhouseFormatType {
     pub typ: StageFileFormatType,
     pub suffixes: ClickhouseSuffix,
+    pub binary: Option<ClickhouseBinaryFormat>,
 }
 
 fn try_remove_suffix<'a>(name: &'a str, suffix: &str) -> (&'a str, bool) {
@@ -67,6 +77,19 @@ impl ClickhouseFormatType {
             }
         }
 
+        let binary = match base {
+            "rowbinary" => Some(ClickhouseBinaryFormat::RowBinary),
+            "native" if suffixes.headers == 0 => Some(ClickhouseBinaryFormat::Native),
+            _ => None,
+        };
+        if binary.is_some() {
+            return Ok(ClickhouseFormatType {
+                typ: StageFileFormatType::None,
+                suffixes,
+                binary,
+            });
+        }
+
         if base.starts_with("json") {
             let mut json = ClickhouseTypeSuffixJson::default();
             (base, json.is_eachrow) = try_remove_suffix(base, SUFFIX_EACHROW);
@@ -90,6 +113,258 @@ impl ClickhouseFormatType {
         Ok(ClickhouseFormatType {
             typ: format_type,
             suffixes,
+            binary: None,
+        })
+    }
+}
+
+/// Returns the ClickHouse type name used in the headers of `RowBinaryWithNamesAndTypes`
+/// and `Native`, the value layout of each type follows the ClickHouse one.
+pub fn clickhouse_type_name(data_type: &DataType) -> Result<String> {
+    let name = match data_type {
+        DataType::Boolean => "Bool".to_string(),
+        DataType::String => "String".to_string(),
+        DataType::Number(n) => match n {
+            NumberDataType::UInt8 => "UInt8",
+            NumberDataType::UInt16 => "UInt16",
+            NumberDataType::UInt32 => "UInt32",
+            NumberDataType::UInt64 => "UInt64",
+            NumberDataType::Int8 => "Int8",
+            NumberDataType::Int16 => "Int16",
+            NumberDataType::Int32 => "Int32",
+            NumberDataType::Int64 => "Int64",
+            NumberDataType::Float32 => "Float32",
+            NumberDataType::Float64 => "Float64",
+        }
+        .to_string(),
+        DataType::Decimal(d) => {
+            let size = d.size();
+            format!("Decimal({}, {})", size.precision, size.scale)
+        }
+        DataType::Date => "Date32".to_string(),
+        DataType::Timestamp => "DateTime64(6)".to_string(),
+        DataType::Nullable(inner) => format!("Nullable({})", clickhouse_type_name(inner)?),
+        DataType::Array(inner) => format!("Array({})", clickhouse_type_name(inner)?),
+        DataType::Map(box DataType::Tuple(kv)) if kv.len() == 2 => format!(
+            "Map({}, {})",
+            clickhouse_type_name(&kv[0])?,
+            clickhouse_type_name(&kv[1])?
+        ),
+        DataType::Tuple(fields) => format!(
+            "Tuple({})",
+            fields
+                .iter()
+                .map(clickhouse_type_name)
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "data type {} is not supported by ClickHouse binary formats",
+                data_type
+            )));
+        }
+    };
+    Ok(name)
+}
+
+/// The type of a column in the ClickHouse binary formats, parsed from its name in the
+/// headers of `RowBinaryWithNamesAndTypes` and `Native`. It decides the layout of the values,
+/// which may differ from the one of the databend type the column is loaded into.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClickhouseType {
+    Bool,
+    Number(NumberDataType),
+    Decimal {
+        precision: u8,
+        scale: u8,
+    },
+    String,
+    Date,
+    Date32,
+    DateTime,
+    DateTime64(u8),
+    Nullable(Box<ClickhouseType>),
+    LowCardinality(Box<ClickhouseType>),
+    Array(Box<ClickhouseType>),
+    /// Stored as an array of `Tuple(key, value)`, which is the inner type.
+    Map(Box<ClickhouseType>),
+    Tuple(Vec<ClickhouseType>),
+}
+
+impl ClickhouseType {
+    pub fn parse(name: &str) -> Result<ClickhouseType> {
+        Self::parse_name(name.trim()).ok_or_else(|| {
+            ErrorCode::BadBytes(format!("unknown or unsupported ClickHouse type {}", name))
         })
     }
+
+    /// The type in which the values of `data_type` are written.
+    pub fn from_data_type(data_type: &DataType) -> Result<ClickhouseType> {
+        Self::parse(&clickhouse_type_name(data_type)?)
+    }
+
+    fn parse_name(name: &str) -> Option<ClickhouseType> {
+        let (ident, args) = match name.find('(') {
+            Some(i) if name.ends_with(')') => (
+                name[..i].trim(),
+                split_type_args(&name[i + 1..name.len() - 1])?,
+            ),
+            Some(_) => return None,
+            None => (name, vec![]),
+        };
+        let parse_box = |name: &str| Self::parse_name(name).map(Box::new);
+        let decimal = |precision: u8, scale: &str| {
+            Some(ClickhouseType::Decimal {
+                precision,
+                scale: scale.parse().ok()?,
+            })
+        };
+        let typ = match (ident, args.as_slice()) {
+            ("Bool", []) => ClickhouseType::Bool,
+            ("UInt8", []) => ClickhouseType::Number(NumberDataType::UInt8),
+            ("UInt16", []) => ClickhouseType::Number(NumberDataType::UInt16),
+            ("UInt32", []) => ClickhouseType::Number(NumberDataType::UInt32),
+            ("UInt64", []) => ClickhouseType::Number(NumberDataType::UInt64),
+            ("Int8", []) => ClickhouseType::Number(NumberDataType::Int8),
+            ("Int16", []) => ClickhouseType::Number(NumberDataType::Int16),
+            ("Int32", []) => ClickhouseType::Number(NumberDataType::Int32),
+            ("Int64", []) => ClickhouseType::Number(NumberDataType::Int64),
+            ("Float32", []) => ClickhouseType::Number(NumberDataType::Float32),
+            ("Float64", []) => ClickhouseType::Number(NumberDataType::Float64),
+            ("Decimal", [precision, scale]) => decimal(precision.parse().ok()?, scale)?,
+            ("Decimal32", [scale]) => decimal(9, scale)?,
+            ("Decimal64", [scale]) => decimal(18, scale)?,
+            ("Decimal128", [scale]) => decimal(38, scale)?,
+            ("Decimal256", [scale]) => decimal(76, scale)?,
+            ("String", []) => ClickhouseType::String,
+            ("Date", []) => ClickhouseType::Date,
+            ("Date32", []) => ClickhouseType::Date32,
+            // the time zone only affects how the values are displayed.
+            ("DateTime", [] | [_]) => ClickhouseType::DateTime,
+            ("DateTime64", [precision] | [precision, _]) => {
+                let precision = precision.parse().ok()?;
+                if precision > 9 {
+                    return None;
+                }
+                ClickhouseType::DateTime64(precision)
+            }
+            ("Nullable", [inner]) => ClickhouseType::Nullable(parse_box(inner)?),
+            ("LowCardinality", [inner]) => ClickhouseType::LowCardinality(parse_box(inner)?),
+            ("Array", [inner]) => ClickhouseType::Array(parse_box(inner)?),
+            ("Map", [key, value]) => ClickhouseType::Map(Box::new(ClickhouseType::Tuple(vec![
+                Self::parse_name(key)?,
+                Self::parse_name(value)?,
+            ]))),
+            ("Tuple", fields) if !fields.is_empty() => ClickhouseType::Tuple(
+                fields
+                    .iter()
+                    .map(|field| {
+                        // the elements of a named tuple are `name type`.
+                        Self::parse_name(field)
+                            .or_else(|| Self::parse_name(field.split_once(' ')?.1.trim()))
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            _ => return None,
+        };
+        Some(typ)
+    }
+
+    /// Whether the values of this type can be loaded into a column of `data_type`.
+    pub fn is_compatible(&self, data_type: &DataType) -> bool {
+        match (self, data_type) {
+            (ClickhouseType::LowCardinality(inner), _) => inner.is_compatible(data_type),
+            (ClickhouseType::Nullable(inner), DataType::Nullable(box data_type)) => {
+                inner.is_compatible(data_type)
+            }
+            (ClickhouseType::Nullable(_), _) => false,
+            (_, DataType::Nullable(box data_type)) => self.is_compatible(data_type),
+            (ClickhouseType::Bool, DataType::Boolean) => true,
+            (ClickhouseType::Number(NumberDataType::UInt8), DataType::Boolean) => true,
+            (ClickhouseType::Number(a), DataType::Number(b)) => a == b,
+            (ClickhouseType::Decimal { precision, scale }, DataType::Decimal(d)) => {
+                let size = d.size();
+                *scale == size.scale && *precision <= size.precision
+            }
+            (ClickhouseType::String, DataType::String) => true,
+            (ClickhouseType::Date | ClickhouseType::Date32, DataType::Date) => true,
+            (ClickhouseType::DateTime | ClickhouseType::DateTime64(_), DataType::Timestamp) => true,
+            (ClickhouseType::Array(inner), DataType::Array(box data_type))
+            | (ClickhouseType::Map(inner), DataType::Map(box data_type)) => {
+                inner.is_compatible(data_type)
+            }
+            (ClickhouseType::Tuple(types), DataType::Tuple(data_types)) => {
+                types.len() == data_types.len()
+                    && types
+                        .iter()
+                        .zip(data_types)
+                        .all(|(typ, data_type)| typ.is_compatible(data_type))
+            }
+            _ => false,
+        }
+    }
+
+    /// The size of a value if it is fixed.
+    pub fn fixed_size(&self) -> Option<usize> {
+        let size = match self {
+            ClickhouseType::Bool => 1,
+            ClickhouseType::Number(n) => n.bit_width() as usize / 8,
+            ClickhouseType::Decimal { precision, .. } => decimal_size(*precision),
+            ClickhouseType::Date => 2,
+            ClickhouseType::Date32 | ClickhouseType::DateTime => 4,
+            ClickhouseType::DateTime64(_) => 8,
+            _ => return None,
+        };
+        Some(size)
+    }
+
+    pub fn contains_low_cardinality(&self) -> bool {
+        match self {
+            ClickhouseType::LowCardinality(_) => true,
+            ClickhouseType::Nullable(inner)
+            | ClickhouseType::Array(inner)
+            | ClickhouseType::Map(inner) => inner.contains_low_cardinality(),
+            ClickhouseType::Tuple(types) => types.iter().any(|t| t.contains_low_cardinality()),
+            _ => false,
+        }
+    }
+}
+
+/// ClickHouse stores decimals in the smallest integer that fits the precision.
+pub fn decimal_size(precision: u8) -> usize {
+    match precision {
+        0..=9 => 4,
+        10..=18 => 8,
+        19..=38 => 16,
+        _ => 32,
+    }
+}
+
+// Split the arguments of a type by the top level commas.
+fn split_type_args(args: &str) -> Option<Vec<&str>> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut in_quote = false;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '\'' => in_quote = !in_quote,
+            '(' if !in_quote => depth += 1,
+            ')' if !in_quote => depth -= 1,
+            ',' if !in_quote && depth == 0 => {
+                result.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        if depth < 0 {
+            return None;
+        }
+    }
+    if depth != 0 || in_quote {
+        return None;
+    }
+    result.push(args[start..].trim());
+    Some(result)
 }
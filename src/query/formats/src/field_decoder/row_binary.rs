@@ -0,0 +1,505 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::io::Cursor;
+use std::io::Read;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumnBuilder;
+use databend_common_expression::types::date::check_date;
+use databend_common_expression::types::decimal::DecimalColumnBuilder;
+use databend_common_expression::types::nullable::NullableColumnBuilder;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::F32;
+use databend_common_expression::types::F64;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::ScalarRef;
+use databend_common_io::prelude::BinaryRead;
+use ethnum::i256;
+
+use crate::clickhouse::decimal_size;
+use crate::field_decoder::FieldDecoder;
+use crate::ClickhouseType;
+
+/// Decodes values with the ClickHouse binary layout.
+///
+/// `read_field` reads a single value as `RowBinary` does, `read_column` reads a
+/// whole column of `rows` values as `Native` does. The layout follows the ClickHouse
+/// type of the values, which must be compatible with the type of the column,
+/// see [`ClickhouseType::is_compatible`].
+///
+/// `skip_field` and `skip_column` only move over the values, they are used to find out
+/// whether a row or a block is complete before decoding it.
+#[derive(Default)]
+pub struct FieldDecoderRowBinary;
+
+impl FieldDecoder for FieldDecoderRowBinary {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl FieldDecoderRowBinary {
+    pub fn read_field<R: AsRef<[u8]>>(
+        &self,
+        column: &mut ColumnBuilder,
+        typ: &ClickhouseType,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        match (column, typ) {
+            // a single value is stored as the value itself.
+            (column, ClickhouseType::LowCardinality(typ)) => self.read_field(column, typ, reader),
+            (ColumnBuilder::Nullable(c), ClickhouseType::Nullable(typ)) => {
+                self.read_nullable(c, typ, reader)
+            }
+            (ColumnBuilder::Nullable(c), typ) => {
+                self.read_field(&mut c.builder, typ, reader)?;
+                c.validity.push(true);
+                Ok(())
+            }
+            (ColumnBuilder::Boolean(c), _) => {
+                c.push(read_bytes::<1, R>(reader)?[0] != 0);
+                Ok(())
+            }
+            (ColumnBuilder::Number(c), _) => read_number(c, reader),
+            (ColumnBuilder::Decimal(c), ClickhouseType::Decimal { precision, .. }) => {
+                read_decimal(c, *precision, reader)
+            }
+            (ColumnBuilder::Date(c), ClickhouseType::Date) => {
+                let days = u16::from_le_bytes(read_bytes(reader)?);
+                c.push(days as i32);
+                Ok(())
+            }
+            (ColumnBuilder::Date(c), _) => {
+                let days = i32::from_le_bytes(read_bytes(reader)?);
+                c.push(check_date(days as i64).map_err(ErrorCode::BadBytes)?);
+                Ok(())
+            }
+            (ColumnBuilder::Timestamp(c), ClickhouseType::DateTime) => {
+                let seconds = u32::from_le_bytes(read_bytes(reader)?);
+                c.push(seconds as i64 * MICROS_PER_SECOND);
+                Ok(())
+            }
+            (ColumnBuilder::Timestamp(c), ClickhouseType::DateTime64(precision)) => {
+                let value = i64::from_le_bytes(read_bytes(reader)?);
+                let micros = datetime64_to_micros(value, *precision)?;
+                c.push(check_timestamp(micros).map_err(ErrorCode::BadBytes)?);
+                Ok(())
+            }
+            (ColumnBuilder::String(c), _) => self.read_string(c, reader),
+            (
+                ColumnBuilder::Array(c) | ColumnBuilder::Map(c),
+                ClickhouseType::Array(typ) | ClickhouseType::Map(typ),
+            ) => self.read_array(c, typ, reader),
+            (ColumnBuilder::Tuple(fields), ClickhouseType::Tuple(types)) => {
+                for (field, typ) in fields.iter_mut().zip(types) {
+                    self.read_field(field, typ, reader)?;
+                }
+                Ok(())
+            }
+            (column, typ) => Err(mismatch(column, typ)),
+        }
+    }
+
+    pub fn read_column<R: AsRef<[u8]>>(
+        &self,
+        column: &mut ColumnBuilder,
+        typ: &ClickhouseType,
+        rows: usize,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        match (column, typ) {
+            (column, ClickhouseType::LowCardinality(typ)) => {
+                self.read_low_cardinality(column, typ, rows, reader)?;
+            }
+            (ColumnBuilder::Nullable(c), ClickhouseType::Nullable(typ)) => {
+                let nulls = read_slice(reader, rows)?;
+                self.read_column(&mut c.builder, typ, rows, reader)?;
+                for null in nulls {
+                    c.validity.push(null == 0);
+                }
+            }
+            (ColumnBuilder::Nullable(c), typ) => {
+                self.read_column(&mut c.builder, typ, rows, reader)?;
+                c.validity.extend_constant(rows, true);
+            }
+            (
+                ColumnBuilder::Array(c) | ColumnBuilder::Map(c),
+                ClickhouseType::Array(typ) | ClickhouseType::Map(typ),
+            ) => {
+                let base = *c.offsets.last().unwrap();
+                let mut offsets = Vec::with_capacity(rows);
+                let mut last = 0;
+                for _ in 0..rows {
+                    let offset = u64::from_le_bytes(read_bytes(reader)?);
+                    if offset < last {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "array offsets must be ascending, got {} after {}",
+                            offset, last
+                        )));
+                    }
+                    offsets.push(base + offset);
+                    last = offset;
+                }
+                check_remaining(reader, last as usize)?;
+                self.read_column(&mut c.builder, typ, last as usize, reader)?;
+                c.offsets.extend(offsets);
+            }
+            (ColumnBuilder::Tuple(fields), ClickhouseType::Tuple(types)) => {
+                for (field, typ) in fields.iter_mut().zip(types) {
+                    self.read_column(field, typ, rows, reader)?;
+                }
+            }
+            (column, typ) => {
+                for _ in 0..rows {
+                    self.read_field(column, typ, reader)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn skip_field<R: AsRef<[u8]>>(
+        &self,
+        typ: &ClickhouseType,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        match typ {
+            ClickhouseType::LowCardinality(typ) => self.skip_field(typ, reader),
+            ClickhouseType::Nullable(typ) => match read_bytes::<1, R>(reader)?[0] {
+                0 => self.skip_field(typ, reader),
+                _ => Ok(()),
+            },
+            ClickhouseType::String => {
+                let len = reader.read_uvarint()? as usize;
+                skip(reader, len)
+            }
+            ClickhouseType::Array(typ) | ClickhouseType::Map(typ) => {
+                let len = reader.read_uvarint()? as usize;
+                for _ in 0..len {
+                    self.skip_field(typ, reader)?;
+                }
+                Ok(())
+            }
+            ClickhouseType::Tuple(types) => {
+                for typ in types {
+                    self.skip_field(typ, reader)?;
+                }
+                Ok(())
+            }
+            _ => skip(reader, fixed_size(typ)?),
+        }
+    }
+
+    pub fn skip_column<R: AsRef<[u8]>>(
+        &self,
+        typ: &ClickhouseType,
+        rows: usize,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        match typ {
+            ClickhouseType::LowCardinality(typ) => {
+                let header = read_low_cardinality_header(reader)?;
+                if let Some(num_keys) = header.num_keys {
+                    self.skip_column(remove_nullable(typ), num_keys, reader)?;
+                }
+                let num_indexes = read_u64(reader)?;
+                skip(reader, checked_size(num_indexes, header.width)?)
+            }
+            ClickhouseType::Nullable(typ) => {
+                skip(reader, rows)?;
+                self.skip_column(typ, rows, reader)
+            }
+            ClickhouseType::String => {
+                for _ in 0..rows {
+                    let len = reader.read_uvarint()? as usize;
+                    skip(reader, len)?;
+                }
+                Ok(())
+            }
+            ClickhouseType::Array(typ) | ClickhouseType::Map(typ) => {
+                if rows == 0 {
+                    return Ok(());
+                }
+                // only the last offset is needed, it is the number of the inner values.
+                skip(reader, checked_size(rows - 1, 8)?)?;
+                let len = read_u64(reader)?;
+                self.skip_column(typ, len, reader)
+            }
+            ClickhouseType::Tuple(types) => {
+                for typ in types {
+                    self.skip_column(typ, rows, reader)?;
+                }
+                Ok(())
+            }
+            _ => skip(reader, checked_size(rows, fixed_size(typ)?)?),
+        }
+    }
+
+    fn read_nullable<R: AsRef<[u8]>>(
+        &self,
+        column: &mut NullableColumnBuilder<AnyType>,
+        typ: &ClickhouseType,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        if read_bytes::<1, R>(reader)?[0] != 0 {
+            column.push_null();
+        } else {
+            self.read_field(&mut column.builder, typ, reader)?;
+            column.validity.push(true);
+        }
+        Ok(())
+    }
+
+    fn read_string<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let len = reader.read_uvarint()? as usize;
+        let v = read_slice(reader, len)?;
+        column.put_slice(&v);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_array<R: AsRef<[u8]>>(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        typ: &ClickhouseType,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let len = reader.read_uvarint()? as usize;
+        check_remaining(reader, len)?;
+        for _ in 0..len {
+            self.read_field(&mut column.builder, typ, reader)?;
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    // `Native` stores the distinct values of each block as a dictionary, followed by the
+    // index of the value of each row. Index 0 of a nullable dictionary stands for NULL.
+    // Only the top level columns are supported, where the header directly precedes the data.
+    fn read_low_cardinality<R: AsRef<[u8]>>(
+        &self,
+        column: &mut ColumnBuilder,
+        typ: &ClickhouseType,
+        rows: usize,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let header = read_low_cardinality_header(reader)?;
+        let Some(num_keys) = header.num_keys else {
+            return Err(ErrorCode::BadBytes(
+                "LowCardinality column without dictionary",
+            ));
+        };
+        let mut dictionary =
+            ColumnBuilder::with_capacity(&column.data_type().remove_nullable(), num_keys);
+        self.read_column(&mut dictionary, remove_nullable(typ), num_keys, reader)?;
+        let dictionary = dictionary.build();
+
+        let num_indexes = read_u64(reader)?;
+        if num_indexes != rows {
+            return Err(ErrorCode::BadBytes(format!(
+                "expect {} LowCardinality indexes, got {}",
+                rows, num_indexes
+            )));
+        }
+        check_remaining(reader, checked_size(rows, header.width)?)?;
+        let is_nullable = matches!(typ, ClickhouseType::Nullable(_));
+        for _ in 0..rows {
+            let mut index = [0u8; 8];
+            reader.read_exact(&mut index[..header.width])?;
+            let index = u64::from_le_bytes(index) as usize;
+            if is_nullable && index == 0 {
+                column.push(ScalarRef::Null);
+                continue;
+            }
+            let value = dictionary.index(index).ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "LowCardinality index {} out of dictionary of {} keys",
+                    index, num_keys
+                ))
+            })?;
+            column.push(value);
+        }
+        Ok(())
+    }
+}
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+
+// The flags of the index type of `LowCardinality`, see `SerializationLowCardinality` of ClickHouse.
+const LOW_CARDINALITY_SHARED_DICTIONARIES: u64 = 1;
+const LOW_CARDINALITY_NEED_GLOBAL_DICTIONARY: u64 = 1 << 8;
+const LOW_CARDINALITY_HAS_ADDITIONAL_KEYS: u64 = 1 << 9;
+
+struct LowCardinalityHeader {
+    width: usize,
+    num_keys: Option<usize>,
+}
+
+fn read_low_cardinality_header<R: AsRef<[u8]>>(
+    reader: &mut Cursor<R>,
+) -> Result<LowCardinalityHeader> {
+    let version = u64::from_le_bytes(read_bytes(reader)?);
+    if version != LOW_CARDINALITY_SHARED_DICTIONARIES {
+        return Err(ErrorCode::BadBytes(format!(
+            "unknown LowCardinality serialization version {}",
+            version
+        )));
+    }
+    let index_type = u64::from_le_bytes(read_bytes(reader)?);
+    if index_type & LOW_CARDINALITY_NEED_GLOBAL_DICTIONARY != 0 {
+        return Err(ErrorCode::Unimplemented(
+            "global dictionary of LowCardinality is not supported",
+        ));
+    }
+    let width = match index_type & 0xff {
+        0 => 1,
+        1 => 2,
+        2 => 4,
+        3 => 8,
+        other => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unknown LowCardinality index type {}",
+                other
+            )));
+        }
+    };
+    let num_keys = match index_type & LOW_CARDINALITY_HAS_ADDITIONAL_KEYS {
+        0 => None,
+        _ => Some(read_u64(reader)?),
+    };
+    Ok(LowCardinalityHeader { width, num_keys })
+}
+
+fn remove_nullable(typ: &ClickhouseType) -> &ClickhouseType {
+    match typ {
+        ClickhouseType::Nullable(typ) => typ,
+        typ => typ,
+    }
+}
+
+fn fixed_size(typ: &ClickhouseType) -> Result<usize> {
+    typ.fixed_size()
+        .ok_or_else(|| ErrorCode::Internal(format!("ClickHouse type {:?} has no fixed size", typ)))
+}
+
+fn checked_size(count: usize, size: usize) -> Result<usize> {
+    count
+        .checked_mul(size)
+        .ok_or_else(|| ErrorCode::BadBytes("binary data is too large"))
+}
+
+fn mismatch(column: &ColumnBuilder, typ: &ClickhouseType) -> ErrorCode {
+    ErrorCode::BadBytes(format!(
+        "ClickHouse type {:?} can not be loaded as {}",
+        typ,
+        column.data_type()
+    ))
+}
+
+fn datetime64_to_micros(value: i64, precision: u8) -> Result<i64> {
+    let micros = if precision <= 6 {
+        value.checked_mul(10i64.pow(6 - precision as u32))
+    } else {
+        Some(value / 10i64.pow(precision as u32 - 6))
+    };
+    micros.ok_or_else(|| ErrorCode::BadBytes(format!("DateTime64 value {} out of range", value)))
+}
+
+fn read_number<R: AsRef<[u8]>>(
+    column: &mut NumberColumnBuilder,
+    reader: &mut Cursor<R>,
+) -> Result<()> {
+    match column {
+        NumberColumnBuilder::UInt8(c) => c.push(u8::from_le_bytes(read_bytes(reader)?)),
+        NumberColumnBuilder::UInt16(c) => c.push(u16::from_le_bytes(read_bytes(reader)?)),
+        NumberColumnBuilder::UInt32(c) => c.push(u32::from_le_bytes(read_bytes(reader)?)),
+        NumberColumnBuilder::UInt64(c) => c.push(u64::from_le_bytes(read_bytes(reader)?)),
+        NumberColumnBuilder::Int8(c) => c.push(i8::from_le_bytes(read_bytes(reader)?)),
+        NumberColumnBuilder::Int16(c) => c.push(i16::from_le_bytes(read_bytes(reader)?)),
+        NumberColumnBuilder::Int32(c) => c.push(i32::from_le_bytes(read_bytes(reader)?)),
+        NumberColumnBuilder::Int64(c) => c.push(i64::from_le_bytes(read_bytes(reader)?)),
+        NumberColumnBuilder::Float32(c) => {
+            c.push(F32::from(f32::from_le_bytes(read_bytes(reader)?)))
+        }
+        NumberColumnBuilder::Float64(c) => {
+            c.push(F64::from(f64::from_le_bytes(read_bytes(reader)?)))
+        }
+    }
+    Ok(())
+}
+
+// The size of a decimal follows the precision of the ClickHouse type.
+fn read_decimal<R: AsRef<[u8]>>(
+    column: &mut DecimalColumnBuilder,
+    precision: u8,
+    reader: &mut Cursor<R>,
+) -> Result<()> {
+    let v = match decimal_size(precision) {
+        4 => i256::from(i32::from_le_bytes(read_bytes(reader)?)),
+        8 => i256::from(i64::from_le_bytes(read_bytes(reader)?)),
+        16 => i256::from(i128::from_le_bytes(read_bytes(reader)?)),
+        _ => i256::from_le_bytes(read_bytes(reader)?),
+    };
+    match column {
+        DecimalColumnBuilder::Decimal128(c, _) => c.push(v.as_i128()),
+        DecimalColumnBuilder::Decimal256(c, _) => c.push(v),
+    }
+    Ok(())
+}
+
+fn read_bytes<const N: usize, R: AsRef<[u8]>>(reader: &mut Cursor<R>) -> Result<[u8; N]> {
+    let mut buf = [0u8; N];
+    reader
+        .read_exact(&mut buf)
+        .map_err(|_| ErrorCode::BadBytes("unexpected end of binary data"))?;
+    Ok(buf)
+}
+
+fn read_u64<R: AsRef<[u8]>>(reader: &mut Cursor<R>) -> Result<usize> {
+    Ok(u64::from_le_bytes(read_bytes(reader)?) as usize)
+}
+
+fn skip<R: AsRef<[u8]>>(reader: &mut Cursor<R>, len: usize) -> Result<()> {
+    check_remaining(reader, len)?;
+    reader.set_position(reader.position() + len as u64);
+    Ok(())
+}
+
+fn read_slice<R: AsRef<[u8]>>(reader: &mut Cursor<R>, len: usize) -> Result<Vec<u8>> {
+    check_remaining(reader, len)?;
+    let mut buf = vec![0u8; len];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+// Lengths come from the input, check them before allocating anything.
+fn check_remaining<R: AsRef<[u8]>>(reader: &Cursor<R>, len: usize) -> Result<()> {
+    if reader.remaining_slice().len() < len {
+        return Err(ErrorCode::BadBytes(format!(
+            "unexpected end of binary data, expect at least {} more bytes",
+            len
+        )));
+    }
+    Ok(())
+}
@@ -15,10 +15,13 @@
 mod csv;
 pub mod helpers;
 mod json;
+mod row_binary;
 mod values;
 
 pub use csv::write_csv_string;
 pub use csv::FieldEncoderCSV;
 pub use helpers::write_tsv_escaped_string;
 pub use json::FieldEncoderJSON;
+pub use row_binary::write_uvarint;
+pub use row_binary::FieldEncoderRowBinary;
 pub use values::FieldEncoderValues;
@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::Column;
+use databend_common_io::prelude::put_uvarint;
+
+/// Encodes values with the ClickHouse binary layout.
+///
+/// `write_field` writes a single value as `RowBinary` does, `write_column` writes a
+/// whole column as `Native` does. The column types must have been checked with
+/// `clickhouse_type_name` before.
+#[derive(Default)]
+pub struct FieldEncoderRowBinary;
+
+impl FieldEncoderRowBinary {
+    pub fn write_field(&self, column: &Column, row_index: usize, out_buf: &mut Vec<u8>) {
+        match column {
+            Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf),
+            Column::Boolean(c) => out_buf.push(c.get_bit(row_index) as u8),
+            Column::Number(c) => write_number(c, row_index, out_buf),
+            Column::Decimal(c) => write_decimal(c, row_index, out_buf),
+            Column::Date(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+            Column::Timestamp(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+            Column::String(c) => {
+                let v = unsafe { c.index_unchecked(row_index) };
+                write_uvarint(v.len() as u64, out_buf);
+                out_buf.extend_from_slice(v);
+            }
+            Column::Array(box c) | Column::Map(box c) => {
+                let start = c.offsets[row_index] as usize;
+                let end = c.offsets[row_index + 1] as usize;
+                write_uvarint((end - start) as u64, out_buf);
+                for i in start..end {
+                    self.write_field(&c.values, i, out_buf);
+                }
+            }
+            Column::Tuple(fields) => {
+                for f in fields {
+                    self.write_field(f, row_index, out_buf);
+                }
+            }
+            _ => unreachable!("unsupported column {:?} for RowBinary", column.data_type()),
+        }
+    }
+
+    pub fn write_column(&self, column: &Column, out_buf: &mut Vec<u8>) {
+        match column {
+            Column::Nullable(box c) => {
+                out_buf.extend(c.validity.iter().map(|v| !v as u8));
+                self.write_column(&c.column, out_buf);
+            }
+            Column::Array(box c) | Column::Map(box c) => self.write_array_column(c, out_buf),
+            Column::Tuple(fields) => {
+                for f in fields {
+                    self.write_column(f, out_buf);
+                }
+            }
+            _ => {
+                for row_index in 0..column.len() {
+                    self.write_field(column, row_index, out_buf);
+                }
+            }
+        }
+    }
+
+    fn write_nullable(
+        &self,
+        column: &NullableColumn<AnyType>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+    ) {
+        if !column.validity.get_bit(row_index) {
+            out_buf.push(1);
+        } else {
+            out_buf.push(0);
+            self.write_field(&column.column, row_index, out_buf);
+        }
+    }
+
+    fn write_array_column(&self, column: &ArrayColumn<AnyType>, out_buf: &mut Vec<u8>) {
+        let first = column.offsets[0];
+        let last = column.offsets[column.offsets.len() - 1];
+        for offset in column.offsets.iter().skip(1) {
+            out_buf.extend_from_slice(&(offset - first).to_le_bytes());
+        }
+        let values = column.values.slice(first as usize..last as usize);
+        self.write_column(&values, out_buf);
+    }
+}
+
+fn write_number(column: &NumberColumn, row_index: usize, out_buf: &mut Vec<u8>) {
+    match column {
+        NumberColumn::UInt8(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+        NumberColumn::UInt16(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+        NumberColumn::UInt32(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+        NumberColumn::UInt64(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+        NumberColumn::Int8(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+        NumberColumn::Int16(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+        NumberColumn::Int32(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+        NumberColumn::Int64(c) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+        NumberColumn::Float32(c) => out_buf.extend_from_slice(&c[row_index].0.to_le_bytes()),
+        NumberColumn::Float64(c) => out_buf.extend_from_slice(&c[row_index].0.to_le_bytes()),
+    }
+}
+
+// ClickHouse stores decimals in the smallest integer that fits the precision.
+fn write_decimal(column: &DecimalColumn, row_index: usize, out_buf: &mut Vec<u8>) {
+    match column {
+        DecimalColumn::Decimal128(c, size) => {
+            let v = c[row_index];
+            if size.precision <= 9 {
+                out_buf.extend_from_slice(&(v as i32).to_le_bytes());
+            } else if size.precision <= 18 {
+                out_buf.extend_from_slice(&(v as i64).to_le_bytes());
+            } else {
+                out_buf.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        DecimalColumn::Decimal256(c, _) => out_buf.extend_from_slice(&c[row_index].to_le_bytes()),
+    }
+}
+
+pub fn write_uvarint(v: u64, out_buf: &mut Vec<u8>) {
+    let mut buf = [0u8; 10];
+    let n = put_uvarint(&mut buf[..], v);
+    out_buf.extend_from_slice(&buf[..n]);
+}
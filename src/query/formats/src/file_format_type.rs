@@ -25,11 +25,16 @@ use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
 use crate::output_format::JSONOutputFormat;
 use crate::output_format::NDJSONOutputFormatBase;
+use crate::output_format::NativeOutputFormat;
 use crate::output_format::OutputFormat;
 use crate::output_format::ParquetOutputFormat;
+use crate::output_format::RowBinaryOutputFormat;
+use crate::output_format::RowBinaryWithNamesAndTypesOutputFormat;
+use crate::output_format::RowBinaryWithNamesOutputFormat;
 use crate::output_format::TSVOutputFormat;
 use crate::output_format::TSVWithNamesAndTypesOutputFormat;
 use crate::output_format::TSVWithNamesOutputFormat;
+use crate::ClickhouseBinaryFormat;
 use crate::ClickhouseFormatType;
 
 pub trait FileFormatTypeExt {
@@ -93,6 +98,24 @@ impl FileFormatOptionsExt {
         schema: TableSchemaRef,
         settings: &Settings,
     ) -> Result<Box<dyn OutputFormat>> {
+        if let Some(binary) = typ.binary {
+            let output: Box<dyn OutputFormat> = match (binary, typ.suffixes.headers) {
+                (ClickhouseBinaryFormat::RowBinary, 0) => {
+                    Box::new(RowBinaryOutputFormat::create(schema)?)
+                }
+                (ClickhouseBinaryFormat::RowBinary, 1) => {
+                    Box::new(RowBinaryWithNamesOutputFormat::create(schema)?)
+                }
+                (ClickhouseBinaryFormat::RowBinary, 2) => {
+                    Box::new(RowBinaryWithNamesAndTypesOutputFormat::create(schema)?)
+                }
+                (ClickhouseBinaryFormat::Native, _) => {
+                    Box::new(NativeOutputFormat::create(schema)?)
+                }
+                _ => unreachable!(),
+            };
+            return Ok(output);
+        }
         let params = FileFormatParams::default_by_type(typ.typ.clone())?;
         let mut options = FileFormatOptionsExt::create_from_clickhouse_format(typ, settings)?;
         options.get_output_format(schema, params)
@@ -168,6 +191,15 @@ impl FileFormatOptionsExt {
     }
 }
 
+impl FileFormatTypeExt for ClickhouseFormatType {
+    fn get_content_type(&self) -> String {
+        match self.binary {
+            Some(_) => "application/octet-stream".to_string(),
+            None => self.typ.get_content_type(),
+        }
+    }
+}
+
 impl FileFormatTypeExt for StageFileFormatType {
     fn get_content_type(&self) -> String {
         match self {
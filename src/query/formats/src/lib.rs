@@ -25,7 +25,10 @@ pub mod field_encoder;
 mod file_format_type;
 pub mod output_format;
 
+pub use clickhouse::clickhouse_type_name;
+pub use clickhouse::ClickhouseBinaryFormat;
 pub use clickhouse::ClickhouseFormatType;
+pub use clickhouse::ClickhouseType;
 pub use delimiter::RecordDelimiter;
 pub use field_decoder::*;
 pub use file_format_type::parse_timezone;
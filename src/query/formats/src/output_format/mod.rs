@@ -16,16 +16,22 @@ use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 pub mod csv;
 pub mod json;
+pub mod native;
 pub mod ndjson;
 pub mod parquet;
+pub mod row_binary;
 pub mod tsv;
 
 pub use csv::CSVOutputFormat;
 pub use csv::CSVWithNamesAndTypesOutputFormat;
 pub use csv::CSVWithNamesOutputFormat;
 pub use json::JSONOutputFormat;
+pub use native::NativeOutputFormat;
 pub use ndjson::NDJSONOutputFormatBase;
 pub use parquet::ParquetOutputFormat;
+pub use row_binary::RowBinaryOutputFormat;
+pub use row_binary::RowBinaryWithNamesAndTypesOutputFormat;
+pub use row_binary::RowBinaryWithNamesOutputFormat;
 pub use tsv::TSVOutputFormat;
 pub use tsv::TSVWithNamesAndTypesOutputFormat;
 pub use tsv::TSVWithNamesOutputFormat;
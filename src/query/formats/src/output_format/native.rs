@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+
+use crate::clickhouse_type_name;
+use crate::field_encoder::write_uvarint;
+use crate::field_encoder::FieldEncoderRowBinary;
+use crate::output_format::OutputFormat;
+
+/// ClickHouse `Native` format: every block is written as the number of columns and
+/// rows, followed by the name, type and data of each column.
+pub struct NativeOutputFormat {
+    schema: TableSchemaRef,
+    type_names: Vec<String>,
+    field_encoder: FieldEncoderRowBinary,
+}
+
+impl NativeOutputFormat {
+    pub fn create(schema: TableSchemaRef) -> Result<Self> {
+        let type_names = schema
+            .fields()
+            .iter()
+            .map(|f| clickhouse_type_name(&DataType::from(f.data_type())))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            schema,
+            type_names,
+            field_encoder: FieldEncoderRowBinary,
+        })
+    }
+}
+
+impl OutputFormat for NativeOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(block.memory_size());
+        if block.num_rows() == 0 {
+            return Ok(buf);
+        }
+
+        write_uvarint(self.schema.num_fields() as u64, &mut buf);
+        write_uvarint(block.num_rows() as u64, &mut buf);
+        let block = block.convert_to_full();
+        for ((field, type_name), entry) in self
+            .schema
+            .fields()
+            .iter()
+            .zip(self.type_names.iter())
+            .zip(block.columns())
+        {
+            write_uvarint(field.name().len() as u64, &mut buf);
+            buf.extend_from_slice(field.name().as_bytes());
+            write_uvarint(type_name.len() as u64, &mut buf);
+            buf.extend_from_slice(type_name.as_bytes());
+            let column = entry.value.clone().into_column().unwrap();
+            self.field_encoder.write_column(&column, &mut buf);
+        }
+        Ok(buf)
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+
+use crate::clickhouse_type_name;
+use crate::field_encoder::write_uvarint;
+use crate::field_encoder::FieldEncoderRowBinary;
+use crate::output_format::OutputFormat;
+
+pub type RowBinaryOutputFormat = RowBinaryOutputFormatBase<false, false>;
+pub type RowBinaryWithNamesOutputFormat = RowBinaryOutputFormatBase<true, false>;
+pub type RowBinaryWithNamesAndTypesOutputFormat = RowBinaryOutputFormatBase<true, true>;
+
+pub struct RowBinaryOutputFormatBase<const WITH_NAMES: bool, const WITH_TYPES: bool> {
+    schema: TableSchemaRef,
+    type_names: Vec<String>,
+    field_encoder: FieldEncoderRowBinary,
+}
+
+impl<const WITH_NAMES: bool, const WITH_TYPES: bool>
+    RowBinaryOutputFormatBase<WITH_NAMES, WITH_TYPES>
+{
+    pub fn create(schema: TableSchemaRef) -> Result<Self> {
+        let type_names = schema
+            .fields()
+            .iter()
+            .map(|f| clickhouse_type_name(&DataType::from(f.data_type())))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            schema,
+            type_names,
+            field_encoder: FieldEncoderRowBinary,
+        })
+    }
+}
+
+impl<const WITH_NAMES: bool, const WITH_TYPES: bool> OutputFormat
+    for RowBinaryOutputFormatBase<WITH_NAMES, WITH_TYPES>
+{
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        let rows_size = block.num_rows();
+        let mut buf = Vec::with_capacity(block.memory_size());
+
+        let columns: Vec<Column> = block
+            .convert_to_full()
+            .columns()
+            .iter()
+            .map(|column| column.value.clone().into_column().unwrap())
+            .collect();
+
+        for row_index in 0..rows_size {
+            for column in columns.iter() {
+                self.field_encoder.write_field(column, row_index, &mut buf);
+            }
+        }
+        Ok(buf)
+    }
+
+    fn serialize_prefix(&self) -> Result<Vec<u8>> {
+        let mut buf = vec![];
+        if WITH_NAMES {
+            write_uvarint(self.schema.num_fields() as u64, &mut buf);
+            for field in self.schema.fields() {
+                write_uvarint(field.name().len() as u64, &mut buf);
+                buf.extend_from_slice(field.name().as_bytes());
+            }
+            if WITH_TYPES {
+                for name in &self.type_names {
+                    write_uvarint(name.len() as u64, &mut buf);
+                    buf.extend_from_slice(name.as_bytes());
+                }
+            }
+        }
+        Ok(buf)
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        Ok(vec![])
+    }
+}
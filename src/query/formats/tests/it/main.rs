@@ -22,6 +22,7 @@ use databend_common_settings::Settings;
 mod field_decoder;
 mod field_encoder;
 mod output_format_json_each_row;
+mod output_format_row_binary;
 mod output_format_tcsv;
 mod output_format_utils;
 
@@ -0,0 +1,231 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::Int32Type;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_formats::ClickhouseType;
+use databend_common_formats::FieldDecoderRowBinary;
+use pretty_assertions::assert_eq;
+
+use crate::get_output_format_clickhouse;
+use crate::output_format_utils::gen_schema_and_block;
+use crate::output_format_utils::get_simple_block;
+
+#[test]
+fn test_row_binary() -> Result<()> {
+    let (schema, block) = gen_schema_and_block(
+        vec![
+            TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("b", TableDataType::String),
+        ],
+        vec![
+            Int32Type::from_data(vec![1i32, -1]),
+            StringType::from_data(vec!["x", "yz"]),
+        ],
+    );
+
+    let mut formatter = get_output_format_clickhouse("RowBinary", schema.clone())?;
+    assert!(formatter.serialize_prefix()?.is_empty());
+    let buffer = formatter.serialize_block(&block)?;
+    let expect = [vec![1, 0, 0, 0, 1, b'x'], vec![
+        0xff, 0xff, 0xff, 0xff, 2, b'y', b'z',
+    ]]
+    .concat();
+    assert_eq!(buffer, expect);
+
+    let formatter = get_output_format_clickhouse("RowBinaryWithNamesAndTypes", schema.clone())?;
+    let buffer = formatter.serialize_prefix()?;
+    let expect = [&[2, 1, b'a', 1, b'b', 5][..], b"Int32", &[6], b"String"].concat();
+    assert_eq!(buffer, expect);
+
+    let mut formatter = get_output_format_clickhouse("Native", schema)?;
+    let buffer = formatter.serialize_block(&block)?;
+    let expect = [
+        &[2, 2, 1, b'a', 5][..],
+        b"Int32",
+        &[1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 1, b'b', 6],
+        b"String",
+        &[1, b'x', 2, b'y', b'z'],
+    ]
+    .concat();
+    assert_eq!(buffer, expect);
+
+    Ok(())
+}
+
+#[test]
+fn test_row_binary_round_trip() -> Result<()> {
+    for is_nullable in [false, true] {
+        let (schema, block) = get_simple_block(is_nullable);
+        let data_types = schema
+            .fields()
+            .iter()
+            .map(|f| DataType::from(f.data_type()))
+            .collect::<Vec<_>>();
+        let types = data_types
+            .iter()
+            .map(ClickhouseType::from_data_type)
+            .collect::<Result<Vec<_>>>()?;
+        let decoder = FieldDecoderRowBinary;
+
+        let mut formatter = get_output_format_clickhouse("RowBinary", schema.clone())?;
+        let buffer = formatter.serialize_block(&block)?;
+        let mut reader = Cursor::new(buffer.as_slice());
+        let mut columns = data_types
+            .iter()
+            .map(|t| ColumnBuilder::with_capacity(t, block.num_rows()))
+            .collect::<Vec<_>>();
+        for _ in 0..block.num_rows() {
+            for (column, typ) in columns.iter_mut().zip(&types) {
+                decoder.read_field(column, typ, &mut reader)?;
+            }
+        }
+        assert_eq!(reader.position() as usize, buffer.len());
+        for (column, entry) in columns.into_iter().zip(block.columns()) {
+            assert_eq!(column.build(), entry.value.clone().into_column().unwrap());
+        }
+
+        let mut formatter = get_output_format_clickhouse("Native", schema.clone())?;
+        let buffer = formatter.serialize_block(&block)?;
+        // skip the number of columns and rows, then the name and type of each column.
+        let mut reader = Cursor::new(buffer.as_slice());
+        reader.set_position(2);
+        for ((data_type, typ), entry) in data_types.iter().zip(&types).zip(block.columns()) {
+            let name_len = buffer[reader.position() as usize] as u64;
+            reader.set_position(reader.position() + 1 + name_len);
+            let type_len = buffer[reader.position() as usize] as u64;
+            reader.set_position(reader.position() + 1 + type_len);
+            let mut column = ColumnBuilder::with_capacity(data_type, 0);
+            decoder.read_column(&mut column, typ, block.num_rows(), &mut reader)?;
+            assert_eq!(column.build(), entry.value.clone().into_column().unwrap());
+        }
+        assert_eq!(reader.position() as usize, buffer.len());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_clickhouse_type_parse() -> Result<()> {
+    let string = || Box::new(ClickhouseType::String);
+    assert_eq!(
+        ClickhouseType::parse("DateTime('Asia/Shanghai')")?,
+        ClickhouseType::DateTime
+    );
+    assert_eq!(
+        ClickhouseType::parse("DateTime64(3, 'UTC')")?,
+        ClickhouseType::DateTime64(3)
+    );
+    assert_eq!(
+        ClickhouseType::parse("LowCardinality(Nullable(String))")?,
+        ClickhouseType::LowCardinality(Box::new(ClickhouseType::Nullable(string())))
+    );
+    assert_eq!(
+        ClickhouseType::parse("Map(String, Tuple(a Int32, b Nullable(String)))")?,
+        ClickhouseType::Map(Box::new(ClickhouseType::Tuple(vec![
+            ClickhouseType::String,
+            ClickhouseType::Tuple(vec![
+                ClickhouseType::Number(NumberDataType::Int32),
+                ClickhouseType::Nullable(string()),
+            ]),
+        ])))
+    );
+    assert!(ClickhouseType::parse("Array(String").is_err());
+    assert!(ClickhouseType::parse("FixedString(4)").is_err());
+
+    let nullable_string = DataType::Nullable(Box::new(DataType::String));
+    assert!(ClickhouseType::parse("LowCardinality(String)")?.is_compatible(&DataType::String));
+    assert!(ClickhouseType::parse("String")?.is_compatible(&nullable_string));
+    assert!(!ClickhouseType::parse("Nullable(String)")?.is_compatible(&DataType::String));
+    assert!(ClickhouseType::parse("DateTime")?.is_compatible(&DataType::Timestamp));
+    assert!(ClickhouseType::parse("Date")?.is_compatible(&DataType::Date));
+    assert!(!ClickhouseType::parse("Int64")?.is_compatible(&DataType::Timestamp));
+    Ok(())
+}
+
+#[test]
+fn test_row_binary_compatible_types() -> Result<()> {
+    let decoder = FieldDecoderRowBinary;
+    let cases = [
+        ("DateTime", DataType::Timestamp, vec![1, 0, 0, 0]),
+        ("DateTime64(3)", DataType::Timestamp, vec![
+            0xe8, 0x03, 0, 0, 0, 0, 0, 0,
+        ]),
+        ("Date", DataType::Date, vec![1, 0]),
+    ];
+    let expects = [
+        TimestampType::from_data(vec![1_000_000]),
+        TimestampType::from_data(vec![1_000_000]),
+        DateType::from_data(vec![1]),
+    ];
+    for ((name, data_type, data), expect) in cases.into_iter().zip(expects) {
+        let typ = ClickhouseType::parse(name)?;
+        let mut reader = Cursor::new(data.as_slice());
+        decoder.skip_field(&typ, &mut reader)?;
+        assert_eq!(reader.position() as usize, data.len());
+
+        let mut reader = Cursor::new(data.as_slice());
+        let mut column = ColumnBuilder::with_capacity(&data_type, 1);
+        decoder.read_field(&mut column, &typ, &mut reader)?;
+        assert_eq!(column.build(), expect, "{name}");
+    }
+
+    // an incomplete value can't be skipped.
+    let typ = ClickhouseType::String;
+    assert!(
+        decoder
+            .skip_field(&typ, &mut Cursor::new([3, b'a']))
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_native_low_cardinality() -> Result<()> {
+    let decoder = FieldDecoderRowBinary;
+    let data = [
+        // version, index type of UInt8 with additional keys
+        &1u64.to_le_bytes()[..],
+        &(1u64 << 9).to_le_bytes(),
+        // dictionary of 2 keys
+        &2u64.to_le_bytes(),
+        &[1, b'a', 1, b'b'],
+        // 3 indexes
+        &3u64.to_le_bytes(),
+        &[1, 0, 1],
+    ]
+    .concat();
+
+    let typ = ClickhouseType::parse("LowCardinality(String)")?;
+    let mut reader = Cursor::new(data.as_slice());
+    decoder.skip_column(&typ, 3, &mut reader)?;
+    assert_eq!(reader.position() as usize, data.len());
+
+    let mut reader = Cursor::new(data.as_slice());
+    let mut column = ColumnBuilder::with_capacity(&DataType::String, 3);
+    decoder.read_column(&mut column, &typ, 3, &mut reader)?;
+    assert_eq!(column.build(), StringType::from_data(vec!["b", "a", "b"]));
+    assert_eq!(reader.position() as usize, data.len());
+    Ok(())
+}
@@ -35,6 +35,7 @@ use databend_common_expression::types::ValueType;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::types::ALL_NUMBER_CLASSES;
 use databend_common_expression::values::Value;
+use databend_common_expression::vectorize_with_builder_2_arg;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::Column;
 use databend_common_expression::EvalContext;
@@ -561,6 +562,21 @@ fn register_like(registry: &mut FunctionRegistry) {
         }),
     );
 
+    // Full-text search, a value matches if it contains all the whitespace separated terms.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |text, query, builder, _| {
+                let matched = query
+                    .split(|c| c.is_ascii_whitespace())
+                    .filter(|term| !term.is_empty())
+                    .all(|term| memmem::find(text, term).is_some());
+                builder.push(matched);
+            },
+        ),
+    );
+
     registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
         "regexp",
         |_, _, _| FunctionDomain::Full,
@@ -24,8 +24,10 @@ mod role;
 mod serde;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
+mod workload_group;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
@@ -47,7 +49,11 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskApi;
+pub use task::TaskMgr;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
+pub use workload_group::WorkloadGroupApi;
+pub use workload_group::WorkloadGroupMgr;
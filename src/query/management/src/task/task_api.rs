@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait TaskApi: Sync + Send {
+    /// Returns an id that is unique in the tenant, for a task or a task run.
+    async fn generate_id(&self) -> Result<u64>;
+
+    async fn add_task(&self, task: Task) -> Result<u64>;
+
+    async fn update_task(&self, task: Task, seq: MatchSeq) -> Result<u64>;
+
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Task>>;
+
+    async fn get_tasks(&self) -> Result<Vec<Task>>;
+
+    /// Records a run of a task, it is removed after the retention period.
+    async fn add_task_run(&self, run: TaskRun, retention: Duration) -> Result<()>;
+
+    /// Returns the runs of the given task, or of all the tasks if no name is given.
+    async fn get_task_runs(&self, task_name: Option<&str>) -> Result<Vec<TaskRun>>;
+
+    /// Tries to become, or to stay, the node that schedules the tasks of the tenant.
+    ///
+    /// The lease is kept by calling this again before `ttl` elapses.
+    async fn try_acquire_scheduler_lease(&self, node_id: &str, ttl: Duration) -> Result<bool>;
+}
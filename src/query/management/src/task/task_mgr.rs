@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::task::task_api::TaskApi;
+
+static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+static TASK_RUN_API_KEY_PREFIX: &str = "__fd_task_runs";
+static TASK_ID_GEN_KEY_PREFIX: &str = "__fd_task_id_gen";
+static TASK_SCHEDULER_KEY_PREFIX: &str = "__fd_task_scheduler";
+
+pub struct TaskMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    task_prefix: String,
+    task_run_prefix: String,
+    id_gen_key: String,
+    scheduler_key: String,
+}
+
+impl TaskMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create task)",
+            ));
+        }
+
+        Ok(TaskMgr {
+            kv_api,
+            task_prefix: format!("{}/{}", TASK_API_KEY_PREFIX, tenant),
+            task_run_prefix: format!("{}/{}", TASK_RUN_API_KEY_PREFIX, tenant),
+            id_gen_key: format!("{}/{}", TASK_ID_GEN_KEY_PREFIX, tenant),
+            scheduler_key: format!("{}/{}", TASK_SCHEDULER_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_task_key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.task_prefix, escape_for_key(name)?))
+    }
+
+    fn make_task_run_prefix(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.task_run_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskApi for TaskMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn generate_id(&self) -> Result<u64> {
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.id_gen_key,
+                MatchSeq::GE(0),
+                Operation::Update(vec![]),
+                None,
+            ))
+            .await?;
+
+        match res.result {
+            Some(SeqV { seq, .. }) => Ok(seq),
+            None => Err(ErrorCode::IllegalTask("fail to generate task id")),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_task(&self, task: Task) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_task_key(task.task_name.as_str())?;
+        let value = Operation::Update(serialize_struct(&task, ErrorCode::IllegalTask, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res_seq = upsert_kv.await?.added_seq_or_else(|v| {
+            ErrorCode::TaskAlreadyExists(format!("Task already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res_seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_task(&self, task: Task, match_seq: MatchSeq) -> Result<u64> {
+        let key = self.make_task_key(task.task_name.as_str())?;
+        let value = Operation::Update(serialize_struct(&task, ErrorCode::IllegalTask, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) if upsert_kv.is_changed() => Ok(s),
+            _ => Err(ErrorCode::UnknownTask(format!(
+                "Unknown Task, or seq not match {}",
+                task.task_name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_task_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownTask(format!("Unknown Task {}", name)))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Task>> {
+        let key = self.make_task_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownTask(format!("Unknown Task {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalTask, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownTask(format!("Unknown Task {}", name))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_tasks(&self) -> Result<Vec<Task>> {
+        let values = self.kv_api.prefix_list_kv(&self.task_prefix).await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let task = deserialize_struct(&value.data, ErrorCode::IllegalTask, || "")?;
+            tasks.push(task);
+        }
+        Ok(tasks)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_task_run(&self, run: TaskRun, retention: Duration) -> Result<()> {
+        // Zero padded run id keeps the runs of a task listed in the order they are started.
+        let key = format!(
+            "{}/{:020}",
+            self.make_task_run_prefix(&run.task.task_name)?,
+            run.run_id
+        );
+        let value = Operation::Update(serialize_struct(&run, ErrorCode::IllegalTask, || "")?);
+
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                value,
+                Some(MetaSpec::new_ttl(retention)),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_task_runs(&self, task_name: Option<&str>) -> Result<Vec<TaskRun>> {
+        let prefix = match task_name {
+            Some(name) => format!("{}/", self.make_task_run_prefix(name)?),
+            None => format!("{}/", self.task_run_prefix),
+        };
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut runs = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let run = deserialize_struct(&value.data, ErrorCode::IllegalTask, || "")?;
+            runs.push(run);
+        }
+        Ok(runs)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn try_acquire_scheduler_lease(&self, node_id: &str, ttl: Duration) -> Result<bool> {
+        let match_seq = match self.kv_api.get_kv(&self.scheduler_key).await? {
+            None => MatchSeq::Exact(0),
+            Some(v) if v.data == node_id.as_bytes() => MatchSeq::Exact(v.seq),
+            Some(_) => return Ok(false),
+        };
+
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.scheduler_key,
+                match_seq,
+                Operation::Update(node_id.as_bytes().to_vec()),
+                Some(MetaSpec::new_ttl(ttl)),
+            ))
+            .await?;
+        Ok(res.is_changed())
+    }
+}
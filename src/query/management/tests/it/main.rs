@@ -17,5 +17,6 @@
 mod cluster;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::TaskRunState;
+use databend_common_meta_app::principal::TaskSchedule;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_update_drop_task() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+
+    let task = create_test_task("t1");
+    let seq = task_api.add_task(task.clone()).await?;
+    match task_api.add_task(task.clone()).await {
+        Ok(_) => panic!("Already exists add task must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::TASK_ALREADY_EXISTS),
+    }
+
+    let got = task_api.get_task("t1", MatchSeq::GE(0)).await?;
+    assert_eq!(got.seq, seq);
+    assert_eq!(got.data, task);
+
+    let mut resumed = task.clone();
+    resumed.status = TaskStatus::Started;
+    let new_seq = task_api
+        .update_task(resumed.clone(), MatchSeq::Exact(seq))
+        .await?;
+    assert!(new_seq > seq);
+
+    // The task is updated by someone else.
+    match task_api
+        .update_task(resumed.clone(), MatchSeq::Exact(seq))
+        .await
+    {
+        Ok(_) => panic!("Update task with stale seq must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::UNKNOWN_TASK),
+    }
+
+    task_api.add_task(create_test_task("t2")).await?;
+    let tasks = task_api.get_tasks().await?;
+    assert_eq!(tasks, vec![resumed, create_test_task("t2")]);
+
+    task_api.drop_task("t1", MatchSeq::GE(1)).await?;
+    match task_api.get_task("t1", MatchSeq::GE(0)).await {
+        Ok(_) => panic!("Dropped task must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::UNKNOWN_TASK),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_runs() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+
+    let retention = Duration::from_secs(3600);
+    for (name, run_id) in [("t1", 2), ("t2", 3), ("t1", 1), ("t10", 4)] {
+        let run = TaskRun {
+            task: create_test_task(name),
+            run_id,
+            state: TaskRunState::Succeeded,
+            ..Default::default()
+        };
+        task_api.add_task_run(run, retention).await?;
+    }
+
+    let runs = task_api.get_task_runs(Some("t1")).await?;
+    let run_ids = runs.iter().map(|r| r.run_id).collect::<Vec<_>>();
+    assert_eq!(run_ids, vec![1, 2]);
+
+    let runs = task_api.get_task_runs(None).await?;
+    assert_eq!(runs.len(), 4);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_scheduler_lease() -> Result<()> {
+    let (kv_api, task_api) = new_task_api().await?;
+    let other_api = TaskMgr::create(kv_api, "admin")?;
+
+    let ttl = Duration::from_secs(60);
+    assert!(task_api.try_acquire_scheduler_lease("node1", ttl).await?);
+    assert!(!other_api.try_acquire_scheduler_lease("node2", ttl).await?);
+    // The holder renews the lease.
+    assert!(task_api.try_acquire_scheduler_lease("node1", ttl).await?);
+
+    let id1 = task_api.generate_id().await?;
+    let id2 = other_api.generate_id().await?;
+    assert!(id2 > id1);
+    Ok(())
+}
+
+fn create_test_task(name: &str) -> Task {
+    Task {
+        task_id: 1,
+        task_name: name.to_string(),
+        query_text: "INSERT INTO t SELECT 1".to_string(),
+        comment: "".to_string(),
+        owner: "account_admin".to_string(),
+        schedule: Some(TaskSchedule::IntervalMinutes(5)),
+        warehouse: None,
+        status: TaskStatus::Suspended,
+        suspend_task_after_num_failures: Some(3),
+        consecutive_failures: 0,
+        next_scheduled_at: None,
+        last_suspended_at: None,
+        created_at: Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap(),
+        after: vec![],
+        when_condition: None,
+    }
+}
+
+async fn new_task_api() -> Result<(Arc<MetaEmbedded>, TaskMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = TaskMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
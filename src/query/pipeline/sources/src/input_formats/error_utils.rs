@@ -12,7 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
 use databend_common_expression::TableSchemaRef;
+use databend_common_storage::CopyStatus;
 use databend_common_storage::FileParseError;
 
 pub fn truncate_column_data(s: String) -> String {
@@ -39,3 +49,50 @@ pub fn get_decode_error_by_pos(
         column_data: truncate_column_data(column_data),
     }
 }
+
+/// The schema of the rows rejected by COPY, one row for each error.
+pub fn rejected_rows_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("File", DataType::String),
+        DataField::new("Line", DataType::Number(NumberDataType::Int32)),
+        DataField::new(
+            "Column_name",
+            DataType::Nullable(Box::new(DataType::String)),
+        ),
+        DataField::new("Error", DataType::String),
+    ])
+}
+
+/// Collects the rejected rows recorded in the copy status, ordered by file and line.
+pub fn rejected_rows_block(copy_status: &CopyStatus) -> DataBlock {
+    let mut rows = vec![];
+    for entry in copy_status.files.iter() {
+        if let Some(errors) = &entry.value().error {
+            for row in &errors.rejected_rows {
+                rows.push((entry.key().clone(), row.clone()));
+            }
+        }
+    }
+    rows.sort_by(|(file_a, row_a), (file_b, row_b)| {
+        file_a.cmp(file_b).then(row_a.line.cmp(&row_b.line))
+    });
+
+    let n = rows.len();
+    let mut files = Vec::with_capacity(n);
+    let mut lines = Vec::with_capacity(n);
+    let mut column_names = Vec::with_capacity(n);
+    let mut errors = Vec::with_capacity(n);
+    for (file, row) in rows {
+        column_names.push(row.error.column_name().map(|name| name.as_bytes().to_vec()));
+        errors.push(row.error.to_string().as_bytes().to_vec());
+        // the line start from 0.
+        lines.push(row.line as i32 + 1);
+        files.push(file.into_bytes());
+    }
+    DataBlock::new_from_columns(vec![
+        StringType::from_data(files),
+        Int32Type::from_data(lines),
+        StringType::from_opt_data(column_names),
+        StringType::from_data(errors),
+    ])
+}
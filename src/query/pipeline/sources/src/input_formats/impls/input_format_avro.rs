@@ -0,0 +1,302 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use apache_avro::schema::RecordSchema;
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Reader;
+use apache_avro::Schema as AvroSchema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchemaRef;
+use databend_common_formats::FieldDecoder;
+use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_storage::FileParseError;
+use ethnum::i256;
+use serde_json::Value as JsonValue;
+
+use super::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::error_utils::truncate_column_data;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// Avro object container files, each file is read as a whole.
+///
+/// Records are converted to json with the writer schema of the file,
+/// and then decoded by `FieldJsonAstDecoder`.
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_row(
+        field_decoder: &FieldJsonAstDecoder,
+        record: AvroValue,
+        record_schema: &RecordSchema,
+        field_positions: &[Option<usize>],
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+        default_values: &Option<Vec<Scalar>>,
+    ) -> std::result::Result<(), FileParseError> {
+        let mut values: Vec<Option<AvroValue>> = match record {
+            AvroValue::Record(fields) => fields.into_iter().map(|(_, v)| Some(v)).collect(),
+            _ => vec![],
+        };
+        for ((column_index, field), column) in
+            schema.fields().iter().enumerate().zip(columns.iter_mut())
+        {
+            let value = field_positions[column_index].and_then(|i| Some((i, values[i].take()?)));
+            match value {
+                Some((i, value)) => {
+                    let value = avro_to_json(value, &record_schema.fields[i].schema);
+                    let decoded = value.and_then(|v| {
+                        field_decoder
+                            .read_field(column, &v)
+                            .map_err(|e| (e, v.to_string()))
+                    });
+                    if let Err((e, column_data)) = decoded {
+                        return Err(FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: e.message(),
+                            column_data: truncate_column_data(column_data),
+                        });
+                    }
+                }
+                None => {
+                    if let Some(values) = default_values {
+                        column.push(values[column_index].as_ref());
+                    } else if field.is_nullable_or_null() {
+                        column.push_default();
+                    } else {
+                        return Err(FileParseError::ColumnMissingError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+        if batch.data.is_empty() {
+            return Ok(());
+        }
+
+        let reader = Reader::new(batch.data.as_slice()).map_err(|e| avro_error(path, e))?;
+        let writer_schema = reader.writer_schema().clone();
+        let record_schema = match &writer_schema {
+            AvroSchema::Record(record_schema) => record_schema,
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "the top level schema of avro file {} must be a record",
+                    path
+                )));
+            }
+        };
+
+        // fields are matched by name, the order of fields in the file does not matter.
+        let field_positions = builder
+            .ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|f| {
+                record_schema.fields.iter().position(|r| {
+                    if builder.ident_case_sensitive {
+                        &r.name == f.name()
+                    } else {
+                        r.name.eq_ignore_ascii_case(f.name())
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for (row, record) in reader.enumerate() {
+            // a broken block can not be skipped
+            let record = record.map_err(|e| avro_error(path, e))?;
+            if let Err(e) = Self::read_row(
+                field_decoder,
+                record,
+                record_schema,
+                &field_positions,
+                columns,
+                &builder.ctx.schema,
+                &builder.ctx.default_values,
+            ) {
+                builder.ctx.on_error(
+                    e,
+                    Some((columns, builder.num_rows)),
+                    &mut builder.file_status,
+                    path,
+                    row,
+                )?
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Convert an avro value to json in the form `FieldJsonAstDecoder` reads for
+/// the type inferred from `schema` by `infer_avro_schema`.
+fn avro_to_json(
+    value: AvroValue,
+    schema: &AvroSchema,
+) -> std::result::Result<JsonValue, (ErrorCode, String)> {
+    let json = match (value, schema) {
+        (AvroValue::Null, _) => JsonValue::Null,
+        (AvroValue::Union(i, value), AvroSchema::Union(union)) => {
+            return match union.variants().get(i as usize) {
+                Some(schema) => avro_to_json(*value, schema),
+                None => Err((
+                    ErrorCode::BadBytes(format!("invalid union index {}", i)),
+                    format!("{:?}", value),
+                )),
+            };
+        }
+        (AvroValue::Record(fields), AvroSchema::Record(record_schema)) => {
+            let mut object = serde_json::Map::with_capacity(fields.len());
+            for ((name, value), field) in fields.into_iter().zip(record_schema.fields.iter()) {
+                object.insert(name, avro_to_json(value, &field.schema)?);
+            }
+            JsonValue::Object(object)
+        }
+        (AvroValue::Array(items), AvroSchema::Array(item_schema)) => JsonValue::Array(
+            items
+                .into_iter()
+                .map(|v| avro_to_json(v, item_schema))
+                .collect::<std::result::Result<_, _>>()?,
+        ),
+        (AvroValue::Map(entries), AvroSchema::Map(value_schema)) => {
+            let mut object = serde_json::Map::with_capacity(entries.len());
+            for (key, value) in entries {
+                object.insert(key, avro_to_json(value, value_schema)?);
+            }
+            JsonValue::Object(object)
+        }
+        (AvroValue::Decimal(decimal), AvroSchema::Decimal(decimal_schema)) => {
+            let bytes = Vec::<u8>::try_from(&decimal)
+                .map_err(|e| (ErrorCode::BadBytes(e.to_string()), format!("{:?}", decimal)))?;
+            let size = DecimalSize {
+                precision: decimal_schema.precision as u8,
+                scale: decimal_schema.scale as u8,
+            };
+            let scalar = match bytes.len() {
+                0 => DecimalScalar::Decimal128(0, size),
+                1..=16 => {
+                    DecimalScalar::Decimal128(i128::from_be_bytes(sign_extend_be(&bytes)), size)
+                }
+                17..=32 => {
+                    DecimalScalar::Decimal256(i256::from_be_bytes(sign_extend_be(&bytes)), size)
+                }
+                n => {
+                    return Err((
+                        ErrorCode::BadBytes(format!("decimal of {} bytes is too large", n)),
+                        format!("{:?}", bytes),
+                    ));
+                }
+            };
+            JsonValue::String(scalar.to_string())
+        }
+        (AvroValue::Bytes(bytes), _) | (AvroValue::Fixed(_, bytes), _) => {
+            JsonValue::String(String::from_utf8_lossy(&bytes).into_owned())
+        }
+        (AvroValue::Enum(_, symbol), _) => JsonValue::String(symbol),
+        (AvroValue::Uuid(uuid), _) => JsonValue::String(uuid.to_string()),
+        (AvroValue::Date(days), _) => JsonValue::from(days),
+        (AvroValue::TimestampMillis(millis), _) | (AvroValue::LocalTimestampMillis(millis), _) => {
+            match millis.checked_mul(1000) {
+                Some(micros) => JsonValue::from(micros),
+                None => {
+                    return Err((
+                        ErrorCode::BadBytes("timestamp out of range"),
+                        millis.to_string(),
+                    ));
+                }
+            }
+        }
+        (AvroValue::TimestampMicros(micros), _) | (AvroValue::LocalTimestampMicros(micros), _) => {
+            JsonValue::from(micros)
+        }
+        // numbers, strings and values of recursive types
+        (value, _) => {
+            let data = format!("{:?}", value);
+            JsonValue::try_from(value).map_err(|e| (ErrorCode::BadBytes(e.to_string()), data))?
+        }
+    };
+    Ok(json)
+}
+
+// from arrow-rs
+fn sign_extend_be<const N: usize>(b: &[u8]) -> [u8; N] {
+    let is_negative = (b[0] & 128u8) == 128u8;
+    let mut result = if is_negative { [255u8; N] } else { [0u8; N] };
+    for (d, s) in result.iter_mut().skip(N - b.len()).zip(b) {
+        *d = *s;
+    }
+    result
+}
+
+fn avro_error(path: &str, e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to parse avro file {}: {}", path, e))
+}
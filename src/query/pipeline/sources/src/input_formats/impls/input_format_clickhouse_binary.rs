@@ -0,0 +1,398 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::mem;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_formats::ClickhouseType;
+use databend_common_formats::FieldDecoderRowBinary;
+use databend_common_io::prelude::BinaryRead;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_settings::Settings;
+use databend_common_storage::StageFileInfo;
+use log::debug;
+use opendal::Operator;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+pub type InputFormatRowBinary = InputFormatClickhouseBinary<false>;
+pub type InputFormatNative = InputFormatClickhouseBinary<true>;
+
+/// ClickHouse `RowBinary` and `Native`, only used by the ClickHouse handler.
+#[derive(Default)]
+pub struct InputFormatClickhouseBinary<const NATIVE: bool>;
+
+#[async_trait::async_trait]
+impl<const NATIVE: bool> InputFormat for InputFormatClickhouseBinary<NATIVE> {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        _file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        unimplemented!("InputFormatClickhouseBinary::get_splits")
+    }
+
+    fn exec_copy(&self, _ctx: Arc<InputContext>, _pipeline: &mut Pipeline) -> Result<()> {
+        unimplemented!("ClickhouseBinaryFormatPipe::exec_copy")
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ClickhouseBinaryFormatPipe::<NATIVE>::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct ClickhouseBinaryFormatPipe<const NATIVE: bool>;
+
+#[async_trait::async_trait]
+impl<const NATIVE: bool> InputFormatPipe for ClickhouseBinaryFormatPipe<NATIVE> {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = BinaryBlocks;
+    type AligningState = ClickhouseBinaryAligningState<NATIVE>;
+    type BlockBuilder = ClickhouseBinaryBlockBuilder<NATIVE>;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        let data_types = ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|f| DataType::from(f.data_type()))
+            .collect::<Vec<_>>();
+        Ok(ClickhouseBinaryAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            data_types,
+            column_types: None,
+            buf: vec![],
+        })
+    }
+
+    fn try_create_block_builder(_ctx: &Arc<InputContext>) -> Result<Self::BlockBuilder> {
+        Ok(ClickhouseBinaryBlockBuilder {})
+    }
+}
+
+/// Blocks decoded from the complete rows (or `Native` blocks) of a read batch.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct BinaryBlocks {
+    size: usize,
+    rows: usize,
+    #[serde(skip)]
+    blocks: Vec<DataBlock>,
+}
+
+impl RowBatchTrait for BinaryBlocks {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+}
+
+#[typetag::serde(name = "row_batch_clickhouse_binary")]
+impl BlockMetaInfo for BinaryBlocks {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("BinaryBlocks as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("BinaryBlocks as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct ClickhouseBinaryBlockBuilder<const NATIVE: bool> {}
+
+impl<const NATIVE: bool> BlockBuilderTrait for ClickhouseBinaryBlockBuilder<NATIVE> {
+    type Pipe = ClickhouseBinaryFormatPipe<NATIVE>;
+
+    fn deserialize(&mut self, batch: Option<BinaryBlocks>) -> Result<Vec<DataBlock>> {
+        match batch {
+            Some(mut b) => Ok(mem::take(&mut b.blocks)),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+pub struct ClickhouseBinaryAligningState<const NATIVE: bool> {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    data_types: Vec<DataType>,
+    /// The ClickHouse types of the `RowBinary` columns, known once the header is read.
+    column_types: Option<Vec<ClickhouseType>>,
+    /// The incomplete row or block at the end of the data read so far.
+    buf: Vec<u8>,
+}
+
+impl<const NATIVE: bool> AligningStateTrait for ClickhouseBinaryAligningState<NATIVE> {
+    type Pipe = ClickhouseBinaryFormatPipe<NATIVE>;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<BinaryBlocks>> {
+        let is_end = read_batch.is_none();
+        let data = match read_batch {
+            Some(b) if self.buf.is_empty() => b,
+            Some(b) => {
+                let mut data = mem::take(&mut self.buf);
+                data.extend_from_slice(&b);
+                data
+            }
+            None => mem::take(&mut self.buf),
+        };
+
+        let mut reader = Cursor::new(data.as_slice());
+        let blocks = if NATIVE {
+            self.read_native(&mut reader)?
+        } else {
+            self.read_row_binary(&mut reader)?
+        };
+        let size = reader.position() as usize;
+        self.buf = data[size..].to_vec();
+        debug!(
+            "aligning {}: decoded {} bytes, {} bytes left",
+            self.split_info.file.path,
+            size,
+            self.buf.len()
+        );
+        if is_end && !self.buf.is_empty() {
+            return Err(ErrorCode::BadBytes(format!(
+                "unexpected end of binary data, {} bytes of incomplete {} left",
+                self.buf.len(),
+                if NATIVE { "block" } else { "row" }
+            )));
+        }
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![BinaryBlocks {
+            size,
+            rows: blocks.iter().map(|b| b.num_rows()).sum(),
+            blocks,
+        }])
+    }
+}
+
+impl<const NATIVE: bool> ClickhouseBinaryAligningState<NATIVE> {
+    /// Decodes the complete rows, the reader is left at the start of the first incomplete one.
+    fn read_row_binary(&mut self, reader: &mut Cursor<&[u8]>) -> Result<Vec<DataBlock>> {
+        if self.column_types.is_none() {
+            let start = reader.position();
+            match self.read_row_binary_header(reader)? {
+                Some(column_types) => self.column_types = Some(column_types),
+                None => {
+                    reader.set_position(start);
+                    return Ok(vec![]);
+                }
+            }
+        }
+        let column_types = self.column_types.as_ref().unwrap();
+
+        let decoder = FieldDecoderRowBinary;
+        let max_rows = self.ctx.block_compact_thresholds.max_rows_per_block;
+        let mut blocks = vec![];
+        let mut columns = new_builders(&self.data_types, max_rows);
+        let mut rows = 0;
+        while !reader.remaining_slice().is_empty() {
+            let start = reader.position();
+            let is_complete = column_types
+                .iter()
+                .try_for_each(|typ| decoder.skip_field(typ, reader))
+                .is_ok();
+            reader.set_position(start);
+            if !is_complete {
+                break;
+            }
+            for (column, typ) in columns.iter_mut().zip(column_types) {
+                decoder.read_field(column, typ, reader)?;
+            }
+            rows += 1;
+            if rows == max_rows {
+                let full = mem::replace(&mut columns, new_builders(&self.data_types, max_rows));
+                blocks.push(build_block(full));
+                rows = 0;
+            }
+        }
+        if rows > 0 {
+            blocks.push(build_block(columns));
+        }
+        Ok(blocks)
+    }
+
+    /// Returns `None` if the header is not complete yet.
+    fn read_row_binary_header(
+        &self,
+        reader: &mut Cursor<&[u8]>,
+    ) -> Result<Option<Vec<ClickhouseType>>> {
+        let headers = self.ctx.file_format_options_ext.headers;
+        if headers > 0 {
+            let read_header = |reader: &mut Cursor<&[u8]>| -> Result<(usize, Vec<String>)> {
+                let num_columns = reader.read_uvarint()? as usize;
+                // columns are matched by position, names are ignored.
+                for _ in 0..num_columns {
+                    reader.skip_string()?;
+                }
+                let mut type_names = vec![];
+                if headers > 1 {
+                    for _ in 0..num_columns {
+                        type_names.push(reader.read_string()?);
+                    }
+                }
+                Ok((num_columns, type_names))
+            };
+            // the header can only fail by reaching the end of the data read so far.
+            let Ok((num_columns, type_names)) = read_header(reader) else {
+                return Ok(None);
+            };
+            check_num_columns(num_columns, self.data_types.len())?;
+            if headers > 1 {
+                return type_names
+                    .iter()
+                    .zip(&self.data_types)
+                    .map(|(name, data_type)| parse_column_type(name, data_type, false))
+                    .collect::<Result<Vec<_>>>()
+                    .map(Some);
+            }
+        }
+        self.data_types
+            .iter()
+            .map(ClickhouseType::from_data_type)
+            .collect::<Result<Vec<_>>>()
+            .map(Some)
+    }
+
+    /// Decodes the complete blocks, the reader is left at the start of the first incomplete one.
+    fn read_native(&self, reader: &mut Cursor<&[u8]>) -> Result<Vec<DataBlock>> {
+        let decoder = FieldDecoderRowBinary;
+        let mut blocks = vec![];
+        while !reader.remaining_slice().is_empty() {
+            let start = reader.position();
+            let Some((num_rows, column_types)) = self.check_native_block(reader)? else {
+                reader.set_position(start);
+                break;
+            };
+
+            reader.set_position(start);
+            reader.read_uvarint()?;
+            reader.read_uvarint()?;
+            let mut columns = Vec::with_capacity(column_types.len());
+            for (data_type, typ) in self.data_types.iter().zip(&column_types) {
+                reader.skip_string()?;
+                reader.skip_string()?;
+                let mut column = ColumnBuilder::with_capacity(data_type, num_rows);
+                decoder.read_column(&mut column, typ, num_rows, reader)?;
+                columns.push(column);
+            }
+            if num_rows > 0 {
+                blocks.push(build_block(columns));
+            }
+        }
+        Ok(blocks)
+    }
+
+    /// Checks the column types of the next block and moves over it, returns `None` if the
+    /// block is not complete yet.
+    fn check_native_block(
+        &self,
+        reader: &mut Cursor<&[u8]>,
+    ) -> Result<Option<(usize, Vec<ClickhouseType>)>> {
+        let decoder = FieldDecoderRowBinary;
+        let Ok(num_columns) = reader.read_uvarint() else {
+            return Ok(None);
+        };
+        let Ok(num_rows) = reader.read_uvarint() else {
+            return Ok(None);
+        };
+        check_num_columns(num_columns as usize, self.data_types.len())?;
+        let mut column_types = Vec::with_capacity(self.data_types.len());
+        for data_type in &self.data_types {
+            let (Ok(_), Ok(type_name)) = (reader.skip_string(), reader.read_string()) else {
+                return Ok(None);
+            };
+            let typ = parse_column_type(&type_name, data_type, true)?;
+            if decoder
+                .skip_column(&typ, num_rows as usize, reader)
+                .is_err()
+            {
+                return Ok(None);
+            }
+            column_types.push(typ);
+        }
+        Ok(Some((num_rows as usize, column_types)))
+    }
+}
+
+fn new_builders(data_types: &[DataType], capacity: usize) -> Vec<ColumnBuilder> {
+    data_types
+        .iter()
+        .map(|t| ColumnBuilder::with_capacity(t, capacity))
+        .collect()
+}
+
+fn build_block(columns: Vec<ColumnBuilder>) -> DataBlock {
+    DataBlock::new_from_columns(columns.into_iter().map(|c| c.build()).collect())
+}
+
+fn check_num_columns(actual: usize, expected: usize) -> Result<()> {
+    if actual != expected {
+        return Err(ErrorCode::BadBytes(format!(
+            "expect {} columns, got {}",
+            expected, actual
+        )));
+    }
+    Ok(())
+}
+
+fn parse_column_type(name: &str, data_type: &DataType, native: bool) -> Result<ClickhouseType> {
+    let typ = ClickhouseType::parse(name)?;
+    if !typ.is_compatible(data_type) {
+        return Err(ErrorCode::BadBytes(format!(
+            "column type mismatch, {} can not be loaded as {}",
+            name, data_type
+        )));
+    }
+    // the dictionary of a nested `LowCardinality` is not stored along with its values.
+    if native {
+        let nested = match &typ {
+            ClickhouseType::LowCardinality(typ) => typ.as_ref(),
+            typ => typ,
+        };
+        if nested.contains_low_cardinality() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "nested LowCardinality column {} is not supported in Native",
+                name
+            )));
+        }
+    }
+    Ok(typ)
+}
@@ -0,0 +1,390 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::io::Cursor;
+use std::mem;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_settings::Settings;
+use databend_common_storage::infer_orc_field;
+use databend_common_storage::orc_column_names;
+use databend_common_storage::read_orc_metadata_async;
+use databend_common_storage::FileStatus;
+use databend_common_storage::StageFileInfo;
+use futures::StreamExt;
+use log::debug;
+use opendal::Operator;
+use orc_rust::arrow_reader::create_arrow_schema;
+use orc_rust::arrow_reader::NaiveStripeDecoder;
+use orc_rust::reader::Reader;
+use orc_rust::ArrowStreamReader;
+use orc_rust::Cursor as OrcCursor;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+use crate::AsyncSource;
+use crate::AsyncSourcer;
+
+/// ORC files.
+///
+/// COPY and stage queries read the footer of each file first and then decode it stripe by
+/// stripe with range reads, so at most one stripe of a file is held in memory.
+/// Streaming load can not seek in the uploaded data, the file is buffered as a whole.
+pub struct InputFormatOrc;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // stripes can not be located without the footer at the end of the file.
+        let infos = file_infos
+            .into_iter()
+            .map(|info| {
+                let size = info.size as usize;
+                let file = Arc::new(FileInfo {
+                    path: info.path,
+                    size,
+                    num_splits: 1,
+                    compress_alg: None,
+                });
+                Arc::new(SplitInfo {
+                    file,
+                    seq_in_file: 0,
+                    offset: 0,
+                    size,
+                    num_file_splits: 1,
+                    format_info: None,
+                })
+            })
+            .collect();
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        let max_threads = ctx.settings.get_max_threads()? as usize;
+        let num_sources = ctx.splits.len().min(max_threads).max(1);
+        let next_split = Arc::new(AtomicUsize::new(0));
+        pipeline.add_source(
+            |output| {
+                let source = OrcSource {
+                    ctx: ctx.clone(),
+                    next_split: next_split.clone(),
+                    current: None,
+                };
+                AsyncSourcer::create(ctx.table_context.clone(), output, source)
+            },
+            num_sources,
+        )
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+/// The columns of an ORC file to decode, matched with the schema by name.
+struct OrcProjection {
+    file_columns: Vec<String>,
+    /// position in `file_columns` of each field of the schema, `None` if it is not projected.
+    positions: Vec<Option<usize>>,
+}
+
+impl OrcProjection {
+    fn try_create<R>(ctx: &InputContext, path: &str, reader: &Reader<R>) -> Result<Self> {
+        let names = orc_column_names(reader);
+        let ident_case_sensitive = ctx.file_format_options_ext.ident_case_sensitive;
+        let mut file_columns = vec![];
+        let mut positions = Vec::with_capacity(ctx.schema.num_fields());
+        for (i, field) in ctx.schema.fields().iter().enumerate() {
+            if let Some(projection) = &ctx.projection {
+                if !projection.contains(&i) {
+                    positions.push(None);
+                    continue;
+                }
+            }
+            let name = names.iter().find(|n| {
+                if ident_case_sensitive {
+                    n.as_str() == field.name()
+                } else {
+                    n.eq_ignore_ascii_case(field.name())
+                }
+            });
+            let name = name.ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "orc file {} has no column named {}",
+                    path,
+                    field.name()
+                ))
+            })?;
+            let file_type = infer_orc_field(reader, name)?.data_type().clone();
+            if file_type.remove_nullable() != field.data_type().remove_nullable() {
+                return Err(ErrorCode::BadBytes(format!(
+                    "column {} of orc file {} is {}, expect {}",
+                    field.name(),
+                    path,
+                    file_type,
+                    field.data_type()
+                )));
+            }
+            positions.push(Some(file_columns.len()));
+            file_columns.push(name.clone());
+        }
+        // the number of rows comes from the decoded columns, so at least one is read.
+        if file_columns.is_empty() {
+            if let Some(name) = names.iter().find(|n| infer_orc_field(reader, n).is_ok()) {
+                file_columns.push(name.clone());
+            }
+        }
+        Ok(Self {
+            file_columns,
+            positions,
+        })
+    }
+
+    fn to_block(&self, ctx: &InputContext, batch: &RecordBatch) -> Result<DataBlock> {
+        let num_rows = batch.num_rows();
+        let mut columns = Vec::with_capacity(self.positions.len());
+        for (field, position) in ctx.schema.fields().iter().zip(self.positions.iter()) {
+            let column = match position {
+                Some(position) => {
+                    Column::from_arrow_rs(batch.column(*position).clone(), &DataField::from(field))?
+                }
+                // columns not in the projection are not read, as the text formats do.
+                None => Column::String(
+                    StringColumnBuilder {
+                        need_estimated: false,
+                        data: vec![],
+                        offsets: vec![0; num_rows + 1],
+                    }
+                    .build(),
+                ),
+            };
+            columns.push(column);
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+/// Reads the ORC files of the splits one after another, the splits are shared by all the sources.
+pub struct OrcSource {
+    ctx: Arc<InputContext>,
+    next_split: Arc<AtomicUsize>,
+    current: Option<OrcFileStream>,
+}
+
+struct OrcFileStream {
+    path: String,
+    projection: OrcProjection,
+    stripes: ArrowStreamReader<opendal::Reader>,
+}
+
+impl OrcSource {
+    #[async_backtrace::framed]
+    async fn open(&self, path: String) -> Result<OrcFileStream> {
+        let operator = self.ctx.source.get_operator()?;
+        let reader = read_orc_metadata_async(&operator, &path).await?;
+        let projection = OrcProjection::try_create(&self.ctx, &path, &reader)?;
+        let cursor =
+            OrcCursor::new(reader, &projection.file_columns).map_err(|e| orc_error(&path, e))?;
+        let batch_size = self.ctx.block_compact_thresholds.max_rows_per_block;
+        Ok(OrcFileStream {
+            path,
+            projection,
+            stripes: ArrowStreamReader::new(cursor, Some(batch_size)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for OrcSource {
+    const NAME: &'static str = "OrcSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if self.current.is_none() {
+                let index = self.next_split.fetch_add(1, Ordering::Relaxed);
+                let path = match self.ctx.splits.get(index) {
+                    Some(split) => split.file.path.clone(),
+                    None => return Ok(None),
+                };
+                debug!("reading orc file {}", path);
+                self.current = Some(self.open(path).await?);
+            }
+
+            let file = self.current.as_mut().unwrap();
+            let batch = file.stripes.next().await;
+            match batch {
+                Some(batch) => {
+                    let batch = batch.map_err(|e| orc_error(&file.path, e))?;
+                    let block = file.projection.to_block(&self.ctx, &batch)?;
+                    self.ctx
+                        .table_context
+                        .add_file_status(&file.path, FileStatus {
+                            num_rows_loaded: block.num_rows(),
+                            error: None,
+                        })?;
+                    return Ok(Some(block));
+                }
+                None => self.current = None,
+            }
+        }
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = OrcBlocks;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        Ok(OrcAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(_ctx: &Arc<InputContext>) -> Result<Self::BlockBuilder> {
+        Ok(OrcBlockBuilder {})
+    }
+}
+
+/// Blocks decoded from a whole uploaded ORC file.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct OrcBlocks {
+    size: usize,
+    rows: usize,
+    #[serde(skip)]
+    blocks: Vec<DataBlock>,
+}
+
+impl RowBatchTrait for OrcBlocks {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+}
+
+#[typetag::serde(name = "row_batch_orc")]
+impl BlockMetaInfo for OrcBlocks {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("OrcBlocks as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("OrcBlocks as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct OrcBlockBuilder {}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<OrcBlocks>) -> Result<Vec<DataBlock>> {
+        match batch {
+            Some(mut b) => Ok(mem::take(&mut b.blocks)),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+pub struct OrcAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<OrcBlocks>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            return Ok(vec![]);
+        }
+        let data = mem::take(&mut self.buffers).concat();
+        let size = data.len();
+        let path = &self.split_info.file.path;
+        debug!("aligning orc file {} of {} bytes", path, size);
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let reader = Reader::new(Cursor::new(data)).map_err(|e| orc_error(path, e))?;
+        let projection = OrcProjection::try_create(&self.ctx, path, &reader)?;
+        let cursor =
+            OrcCursor::new(reader, &projection.file_columns).map_err(|e| orc_error(path, e))?;
+        let arrow_schema = Arc::new(create_arrow_schema(&cursor));
+        let batch_size = self.ctx.block_compact_thresholds.max_rows_per_block;
+        let mut blocks = vec![];
+        // `ArrowReader` stops after the first stripe, the stripes are decoded one by one.
+        for stripe in cursor {
+            let stripe = stripe.map_err(|e| orc_error(path, e))?;
+            let decoder = NaiveStripeDecoder::new(stripe, arrow_schema.clone(), batch_size)
+                .map_err(|e| orc_error(path, e))?;
+            for batch in decoder {
+                let batch = batch.map_err(|e| orc_error(path, e))?;
+                if batch.num_rows() > 0 {
+                    blocks.push(projection.to_block(&self.ctx, &batch)?);
+                }
+            }
+        }
+        Ok(vec![OrcBlocks {
+            size,
+            rows: blocks.iter().map(|b| b.num_rows()).sum(),
+            blocks,
+        }])
+    }
+}
+
+fn orc_error(path: &str, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to parse orc file {}: {}", path, e))
+}
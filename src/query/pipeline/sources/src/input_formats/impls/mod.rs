@@ -12,14 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod input_format_avro;
+mod input_format_clickhouse_binary;
 mod input_format_csv;
 mod input_format_ndjson;
+mod input_format_orc;
 mod input_format_parquet;
 mod input_format_tsv;
 mod input_format_xml;
 
+pub use input_format_avro::InputFormatAvro;
+pub use input_format_clickhouse_binary::InputFormatNative;
+pub use input_format_clickhouse_binary::InputFormatRowBinary;
 pub use input_format_csv::InputFormatCSV;
 pub use input_format_ndjson::InputFormatNDJson;
+pub use input_format_orc::InputFormatOrc;
 pub use input_format_parquet::InputFormatParquet;
 pub use input_format_tsv::InputFormatTSV;
 pub use input_format_xml::InputFormatXML;
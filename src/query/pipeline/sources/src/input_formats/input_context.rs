@@ -33,6 +33,7 @@ use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataSchema;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
+use databend_common_formats::ClickhouseBinaryFormat;
 use databend_common_formats::ClickhouseFormatType;
 use databend_common_formats::FileFormatOptionsExt;
 use databend_common_meta_app::principal::FileFormatParams;
@@ -45,9 +46,13 @@ use databend_common_storage::FileParseError;
 use databend_common_storage::FileStatus;
 use opendal::Operator;
 
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::impls::InputFormatNative;
+use crate::input_formats::impls::InputFormatOrc;
 use crate::input_formats::impls::InputFormatParquet;
+use crate::input_formats::impls::InputFormatRowBinary;
 use crate::input_formats::impls::InputFormatTSV;
 use crate::input_formats::impls::InputFormatXML;
 use crate::input_formats::InputFormat;
@@ -157,6 +162,8 @@ impl InputContext {
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro::create())),
+            FileFormatParams::Orc(_) => Ok(Arc::new(InputFormatOrc {})),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -224,7 +231,11 @@ impl InputContext {
         let typ = ClickhouseFormatType::parse_clickhouse_format(format_name)?;
         let file_format_options_ext =
             FileFormatOptionsExt::create_from_clickhouse_format(typ.clone(), &settings)?;
-        let mut file_format_params = FileFormatParams::default_by_type(typ.typ)?;
+        // binary formats have no params of their own, the default one is never used.
+        let mut file_format_params = match typ.binary {
+            Some(_) => FileFormatParams::default(),
+            None => FileFormatParams::default_by_type(typ.typ)?,
+        };
 
         let headers = file_format_options_ext.headers as u64;
         if headers > 0 {
@@ -239,9 +250,21 @@ impl InputContext {
             }
         }
 
-        let format = Self::get_input_format(&file_format_params)?;
+        let (format, compression): (Arc<dyn InputFormat>, _) = match typ.binary {
+            Some(ClickhouseBinaryFormat::RowBinary) => (
+                Arc::new(InputFormatRowBinary::default()),
+                StageFileCompression::None,
+            ),
+            Some(ClickhouseBinaryFormat::Native) => (
+                Arc::new(InputFormatNative::default()),
+                StageFileCompression::None,
+            ),
+            None => (
+                Self::get_input_format(&file_format_params)?,
+                StageFileCompression::Auto,
+            ),
+        };
         let read_batch_size = settings.get_input_read_buffer_size()? as usize;
-        let compression = StageFileCompression::Auto;
         let plan = StreamPlan {
             is_multi_part: false,
             compression,
@@ -24,6 +24,8 @@ mod source_aligner;
 mod transform_deserializer;
 
 pub use beyond_end_reader::BeyondEndReader;
+pub use error_utils::rejected_rows_block;
+pub use error_utils::rejected_rows_schema;
 pub use input_context::InputContext;
 pub use input_context::InputPlan;
 pub use input_context::StreamPlan;
@@ -74,6 +74,8 @@ pub enum OperatorType {
     Insert,
     ConstantTableScan,
     Udf,
+    RecursiveCte,
+    RecursiveCteScan,
 }
 
 impl Display for OperatorType {
@@ -97,6 +99,8 @@ impl Display for OperatorType {
             OperatorType::CteScan => write!(f, "CteScan"),
             OperatorType::ConstantTableScan => write!(f, "ConstantTableScan"),
             OperatorType::Udf => write!(f, "Udf"),
+            OperatorType::RecursiveCte => write!(f, "RecursiveCte"),
+            OperatorType::RecursiveCteScan => write!(f, "RecursiveCteScan"),
         }
     }
 }
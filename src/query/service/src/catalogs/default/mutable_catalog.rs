@@ -30,6 +30,8 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreatePipeReply;
+use databend_common_meta_app::schema::CreatePipeReq;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReply;
@@ -44,6 +46,8 @@ use databend_common_meta_app::schema::DropDatabaseReply;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReply;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropPipeReply;
+use databend_common_meta_app::schema::DropPipeReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableReply;
 use databend_common_meta_app::schema::DropVirtualColumnReply;
@@ -55,6 +59,9 @@ use databend_common_meta_app::schema::GcDroppedTableResp;
 use databend_common_meta_app::schema::GetDatabaseReq;
 use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
+use databend_common_meta_app::schema::GetPipeLoadedFileReply;
+use databend_common_meta_app::schema::GetPipeLoadedFileReq;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::IndexMeta;
@@ -64,15 +71,19 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListPipesReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
+use databend_common_meta_app::schema::PipeInfo;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -84,6 +95,10 @@ use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReply;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdatePipeMetaReply;
+use databend_common_meta_app::schema::UpdatePipeMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -489,6 +504,18 @@ impl Catalog for MutableCatalog {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        info!(
+            "updating multi table meta. number of tables: {}",
+            req.update_table_metas.len()
+        );
+        Ok(self.ctx.meta.update_multi_table_meta(req).await?)
+    }
+
     async fn set_table_column_mask_policy(
         &self,
         req: SetTableColumnMaskPolicyReq,
@@ -496,6 +523,13 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.set_table_column_mask_policy(req).await?)
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Ok(self.ctx.meta.set_table_row_access_policy(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
@@ -555,6 +589,39 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.list_locks(req).await?)
     }
 
+    #[async_backtrace::framed]
+    async fn create_pipe(&self, req: CreatePipeReq) -> Result<CreatePipeReply> {
+        Ok(self.ctx.meta.create_pipe(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_pipe(&self, req: DropPipeReq) -> Result<DropPipeReply> {
+        Ok(self.ctx.meta.drop_pipe(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipe(&self, req: GetPipeReq) -> Result<Arc<PipeInfo>> {
+        Ok(self.ctx.meta.get_pipe(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn update_pipe_meta(&self, req: UpdatePipeMetaReq) -> Result<UpdatePipeMetaReply> {
+        Ok(self.ctx.meta.update_pipe_meta(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_pipes(&self, req: ListPipesReq) -> Result<Vec<Arc<PipeInfo>>> {
+        Ok(self.ctx.meta.list_pipes(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipe_loaded_file_info(
+        &self,
+        req: GetPipeLoadedFileReq,
+    ) -> Result<GetPipeLoadedFileReply> {
+        Ok(self.ctx.meta.get_pipe_loaded_file_info(req).await?)
+    }
+
     fn get_table_engines(&self) -> Vec<StorageDescription> {
         self.ctx.storage_factory.get_storage_descriptors()
     }
@@ -42,7 +42,9 @@ use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::locks::LockManager;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::QueryQueueManager;
 use crate::sessions::SessionManager;
+use crate::task::TaskScheduler;
 
 pub struct GlobalServices;
 
@@ -100,6 +102,8 @@ impl GlobalServices {
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
         LockManager::init()?;
+        QueryQueueManager::init()?;
+        TaskScheduler::init(config)?;
         AuthMgr::init(config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
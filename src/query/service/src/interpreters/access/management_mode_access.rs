@@ -108,6 +108,10 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
                 | Plan::DropPasswordPolicy(_)
+                // Workload group.
+                | Plan::CreateWorkloadGroup(_)
+                | Plan::AlterWorkloadGroup(_)
+                | Plan::DropWorkloadGroup(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
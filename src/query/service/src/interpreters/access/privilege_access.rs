@@ -374,6 +374,18 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::RefreshNgramIndex(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    false,
+                )
+                    .await?;
+            }
 
             // Table.
             Plan::ShowCreateTable(plan) => {
@@ -632,6 +644,7 @@ impl AccessChecker for PrivilegeAccess {
                     }
                     InsertInputSource::StreamingWithFormat(..)
                     | InsertInputSource::StreamingWithFileFormat {..}
+                    | InsertInputSource::StreamingWithBlocks(_)
                     | InsertInputSource::Values {..} => {}
                 }
             }
@@ -656,6 +669,7 @@ impl AccessChecker for PrivilegeAccess {
                     }
                     InsertInputSource::StreamingWithFormat(..)
                     | InsertInputSource::StreamingWithFileFormat {..}
+                    | InsertInputSource::StreamingWithBlocks(_)
                     | InsertInputSource::Values {..} => {}
                 }
             }
@@ -936,6 +950,11 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
+            | Plan::DescWorkloadGroup(_)
+            | Plan::ShowWorkloadGroups(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -945,7 +964,11 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DescribeTask(_) // TODO: need to build ownership info for task
             | Plan::ExecuteTask(_)  // TODO: need to build ownership info for task
             | Plan::DropTask(_)     // TODO: need to build ownership info for task
-            | Plan::AlterTask(_) => {
+            | Plan::AlterTask(_)
+            | Plan::CreatePipe(_)
+            | Plan::AlterPipe(_)
+            | Plan::DropPipe(_)
+            | Plan::DescribePipe(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
                     .await?;
             }
@@ -957,11 +980,21 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            // Attaching a policy needs SUPER too, or a table owner could drop the policy restricting them
+            Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::AddTableRowAccessPolicy(_)
+            | Plan::DropTableRowAccessPolicy(_) => {
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
+                    .await?;
+            }
             // Note: No need to check privileges
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
             Plan::ShowRoles(_) => {}
+            // Transaction control only touches the session state, the statements inside are checked on their own
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
             Plan::Presign(plan) => {
                 let privilege = match &plan.action {
                     PresignAction::Upload => UserPrivilegeType::Write,
@@ -974,6 +1007,7 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::DescRowAccessPolicy(_) => {}
         }
 
         Ok(())
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_ast::ast::ScheduleOptions;
@@ -20,7 +21,12 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::ClientConfig;
 use databend_common_cloud_control::pb::schedule_options::ScheduleType;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::task_dag_root;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskSchedule;
+use databend_common_users::UserApiProvider;
 
 use crate::sessions::QueryContext;
 
@@ -47,6 +53,30 @@ pub fn make_schedule_options(
     }
 }
 
+pub fn make_task_schedule(opt: ScheduleOptions) -> TaskSchedule {
+    match opt {
+        ScheduleOptions::IntervalMinutes(minute) => TaskSchedule::IntervalMinutes(minute),
+        ScheduleOptions::CronExpression(expr, timezone) => TaskSchedule::Cron(expr, timezone),
+    }
+}
+
+/// Checks that the tasks of the tenant still form DAGs with a single root each, once `task`
+/// is created or altered.
+#[async_backtrace::framed]
+pub async fn check_task_dags(tenant: &str, task: &Task) -> Result<()> {
+    let mut tasks = UserApiProvider::instance()
+        .get_tasks(tenant)
+        .await?
+        .into_iter()
+        .map(|task| (task.task_name.clone(), task))
+        .collect::<BTreeMap<_, _>>();
+    tasks.insert(task.task_name.clone(), task.clone());
+    for task_name in tasks.keys() {
+        task_dag_root(&tasks, task_name).map_err(ErrorCode::IllegalTask)?;
+    }
+    Ok(())
+}
+
 pub fn make_warehouse_options(
     opt: WarehouseOptions,
 ) -> databend_common_cloud_control::pb::WarehouseOptions {
@@ -15,6 +15,9 @@
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::ThreadTracker;
+use databend_common_base::runtime::TrackedFuture;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -55,9 +58,22 @@ pub trait Interpreter: Sync + Send {
             log_query_finished(&ctx, Some(err.clone()), false);
             return Err(err);
         }
-        let mut build_res = match self.execute2().await {
+        if ctx.get_current_session().get_type().is_user_session() {
+            if let Err(err) = ctx.acquire_workload_group().await {
+                InterpreterMetrics::record_query_error(&ctx);
+                log_query_finished(&ctx, Some(err.clone()), false);
+                return Err(err);
+            }
+        }
+        // The memory of a query admitted by a workload group is reported to the group,
+        // including the threads spawned to build and execute the pipeline.
+        let mem_stat = ctx.get_workload_group_mem_stat().or_else(MemStat::current);
+        let build_res =
+            TrackedFuture::create_with_mem_stat(mem_stat.clone(), self.execute2()).await;
+        let mut build_res = match build_res {
             Ok(build_res) => build_res,
             Err(build_error) => {
+                ctx.txn_mgr().lock().set_fail();
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()), false);
                 return Err(build_error);
@@ -102,7 +118,10 @@ pub trait Interpreter: Sync + Send {
 
             let err_opt = match may_error {
                 Ok(_) => None,
-                Err(e) => Some(e.clone()),
+                Err(e) => {
+                    query_ctx.txn_mgr().lock().set_fail();
+                    Some(e.clone())
+                }
             };
 
             InterpreterMetrics::record_query_finished(&query_ctx, err_opt.clone());
@@ -115,6 +134,7 @@ pub trait Interpreter: Sync + Send {
         });
 
         ctx.set_status_info("executing pipeline");
+        let _guard = ThreadTracker::enter(mem_stat);
 
         let settings = ctx.get_settings();
         let query_id = ctx.get_id();
@@ -175,6 +195,7 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
     let now = SystemTime::now();
     let session = ctx.get_current_session();
 
+    ctx.release_workload_group();
     session.get_status().write().query_finish();
     if session.get_type().is_user_session() {
         SessionManager::instance().status.write().query_finish(now)
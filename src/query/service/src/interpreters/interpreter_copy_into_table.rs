@@ -27,7 +27,10 @@ use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::FromData;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::EmptySink;
+use databend_common_pipeline_sources::input_formats::rejected_rows_block;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
 use databend_common_sql::executor::physical_plans::Exchange;
@@ -49,6 +52,7 @@ use crate::interpreters::hook::CompactTargetTableDescription;
 use crate::interpreters::hook::RefreshDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -56,6 +60,7 @@ use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::CopyIntoTablePlan;
 use crate::sql::plans::Plan;
+use crate::sql::plans::ValidationMode;
 use crate::stream::DataBlockStream;
 
 pub struct CopyIntoTableInterpreter {
@@ -224,6 +229,57 @@ impl CopyIntoTableInterpreter {
         Ok(())
     }
 
+    /// Build a pipeline which only reads and parses the files without loading them,
+    /// used by VALIDATION_MODE.
+    #[async_backtrace::framed]
+    async fn build_validation_pipeline(
+        &self,
+        plan: &CopyIntoTablePlan,
+    ) -> Result<PipelineBuildResult> {
+        let to_table = self
+            .ctx
+            .get_table(
+                plan.catalog_info.catalog_name(),
+                &plan.database_name,
+                &plan.table_name,
+            )
+            .await?;
+        let files = plan.collect_files(self.ctx.as_ref()).await?;
+        let mut build_res = PipelineBuildResult::create();
+        if files.is_empty() {
+            return Ok(build_res);
+        }
+
+        self.build_read_stage_table_data_pipeline(
+            &mut build_res.main_pipeline,
+            plan,
+            to_table.get_block_thresholds(),
+            files,
+        )
+        .await?;
+        match plan.validation_mode {
+            // The parsed rows are returned as the result set.
+            ValidationMode::ReturnNRows(n) => {
+                build_res.main_pipeline.try_resize(1)?;
+                build_res.main_pipeline.add_transform(|input, output| {
+                    Ok(ProcessorPtr::create(TransformLimit::try_create(
+                        Some(n as usize),
+                        0,
+                        input,
+                        output,
+                    )?))
+                })?;
+            }
+            // The rejected rows are collected in the copy status, see `inject_result`.
+            _ => {
+                build_res
+                    .main_pipeline
+                    .add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))))?;
+            }
+        }
+        Ok(build_res)
+    }
+
     fn get_copy_into_table_result(&self) -> Result<Vec<DataBlock>> {
         let return_all = !self
             .plan
@@ -341,6 +397,9 @@ impl Interpreter for CopyIntoTableInterpreter {
         if self.plan.no_file_to_copy {
             return Ok(PipelineBuildResult::create());
         }
+        if self.plan.validation_mode != ValidationMode::None {
+            return self.build_validation_pipeline(&self.plan).await;
+        }
         let (physical_plan, files, update_stream_meta) =
             self.build_physical_plan(&self.plan).await?;
         let mut build_res =
@@ -399,6 +458,11 @@ impl Interpreter for CopyIntoTableInterpreter {
     fn inject_result(&self) -> Result<SendableDataBlockStream> {
         let blocks = if self.plan.no_file_to_copy {
             vec![DataBlock::empty_with_schema(self.plan.schema())]
+        } else if matches!(
+            self.plan.validation_mode,
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors
+        ) {
+            vec![rejected_rows_block(&self.ctx.get_copy_status())]
         } else {
             self.get_copy_into_table_result()?
         };
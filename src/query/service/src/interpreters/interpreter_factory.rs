@@ -15,7 +15,10 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::ExplainKind;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_storages_fuse::FuseTable;
 use log::error;
 
 use super::interpreter_catalog_create::CreateCatalogInterpreter;
@@ -38,6 +41,10 @@ use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
 use crate::interpreters::interpreter_file_format_create::CreateFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
+use crate::interpreters::interpreter_pipe_alter::AlterPipeInterpreter;
+use crate::interpreters::interpreter_pipe_create::CreatePipeInterpreter;
+use crate::interpreters::interpreter_pipe_describe::DescribePipeInterpreter;
+use crate::interpreters::interpreter_pipe_drop::DropPipeInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
@@ -48,6 +55,9 @@ use crate::interpreters::interpreter_task_describe::DescribeTaskInterpreter;
 use crate::interpreters::interpreter_task_drop::DropTaskInterpreter;
 use crate::interpreters::interpreter_task_execute::ExecuteTaskInterpreter;
 use crate::interpreters::interpreter_tasks_show::ShowTasksInterpreter;
+use crate::interpreters::interpreter_txn_abort::AbortInterpreter;
+use crate::interpreters::interpreter_txn_begin::BeginInterpreter;
+use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
@@ -68,15 +78,50 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        // A failed transaction can only be ended.
+        if ctx.txn_mgr().lock().is_fail() && !matches!(plan, Plan::Commit | Plan::Abort) {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+        if ctx.txn_mgr().lock().is_active() {
+            Self::check_in_transaction(&ctx, plan).await.map_err(|e| {
+                ctx.txn_mgr().lock().set_fail();
+                e
+            })?;
+        }
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
             error!("Access.denied(v2): {:?}", e);
+            ctx.txn_mgr().lock().set_fail();
             e
         })?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Only the Fuse tables take part in a transaction, the writes to the other
+    /// tables would be committed immediately.
+    async fn check_in_transaction(ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        if !plan.is_allowed_in_transaction() {
+            return Err(ErrorCode::DDLInTransaction(
+                "only queries and DML statements are allowed in an explicit transaction",
+            ));
+        }
+        if let Some((catalog, database, table)) = plan.write_target() {
+            let table = ctx.get_table(catalog, database, table).await?;
+            if FuseTable::try_from_table(table.as_ref()).is_err() {
+                return Err(ErrorCode::TableNotSupportTransaction(format!(
+                    "writing to the {} table `{}` is not allowed in an explicit transaction",
+                    table.engine(),
+                    table.name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
             Plan::Query {
@@ -181,6 +226,12 @@ impl InterpreterFactory {
                 ctx,
                 *set_options.clone(),
             )?)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
                 RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
             )),
@@ -262,6 +313,9 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::RefreshNgramIndex(plan) => Ok(Arc::new(
+                RefreshNgramIndexInterpreter::try_create(ctx, *plan.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -452,6 +506,15 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
 
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
@@ -486,6 +549,25 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
 
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescWorkloadGroup(p) => Ok(Arc::new(DescWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowWorkloadGroups(_) => {
+                Ok(Arc::new(ShowWorkloadGroupsInterpreter::try_create(ctx)?))
+            }
+
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -502,6 +584,17 @@ impl InterpreterFactory {
             )?)),
             Plan::ShowTasks(p) => Ok(Arc::new(ShowTasksInterpreter::try_create(ctx, *p.clone())?)),
 
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescribePipe(p) => Ok(Arc::new(DescribePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
             Plan::CreateConnection(p) => Ok(Arc::new(CreateConnectionInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -515,6 +608,10 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowConnections(_) => Ok(Arc::new(ShowConnectionsInterpreter::try_create(ctx)?)),
+
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
         }
     }
 }
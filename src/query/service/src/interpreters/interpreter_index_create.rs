@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use chrono::Utc;
+use databend_common_ast::ast::TableIndexType;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_license::license::Feature;
@@ -51,17 +52,25 @@ impl Interpreter for CreateIndexInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let tenant = self.ctx.get_tenant();
-        let license_manager = get_license_manager();
-        license_manager
-            .manager
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::AggregateIndex)?;
+        let index_type = match self.plan.index_type {
+            TableIndexType::Aggregating => {
+                let license_manager = get_license_manager();
+                license_manager.manager.check_enterprise_enabled(
+                    self.ctx.get_license_key(),
+                    Feature::AggregateIndex,
+                )?;
+                IndexType::AGGREGATING
+            }
+            TableIndexType::Vector => IndexType::VECTOR,
+        };
 
         let index_name = self.plan.index_name.clone();
         let catalog = self.ctx.get_current_catalog();
         if catalog != "default" {
-            return Err(ErrorCode::CatalogNotSupported(
-                "Only allow creating aggregating index in default catalog",
-            ));
+            return Err(ErrorCode::CatalogNotSupported(format!(
+                "Only allow creating {} index in default catalog",
+                self.plan.index_type.to_string().to_lowercase()
+            )));
         }
 
         let catalog = self.ctx.get_catalog(&catalog).await?;
@@ -71,7 +80,7 @@ impl Interpreter for CreateIndexInterpreter {
             name_ident: IndexNameIdent { tenant, index_name },
             meta: IndexMeta {
                 table_id: self.plan.table_id,
-                index_type: IndexType::AGGREGATING,
+                index_type,
                 created_on: Utc::now(),
                 dropped_on: None,
                 updated_on: None,
@@ -81,8 +90,12 @@ impl Interpreter for CreateIndexInterpreter {
             },
         };
 
-        let handler = get_agg_index_handler();
-        let _ = handler.do_create_index(catalog, create_index_req).await?;
+        if index_type == IndexType::AGGREGATING {
+            let handler = get_agg_index_handler();
+            let _ = handler.do_create_index(catalog, create_index_req).await?;
+        } else {
+            let _ = catalog.create_index(create_index_req).await?;
+        }
 
         Ok(PipelineBuildResult::create())
     }
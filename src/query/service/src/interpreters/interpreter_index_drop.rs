@@ -14,11 +14,15 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::TableIndexType;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::IndexType;
 use databend_common_sql::plans::DropIndexPlan;
 use databend_enterprise_aggregating_index::get_agg_index_handler;
 
@@ -47,23 +51,45 @@ impl Interpreter for DropIndexInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let tenant = self.ctx.get_tenant();
-        let license_manager = get_license_manager();
-        license_manager
-            .manager
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::AggregateIndex)?;
-
         let index_name = self.plan.index.clone();
         let catalog = self
             .ctx
             .get_catalog(&self.ctx.get_current_catalog())
             .await?;
+        let name_ident = IndexNameIdent { tenant, index_name };
         let drop_index_req = DropIndexReq {
             if_exists: self.plan.if_exists,
-            name_ident: IndexNameIdent { tenant, index_name },
+            name_ident: name_ident.clone(),
         };
 
-        let handler = get_agg_index_handler();
-        let _ = handler.do_drop_index(catalog, drop_index_req).await?;
+        match self.plan.index_type {
+            TableIndexType::Aggregating => {
+                let license_manager = get_license_manager();
+                license_manager.manager.check_enterprise_enabled(
+                    self.ctx.get_license_key(),
+                    Feature::AggregateIndex,
+                )?;
+
+                let handler = get_agg_index_handler();
+                let _ = handler.do_drop_index(catalog, drop_index_req).await?;
+            }
+            TableIndexType::Vector => {
+                let index = match catalog.get_index(GetIndexReq { name_ident }).await {
+                    Ok(index) => index,
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_INDEX && self.plan.if_exists => {
+                        return Ok(PipelineBuildResult::create());
+                    }
+                    Err(e) => return Err(e),
+                };
+                if index.index_meta.index_type != IndexType::VECTOR {
+                    return Err(ErrorCode::UnsupportedIndex(format!(
+                        "Index {} is not a vector index",
+                        self.plan.index
+                    )));
+                }
+                let _ = catalog.drop_index(drop_index_req).await?;
+            }
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_base::runtime::GlobalIORuntime;
@@ -29,6 +30,7 @@ use databend_common_expression::BLOCK_NAME_COL_NAME;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::IndexMeta;
+use databend_common_meta_app::schema::IndexType;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::evaluator::BlockOperator;
@@ -40,6 +42,7 @@ use databend_common_sql::plans::Plan;
 use databend_common_sql::plans::RefreshIndexPlan;
 use databend_common_sql::plans::RelOperator;
 use databend_common_storages_fuse::operations::AggIndexSink;
+use databend_common_storages_fuse::operations::VectorIndexSink;
 use databend_common_storages_fuse::pruning::create_segment_location_vector;
 use databend_common_storages_fuse::FuseLazyPartInfo;
 use databend_common_storages_fuse::FusePartInfo;
@@ -217,10 +220,13 @@ impl Interpreter for RefreshIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let license_manager = get_license_manager();
-        license_manager
-            .manager
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::AggregateIndex)?;
+        let is_agg_index = self.plan.index_meta.index_type == IndexType::AGGREGATING;
+        if is_agg_index {
+            let license_manager = get_license_manager();
+            license_manager
+                .manager
+                .check_enterprise_enabled(self.ctx.get_license_key(), Feature::AggregateIndex)?;
+        }
         let (mut query_plan, output_schema, select_columns) = match self.plan.query_plan.as_ref() {
             Plan::Query {
                 s_expr,
@@ -285,6 +291,15 @@ impl Interpreter for RefreshIndexInterpreter {
 
         let new_index_meta = self.update_index_meta(&new_read_source)?;
 
+        // The vector index of a block is written once all the rows of the block are read.
+        let mut block_rows: HashMap<String, usize> = HashMap::new();
+        if !is_agg_index {
+            for part in new_read_source.parts.partitions.iter() {
+                let part = FusePartInfo::from_part(part)?;
+                *block_rows.entry(part.location.clone()).or_default() += part.nums_rows;
+            }
+        }
+
         let mut replace_read_source = ReplaceReadSource {
             source: new_read_source,
         };
@@ -326,6 +341,47 @@ impl Interpreter for RefreshIndexInterpreter {
             })?;
         let block_name_offset = output_schema.index_of(&block_name_col.index.to_string())?;
 
+        let ctx = self.ctx.clone();
+        let req = UpdateIndexReq {
+            index_id: self.plan.index_id,
+            index_name: self.plan.index_name.clone(),
+            index_meta: new_index_meta,
+        };
+
+        if !is_agg_index {
+            // The vector index query selects exactly one column besides `_block_name`.
+            let vector_offset = (0..output_schema.num_fields())
+                .find(|i| *i != block_name_offset)
+                .ok_or_else(|| {
+                    ErrorCode::Internal(
+                        "vector column should contained in the input of refresh processor",
+                    )
+                })?;
+
+            build_res.main_pipeline.try_resize(1)?;
+            build_res.main_pipeline.add_sink(|input| {
+                VectorIndexSink::try_create(
+                    input,
+                    ctx.clone(),
+                    data_accessor.operator(),
+                    self.plan.index_id,
+                    vector_offset,
+                    block_name_offset,
+                    block_rows.clone(),
+                )
+            })?;
+
+            build_res
+                .main_pipeline
+                .set_on_finished(move |may_error| match may_error {
+                    Ok(_) => GlobalIORuntime::instance()
+                        .block_on(async move { modify_last_update(ctx, req, false).await }),
+                    Err(error_code) => Err(error_code.clone()),
+                });
+
+            return Ok(build_res);
+        }
+
         let fields = output_schema
             .fields()
             .iter()
@@ -348,7 +404,6 @@ impl Interpreter for RefreshIndexInterpreter {
 
         let write_settings = fuse_table.get_write_settings();
 
-        let ctx = self.ctx.clone();
         build_res.main_pipeline.try_resize(1)?;
         build_res.main_pipeline.add_sink(|input| {
             AggIndexSink::try_create(
@@ -363,18 +418,11 @@ impl Interpreter for RefreshIndexInterpreter {
             )
         })?;
 
-        let ctx = self.ctx.clone();
-        let req = UpdateIndexReq {
-            index_id: self.plan.index_id,
-            index_name: self.plan.index_name.clone(),
-            index_meta: new_index_meta,
-        };
-
         build_res
             .main_pipeline
             .set_on_finished(move |may_error| match may_error {
                 Ok(_) => GlobalIORuntime::instance()
-                    .block_on(async move { modify_last_update(ctx, req).await }),
+                    .block_on(async move { modify_last_update(ctx, req, true).await }),
                 Err(error_code) => Err(error_code.clone()),
             });
 
@@ -382,9 +430,17 @@ impl Interpreter for RefreshIndexInterpreter {
     }
 }
 
-async fn modify_last_update(ctx: Arc<QueryContext>, req: UpdateIndexReq) -> Result<()> {
+async fn modify_last_update(
+    ctx: Arc<QueryContext>,
+    req: UpdateIndexReq,
+    is_agg_index: bool,
+) -> Result<()> {
     let catalog = ctx.get_catalog(&ctx.get_current_catalog()).await?;
-    let handler = get_agg_index_handler();
-    let _ = handler.do_update_index(catalog, req).await?;
+    if is_agg_index {
+        let handler = get_agg_index_handler();
+        let _ = handler.do_update_index(catalog, req).await?;
+    } else {
+        let _ = catalog.update_index(req).await?;
+    }
     Ok(())
 }
@@ -22,7 +22,11 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::Pipe;
+use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_sources::StreamSource;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::executor::PhysicalPlanBuilder;
@@ -173,6 +177,33 @@ impl Interpreter for InsertInterpreter {
                     )?;
                 }
             }
+            InsertInputSource::StreamingWithBlocks(stream) => {
+                let stream = stream.lock().take().ok_or_else(|| {
+                    ErrorCode::Internal("the input stream of insert has been consumed")
+                })?;
+                let output = OutputPort::create();
+                let source = StreamSource::create(self.ctx.clone(), Some(stream), output.clone())?;
+                build_res
+                    .main_pipeline
+                    .add_pipe(Pipe::create(0, 1, vec![PipeItem::create(
+                        source,
+                        vec![],
+                        vec![output],
+                    )]));
+
+                let dest_schema = self.plan.schema();
+                let func_ctx = self.ctx.get_function_context()?;
+                build_res.main_pipeline.add_transform(
+                    |transform_input_port, transform_output_port| {
+                        TransformRuntimeCastSchema::try_create(
+                            transform_input_port,
+                            transform_output_port,
+                            dest_schema.clone(),
+                            func_ctx.clone(),
+                        )
+                    },
+                )?;
+            }
             InsertInputSource::SelectPlan(plan) => {
                 let table1 = table.clone();
                 let (mut select_plan, select_column_bindings, metadata) = match plan.as_ref() {
@@ -294,6 +294,8 @@ impl MergeIntoInterpreter {
             RelOperator::Window(_) => {}
             RelOperator::ProjectSet(_) => {}
             RelOperator::MaterializedCte(_) => {}
+            RelOperator::RecursiveCte(_) => {}
+            RelOperator::RecursiveCteScan(_) => {}
             RelOperator::ConstantTableScan(_) => {}
             RelOperator::Pattern(_) => {}
             RelOperator::AddRowNumber(_) => {}
@@ -0,0 +1,302 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::COPY_MAX_FILES_PER_COMMIT;
+use databend_common_meta_app::schema::GetPipeLoadedFileReq;
+use databend_common_meta_app::schema::GetPipeReq;
+use databend_common_meta_app::schema::PipeInfo;
+use databend_common_meta_app::schema::PipeMeta;
+use databend_common_meta_app::schema::TableCopiedFileInfo;
+use databend_common_meta_app::schema::UpdatePipeMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AlterPipePlan;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFilesInfo;
+use log::info;
+
+use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+const MAX_RETRY: usize = 10;
+
+pub struct AlterPipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPipePlan,
+}
+
+impl AlterPipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPipePlan) -> Result<Self> {
+        Ok(AlterPipeInterpreter { ctx, plan })
+    }
+}
+
+impl AlterPipeInterpreter {
+    async fn update_pipe(
+        &self,
+        pipe: &PipeInfo,
+        execution_paused: &Option<bool>,
+        comments: &Option<String>,
+    ) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_default_catalog()?;
+        update_pipe_meta(catalog, &self.plan.tenant, pipe, BTreeMap::new(), |meta| {
+            if let Some(execution_paused) = execution_paused {
+                meta.execution_paused = *execution_paused;
+            }
+            if let Some(comments) = comments {
+                meta.comment = comments.clone();
+            }
+            meta.updated_on = Utc::now();
+        })
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+
+    /// Run the `COPY INTO` of the pipe over the files it has not loaded yet.
+    ///
+    /// The loaded files are recorded once the copy is committed.
+    /// If the node crashes in between, the next refresh picks the files again
+    /// and the copied-files of the target table prevents loading them twice.
+    async fn refresh_pipe(
+        &self,
+        pipe: &PipeInfo,
+        prefix: &Option<String>,
+        modified_after: &Option<String>,
+    ) -> Result<PipelineBuildResult> {
+        if pipe.meta.execution_paused {
+            return Err(ErrorCode::BadArguments(format!(
+                "pipe '{}' is paused, set PIPE_EXECUTION_PAUSED = false before refreshing it",
+                pipe.pipe_name()
+            )));
+        }
+
+        let modified_after = match modified_after {
+            Some(v) => Some(
+                DateTime::parse_from_rfc3339(v)
+                    .map_err(|e| {
+                        ErrorCode::BadArguments(format!("invalid MODIFIED_AFTER '{}': {}", v, e))
+                    })?
+                    .with_timezone(&Utc),
+            ),
+            None => None,
+        };
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&pipe.meta.copy_stmt).await?;
+        let mut copy_plan = match plan {
+            Plan::CopyIntoTable(copy_plan) => copy_plan,
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "pipe '{}' is not defined by a COPY INTO <table> statement",
+                    pipe.pipe_name()
+                )));
+            }
+        };
+
+        let files = self
+            .list_new_files(pipe, &copy_plan.stage_table_info, prefix, modified_after)
+            .await?;
+        info!(
+            "refresh pipe '{}': {} new files to load",
+            pipe.pipe_name(),
+            files.len()
+        );
+        if files.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The listed paths are relative to the root of the stage,
+        // an empty path makes the copy read exactly these files.
+        copy_plan.stage_table_info.files_info = StageFilesInfo {
+            path: "".to_string(),
+            files: Some(files.iter().map(|f| f.path.clone()).collect()),
+            pattern: None,
+        };
+
+        let interpreter = CopyIntoTableInterpreter::try_create(self.ctx.clone(), *copy_plan)?;
+        let mut build_res = interpreter.execute2().await?;
+
+        let loaded_files = files
+            .into_iter()
+            .map(|f| {
+                (f.path, TableCopiedFileInfo {
+                    etag: f.etag,
+                    content_length: f.size,
+                    last_modified: Some(f.last_modified),
+                })
+            })
+            .collect();
+
+        let catalog = self.ctx.get_default_catalog()?;
+        let tenant = self.plan.tenant.clone();
+        let pipe = pipe.clone();
+        build_res.main_pipeline.set_on_finished(move |may_error| {
+            if may_error.is_ok() {
+                GlobalIORuntime::instance().block_on(async move {
+                    update_pipe_meta(catalog, &tenant, &pipe, loaded_files, |meta| {
+                        meta.last_refreshed_on = Some(Utc::now());
+                    })
+                    .await
+                })?;
+            }
+            Ok(())
+        });
+
+        Ok(build_res)
+    }
+
+    /// List the files of the pipe's location that match the refresh options
+    /// and have not been loaded by the pipe.
+    async fn list_new_files(
+        &self,
+        pipe: &PipeInfo,
+        stage_table_info: &StageTableInfo,
+        prefix: &Option<String>,
+        modified_after: Option<DateTime<Utc>>,
+    ) -> Result<Vec<StageFileInfo>> {
+        let operator = init_stage_operator(&stage_table_info.stage_info)?;
+        let files_info = &stage_table_info.files_info;
+        let all_files = files_info.list(&operator, false, None).await?;
+
+        let prefix = prefix.as_ref().map(|p| {
+            Path::new(&files_info.path)
+                .join(p)
+                .to_string_lossy()
+                .trim_start_matches('/')
+                .to_string()
+        });
+
+        let candidates = all_files
+            .into_iter()
+            .filter(|f| match &prefix {
+                Some(prefix) => f.path.trim_start_matches('/').starts_with(prefix.as_str()),
+                None => true,
+            })
+            .filter(|f| match modified_after {
+                Some(t) => f.last_modified > t,
+                None => true,
+            })
+            .collect::<Vec<_>>();
+
+        let catalog = self.ctx.get_default_catalog()?;
+        let loaded = catalog
+            .get_pipe_loaded_file_info(GetPipeLoadedFileReq {
+                pipe_id: pipe.ident.pipe_id,
+                files: candidates.iter().map(|f| f.path.clone()).collect(),
+            })
+            .await?
+            .file_info;
+
+        // Files beyond the limit of a single commit are left to the next refresh.
+        Ok(candidates
+            .into_iter()
+            .filter(|f| !loaded.contains_key(&f.path))
+            .take(COPY_MAX_FILES_PER_COMMIT)
+            .collect())
+    }
+}
+
+/// Apply `update` to the latest meta of the pipe and write it back.
+///
+/// The write only succeeds if the pipe has not been changed since it was read,
+/// otherwise the meta is read again and the update retried,
+/// so that a concurrent `ALTER PIPE` is never overwritten.
+async fn update_pipe_meta(
+    catalog: Arc<dyn Catalog>,
+    tenant: &str,
+    pipe: &PipeInfo,
+    loaded_files: BTreeMap<String, TableCopiedFileInfo>,
+    update: impl Fn(&mut PipeMeta),
+) -> Result<()> {
+    let mut pipe = Arc::new(pipe.clone());
+    let mut retry = 0;
+    loop {
+        let mut new_meta = pipe.meta.clone();
+        update(&mut new_meta);
+
+        let req = UpdatePipeMetaReq {
+            pipe_id: pipe.ident.pipe_id,
+            pipe_name: pipe.pipe_name().to_string(),
+            seq: MatchSeq::Exact(pipe.ident.seq),
+            new_meta,
+            loaded_files: loaded_files.clone(),
+        };
+        match catalog.update_pipe_meta(req).await {
+            Ok(_) => return Ok(()),
+            Err(e) if e.code() == ErrorCode::PIPE_VERSION_MISMATCHED && retry < MAX_RETRY => {
+                retry += 1;
+                info!(
+                    "pipe '{}' has been changed concurrently, retry updating it ({})",
+                    pipe.pipe_name(),
+                    retry
+                );
+                pipe = catalog
+                    .get_pipe(GetPipeReq::new(tenant, pipe.pipe_name()))
+                    .await?;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPipeInterpreter {
+    fn name(&self) -> &str {
+        "AlterPipeInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_default_catalog()?;
+        let pipe = match catalog
+            .get_pipe(GetPipeReq::new(&self.plan.tenant, &self.plan.pipe_name))
+            .await
+        {
+            Ok(pipe) => pipe,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_PIPE && self.plan.if_exists => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        match &self.plan.alter_options {
+            AlterPipeOptions::Set {
+                execution_paused,
+                comments,
+            } => self.update_pipe(&pipe, execution_paused, comments).await,
+            AlterPipeOptions::Refresh {
+                prefix,
+                modified_after,
+            } => self.refresh_pipe(&pipe, prefix, modified_after).await,
+        }
+    }
+}
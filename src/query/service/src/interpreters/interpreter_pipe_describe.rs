@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::schema::GetPipeReq;
+use databend_common_sql::plans::DescribePipePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DescribePipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescribePipePlan,
+}
+
+impl DescribePipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescribePipePlan) -> Result<Self> {
+        Ok(DescribePipeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescribePipeInterpreter {
+    fn name(&self) -> &str {
+        "DescribePipeInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_default_catalog()?;
+        let pipe = catalog
+            .get_pipe(GetPipeReq::new(&self.plan.tenant, &self.plan.pipe_name))
+            .await?;
+        let meta = &pipe.meta;
+
+        let block = DataBlock::new_from_columns(vec![
+            TimestampType::from_data(vec![meta.created_on.timestamp_micros()]),
+            StringType::from_data(vec![pipe.pipe_name()]),
+            StringType::from_data(vec![meta.copy_stmt.as_str()]),
+            BooleanType::from_data(vec![meta.auto_ingest]),
+            BooleanType::from_data(vec![meta.execution_paused]),
+            StringType::from_data(vec![meta.comment.as_str()]),
+            TimestampType::from_data(vec![meta.updated_on.timestamp_micros()]),
+            TimestampType::from_opt_data(vec![
+                meta.last_refreshed_on.map(|t| t.timestamp_micros()),
+            ]),
+        ]);
+
+        PipelineBuildResult::from_blocks(vec![block])
+    }
+}
@@ -49,6 +49,7 @@ use databend_common_users::UserApiProvider;
 use databend_enterprise_data_mask_feature::get_datamask_handler;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use super::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
@@ -234,6 +235,12 @@ impl ModifyTableColumnInterpreter {
                 bloom_index_cols = cols;
             }
         }
+        let mut ngram_index_cols = vec![];
+        if let Some(v) = table_info.options().get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+            if let BloomIndexColumns::Specify(cols) = v.parse::<BloomIndexColumns>()? {
+                ngram_index_cols = cols;
+            }
+        }
 
         let mut table_info = table.get_table_info().clone();
         table_info.meta.fill_field_comments();
@@ -261,6 +268,14 @@ impl ModifyTableColumnInterpreter {
                             data_type
                         )));
                     }
+                    if ngram_index_cols.iter().any(|v| v.as_str() == column)
+                        && !BloomIndex::ngram_supported_type(data_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for ngram index",
+                            data_type
+                        )));
+                    }
                     new_schema.fields[i].data_type = data_type.clone();
                     table_info.meta.field_comments[i] = comment.to_string();
                 }
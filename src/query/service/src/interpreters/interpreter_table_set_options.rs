@@ -30,6 +30,7 @@ use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_change_tracking;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_ngram_index_columns;
 use super::interpreter_table_create::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -97,8 +98,9 @@ impl Interpreter for SetOptionsInterpreter {
         // check mutability
         table.check_mutable()?;
 
-        // check bloom_index_columns.
+        // check bloom_index_columns and ngram_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        is_valid_ngram_index_columns(&self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
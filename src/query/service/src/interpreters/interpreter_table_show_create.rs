@@ -24,6 +24,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
 use databend_common_sql::plans::ShowCreateTablePlan;
+use databend_common_storages_stream::stream_table::StreamMode;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::QUERY;
@@ -228,6 +229,10 @@ impl ShowCreateTableInterpreter {
             stream_table.source_table_name()
         );
 
+        if stream_table.mode() == StreamMode::Standard {
+            create_sql.push_str(" APPEND_ONLY = false");
+        }
+
         let comment = stream_table.get_table_info().meta.comment.clone();
         if !comment.is_empty() {
             create_sql.push_str(format!(" COMMENT = '{}'", comment).as_str());
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
@@ -24,10 +25,15 @@ use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_users::UserApiProvider;
 
+use crate::interpreters::common::check_task_dags;
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -65,6 +71,9 @@ impl AlterTaskInterpreter {
             schedule_options: None,
             warehouse_options: None,
             suspend_task_after_num_failures: None,
+            when_condition: None,
+            add_after: vec![],
+            remove_after: vec![],
         };
         match plan.alter_options {
             AlterTaskOptions::Resume => {
@@ -96,11 +105,132 @@ impl AlterTaskInterpreter {
                 req.alter_task_type = AlterTaskType::ModifyAs as i32;
                 req.query_text = Some(sql);
             }
+            AlterTaskOptions::ModifyWhen(when_condition) => {
+                req.alter_task_type = AlterTaskType::ModifyWhen as i32;
+                req.when_condition = Some(when_condition);
+            }
+            AlterTaskOptions::AddAfter(after) => {
+                req.alter_task_type = AlterTaskType::AddAfter as i32;
+                req.add_after = after;
+            }
+            AlterTaskOptions::RemoveAfter(after) => {
+                req.alter_task_type = AlterTaskType::RemoveAfter as i32;
+                req.remove_after = after;
+            }
         }
         req
     }
 }
 
+impl AlterTaskInterpreter {
+    #[async_backtrace::framed]
+    async fn alter_local_task(&self) -> Result<()> {
+        let plan = self.plan.clone();
+        let user_api = UserApiProvider::instance();
+        let seq_task = match user_api.get_task(&plan.tenant, &plan.task_name).await {
+            Ok(seq_task) => seq_task,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_TASK => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        let mut task = seq_task.data;
+        let now = Utc::now();
+        match plan.alter_options {
+            AlterTaskOptions::Resume => {
+                if task.status != TaskStatus::Started {
+                    task.status = TaskStatus::Started;
+                    task.consecutive_failures = 0;
+                    // The tasks running after other tasks are not scheduled by themselves.
+                    task.next_scheduled_at = match &task.schedule {
+                        Some(schedule) => {
+                            Some(schedule.next_after(now).map_err(ErrorCode::IllegalTask)?)
+                        }
+                        None => None,
+                    };
+                }
+            }
+            AlterTaskOptions::Suspend => {
+                if task.status != TaskStatus::Suspended {
+                    task.status = TaskStatus::Suspended;
+                    task.next_scheduled_at = None;
+                    task.last_suspended_at = Some(now);
+                }
+            }
+            AlterTaskOptions::Set {
+                schedule,
+                comments,
+                warehouse,
+                suspend_task_after_num_failures,
+            } => {
+                if let Some(schedule) = schedule {
+                    if !task.after.is_empty() {
+                        return Err(ErrorCode::IllegalTask(format!(
+                            "task {} runs after other tasks, it can not have a schedule",
+                            task.task_name
+                        )));
+                    }
+                    let schedule = make_task_schedule(schedule);
+                    if task.status == TaskStatus::Started {
+                        task.next_scheduled_at =
+                            Some(schedule.next_after(now).map_err(ErrorCode::IllegalTask)?);
+                    }
+                    task.schedule = Some(schedule);
+                }
+                if let Some(comment) = comments {
+                    task.comment = comment;
+                }
+                if warehouse.is_some() {
+                    task.warehouse = warehouse;
+                }
+                if suspend_task_after_num_failures.is_some() {
+                    task.suspend_task_after_num_failures = suspend_task_after_num_failures;
+                }
+            }
+            AlterTaskOptions::Unset { warehouse } => {
+                if warehouse {
+                    task.warehouse = None;
+                }
+            }
+            AlterTaskOptions::ModifyAs(sql) => {
+                task.query_text = sql;
+            }
+            AlterTaskOptions::ModifyWhen(when_condition) => {
+                task.when_condition = Some(when_condition);
+            }
+            AlterTaskOptions::AddAfter(after) => {
+                if task.schedule.is_some() {
+                    return Err(ErrorCode::IllegalTask(format!(
+                        "task {} has a schedule, it can not run after other tasks",
+                        task.task_name
+                    )));
+                }
+                for predecessor in after {
+                    if !task.after.contains(&predecessor) {
+                        task.after.push(predecessor);
+                    }
+                }
+                check_task_dags(&plan.tenant, &task).await?;
+            }
+            AlterTaskOptions::RemoveAfter(after) => {
+                task.after
+                    .retain(|predecessor| !after.contains(predecessor));
+                if task.after.is_empty() {
+                    return Err(ErrorCode::IllegalTask(format!(
+                        "task {} has no schedule, it must run after at least one task",
+                        task.task_name
+                    )));
+                }
+            }
+        }
+        task.updated_at = now;
+
+        user_api
+            .update_task(&plan.tenant, task, MatchSeq::Exact(seq_task.seq))
+            .await?;
+        Ok(())
+    }
+}
+
 #[async_trait::async_trait]
 impl Interpreter for AlterTaskInterpreter {
     fn name(&self) -> &str {
@@ -112,9 +242,8 @@ impl Interpreter for AlterTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.alter_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
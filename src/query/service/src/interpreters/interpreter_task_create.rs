@@ -14,17 +14,22 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::CreateTaskRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskStatus;
 use databend_common_sql::plans::CreateTaskPlan;
+use databend_common_users::UserApiProvider;
 
+use crate::interpreters::common::check_task_dags;
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule;
 use crate::interpreters::common::make_warehouse_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -57,14 +62,51 @@ impl CreateTaskInterpreter {
             query_text: plan.sql,
             owner,
             comment: Some(plan.comment),
-            schedule_options: Some(make_schedule_options(plan.schedule_opts)),
+            schedule_options: plan.schedule_opts.map(make_schedule_options),
             warehouse_options: Some(make_warehouse_options(plan.warehouse_opts)),
             suspend_task_after_num_failures: plan.suspend_task_after_num_failures.map(|x| x as i32),
             if_not_exist: plan.if_not_exists,
+            after: plan.after,
+            when_condition: plan.when_condition,
         }
     }
 }
 
+impl CreateTaskInterpreter {
+    /// Stores the task in the meta service, it is run by the task scheduler of the query nodes.
+    #[async_backtrace::framed]
+    async fn create_local_task(&self) -> Result<()> {
+        let plan = self.plan.clone();
+        let owner = self.ctx.get_current_role().unwrap_or_default().name;
+        let now = Utc::now();
+        let task = Task {
+            task_id: 0,
+            task_name: plan.task_name,
+            query_text: plan.sql,
+            comment: plan.comment,
+            owner,
+            schedule: plan.schedule_opts.map(make_task_schedule),
+            warehouse: plan.warehouse_opts.warehouse,
+            status: TaskStatus::Suspended,
+            suspend_task_after_num_failures: plan.suspend_task_after_num_failures,
+            consecutive_failures: 0,
+            next_scheduled_at: None,
+            last_suspended_at: None,
+            created_at: now,
+            updated_at: now,
+            after: plan.after,
+            when_condition: plan.when_condition,
+        };
+        if !task.after.is_empty() {
+            check_task_dags(&plan.tenant, &task).await?;
+        }
+        UserApiProvider::instance()
+            .add_task(&plan.tenant, task, plan.if_not_exists)
+            .await?;
+        Ok(())
+    }
+}
+
 #[async_trait::async_trait]
 impl Interpreter for CreateTaskInterpreter {
     fn name(&self) -> &str {
@@ -76,9 +118,8 @@ impl Interpreter for CreateTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.create_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -21,6 +21,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DropTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
@@ -48,6 +49,30 @@ impl DropTaskInterpreter {
             if_exist: plan.if_exists,
         }
     }
+
+    /// Drops the task stored in the meta service, unless other tasks run after it.
+    #[async_backtrace::framed]
+    async fn drop_local_task(&self) -> Result<()> {
+        let plan = &self.plan;
+        let user_api = UserApiProvider::instance();
+        let successors = user_api
+            .get_tasks(&plan.tenant)
+            .await?
+            .into_iter()
+            .filter(|task| task.after.contains(&plan.task_name))
+            .map(|task| task.task_name)
+            .collect::<Vec<_>>();
+        if !successors.is_empty() {
+            return Err(ErrorCode::IllegalTask(format!(
+                "task {} can not be dropped, tasks {} run after it",
+                plan.task_name,
+                successors.join(", ")
+            )));
+        }
+        user_api
+            .drop_task(&plan.tenant, &plan.task_name, plan.if_exists)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -61,9 +86,8 @@ impl Interpreter for DropTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.drop_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
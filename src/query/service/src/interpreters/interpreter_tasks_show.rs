@@ -12,16 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::ShowTasksPlan;
+use databend_common_storages_system::parse_local_tasks_to_datablock;
 use databend_common_storages_system::parse_tasks_to_datablock;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
@@ -73,9 +75,20 @@ impl Interpreter for ShowTasksInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let roles = self
+                .ctx
+                .get_current_session()
+                .get_all_available_roles()
+                .await?
+                .into_iter()
+                .map(|role| role.name)
+                .collect::<HashSet<_>>();
+            let mut tasks = UserApiProvider::instance()
+                .get_tasks(&self.plan.tenant)
+                .await?;
+            tasks.retain(|task| roles.contains(&task.owner));
+            let result = parse_local_tasks_to_datablock(tasks);
+            return PipelineBuildResult::from_blocks(vec![result]);
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+
+    async fn commit(&self) -> Result<()> {
+        let (req, catalogs) = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let guard = txn_mgr.lock();
+            // The changes of a failed transaction are discarded, the client must know it.
+            if guard.is_fail() {
+                return Err(ErrorCode::CurrentTransactionIsAborted(
+                    "current transaction is aborted, the changes are rolled back",
+                ));
+            }
+            if !guard.is_active() {
+                return Ok(());
+            }
+            (guard.req(), guard.mutated_catalogs())
+        };
+
+        if req.update_table_metas.is_empty() {
+            return Ok(());
+        }
+        if catalogs.len() > 1 {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Transaction across catalogs is not supported, mutated catalogs: {:?}",
+                catalogs
+            )));
+        }
+
+        let catalog = self.ctx.get_catalog(&catalogs[0]).await?;
+        info!(
+            "commit transaction, mutated tables: {:?}",
+            req.update_table_metas
+                .iter()
+                .map(|r| r.table_id)
+                .collect::<Vec<_>>()
+        );
+        catalog.update_multi_table_meta(req).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let res = self.commit().await;
+        // The transaction ends whether it is committed or not.
+        self.ctx.txn_mgr().lock().clear();
+        res?;
+        Ok(PipelineBuildResult::create())
+    }
+}
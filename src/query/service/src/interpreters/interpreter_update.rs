@@ -92,9 +92,7 @@ impl Interpreter for UpdateInterpreter {
 
         let db_name = self.plan.database.as_str();
         let tbl_name = self.plan.table.as_str();
-        let tbl = catalog
-            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
-            .await?;
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
 
         // Add table lock.
         let table_lock = LockManager::create_table_lock(tbl.get_table_info().clone())?;
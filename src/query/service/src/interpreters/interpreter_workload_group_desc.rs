@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::DescWorkloadGroupPlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::interpreter_workload_groups_show::workload_groups_to_block;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescWorkloadGroupInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescWorkloadGroupPlan,
+}
+
+impl DescWorkloadGroupInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescWorkloadGroupPlan) -> Result<Self> {
+        Ok(DescWorkloadGroupInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescWorkloadGroupInterpreter {
+    fn name(&self) -> &str {
+        "DescWorkloadGroupInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let workload_group = user_mgr
+            .get_workload_group(&tenant, self.plan.name.as_str())
+            .await?;
+
+        PipelineBuildResult::from_blocks(vec![workload_groups_to_block(vec![workload_group])])
+    }
+}
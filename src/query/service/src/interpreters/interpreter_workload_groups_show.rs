@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowWorkloadGroupsInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowWorkloadGroupsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowWorkloadGroupsInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowWorkloadGroupsInterpreter {
+    fn name(&self) -> &str {
+        "ShowWorkloadGroupsInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let workload_groups = user_mgr.get_workload_groups(&tenant).await?;
+
+        PipelineBuildResult::from_blocks(vec![workload_groups_to_block(workload_groups)])
+    }
+}
+
+pub(crate) fn workload_groups_to_block(workload_groups: Vec<WorkloadGroup>) -> DataBlock {
+    let mut names = Vec::with_capacity(workload_groups.len());
+    let mut max_concurrencies = Vec::with_capacity(workload_groups.len());
+    let mut max_memory_usages = Vec::with_capacity(workload_groups.len());
+    let mut queue_timeouts = Vec::with_capacity(workload_groups.len());
+    let mut users = Vec::with_capacity(workload_groups.len());
+    let mut roles = Vec::with_capacity(workload_groups.len());
+    let mut comments = Vec::with_capacity(workload_groups.len());
+    for workload_group in workload_groups {
+        names.push(workload_group.name.as_bytes().to_vec());
+        max_concurrencies.push(workload_group.max_concurrency);
+        max_memory_usages.push(workload_group.max_memory_usage);
+        queue_timeouts.push(workload_group.queue_timeout);
+        users.push(workload_group.users.join(",").as_bytes().to_vec());
+        roles.push(workload_group.roles.join(",").as_bytes().to_vec());
+        comments.push(workload_group.comment.as_bytes().to_vec());
+    }
+
+    DataBlock::new_from_columns(vec![
+        StringType::from_data(names),
+        UInt64Type::from_data(max_concurrencies),
+        UInt64Type::from_data(max_memory_usages),
+        UInt64Type::from_data(queue_timeouts),
+        StringType::from_data(users),
+        StringType::from_data(roles),
+        StringType::from_data(comments),
+    ])
+}
@@ -55,10 +55,15 @@ mod interpreter_network_policy_alter;
 mod interpreter_network_policy_create;
 mod interpreter_network_policy_desc;
 mod interpreter_network_policy_drop;
+mod interpreter_ngram_index_refresh;
 mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_pipe_alter;
+mod interpreter_pipe_create;
+mod interpreter_pipe_describe;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -70,6 +75,9 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
@@ -88,11 +96,13 @@ mod interpreter_show_object_grant_privileges;
 mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_row_access_policy;
 mod interpreter_table_exists;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
@@ -111,6 +121,9 @@ mod interpreter_task_describe;
 mod interpreter_task_drop;
 mod interpreter_task_execute;
 mod interpreter_tasks_show;
+mod interpreter_txn_abort;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -131,6 +144,11 @@ mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_desc;
+mod interpreter_workload_group_drop;
+mod interpreter_workload_groups_show;
 
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
@@ -159,6 +177,7 @@ pub use interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
 pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
 pub use interpreter_network_policy_desc::DescNetworkPolicyInterpreter;
 pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_ngram_index_refresh::RefreshNgramIndexInterpreter;
 pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
@@ -172,6 +191,9 @@ pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
@@ -189,11 +211,13 @@ pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInter
 pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
@@ -224,3 +248,8 @@ pub use interpreter_virtual_column_alter::AlterVirtualColumnInterpreter;
 pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
 pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_desc::DescWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
+pub use interpreter_workload_groups_show::ShowWorkloadGroupsInterpreter;
@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_sql::executor::physical_plans::RecursiveCte;
+use databend_common_sql::executor::physical_plans::RecursiveCteScan;
+
+use crate::pipelines::processors::transforms::RecursiveCteScanSource;
+use crate::pipelines::processors::transforms::RecursiveCteSource;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_recursive_cte(&mut self, recursive_cte: &RecursiveCte) -> Result<()> {
+        // The anchor and recursive terms are built and executed by the source at runtime,
+        // because the recursive term has to be re-run for every iteration.
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteSource::create(
+                    self.ctx.clone(),
+                    output,
+                    recursive_cte,
+                    self.cte_state.clone(),
+                )
+            },
+            1,
+        )
+    }
+
+    pub(crate) fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let max_threads = self.settings.get_max_threads()?;
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteScanSource::create(self.ctx.clone(), output, scan.recursive_cte_id)
+            },
+            max_threads as usize,
+        )
+    }
+}
@@ -17,14 +17,26 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_core::Pipe;
+use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::Window;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::TableContext;
 
 use crate::pipelines::processors::transforms::FrameBound;
+use crate::pipelines::processors::transforms::TransformWindowPartitionScatter;
+use crate::pipelines::processors::transforms::TransformWindowPartitionSpill;
 use crate::pipelines::processors::transforms::WindowFunctionInfo;
 use crate::pipelines::processors::TransformWindow;
 use crate::pipelines::PipelineBuilder;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 
 impl PipelineBuilder {
     pub(crate) fn build_window(&mut self, window: &Window) -> Result<()> {
@@ -56,7 +68,95 @@ impl PipelineBuilder {
             .collect::<Result<Vec<_>>>()?;
 
         let old_output_len = self.main_pipeline.output_len();
-        if !partition_by.is_empty() || !order_by.is_empty() {
+        let (max_memory_usage, spilling_bytes_threshold) =
+            self.get_window_memory_settings(old_output_len)?;
+        let partition_spill =
+            !partition_by.is_empty() && max_memory_usage != 0 && spilling_bytes_threshold != 0;
+        if partition_spill {
+            // Partition the rows by hash and sort each bucket, instead of a global sort,
+            // so that the buckets can be spilled.
+            let mut sort_desc = Vec::with_capacity(partition_by.len() + order_by.len());
+            for offset in &partition_by {
+                sort_desc.push(SortColumnDescription {
+                    offset: *offset,
+                    asc: true,
+                    nulls_first: true,
+                    is_nullable: input_schema.field(*offset).is_nullable(),
+                })
+            }
+            sort_desc.extend(order_by.clone());
+
+            // Scatter the rows of each processor to all the processors by the partition hash,
+            // so that every window partition is evaluated by a single processor.
+            let num_processors = old_output_len;
+            if num_processors > 1 {
+                let mut items = Vec::with_capacity(num_processors);
+                for _ in 0..num_processors {
+                    let input = InputPort::create();
+                    let outputs = (0..num_processors)
+                        .map(|_| OutputPort::create())
+                        .collect::<Vec<_>>();
+                    let processor = TransformWindowPartitionScatter::create(
+                        input.clone(),
+                        outputs.clone(),
+                        partition_by.clone(),
+                    );
+                    items.push(PipeItem::create(
+                        ProcessorPtr::create(Box::new(processor)),
+                        vec![input],
+                        outputs,
+                    ));
+                }
+                self.main_pipeline.add_pipe(Pipe::create(
+                    num_processors,
+                    num_processors * num_processors,
+                    items,
+                ));
+
+                // The output `j` of the scatter `i` goes to the input `i` of the processor `j`.
+                let rule = (0..num_processors * num_processors)
+                    .map(|index| (index % num_processors) * num_processors + index / num_processors)
+                    .collect::<Vec<_>>();
+                self.main_pipeline.reorder_inputs(rule);
+            }
+
+            let max_block_size = self.settings.get_max_block_size()? as usize;
+            let config = SpillerConfig::create(query_spill_prefix(&self.ctx.get_tenant()));
+            let mut items = Vec::with_capacity(num_processors);
+            for _ in 0..num_processors {
+                let inputs = (0..num_processors)
+                    .map(|_| InputPort::create())
+                    .collect::<Vec<_>>();
+                let output = OutputPort::create();
+                let operator = DataOperator::instance().operator();
+                let spiller = Spiller::create(
+                    self.ctx.clone(),
+                    operator,
+                    config.clone(),
+                    SpillerType::Window,
+                );
+                let processor = TransformWindowPartitionSpill::create(
+                    inputs.clone(),
+                    output.clone(),
+                    partition_by.clone(),
+                    sort_desc.clone(),
+                    max_block_size,
+                    spiller,
+                    max_memory_usage,
+                    spilling_bytes_threshold,
+                );
+                items.push(PipeItem::create(
+                    ProcessorPtr::create(Box::new(processor)),
+                    inputs,
+                    vec![output],
+                ));
+            }
+            self.main_pipeline.add_pipe(Pipe::create(
+                num_processors * num_processors,
+                num_processors,
+                items,
+            ));
+        } else if !partition_by.is_empty() || !order_by.is_empty() {
             let mut sort_desc = Vec::with_capacity(partition_by.len() + order_by.len());
 
             for offset in &partition_by {
@@ -72,8 +172,10 @@ impl PipelineBuilder {
 
             self.build_sort_pipeline(input_schema.clone(), sort_desc, window.plan_id, None, None)?;
         }
-        // `TransformWindow` is a pipeline breaker.
-        self.main_pipeline.try_resize(1)?;
+        if !partition_spill {
+            // `TransformWindow` is a pipeline breaker.
+            self.main_pipeline.try_resize(1)?;
+        }
         let func = WindowFunctionInfo::try_create(&window.func, &input_schema)?;
         // Window
         self.main_pipeline.add_transform(|input, output| {
@@ -136,4 +238,33 @@ impl PipelineBuilder {
 
         self.main_pipeline.try_resize(old_output_len)
     }
+
+    /// Return `(max_memory_usage, spilling_bytes_threshold)`, both are 0 if the window spill is disabled.
+    fn get_window_memory_settings(&self, num_processors: usize) -> Result<(usize, usize)> {
+        let memory_ratio = self.settings.get_window_partition_spilling_memory_ratio()?;
+        let bytes_limit = self
+            .settings
+            .get_window_partition_spilling_bytes_threshold_per_proc()?;
+        if memory_ratio == 0 && bytes_limit == 0 {
+            return Ok((0, 0));
+        }
+        let memory_ratio = (memory_ratio as f64 / 100_f64).min(1_f64);
+        let max_memory_usage = match self.settings.get_max_memory_usage()? {
+            0 => usize::MAX,
+            max_memory_usage => {
+                if memory_ratio == 0_f64 {
+                    usize::MAX
+                } else {
+                    (max_memory_usage as f64 * memory_ratio) as usize
+                }
+            }
+        };
+        // The window partitions are collected by `num_processors` processors.
+        let spilling_bytes_threshold = match bytes_limit {
+            0 => max_memory_usage / num_processors.max(1),
+            bytes => bytes,
+        };
+
+        Ok((max_memory_usage, spilling_bytes_threshold))
+    }
 }
@@ -134,6 +134,8 @@ impl PipelineBuilder {
             PhysicalPlan::MaterializedCte(materialized_cte) => {
                 self.build_materialized_cte(materialized_cte)
             }
+            PhysicalPlan::RecursiveCte(recursive_cte) => self.build_recursive_cte(recursive_cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
 
             // Copy into.
             PhysicalPlan::CopyIntoTable(copy) => self.build_copy_into_table(copy),
@@ -29,6 +29,7 @@ mod transform_filter;
 mod transform_limit;
 mod transform_materialized_cte;
 mod transform_merge_block;
+mod transform_recursive_cte;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
@@ -55,6 +56,8 @@ pub use transform_materialized_cte::MaterializedCteSink;
 pub use transform_materialized_cte::MaterializedCteSource;
 pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte::RecursiveCteScanSource;
+pub use transform_recursive_cte::RecursiveCteSource;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
@@ -64,4 +67,6 @@ pub use transform_srf::TransformSRF;
 pub use transform_udf::TransformUdf;
 pub use window::FrameBound;
 pub use window::TransformWindow;
+pub use window::TransformWindowPartitionScatter;
+pub use window::TransformWindowPartitionSpill;
 pub use window::WindowFunctionInfo;
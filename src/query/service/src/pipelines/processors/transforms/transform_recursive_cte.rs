@@ -0,0 +1,283 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::HashMethod;
+use databend_common_expression::HashMethodSerializer;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_profile::SharedProcessorProfiles;
+use databend_common_sql::executor::physical_plans::RecursiveCte;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::IndexType;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::processors::transforms::MaterializedCteState;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+
+/// The working table is stored in the materialized ctes of the query context,
+/// keyed by the plan id of the recursive cte with sub index 0, which is never used by `CteScan`.
+fn working_table_idx(recursive_cte_id: u32) -> (IndexType, IndexType) {
+    (recursive_cte_id as IndexType, 0)
+}
+
+/// Evaluate a recursive cte until a fixpoint is reached.
+///
+/// The anchor term is executed once, then the recursive term is executed repeatedly
+/// with the rows produced by the previous iteration as the working table,
+/// until an iteration produces no rows.
+/// The rows of each iteration are output as soon as they are produced,
+/// only the working table of the next iteration is kept in memory.
+pub struct RecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    plan_id: u32,
+    distinct: bool,
+    anchor: PhysicalPlan,
+    anchor_columns: Vec<String>,
+    recursive: PhysicalPlan,
+    recursive_columns: Vec<String>,
+    cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+    max_depth: usize,
+
+    hash_method: HashMethodSerializer,
+    // The serialized rows that have been produced, only used by `UNION`.
+    produced_rows: HashSet<Vec<u8>>,
+
+    // The executor of the running iteration and the offsets of the output columns.
+    executor: Option<PipelinePullingExecutor>,
+    offsets: Vec<usize>,
+    // The number of the recursive iterations that have been started, the anchor is 0.
+    depth: Option<usize>,
+    // The rows produced by the running iteration, which is the working table of the next one.
+    working_table: Vec<DataBlock>,
+}
+
+impl RecursiveCteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        plan: &RecursiveCte,
+        cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+    ) -> Result<ProcessorPtr> {
+        let (anchor_columns, recursive_columns): (Vec<_>, Vec<_>) =
+            plan.pairs.iter().cloned().unzip();
+        let max_depth = ctx.get_settings().get_max_cte_recursive_depth()? as usize;
+        AsyncSourcer::create(ctx.clone(), output_port, RecursiveCteSource {
+            ctx,
+            plan_id: plan.plan_id,
+            distinct: plan.distinct,
+            anchor: plan.anchor.as_ref().clone(),
+            anchor_columns,
+            recursive: plan.recursive.as_ref().clone(),
+            recursive_columns,
+            cte_state,
+            max_depth,
+            hash_method: HashMethodSerializer::default(),
+            produced_rows: HashSet::new(),
+            executor: None,
+            offsets: vec![],
+            depth: None,
+            working_table: vec![],
+        })
+    }
+
+    /// Start the next iteration, return false if the fixpoint is reached.
+    fn start_iteration(&mut self) -> Result<bool> {
+        let (plan, columns) = match self.depth {
+            None => {
+                self.depth = Some(0);
+                (&self.anchor, &self.anchor_columns)
+            }
+            Some(depth) => {
+                if self.working_table.is_empty() {
+                    return Ok(false);
+                }
+                self.depth = Some(depth + 1);
+                self.ctx.set_materialized_cte(
+                    working_table_idx(self.plan_id),
+                    Arc::new(RwLock::new(std::mem::take(&mut self.working_table))),
+                )?;
+                (&self.recursive, &self.recursive_columns)
+            }
+        };
+
+        let schema = plan.output_schema()?;
+        let offsets = columns
+            .iter()
+            .map(|column| schema.index_of(column))
+            .collect::<Result<Vec<_>>>()?;
+        let executor = self.create_executor(plan)?;
+        self.offsets = offsets;
+        self.executor = Some(executor);
+        Ok(true)
+    }
+
+    fn create_executor(&self, plan: &PhysicalPlan) -> Result<PipelinePullingExecutor> {
+        // Each run needs its own context, or it will consume the partitions of others.
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let settings = self.ctx.get_settings();
+        let mut builder = PipelineBuilder::create(
+            self.ctx.get_function_context()?,
+            settings.clone(),
+            ctx,
+            false,
+            SharedProcessorProfiles::default(),
+            vec![],
+        );
+        builder.cte_state = self.cte_state.clone();
+        let mut build_res = builder.finalize(plan)?;
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+
+        let executor_settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let mut executor = PipelinePullingExecutor::from_pipelines(build_res, executor_settings)?;
+        executor.start();
+        Ok(executor)
+    }
+
+    /// Pull the next block of the running iteration with `columns` in order.
+    async fn pull_data(&mut self) -> Result<Option<DataBlock>> {
+        let Some(mut executor) = self.executor.take() else {
+            return Ok(None);
+        };
+        // Pulling blocks the thread until the data is ready, so don't do it on the executor.
+        let (executor, block) = GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                let block = executor.pull_data()?;
+                Ok((executor, block))
+            })
+            .await?;
+        let Some(block) = block else {
+            return Ok(None);
+        };
+        self.executor = Some(executor);
+
+        let num_rows = block.num_rows();
+        let entries = self
+            .offsets
+            .iter()
+            .map(|offset| block.get_by_offset(*offset).clone())
+            .collect();
+        Ok(Some(DataBlock::new(entries, num_rows)))
+    }
+
+    /// `UNION` only keeps the rows that have never been produced.
+    fn discard_produced_rows(&mut self, block: DataBlock) -> Result<DataBlock> {
+        if !self.distinct {
+            return Ok(block);
+        }
+
+        let num_rows = block.num_rows();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|entry| {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                (column, entry.data_type.clone())
+            })
+            .collect::<Vec<_>>();
+        let keys_state = self.hash_method.build_keys_state(&columns, num_rows)?;
+
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for key in self.hash_method.build_keys_iter(&keys_state)? {
+            bitmap.push(self.produced_rows.insert(key.to_vec()));
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if self.executor.is_none() && !self.start_iteration()? {
+                return Ok(None);
+            }
+
+            let Some(block) = self.pull_data().await? else {
+                continue;
+            };
+            let block = self.discard_produced_rows(block)?;
+            if block.is_empty() {
+                continue;
+            }
+
+            let depth = self.depth.unwrap_or_default();
+            if depth > self.max_depth {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Recursive common table expression exceeds the maximum recursive depth {}, \
+                     please check the termination condition or increase `max_cte_recursive_depth`",
+                    self.max_depth
+                )));
+            }
+            self.working_table.push(block.clone());
+            return Ok(Some(block));
+        }
+    }
+}
+
+/// Read the working table of a recursive cte.
+pub struct RecursiveCteScanSource {
+    ctx: Arc<QueryContext>,
+    recursive_cte_id: u32,
+}
+
+impl RecursiveCteScanSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        recursive_cte_id: u32,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx.clone(), output_port, RecursiveCteScanSource {
+            ctx,
+            recursive_cte_id,
+        })
+    }
+}
+
+impl SyncSource for RecursiveCteScanSource {
+    const NAME: &'static str = "RecursiveCteScanSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        // The working table is set before the recursive term is executed.
+        match self
+            .ctx
+            .get_materialized_cte(working_table_idx(self.recursive_cte_id))?
+        {
+            Some(blocks) => Ok(blocks.write().pop()),
+            None => Ok(None),
+        }
+    }
+}
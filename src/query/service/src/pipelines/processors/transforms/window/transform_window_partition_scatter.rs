@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+
+use super::transform_window_partition_spill::partition_bucket;
+use super::transform_window_partition_spill::partition_hashes;
+
+/// Scatter the rows to the [`super::TransformWindowPartitionSpill`] processors
+/// by the hash of the partition columns.
+///
+/// Every output goes to a different processor, so all the rows of one window partition
+/// are collected by the same processor and the window can be evaluated in parallel.
+pub struct TransformWindowPartitionScatter {
+    input: Arc<InputPort>,
+    outputs: Vec<Arc<OutputPort>>,
+
+    input_data: Option<DataBlock>,
+    output_data: Vec<Option<DataBlock>>,
+
+    partition_indices: Vec<usize>,
+}
+
+impl TransformWindowPartitionScatter {
+    pub fn create(
+        input: Arc<InputPort>,
+        outputs: Vec<Arc<OutputPort>>,
+        partition_indices: Vec<usize>,
+    ) -> Self {
+        debug_assert!(!partition_indices.is_empty());
+        let output_data = vec![None; outputs.len()];
+        Self {
+            input,
+            outputs,
+            input_data: None,
+            output_data,
+            partition_indices,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWindowPartitionScatter {
+    fn name(&self) -> String {
+        "TransformWindowPartitionScatter".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.outputs.iter().all(|output| output.is_finished()) {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        let mut pending = false;
+        for (output, data) in self.outputs.iter().zip(self.output_data.iter_mut()) {
+            if output.is_finished() {
+                data.take();
+                continue;
+            }
+            if data.is_some() {
+                if output.can_push() {
+                    output.push_data(Ok(data.take().unwrap()));
+                } else {
+                    pending = true;
+                }
+            }
+        }
+
+        if pending {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            self.input_data = Some(self.input.pull_data().unwrap()?);
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            for output in self.outputs.iter() {
+                output.finish();
+            }
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(block) = self.input_data.take() {
+            if block.is_empty() {
+                return Ok(());
+            }
+            let block = block.convert_to_full();
+            let num_outputs = self.outputs.len();
+            let indices = partition_hashes(&block, &self.partition_indices)
+                .into_iter()
+                .map(|hash| (partition_bucket(hash, 0) % num_outputs) as u16)
+                .collect::<Vec<_>>();
+
+            let scatter_blocks = DataBlock::scatter(&block, &indices, num_outputs)?;
+            for (index, block) in scatter_blocks.into_iter().enumerate() {
+                if !block.is_empty() {
+                    self.output_data[index] = Some(block);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use databend_common_exception::Result;
+use databend_common_expression::group_hash_columns;
+use databend_common_expression::DataBlock;
+use databend_common_expression::SortColumnDescription;
+use databend_common_hashtable::hash2bucket;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use log::info;
+
+use crate::spillers::Spiller;
+
+/// The rows are scattered into `1 << WINDOW_PARTITION_BUCKET_BITS` buckets at each level.
+const WINDOW_PARTITION_BUCKET_BITS: u32 = 6;
+const WINDOW_PARTITION_BUCKETS: usize = 1 << WINDOW_PARTITION_BUCKET_BITS;
+/// An oversized bucket is re-partitioned by the next bits of the hash, until the bits are used up.
+const WINDOW_PARTITION_MAX_LEVEL: u32 = u64::BITS / WINDOW_PARTITION_BUCKET_BITS - 1;
+
+/// The hashes of the partition columns of each row.
+pub(super) fn partition_hashes(block: &DataBlock, partition_indices: &[usize]) -> Vec<u64> {
+    let partition_columns = partition_indices
+        .iter()
+        .map(|i| block.get_by_offset(*i).value.as_column().unwrap().clone())
+        .collect::<Vec<_>>();
+    group_hash_columns(&partition_columns)
+}
+
+/// The bucket of the row at the given level, each level uses different bits of the hash.
+pub(super) fn partition_bucket(hash: u64, level: u32) -> usize {
+    hash2bucket::<WINDOW_PARTITION_BUCKET_BITS, false>(
+        (hash >> (WINDOW_PARTITION_BUCKET_BITS * level)) as usize,
+    )
+}
+
+struct WindowBucket {
+    level: u32,
+    blocks: Vec<DataBlock>,
+    /// The spilled files with the memory size of the spilled blocks.
+    spilled: Vec<(String, usize)>,
+}
+
+impl WindowBucket {
+    fn create(level: u32) -> Self {
+        Self {
+            level,
+            blocks: vec![],
+            spilled: vec![],
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.blocks.is_empty() && self.spilled.is_empty()
+    }
+
+    fn memory_size(&self) -> usize {
+        self.blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn total_size(&self) -> usize {
+        self.memory_size() + self.spilled.iter().map(|(_, size)| size).sum::<usize>()
+    }
+}
+
+enum State {
+    /// Collecting the input blocks into buckets.
+    Collect,
+    /// The buffered buckets need to be spilled.
+    Spill,
+    /// All the input blocks are collected, restore and output the buckets one by one.
+    Restore,
+}
+
+/// Replace the global sort before [`super::TransformWindow`] if the window has `PARTITION BY`.
+///
+/// The rows are scattered into buckets by the hash of the partition columns,
+/// so all the rows of one window partition are in the same bucket.
+/// The input comes from [`super::TransformWindowPartitionScatter`], so each processor
+/// collects its own window partitions and the window is evaluated in parallel.
+/// The buckets are spilled by [`Spiller`] if the memory usage exceeds the limit.
+/// A restored bucket that is still larger than the limit is re-partitioned and spilled again,
+/// the others are sorted by the partition and order by columns when they are output.
+/// The output is not globally sorted, but the rows of one window partition are contiguous and ordered,
+/// which is all that [`super::TransformWindow`] needs.
+pub struct TransformWindowPartitionSpill {
+    inputs: Vec<Arc<InputPort>>,
+    output: Arc<OutputPort>,
+    state: State,
+
+    input_data: Vec<DataBlock>,
+    output_data: VecDeque<DataBlock>,
+
+    partition_indices: Vec<usize>,
+    sort_desc: Vec<SortColumnDescription>,
+    max_block_size: usize,
+
+    /// The buckets being collected.
+    buckets: Vec<WindowBucket>,
+    buffered_bytes: usize,
+    /// The buckets to restore, the last one is restored first.
+    restore_buckets: Vec<WindowBucket>,
+
+    spiller: Spiller,
+    max_memory_usage: usize,
+    spilling_bytes_threshold: usize,
+}
+
+impl TransformWindowPartitionSpill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        inputs: Vec<Arc<InputPort>>,
+        output: Arc<OutputPort>,
+        partition_indices: Vec<usize>,
+        sort_desc: Vec<SortColumnDescription>,
+        max_block_size: usize,
+        spiller: Spiller,
+        max_memory_usage: usize,
+        spilling_bytes_threshold: usize,
+    ) -> Self {
+        debug_assert!(!partition_indices.is_empty());
+        Self {
+            inputs,
+            output,
+            state: State::Collect,
+            input_data: vec![],
+            output_data: VecDeque::new(),
+            partition_indices,
+            sort_desc,
+            max_block_size,
+            buckets: (0..WINDOW_PARTITION_BUCKETS)
+                .map(|_| WindowBucket::create(0))
+                .collect(),
+            buffered_bytes: 0,
+            restore_buckets: vec![],
+            spiller,
+            max_memory_usage,
+            spilling_bytes_threshold,
+        }
+    }
+
+    fn scatter(&self, block: DataBlock, level: u32) -> Result<Vec<DataBlock>> {
+        let indices = partition_hashes(&block, &self.partition_indices)
+            .into_iter()
+            .map(|hash| partition_bucket(hash, level) as u8)
+            .collect::<Vec<_>>();
+        DataBlock::scatter(&block, &indices, WINDOW_PARTITION_BUCKETS)
+    }
+
+    fn collect(&mut self, block: DataBlock) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+        let block = block.convert_to_full();
+        let scatter_blocks = self.scatter(block, 0)?;
+        for (bucket, block) in scatter_blocks.into_iter().enumerate() {
+            if !block.is_empty() {
+                self.buffered_bytes += block.memory_size();
+                self.buckets[bucket].blocks.push(block);
+            }
+        }
+        Ok(())
+    }
+
+    fn need_spill(&self) -> bool {
+        self.buffered_bytes > 0
+            && (self.buffered_bytes >= self.spilling_bytes_threshold
+                || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.max_memory_usage)
+    }
+
+    async fn spill_block(&mut self, block: DataBlock) -> Result<(String, usize)> {
+        let memory_size = block.memory_size();
+        let (location, _) = self.spiller.spill_block(block).await?;
+        Ok((location, memory_size))
+    }
+
+    async fn spill(&mut self) -> Result<()> {
+        info!(
+            "window partition spill {} bytes buffered data",
+            self.buffered_bytes
+        );
+        for bucket in 0..WINDOW_PARTITION_BUCKETS {
+            let blocks = std::mem::take(&mut self.buckets[bucket].blocks);
+            if blocks.is_empty() {
+                continue;
+            }
+            let block = DataBlock::concat(&blocks)?;
+            drop(blocks);
+            let spilled = self.spill_block(block).await?;
+            self.buckets[bucket].spilled.push(spilled);
+        }
+        self.buffered_bytes = 0;
+        Ok(())
+    }
+
+    /// Scatter an oversized bucket into the buckets of the next level.
+    /// The spilled files are read and spilled again one by one,
+    /// so the bucket is never fully in memory.
+    async fn repartition(&mut self, bucket: WindowBucket) -> Result<Vec<WindowBucket>> {
+        let level = bucket.level + 1;
+        info!(
+            "window partition re-partition {} bytes bucket to level {}",
+            bucket.total_size(),
+            level
+        );
+        let mut sub_buckets = (0..WINDOW_PARTITION_BUCKETS)
+            .map(|_| WindowBucket::create(level))
+            .collect::<Vec<_>>();
+        for block in bucket.blocks {
+            for (index, block) in self.scatter(block, level)?.into_iter().enumerate() {
+                if !block.is_empty() {
+                    sub_buckets[index].blocks.push(block);
+                }
+            }
+        }
+        for (location, _) in bucket.spilled {
+            let (block, _) = self.spiller.read_spilled(&location).await?;
+            for (index, block) in self.scatter(block, level)?.into_iter().enumerate() {
+                if !block.is_empty() {
+                    let spilled = self.spill_block(block).await?;
+                    sub_buckets[index].spilled.push(spilled);
+                }
+            }
+        }
+        sub_buckets.retain(|bucket| !bucket.is_empty());
+        if sub_buckets.len() == 1 {
+            // All the rows have the same hash bits, which is most likely one window partition.
+            // Such a partition is buffered by `TransformWindow` anyway, so stop re-partitioning it.
+            sub_buckets[0].level = WINDOW_PARTITION_MAX_LEVEL;
+        }
+        Ok(sub_buckets)
+    }
+
+    /// Restore the next non-empty bucket into `output_data`.
+    async fn restore(&mut self) -> Result<()> {
+        while self.output_data.is_empty() {
+            let Some(bucket) = self.restore_buckets.pop() else {
+                break;
+            };
+            if bucket.is_empty() {
+                continue;
+            }
+
+            if bucket.level < WINDOW_PARTITION_MAX_LEVEL
+                && !bucket.spilled.is_empty()
+                && bucket.total_size() > self.spilling_bytes_threshold
+            {
+                let sub_buckets = self.repartition(bucket).await?;
+                self.restore_buckets.extend(sub_buckets.into_iter().rev());
+                continue;
+            }
+
+            let mut blocks = bucket.blocks;
+            for (location, _) in bucket.spilled {
+                let (block, _) = self.spiller.read_spilled(&location).await?;
+                if !block.is_empty() {
+                    blocks.push(block);
+                }
+            }
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let block = DataBlock::concat(&blocks)?;
+            drop(blocks);
+            let block = DataBlock::sort(&block, &self.sort_desc, None)?;
+            let (blocks, tail) = block.split_by_rows(self.max_block_size);
+            self.output_data.extend(blocks);
+            self.output_data.extend(tail);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWindowPartitionSpill {
+    fn name(&self) -> String {
+        "TransformWindowPartitionSpill".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            for input in self.inputs.iter() {
+                input.finish();
+            }
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            for input in self.inputs.iter() {
+                input.set_not_need_data();
+            }
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.state {
+            State::Spill => Ok(Event::Async),
+            State::Restore => {
+                if !self.restore_buckets.is_empty() {
+                    Ok(Event::Async)
+                } else {
+                    self.output.finish();
+                    Ok(Event::Finished)
+                }
+            }
+            State::Collect => {
+                if !self.input_data.is_empty() {
+                    return Ok(Event::Sync);
+                }
+
+                let mut all_finished = true;
+                for input in self.inputs.iter() {
+                    if input.is_finished() {
+                        continue;
+                    }
+                    all_finished = false;
+                    if input.has_data() {
+                        self.input_data.push(input.pull_data().unwrap()?);
+                    }
+                    input.set_need_data();
+                }
+
+                if !self.input_data.is_empty() {
+                    return Ok(Event::Sync);
+                }
+
+                if all_finished {
+                    self.state = State::Restore;
+                    self.restore_buckets = std::mem::take(&mut self.buckets);
+                    self.restore_buckets.reverse();
+                    return Ok(Event::Async);
+                }
+
+                Ok(Event::NeedData)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        for block in std::mem::take(&mut self.input_data) {
+            self.collect(block)?;
+        }
+        if self.need_spill() {
+            self.state = State::Spill;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.state {
+            State::Spill => {
+                self.spill().await?;
+                self.state = State::Collect;
+            }
+            State::Restore => self.restore().await?,
+            State::Collect => unreachable!(),
+        }
+        Ok(())
+    }
+}
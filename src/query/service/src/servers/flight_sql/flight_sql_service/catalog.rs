@@ -17,20 +17,36 @@ use std::sync::Arc;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
+use arrow_flight::sql::metadata::XdbcTypeInfo;
+use arrow_flight::sql::metadata::XdbcTypeInfoData;
+use arrow_flight::sql::metadata::XdbcTypeInfoDataBuilder;
+use arrow_flight::sql::CommandGetCatalogs;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
+use arrow_flight::sql::CommandGetXdbcTypeInfo;
+use arrow_flight::sql::Nullable;
+use arrow_flight::sql::Searchable;
+use arrow_flight::sql::XdbcDataType;
 use arrow_flight::utils::batches_to_flight_data;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::DataSchema;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+/// The table types reported by `GetTableTypes` and `GetTables`.
+const TABLE_TYPES: [&str; 3] = ["TABLE", "VIEW", "SYSTEM TABLE"];
+
 pub(super) struct CatalogInfoProvider {}
 
 impl CatalogInfoProvider {
@@ -45,42 +61,94 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    async fn list_catalogs(
+        ctx: &Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)>
-    {
+    ) -> databend_common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let tenant = ctx.get_tenant();
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
-            vec![(
+        if let Some(catalog_name) = catalog_name {
+            Ok(vec![(
                 catalog_name.clone(),
                 catalog_mgr.get_catalog(&tenant, &catalog_name).await?,
-            )]
+            )])
         } else {
-            catalog_mgr
+            Ok(catalog_mgr
                 .list_catalogs(&tenant)
                 .await?
                 .iter()
                 .map(|r| (r.name(), r.clone()))
-                .collect()
-        };
-
-        let mut catalog_names = vec![];
-        let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
+                .collect())
+        }
+    }
+
+    fn table_type(database_name: &str, table: &dyn Table) -> &'static str {
+        if table.engine() == "VIEW" {
+            TABLE_TYPES[1]
+        } else if database_name.eq_ignore_ascii_case("system")
+            || database_name.eq_ignore_ascii_case("information_schema")
+        {
+            TABLE_TYPES[2]
+        } else {
+            TABLE_TYPES[0]
+        }
+    }
+
+    pub(crate) async fn get_catalogs(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetCatalogs,
+    ) -> Result<DoGetStream, Status> {
+        let catalogs = Self::list_catalogs(&ctx, None)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut builder = query.into_builder();
+        for (catalog_name, _) in catalogs {
+            builder.append(catalog_name);
+        }
+        let batch = builder
+            .build()
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetDbSchemas,
+    ) -> databend_common_exception::Result<RecordBatch> {
+        let tenant = ctx.get_tenant();
+        let catalogs = Self::list_catalogs(&ctx, query.catalog.clone()).await?;
+        let mut builder = query.into_builder();
+        for (catalog_name, catalog) in catalogs {
+            for db in catalog.list_databases(tenant.as_str()).await? {
+                builder.append(&catalog_name, db.name());
+            }
+        }
+        builder
+            .build()
+            .map_err(|e| ErrorCode::Internal(format!("{e:?}")))
+    }
+
+    pub(crate) async fn get_schemas(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetDbSchemas,
+    ) -> Result<DoGetStream, Status> {
+        let batch = Self::get_schemas_internal(ctx, query)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetTables,
+    ) -> databend_common_exception::Result<RecordBatch> {
+        let tenant = ctx.get_tenant();
+        let catalogs = Self::list_catalogs(&ctx, query.catalog.clone()).await?;
+        let include_schema = query.include_schema;
+        let mut builder = query.into_builder();
         for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(tenant.as_str(), database_name).await?]
-            } else {
-                catalog.list_databases(tenant.as_str()).await?
-            };
-            for db in dbs {
-                let db_name = db.name().to_string().into_boxed_str();
-                let db_name: &str = Box::leak(db_name);
+            for db in catalog.list_databases(tenant.as_str()).await? {
+                let db_name = db.name();
                 let tables = match catalog.list_tables(tenant.as_str(), db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
@@ -90,38 +158,140 @@ impl CatalogInfoProvider {
                     Err(err) => return Err(err),
                 };
                 for table in tables {
-                    catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    // The filters of the command are applied by the builder.
+                    let table_schema = if include_schema {
+                        Schema::from(&DataSchema::from(table.schema()))
+                    } else {
+                        Schema::empty()
+                    };
+                    builder
+                        .append(
+                            &catalog_name,
+                            db_name,
+                            table.name(),
+                            Self::table_type(db_name, table.as_ref()),
+                            &table_schema,
+                        )
+                        .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        builder
+            .build()
+            .map_err(|e| ErrorCode::Internal(format!("{e:?}")))
     }
 
     pub(crate) async fn get_tables(
         ctx: Arc<dyn TableContext>,
-        catalog_name: Option<String>,
-        database_name: Option<String>,
+        query: CommandGetTables,
     ) -> Result<DoGetStream, Status> {
+        let batch = Self::get_tables_internal(ctx, query)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(
+            TABLE_TYPES.iter().map(|t| t.to_string()).collect(),
+        )])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// Databend has no primary keys, the result is always empty.
+    pub(crate) fn get_primary_keys(_query: CommandGetPrimaryKeys) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
-            Field::new("catalog_name", DataType::Utf8, false),
-            Field::new("db_schema_name", DataType::Utf8, false),
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
             Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
-                .await
-                .map_err(|e| Status::internal(format!("{e:?}")))?;
-        let batch = RecordBatch::try_new(schema, vec![
-            Self::string_array(catalog_name),
-            Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
-        ])
-        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
+    fn xdbc_type_info(
+        type_name: &str,
+        data_type: XdbcDataType,
+        column_size: Option<i32>,
+        literal_quote: Option<&str>,
+    ) -> XdbcTypeInfo {
+        let is_number = matches!(
+            data_type,
+            XdbcDataType::XdbcTinyint
+                | XdbcDataType::XdbcSmallint
+                | XdbcDataType::XdbcInteger
+                | XdbcDataType::XdbcBigint
+                | XdbcDataType::XdbcFloat
+                | XdbcDataType::XdbcDouble
+                | XdbcDataType::XdbcDecimal
+        );
+        XdbcTypeInfo {
+            type_name: type_name.to_string(),
+            data_type,
+            column_size,
+            literal_prefix: literal_quote.map(|q| q.to_string()),
+            literal_suffix: literal_quote.map(|q| q.to_string()),
+            create_params: (data_type == XdbcDataType::XdbcDecimal)
+                .then(|| vec!["precision".to_string(), "scale".to_string()]),
+            nullable: Nullable::NullabilityNullable,
+            case_sensitive: data_type == XdbcDataType::XdbcVarchar,
+            searchable: Searchable::Full,
+            unsigned_attribute: is_number.then_some(false),
+            fixed_prec_scale: false,
+            auto_increment: is_number.then_some(false),
+            local_type_name: Some(type_name.to_string()),
+            minimum_scale: (data_type == XdbcDataType::XdbcDecimal).then_some(0),
+            maximum_scale: (data_type == XdbcDataType::XdbcDecimal).then_some(76),
+            sql_data_type: data_type,
+            datetime_subcode: None,
+            num_prec_radix: is_number.then_some(10),
+            interval_precision: None,
+        }
+    }
+
+    /// The SQL types of databend, which are mapped to the XDBC types.
+    pub(crate) fn xdbc_type_info_data() -> Result<XdbcTypeInfoData, Status> {
+        let mut builder = XdbcTypeInfoDataBuilder::new();
+        for info in [
+            Self::xdbc_type_info("BOOLEAN", XdbcDataType::XdbcBit, Some(1), None),
+            Self::xdbc_type_info("TINYINT", XdbcDataType::XdbcTinyint, Some(3), None),
+            Self::xdbc_type_info("SMALLINT", XdbcDataType::XdbcSmallint, Some(5), None),
+            Self::xdbc_type_info("INT", XdbcDataType::XdbcInteger, Some(10), None),
+            Self::xdbc_type_info("BIGINT", XdbcDataType::XdbcBigint, Some(19), None),
+            Self::xdbc_type_info("FLOAT", XdbcDataType::XdbcFloat, Some(7), None),
+            Self::xdbc_type_info("DOUBLE", XdbcDataType::XdbcDouble, Some(15), None),
+            Self::xdbc_type_info("DECIMAL", XdbcDataType::XdbcDecimal, Some(76), None),
+            Self::xdbc_type_info("VARCHAR", XdbcDataType::XdbcVarchar, None, Some("'")),
+            Self::xdbc_type_info("BINARY", XdbcDataType::XdbcBinary, None, None),
+            Self::xdbc_type_info("DATE", XdbcDataType::XdbcDate, Some(10), Some("'")),
+            Self::xdbc_type_info(
+                "TIMESTAMP",
+                XdbcDataType::XdbcTimestamp,
+                Some(26),
+                Some("'"),
+            ),
+        ] {
+            builder.append(info);
+        }
+        builder
+            .build()
+            .map_err(|e| Status::internal(format!("{e:?}")))
+    }
+
+    pub(crate) fn get_xdbc_type_info(query: CommandGetXdbcTypeInfo) -> Result<DoGetStream, Status> {
+        let data = Self::xdbc_type_info_data()?;
+        let batch = query
+            .into_builder(&data)
+            .build()
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
         Self::batch_to_get_stream(batch)
     }
 
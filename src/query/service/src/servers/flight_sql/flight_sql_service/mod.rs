@@ -22,6 +22,8 @@ mod sql_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Instant;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
@@ -30,6 +32,10 @@ use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use futures::Stream;
 use parking_lot::Mutex;
+pub use service::CommandStatementIngest;
+pub use service::TableDefinitionOptions;
+pub use service::TableExistsOption;
+pub use service::TableNotExistOption;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
@@ -51,7 +57,23 @@ type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send
 
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    statements: Arc<DashMap<Uuid, FlightStatement>>,
+    /// The transaction begun by `BeginTransaction` in each session, by session id.
+    transactions: Arc<DashMap<String, FlightTransaction>>,
+}
+
+/// A planned statement, it can only be used by the session that planned it.
+struct FlightStatement {
+    session_id: String,
+    session: Weak<Session>,
+    plan: Plan,
+    plan_extras: PlanExtras,
+    last_used: Instant,
+}
+
+struct FlightTransaction {
+    session: Weak<Session>,
+    transaction_id: Uuid,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -60,6 +82,7 @@ impl FlightSqlServiceImpl {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
             statements: Arc::new(Default::default()),
+            transactions: Arc::new(Default::default()),
         }
     }
 }
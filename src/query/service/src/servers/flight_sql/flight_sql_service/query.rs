@@ -16,6 +16,9 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::error::FlightError;
+use arrow_flight::sql::server::PeekableFlightDataStream;
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
 use arrow_ipc::writer;
@@ -26,17 +29,23 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_sql::plans::Insert;
+use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_storages_fuse::TableContext;
 use futures::Stream;
 use futures::StreamExt;
+use futures::TryStreamExt;
+use parking_lot::Mutex;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
 
 use super::status;
+use super::CommandStatementIngest;
 use super::DoGetStream;
 use super::FlightSqlServiceImpl;
 use crate::interpreters::InterpreterFactory;
@@ -107,6 +116,91 @@ impl FlightSqlServiceImpl {
         Ok(affected_rows as i64)
     }
 
+    /// Append the Arrow record batches of a DoPut stream to an existing table.
+    ///
+    /// The batch columns are matched to the table columns by name and cast to their types,
+    /// the columns missing from the batches are filled with their default values.
+    #[async_backtrace::framed]
+    pub(super) async fn execute_ingest(
+        &self,
+        session: Arc<Session>,
+        command: CommandStatementIngest,
+        flight_data: PeekableFlightDataStream,
+    ) -> Result<i64> {
+        if command.temporary {
+            return Err(ErrorCode::Unimplemented(
+                "ingest into temporary table is not supported",
+            ));
+        }
+
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        let catalog = command
+            .catalog
+            .unwrap_or_else(|| context.get_current_catalog());
+        let database = command
+            .schema
+            .unwrap_or_else(|| context.get_current_database());
+        let table = context
+            .get_table(&catalog, &database, &command.table)
+            .await?;
+
+        let mut batches =
+            FlightRecordBatchStream::new_from_flight_data(flight_data.map_err(FlightError::from));
+        let first_batch = match batches.next().await {
+            Some(batch) => batch.map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?,
+            None => return Ok(0),
+        };
+
+        let batch_schema = DataSchema::try_from(first_batch.schema().as_ref())
+            .map_err(|e| ErrorCode::BadArguments(format!("{e:?}")))?;
+        let table_schema = table.schema().remove_computed_fields();
+        let insert_fields = batch_schema
+            .fields()
+            .iter()
+            .map(|f| table_schema.field_with_name(f.name()).cloned())
+            .collect::<Result<Vec<_>>>()?;
+
+        let blocks = futures::stream::iter(vec![Ok::<_, FlightError>(first_batch)])
+            .chain(batches)
+            .map(move |batch| -> Result<DataBlock> {
+                let batch = batch.map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?;
+                let (block, _) = DataBlock::from_record_batch(&batch_schema, &batch)
+                    .map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?;
+                Ok(block)
+            });
+
+        // Go through the interpreter like `INSERT`, so the privileges are checked
+        // and the query is logged.
+        let plan = Plan::Insert(Box::new(Insert {
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: command.table.clone(),
+            table_id: table.get_id(),
+            schema: TableSchemaRefExt::create(insert_fields),
+            overwrite: false,
+            source: InsertInputSource::StreamingWithBlocks(Arc::new(Mutex::new(Some(Box::pin(
+                blocks,
+            ))))),
+        }));
+        context.attach_query_str(
+            plan.kind(),
+            format!("INGEST INTO {catalog}.{database}.{}", command.table),
+        );
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+
+        let mut blocks = interpreter.execute(context.clone()).await?;
+        while let Some(block) = blocks.next().await {
+            block?;
+        }
+
+        let affected_rows = context.get_write_progress_value().rows;
+        Ok(affected_rows as i64)
+    }
+
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
@@ -49,6 +49,7 @@ use arrow_flight::sql::CommandStatementQuery;
 use arrow_flight::sql::CommandStatementSubstraitPlan;
 use arrow_flight::sql::CommandStatementUpdate;
 use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::EndTransaction;
 use arrow_flight::sql::ProstMessageExt;
 use arrow_flight::sql::SqlInfo;
 use arrow_flight::sql::TicketStatementQuery;
@@ -122,6 +123,18 @@ fn simple_flight_info<T: ProstMessageExt>(message: T) -> Response<FlightInfo> {
     Response::new(info)
 }
 
+fn flight_info_with_schema<T: ProstMessageExt>(
+    message: T,
+    schema: &arrow_schema::Schema,
+) -> Result<Response<FlightInfo>, Status> {
+    let IpcMessage(schema_bytes) = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| status!("Unable to serialize schema", e))?;
+    let mut info = simple_flight_info(message).into_inner();
+    info.schema = schema_bytes;
+    Ok(Response::new(info))
+}
+
 impl NamedService for FlightSqlServiceImpl {
     const NAME: &'static str = "FlightSqlService";
 }
@@ -192,9 +205,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&session, &handle)?;
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_query(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -207,9 +220,24 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        info!("get_flight_info_statement(query={})", query.query);
+        let session = self.get_session(&request)?;
+        if let Some(transaction_id) = &query.transaction_id {
+            self.check_transaction(&session, transaction_id)?;
+        }
+        let handle = Uuid::new_v4();
+        let plan = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let schema = plan.0.schema().as_ref().into();
+        self.insert_statement(&session, handle, plan);
+
+        // The statement is executed and released by `do_get_statement`.
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        flight_info_with_schema(ticket, &schema)
     }
 
     #[async_backtrace::framed]
@@ -218,14 +246,14 @@ impl FlightSqlService for FlightSqlServiceImpl {
         cmd: CommandPreparedStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        let _session = self.get_session(&request);
+        let session = self.get_session(&request)?;
         let handle = Uuid::from_slice(cmd.prepared_statement_handle.as_ref())
             .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
 
         info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
+        let (plan, _) = self.get_statement(&session, &handle)?;
+        let schema = plan.schema().as_ref().into();
         let loc = Location {
             uri: "grpc+tcp://127.0.0.1".to_string(),
         };
@@ -321,12 +349,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
@@ -370,42 +397,74 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(ticket.statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        info!("do_get_statement with handle={handle}");
+
+        let (plan, plan_extras) = self.remove_statement(&session, &handle)?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        info!("do_get_prepared_statement with handle={handle}");
+
+        let (plan, plan_extras) = self.get_statement(&session, &handle)?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
-        _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        query: CommandGetCatalogs,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_catalogs(context, query).await?,
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_schemas(context, query).await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -421,8 +480,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
         Ok(Response::new(
-            super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
+            super::CatalogInfoProvider::get_tables(context, query).await?,
         ))
     }
 
@@ -430,10 +488,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
@@ -450,10 +509,13 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_primary_keys(
+            query,
+        )?))
     }
 
     #[async_backtrace::framed]
@@ -492,6 +554,33 @@ impl FlightSqlService for FlightSqlServiceImpl {
         ))
     }
 
+    // called for the commands unknown to arrow-flight, such as `CommandStatementIngest`.
+    #[async_backtrace::framed]
+    async fn do_put_fallback(
+        &self,
+        request: Request<PeekableFlightDataStream>,
+        message: Any,
+    ) -> Result<Response<<Self as FlightService>::DoPutStream>, Status> {
+        let session = self.get_session(&request)?;
+        let command: CommandStatementIngest = try_unpack_any(message)?;
+        info!("do_put_fallback with ingest into table = {}", command.table);
+        command.check_table_definition_options()?;
+        if let Some(transaction_id) = &command.transaction_id {
+            self.check_transaction(&session, transaction_id)?;
+        }
+
+        let record_count = self
+            .execute_ingest(session, command, request.into_inner())
+            .await
+            .map_err(|e| status!("fail to ingest", e))?;
+        let result = DoPutUpdateResult { record_count };
+        let result = PutResult {
+            app_metadata: result.as_any().encode_to_vec().into(),
+        };
+        let result = futures::stream::iter(vec![Ok(result)]);
+        Ok(Response::new(Box::pin(result)))
+    }
+
     // called by rust FlightSqlServiceClient, which is used in unit test.
     #[async_backtrace::framed]
     async fn do_put_statement_update(
@@ -500,6 +589,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<PeekableFlightDataStream>,
     ) -> Result<i64, Status> {
         let session = self.get_session(&request)?;
+        if let Some(transaction_id) = &ticket.transaction_id {
+            self.check_transaction(&session, transaction_id)?;
+        }
         let query = ticket.query;
         info!("do_put_statement_update with query = {query}");
 
@@ -526,9 +618,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&session, &handle)?;
         let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -552,9 +644,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&session, &handle)?;
         let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -590,7 +682,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             query.query
         );
         let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
+        self.insert_statement(&session, handle, plan);
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
@@ -617,8 +709,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
             );
             match Uuid::try_parse(handle) {
                 Ok(handle) => {
-                    if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
+                    if let Ok(session) = self.get_session(&request) {
+                        let _ = self.remove_statement(&session, &handle);
                     }
                 }
                 Err(e) => {
@@ -637,21 +729,29 @@ impl FlightSqlService for FlightSqlServiceImpl {
     }
 
     /// Get a FlightInfo to extract information about the supported XDBC types.
+    #[async_backtrace::framed]
     async fn get_flight_info_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        info!("get_flight_info_xdbc_type_info({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     /// Get a FlightDataStream containing the data related to the supported XDBC types.
+    #[async_backtrace::framed]
     async fn do_get_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<Ticket>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        unimplemented!()
+        info!("do_get_xdbc_type_info({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_xdbc_type_info(query)?,
+        ))
     }
 
     async fn get_flight_info_substrait_plan(
@@ -659,7 +759,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandStatementSubstraitPlan,
         _request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("substrait plan is not supported"))
     }
 
     async fn do_put_substrait_plan(
@@ -667,7 +767,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandStatementSubstraitPlan,
         _request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<i64, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("substrait plan is not supported"))
     }
 
     async fn do_action_create_prepared_substrait_plan(
@@ -675,23 +775,61 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionCreatePreparedSubstraitPlanRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("substrait plan is not supported"))
     }
 
+    // The transaction is bound to the session, so a session has at most one transaction.
+    #[async_backtrace::framed]
     async fn do_action_begin_transaction(
         &self,
         _query: ActionBeginTransactionRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> std::result::Result<ActionBeginTransactionResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+
+        let (plan, plan_extras) = self
+            .plan_sql(&session, "BEGIN")
+            .await
+            .map_err(|e| status!("Error planning BEGIN", e))?;
+        self.execute_update(session.clone(), &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to begin transaction", e))?;
+
+        let transaction_id = self.register_transaction(&session);
+        info!("do_action_begin_transaction with transaction_id={transaction_id}");
+        Ok(ActionBeginTransactionResult {
+            transaction_id: transaction_id.as_bytes().to_vec().into(),
+        })
     }
 
+    #[async_backtrace::framed]
     async fn do_action_end_transaction(
         &self,
-        _query: ActionEndTransactionRequest,
-        _request: Request<Action>,
+        query: ActionEndTransactionRequest,
+        request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        self.check_transaction(&session, &query.transaction_id)?;
+        let sql = match query.action() {
+            EndTransaction::Commit => "COMMIT",
+            EndTransaction::Rollback => "ROLLBACK",
+            EndTransaction::Unspecified => {
+                return Err(Status::invalid_argument(
+                    "the end transaction action is unspecified",
+                ));
+            }
+        };
+        info!("do_action_end_transaction with action={sql}");
+
+        let (plan, plan_extras) = self
+            .plan_sql(&session, sql)
+            .await
+            .map_err(|e| status!("Error planning end transaction", e))?;
+        self.execute_update(session.clone(), &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to end transaction", e))?;
+        self.unregister_transaction(&session);
+        Ok(())
     }
 
     async fn do_action_begin_savepoint(
@@ -699,7 +837,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionBeginSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionBeginSavepointResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("savepoint is not supported"))
     }
 
     async fn do_action_end_savepoint(
@@ -707,7 +845,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionEndSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        Err(Status::unimplemented("savepoint is not supported"))
     }
 
     async fn do_action_cancel_query(
@@ -715,7 +853,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionCancelQueryRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionCancelQueryResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("cancel query is not supported"))
     }
 }
 
@@ -737,3 +875,92 @@ impl ProstMessageExt for FetchResults {
         }
     }
 }
+
+/// Bulk ingestion of Arrow record batches into an existing table.
+///
+/// It mirrors the message of the Flight SQL protocol, which is not provided by arrow-flight yet.
+/// Only appending is supported, see [`CommandStatementIngest::check_table_definition_options`].
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandStatementIngest {
+    #[prost(message, optional, tag = "1")]
+    pub table_definition_options: ::core::option::Option<TableDefinitionOptions>,
+    #[prost(string, tag = "2")]
+    pub table: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub schema: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag = "5")]
+    pub temporary: bool,
+    #[prost(bytes = "bytes", optional, tag = "6")]
+    pub transaction_id: ::core::option::Option<::prost::bytes::Bytes>,
+    #[prost(map = "string, string", tag = "1000")]
+    pub options:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+
+impl CommandStatementIngest {
+    /// The target table must exist and the batches are appended to it, so the
+    /// options to create, replace or fail on an existing table are rejected.
+    pub fn check_table_definition_options(&self) -> std::result::Result<(), Status> {
+        let Some(options) = &self.table_definition_options else {
+            return Ok(());
+        };
+        match options.if_not_exist() {
+            TableNotExistOption::Unspecified | TableNotExistOption::Fail => {}
+            TableNotExistOption::Create => {
+                return Err(Status::invalid_argument(
+                    "ingest does not support creating the table, it must exist",
+                ));
+            }
+        }
+        match options.if_exists() {
+            TableExistsOption::Unspecified | TableExistsOption::Append => Ok(()),
+            TableExistsOption::Fail => Err(Status::invalid_argument(
+                "ingest only supports appending to an existing table",
+            )),
+            TableExistsOption::Replace => Err(Status::invalid_argument(
+                "ingest does not support replacing the table",
+            )),
+        }
+    }
+}
+
+/// What to do when the target table of [`CommandStatementIngest`] does (not) exist.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TableDefinitionOptions {
+    #[prost(enumeration = "TableNotExistOption", tag = "1")]
+    pub if_not_exist: i32,
+    #[prost(enumeration = "TableExistsOption", tag = "2")]
+    pub if_exists: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TableNotExistOption {
+    Unspecified = 0,
+    Create = 1,
+    Fail = 2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TableExistsOption {
+    Unspecified = 0,
+    Fail = 1,
+    Append = 2,
+    Replace = 3,
+}
+
+impl ProstMessageExt for CommandStatementIngest {
+    fn type_url() -> &'static str {
+        "type.googleapis.com/arrow.flight.protocol.sql.CommandStatementIngest"
+    }
+
+    fn as_any(&self) -> Any {
+        Any {
+            type_url: CommandStatementIngest::type_url().to_string(),
+            value: ::prost::Message::encode_to_vec(self).into(),
+        }
+    }
+}
@@ -13,23 +13,124 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::UserIdentity;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
 use databend_common_users::UserApiProvider;
 use tonic::metadata::MetadataMap;
 use tonic::Request;
 use tonic::Status;
+use uuid::Uuid;
 
 use super::status;
+use super::FlightStatement;
+use super::FlightTransaction;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 
+/// A statement expires if it is not used for this time, e.g. the results of the statement
+/// are never fetched, or the prepared statement is never closed.
+const STATEMENT_MAX_IDLE_TIME: Duration = Duration::from_secs(3600);
+
 impl FlightSqlServiceImpl {
+    pub(super) fn insert_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: Uuid,
+        (plan, plan_extras): (Plan, PlanExtras),
+    ) {
+        // Drop the statements of the expired sessions and the idle ones.
+        self.statements.retain(|_, statement| {
+            statement.session.strong_count() > 0
+                && statement.last_used.elapsed() < STATEMENT_MAX_IDLE_TIME
+        });
+        self.statements.insert(handle, FlightStatement {
+            session_id: session.get_id(),
+            session: Arc::downgrade(session),
+            plan,
+            plan_extras,
+            last_used: Instant::now(),
+        });
+    }
+
+    /// Get the statement planned by the session.
+    pub(super) fn get_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: &Uuid,
+    ) -> Result<(Plan, PlanExtras), Status> {
+        match self.statements.get_mut(handle) {
+            Some(mut statement)
+                if statement.session_id == session.get_id()
+                    && statement.last_used.elapsed() < STATEMENT_MAX_IDLE_TIME =>
+            {
+                statement.last_used = Instant::now();
+                Ok((statement.plan.clone(), statement.plan_extras.clone()))
+            }
+            _ => Err(Status::not_found(format!("statement {handle} not found"))),
+        }
+    }
+
+    /// Remove the statement planned by the session.
+    pub(super) fn remove_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: &Uuid,
+    ) -> Result<(Plan, PlanExtras), Status> {
+        let session_id = session.get_id();
+        match self
+            .statements
+            .remove_if(handle, |_, statement| statement.session_id == session_id)
+        {
+            Some((_, statement)) => Ok((statement.plan, statement.plan_extras)),
+            None => Err(Status::not_found(format!("statement {handle} not found"))),
+        }
+    }
+
+    pub(super) fn register_transaction(&self, session: &Arc<Session>) -> Uuid {
+        self.transactions
+            .retain(|_, transaction| transaction.session.strong_count() > 0);
+        let transaction_id = Uuid::new_v4();
+        self.transactions
+            .insert(session.get_id(), FlightTransaction {
+                session: Arc::downgrade(session),
+                transaction_id,
+            });
+        transaction_id
+    }
+
+    /// Check that the transaction id sent by the client is the transaction of the session.
+    pub(super) fn check_transaction(
+        &self,
+        session: &Arc<Session>,
+        transaction_id: &[u8],
+    ) -> Result<(), Status> {
+        let found = self
+            .transactions
+            .get(&session.get_id())
+            .is_some_and(|transaction| {
+                transaction.transaction_id.as_bytes()[..] == *transaction_id
+            });
+        match found {
+            true => Ok(()),
+            false => Err(Status::invalid_argument(
+                "transaction not found in the session",
+            )),
+        }
+    }
+
+    pub(super) fn unregister_transaction(&self, session: &Arc<Session>) {
+        self.transactions.remove(&session.get_id());
+    }
+
     pub(super) fn get_session<T>(&self, req: &Request<T>) -> Result<Arc<Session>, Status> {
         let auth = req
             .metadata()
@@ -16,6 +16,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_stream::stream;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
 use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::mpsc::Sender;
 use databend_common_base::base::tokio::task::JoinHandle;
@@ -61,6 +63,7 @@ use serde::Serialize;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterPtr;
 use crate::servers::http::middleware::sanitize_request_headers;
+use crate::servers::http::v1::read_full;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::sessions::short_sql;
 use crate::sessions::QueryContext;
@@ -115,7 +118,7 @@ async fn execute(
     params: StatementHandlerParams,
     handle: Option<JoinHandle<()>>,
 ) -> Result<WithContentType<Body>> {
-    let format_typ = format.typ.clone();
+    let format_typ = format.clone();
 
     // the reason of spawning new task to execute the interpreter:
     // (FIXME describe this in a more concise way)
@@ -323,10 +326,16 @@ pub async fn clickhouse_handler_post(
 
         let default_format = get_default_format(&params, headers).map_err(BadRequest)?;
         let mut sql = params.query();
-        if !sql.is_empty() {
-            sql.push(' ');
+        // binary data can not be tokenized as a part of the sql, it is streamed as is.
+        let mut binary_body = None;
+        if is_binary_insert(&sql) {
+            binary_body = Some(body);
+        } else {
+            if !sql.is_empty() {
+                sql.push(' ');
+            }
+            sql.push_str(body.into_string().await?.as_str());
         }
-        sql.push_str(body.into_string().await?.as_str());
         let n = 64;
         // other parts of the request already logged in middleware
         let len = sql.len();
@@ -381,19 +390,19 @@ pub async fn clickhouse_handler_post(
                     .get_compression_alg("")
                     .map_err(|err| err.display_with_sql(&sql))
                     .map_err(BadRequest)?;
-                let start = *start;
-                let sql_cloned = sql.clone();
+                let batch_size = input_context.read_batch_size;
                 let query_id = ctx.get_id();
-                handle = Some(ctx.spawn(query_id, async move {
-                    gen_batches(
-                        sql_cloned,
-                        start,
-                        input_context.read_batch_size,
-                        tx,
-                        compression_alg,
-                    )
-                    .await
-                }));
+                handle = Some(match binary_body.take() {
+                    Some(body) => ctx.spawn(query_id, async move {
+                        gen_batches_from_body(body, batch_size, tx, compression_alg).await
+                    }),
+                    None => {
+                        let data = sql.trim_start().as_bytes()[*start..].to_vec();
+                        ctx.spawn(query_id, async move {
+                            gen_batches(data, batch_size, tx, compression_alg).await
+                        })
+                    }
+                });
             } else if let InsertInputSource::StreamingWithFileFormat {
                 format,
                 on_error_mode,
@@ -434,18 +443,10 @@ pub async fn clickhouse_handler_post(
                     .get_compression_alg("")
                     .map_err(|err| err.display_with_sql(&sql))
                     .map_err(BadRequest)?;
-                let start = *start;
-                let sql_cloned = sql.clone();
+                let data = sql.trim_start().as_bytes()[*start..].to_vec();
                 let query_id = ctx.get_id();
                 handle = Some(ctx.spawn(query_id, async move {
-                    gen_batches(
-                        sql_cloned,
-                        start,
-                        input_context.read_batch_size,
-                        tx,
-                        compression_alg,
-                    )
-                    .await
+                    gen_batches(data, input_context.read_batch_size, tx, compression_alg).await
                 }));
             }
         };
@@ -541,14 +542,68 @@ fn get_format_with_default(
     }
 }
 
+// `INSERT ... FORMAT RowBinary` and `Native` must be sent in the query param.
+fn is_binary_insert(query: &str) -> bool {
+    let Ok(tokens) = tokenize_sql(query) else {
+        return false;
+    };
+    let tokens = tokens
+        .iter()
+        .filter(|t| t.kind != TokenKind::EOI)
+        .collect::<Vec<_>>();
+    match tokens.as_slice() {
+        [.., format, name] if format.kind == TokenKind::FORMAT => {
+            ClickhouseFormatType::parse_clickhouse_format(name.text())
+                .map(|f| f.binary.is_some())
+                .unwrap_or(false)
+        }
+        _ => false,
+    }
+}
+
+async fn gen_batches_from_body(
+    body: Body,
+    batch_size: usize,
+    tx: Sender<Result<StreamingReadBatch>>,
+    compression: Option<CompressAlgorithm>,
+) {
+    let path = "clickhouse_insert".to_string();
+    let mut reader = body.into_async_read();
+    let mut is_start = true;
+    loop {
+        let mut data = vec![0u8; batch_size];
+        let batch = match read_full(&mut reader, &mut data).await {
+            Ok(0) => break,
+            Ok(n) => {
+                data.truncate(n);
+                debug!("sending read {} bytes", n);
+                Ok(StreamingReadBatch {
+                    data,
+                    path: path.clone(),
+                    is_start,
+                    compression,
+                })
+            }
+            Err(e) => Err(e),
+        };
+        let is_err = batch.is_err();
+        if let Err(e) = tx.send(batch).await {
+            warn!("clickhouse handler fail to send ReadBatch: {}", e);
+        }
+        if is_err {
+            break;
+        }
+        is_start = false;
+    }
+}
+
 async fn gen_batches(
-    data: String,
-    start: usize,
+    data: Vec<u8>,
     batch_size: usize,
     tx: Sender<Result<StreamingReadBatch>>,
     compression: Option<CompressAlgorithm>,
 ) {
-    let buf = &data.trim_start().as_bytes()[start..];
+    let buf = &data[..];
     let buf_size = buf.len();
     let mut is_start = true;
     let mut start = 0;
@@ -25,12 +25,15 @@ use minitrace::prelude::*;
 use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::get;
+use poem::http::header;
+use poem::http::HeaderMap;
 use poem::http::StatusCode;
 use poem::post;
 use poem::web::Json;
 use poem::web::Path;
 use poem::EndpointExt;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
@@ -39,7 +42,9 @@ use serde_json::Value as JsonValue;
 use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
+use super::query::PageData;
 use super::query::RemoveReason;
+use super::query::ResultFormat;
 use crate::servers::http::middleware::MetricsMiddleware;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::HttpQueryContext;
@@ -51,6 +56,18 @@ use crate::sessions::QueryAffect;
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
+const HEADER_QUERY_NEXT_URI: &str = "X-DATABEND-QUERY-NEXT-URI";
+// The fields of the JSON response that are carried by the headers of a binary page,
+// the values are JSON-encoded.
+const HEADER_SESSION_ID: &str = "X-DATABEND-SESSION-ID";
+const HEADER_SESSION: &str = "X-DATABEND-SESSION";
+const HEADER_QUERY_SCHEMA: &str = "X-DATABEND-QUERY-SCHEMA";
+const HEADER_QUERY_STATS: &str = "X-DATABEND-QUERY-STATS";
+const HEADER_QUERY_AFFECT: &str = "X-DATABEND-QUERY-AFFECT";
+
+fn json_header<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -137,14 +154,17 @@ impl QueryResponse {
         id: String,
         r: HttpQueryResponseInternal,
         is_final: bool,
-    ) -> impl IntoResponse {
+    ) -> Response {
         let state = r.state.clone();
         let (data, next_uri) = if is_final {
-            (JsonBlock::empty(), None)
+            (PageData::Json(JsonBlock::empty()), None)
         } else {
             match state.state {
                 ExecuteStateKind::Running => match r.data {
-                    None => (JsonBlock::empty(), Some(make_state_uri(&id))),
+                    None => (
+                        PageData::Json(JsonBlock::empty()),
+                        Some(make_state_uri(&id)),
+                    ),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
@@ -153,9 +173,15 @@ impl QueryResponse {
                         (d.page.data, uri)
                     }
                 },
-                ExecuteStateKind::Failed => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                ExecuteStateKind::Failed => (
+                    PageData::Json(JsonBlock::empty()),
+                    Some(make_final_uri(&id)),
+                ),
                 ExecuteStateKind::Succeeded => match r.data {
-                    None => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                    None => (
+                        PageData::Json(JsonBlock::empty()),
+                        Some(make_final_uri(&id)),
+                    ),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
@@ -171,13 +197,47 @@ impl QueryResponse {
             metrics_incr_http_response_errors_count(err.name(), err.code());
         }
 
-        let schema = data.schema().clone();
+        let rows = data.num_rows();
         let session_id = r.session_id.clone();
         let stats = QueryStats {
             progresses: state.progresses.clone(),
             running_time_ms: state.running_time_ms,
         };
-        let rows = data.data.len();
+        let data = match data {
+            PageData::Json(data) => data,
+            PageData::Binary {
+                format,
+                schema,
+                bytes,
+                ..
+            } => {
+                // The body is the page in the negotiated format, so the state of the query
+                // and the session are carried by the headers.
+                let mut builder = Response::builder()
+                    .content_type(format.content_type())
+                    .header(HEADER_QUERY_ID, id.clone())
+                    .header(HEADER_QUERY_STATE, state.state.to_string())
+                    .header(HEADER_QUERY_PAGE_ROWS, rows)
+                    .header(HEADER_SESSION_ID, session_id)
+                    .header(
+                        HEADER_QUERY_SCHEMA,
+                        json_header(&QueryResponseField::from_schema(schema)),
+                    )
+                    .header(HEADER_QUERY_STATS, json_header(&stats));
+                if let Some(session) = &r.session {
+                    builder = builder.header(HEADER_SESSION, json_header(session));
+                }
+                if let Some(affect) = &state.affect {
+                    builder = builder.header(HEADER_QUERY_AFFECT, json_header(affect));
+                }
+                if let Some(next_uri) = next_uri {
+                    builder = builder.header(HEADER_QUERY_NEXT_URI, next_uri);
+                }
+                return builder.body(bytes);
+            }
+        };
+
+        let schema = data.schema().clone();
 
         Json(QueryResponse {
             data: data.into(),
@@ -199,6 +259,7 @@ impl QueryResponse {
         .with_header(HEADER_QUERY_ID, id.clone())
         .with_header(HEADER_QUERY_STATE, state.state.to_string())
         .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        .into_response()
     }
 
     pub(crate) fn fail_to_start_sql(err: &ErrorCode) -> impl IntoResponse {
@@ -366,6 +427,7 @@ async fn query_page_handler(
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
+    headers: &HeaderMap,
     Json(req): Json<HttpQueryRequest>,
 ) -> PoemResult<impl IntoResponse> {
     let trace_id = query_id_to_trace_id(&ctx.query_id);
@@ -376,9 +438,14 @@ pub(crate) async fn query_handler(
         info!("http query new request: {:}", mask_connection_info(&format!("{:?}", req)));
         let http_query_manager = HttpQueryManager::instance();
         let sql = req.sql.clone();
+        let result_format = headers
+            .get(header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .map(ResultFormat::from_accept)
+            .unwrap_or_default();
 
         let query = http_query_manager
-            .try_create_query(ctx, req)
+            .try_create_query(ctx, req, result_format)
             .await
             .map_err(|err| err.display_with_sql(&sql));
         match query {
@@ -27,6 +27,7 @@ pub use http_query_handlers::QueryError;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
 pub(crate) use json_block::JsonBlock;
+pub use load::read_full;
 pub use load::streaming_load;
 pub use load::LoadResponse;
 pub use query::ExecuteStateKind;
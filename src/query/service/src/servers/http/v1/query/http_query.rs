@@ -23,6 +23,8 @@ use databend_common_base::base::tokio::sync::Mutex as TokioMutex;
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::runtime::GlobalQueryRuntime;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::QueryParamValue;
+use databend_common_catalog::table_context::QueryParams;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -47,6 +49,7 @@ use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResultFormat;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::short_sql;
@@ -68,6 +71,7 @@ pub struct HttpQueryRequest {
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    pub params: Option<QueryParamsConf>,
 }
 
 impl Debug for HttpQueryRequest {
@@ -79,6 +83,7 @@ impl Debug for HttpQueryRequest {
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("params", &self.params)
             .finish()
     }
 }
@@ -155,6 +160,33 @@ pub struct StageAttachmentConf {
     pub(crate) copy_options: Option<BTreeMap<String, String>>,
 }
 
+/// The values of the query parameters, a JSON array binds the `?` placeholders by position,
+/// and a JSON object binds the `:name` placeholders by name.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum QueryParamsConf {
+    Positional(Vec<serde_json::Value>),
+    Named(BTreeMap<String, serde_json::Value>),
+}
+
+impl From<QueryParamsConf> for QueryParams {
+    fn from(conf: QueryParamsConf) -> Self {
+        match conf {
+            QueryParamsConf::Positional(positional) => QueryParams {
+                positional: positional.into_iter().map(QueryParamValue::Json).collect(),
+                ..Default::default()
+            },
+            QueryParamsConf::Named(named) => QueryParams {
+                named: named
+                    .into_iter()
+                    .map(|(name, value)| (name, QueryParamValue::Json(value)))
+                    .collect(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ResponseState {
     pub running_time_ms: i64,
@@ -205,6 +237,7 @@ impl HttpQuery {
     pub(crate) async fn try_create(
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
+        result_format: ResultFormat,
     ) -> Result<Arc<HttpQuery>> {
         let http_query_manager = HttpQueryManager::instance();
 
@@ -323,6 +356,16 @@ impl HttpQuery {
             None => {}
         };
 
+        // Query parameters are bound to the placeholders of the query as constants.
+        if let Some(params) = &request.params {
+            if request.stage_attachment.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "Query parameters can not be used together with stage attachment",
+                ));
+            }
+            ctx.attach_query_params(params.clone().into());
+        }
+
         let (block_sender, block_receiver) = sized_spsc(request.pagination.max_rows_in_buffer);
         let state = Arc::new(RwLock::new(Executor {
             query_id: query_id.clone(),
@@ -386,6 +429,7 @@ impl HttpQuery {
             block_receiver,
             schema,
             format_settings,
+            result_format,
         )));
 
         let query = HttpQuery {
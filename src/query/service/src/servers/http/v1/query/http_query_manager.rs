@@ -36,6 +36,7 @@ use super::HttpQueryContext;
 use crate::servers::http::v1::query::http_query::ExpireResult;
 use crate::servers::http::v1::query::http_query::HttpQuery;
 use crate::servers::http::v1::query::HttpQueryRequest;
+use crate::servers::http::v1::query::ResultFormat;
 use crate::sessions::Session;
 
 #[derive(Clone, Debug)]
@@ -115,8 +116,9 @@ impl HttpQueryManager {
         self: &Arc<Self>,
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
+        result_format: ResultFormat,
     ) -> Result<Arc<HttpQuery>> {
-        let query = HttpQuery::try_create(ctx, request).await?;
+        let query = HttpQuery::try_create(ctx, request, result_format).await?;
         self.add_query(&query.id, query.clone()).await;
         Ok(query)
     }
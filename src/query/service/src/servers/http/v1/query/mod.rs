@@ -37,6 +37,8 @@ pub use http_query_context::HttpQueryContext;
 pub use http_query_manager::HttpQueryManager;
 pub(crate) use http_query_manager::RemoveReason;
 pub use page_manager::Page;
+pub use page_manager::PageData;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
+pub use page_manager::ResultFormat;
 pub use page_manager::Wait;
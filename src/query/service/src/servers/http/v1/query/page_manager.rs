@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::VecDeque;
+use std::sync::Arc;
 use std::time::Instant;
 
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Schema as ArrowSchema;
 use databend_common_base::base::tokio;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -23,7 +25,7 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_io::prelude::FormatSettings;
 use log::debug;
 use log::info;
-use serde_json::Value as JsonValue;
+use parquet::arrow::ArrowWriter;
 
 use crate::servers::http::v1::json_block::block_to_json_value;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
@@ -35,9 +37,63 @@ pub enum Wait {
     Deadline(Instant),
 }
 
+/// The format of the result pages, negotiated by the `Accept` header of the query request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultFormat {
+    #[default]
+    Json,
+    Arrow,
+    Parquet,
+}
+
+impl ResultFormat {
+    pub fn from_accept(accept: &str) -> ResultFormat {
+        for media_type in accept.split(',') {
+            let media_type = media_type.split(';').next().unwrap_or_default().trim();
+            if media_type.eq_ignore_ascii_case(ResultFormat::Arrow.content_type()) {
+                return ResultFormat::Arrow;
+            } else if media_type.eq_ignore_ascii_case(ResultFormat::Parquet.content_type()) {
+                return ResultFormat::Parquet;
+            } else if media_type.eq_ignore_ascii_case(ResultFormat::Json.content_type()) {
+                return ResultFormat::Json;
+            }
+        }
+        ResultFormat::Json
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ResultFormat::Json => "application/json",
+            ResultFormat::Arrow => "application/vnd.apache.arrow.stream",
+            ResultFormat::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum PageData {
+    Json(JsonBlock),
+    /// The rows of the page serialized in the binary result format.
+    Binary {
+        format: ResultFormat,
+        schema: DataSchemaRef,
+        num_rows: usize,
+        bytes: Vec<u8>,
+    },
+}
+
+impl PageData {
+    pub fn num_rows(&self) -> usize {
+        match self {
+            PageData::Json(block) => block.num_rows(),
+            PageData::Binary { num_rows, .. } => *num_rows,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Page {
-    pub data: JsonBlock,
+    pub data: PageData,
     pub total_rows: usize,
 }
 
@@ -55,9 +111,10 @@ pub struct PageManager {
     block_end: bool,
     schema: DataSchemaRef,
     last_page: Option<Page>,
-    row_buffer: VecDeque<Vec<JsonValue>>,
+    row_buffer: Option<DataBlock>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: FormatSettings,
+    result_format: ResultFormat,
 }
 
 impl PageManager {
@@ -67,6 +124,7 @@ impl PageManager {
         block_receiver: SizedChannelReceiver<DataBlock>,
         schema: DataSchemaRef,
         format_settings: FormatSettings,
+        result_format: ResultFormat,
     ) -> PageManager {
         PageManager {
             query_id,
@@ -80,6 +138,7 @@ impl PageManager {
             block_receiver,
             max_rows_per_page,
             format_settings,
+            result_format,
         }
     }
 
@@ -96,11 +155,11 @@ impl PageManager {
         let next_no = self.total_pages;
         if page_no == next_no {
             if !self.end {
-                let (block, end) = self.collect_new_page(tp).await?;
-                let num_row = block.num_rows();
+                let (data, end) = self.collect_new_page(tp).await?;
+                let num_row = data.num_rows();
                 self.total_rows += num_row;
                 let page = Page {
-                    data: block,
+                    data,
                     total_rows: self.total_rows,
                 };
                 if num_row > 0 {
@@ -130,41 +189,40 @@ impl PageManager {
         }
     }
 
+    // Take at most `remain` rows of the block into the page, the rest are kept for the next page.
     fn append_block(
         &mut self,
-        rows: &mut Vec<Vec<JsonValue>>,
+        blocks: &mut Vec<DataBlock>,
         block: DataBlock,
         remain: usize,
-    ) -> Result<()> {
-        let format_settings = &self.format_settings;
-        let mut iter = block_to_json_value(&block, format_settings)?
-            .into_iter()
-            .peekable();
-        let chunk: Vec<_> = iter.by_ref().take(remain).collect();
-        rows.extend(chunk);
-        self.row_buffer = iter.by_ref().collect();
-        Ok(())
+    ) -> usize {
+        let num_rows = block.num_rows();
+        if num_rows > remain {
+            self.row_buffer = Some(block.slice(remain..num_rows));
+            blocks.push(block.slice(0..remain));
+            remain
+        } else {
+            blocks.push(block);
+            num_rows
+        }
     }
 
     #[async_backtrace::framed]
-    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(JsonBlock, bool)> {
-        let mut res: Vec<Vec<JsonValue>> = Vec::with_capacity(self.max_rows_per_page);
-        while res.len() < self.max_rows_per_page {
-            if let Some(row) = self.row_buffer.pop_front() {
-                res.push(row)
-            } else {
-                break;
-            }
+    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(PageData, bool)> {
+        let mut blocks = vec![];
+        let mut num_rows = 0;
+        if let Some(block) = self.row_buffer.take() {
+            num_rows += self.append_block(&mut blocks, block, self.max_rows_per_page);
         }
         loop {
-            assert!(self.max_rows_per_page >= res.len());
-            let remain = self.max_rows_per_page - res.len();
+            assert!(self.max_rows_per_page >= num_rows);
+            let remain = self.max_rows_per_page - num_rows;
             if remain == 0 {
                 break;
             }
             match tp {
                 Wait::Async => match self.block_receiver.try_recv() {
-                    Some(block) => self.append_block(&mut res, block, remain)?,
+                    Some(block) => num_rows += self.append_block(&mut blocks, block, remain),
                     None => break,
                 },
                 Wait::Deadline(t) => {
@@ -177,7 +235,7 @@ impl PageManager {
                                 &self.query_id,
                                 block.num_rows()
                             );
-                            self.append_block(&mut res, block, remain)?;
+                            num_rows += self.append_block(&mut blocks, block, remain);
                         }
                         Ok(None) => {
                             info!("{}: http query reach end of blocks", &self.query_id);
@@ -192,17 +250,53 @@ impl PageManager {
             }
         }
 
-        let block = JsonBlock {
-            schema: self.schema.clone(),
-            data: res,
-        };
+        let data = self.serialize_page(blocks, num_rows)?;
 
         // try to report 'no more data' earlier to client to avoid unnecessary http call
         if !self.block_end {
             self.block_end = self.block_receiver.is_empty();
         }
-        let end = self.block_end && self.row_buffer.is_empty();
-        Ok((block, end))
+        let end = self.block_end && self.row_buffer.is_none();
+        Ok((data, end))
+    }
+
+    fn serialize_page(&self, blocks: Vec<DataBlock>, num_rows: usize) -> Result<PageData> {
+        let blocks = blocks.into_iter().filter(|block| !block.is_empty());
+        let bytes = match self.result_format {
+            ResultFormat::Json => {
+                let mut data = Vec::with_capacity(num_rows);
+                for block in blocks {
+                    data.extend(block_to_json_value(&block, &self.format_settings)?);
+                }
+                return Ok(PageData::Json(JsonBlock {
+                    schema: self.schema.clone(),
+                    data,
+                }));
+            }
+            ResultFormat::Arrow => {
+                let arrow_schema = ArrowSchema::from(self.schema.as_ref());
+                let mut writer = StreamWriter::try_new(vec![], &arrow_schema)?;
+                for block in blocks {
+                    writer.write(&block.to_record_batch(&self.schema)?)?;
+                }
+                writer.finish()?;
+                writer.into_inner()?
+            }
+            ResultFormat::Parquet => {
+                let arrow_schema = Arc::new(ArrowSchema::from(self.schema.as_ref()));
+                let mut writer = ArrowWriter::try_new(vec![], arrow_schema, None)?;
+                for block in blocks {
+                    writer.write(&block.to_record_batch(&self.schema)?)?;
+                }
+                writer.into_inner()?
+            }
+        };
+        Ok(PageData::Binary {
+            format: self.result_format,
+            schema: self.schema.clone(),
+            num_rows,
+            bytes,
+        })
     }
 
     #[async_backtrace::framed]
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -19,6 +20,7 @@ use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::QueryParams;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -29,6 +31,9 @@ use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlaceholderRewriter;
+use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
@@ -38,6 +43,9 @@ use log::info;
 use minitrace::full_name;
 use minitrace::prelude::*;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
@@ -48,6 +56,9 @@ use rand::RngCore;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::mysql_prepared_statement::param_column_type;
+use crate::servers::mysql::mysql_prepared_statement::PreparedStatement;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -59,8 +70,14 @@ use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+// The prepared statements a connection can hold at the same time.
+const MAX_PREPARED_STATEMENTS: usize = 1024;
+
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    // The prepared statements of the session, keyed by the statement id.
+    prepared_statements: HashMap<u32, Arc<PreparedStatement>>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -203,12 +220,12 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
                 ));
             }
 
-            let mut writer = DFQueryResultWriter::create(writer);
+            let mut writer = DFQueryResultWriter::create(writer, false);
 
             let instant = Instant::now();
             let query_result = self
                 .base
-                .do_query(query)
+                .do_query(query, None)
                 .await
                 .map_err(|err| err.display_with_sql(query));
 
@@ -273,36 +290,150 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        if self.prepared_statements.len() >= MAX_PREPARED_STATEMENTS {
+            let message =
+                format!("Can't create more than {MAX_PREPARED_STATEMENTS} prepared statements");
+            writer
+                .error(
+                    ErrorKind::ER_MAX_PREPARED_STMT_COUNT_REACHED,
+                    message.as_bytes(),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let sql_dialect = self.session.get_settings().get_sql_dialect()?;
+        let statement = match PreparedStatement::try_create(query, sql_dialect) {
+            Ok(statement) => statement,
+            Err(error) => {
+                writer
+                    .error(ErrorKind::ER_PARSE_ERROR, error.to_string().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let (params, columns) = match self.describe_prepared_statement(&statement).await {
+            Ok(described) => described,
+            Err(error) => {
+                writer
+                    .error(ErrorKind::ER_PARSE_ERROR, error.to_string().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        // The ids wrap around, skip the ones still in use.
+        let mut id = self.next_statement_id;
+        while self.prepared_statements.contains_key(&id) {
+            id = id.wrapping_add(1);
+        }
+        self.next_statement_id = id.wrapping_add(1);
+        self.prepared_statements.insert(id, Arc::new(statement));
+
+        writer.reply(id, &params, &columns).await?;
         Ok(())
     }
 
+    // Plans the statement without parameters to get the types of its parameters and
+    // the columns of its result. The types of the parameters are inferred from where
+    // they are used, the unknown ones are reported as strings.
+    // Only syntax errors fail the prepare, the others are reported on execution,
+    // so describing doesn't fail the explicit transaction of the session.
+    #[async_backtrace::framed]
+    async fn describe_prepared_statement(
+        &self,
+        statement: &PreparedStatement,
+    ) -> Result<(Vec<Column>, Vec<Column>)> {
+        let param_column = |coltype| Column {
+            table: "".to_string(),
+            column: "?".to_string(),
+            coltype,
+            colflags: ColumnFlags::empty(),
+        };
+        let unknown_params =
+            vec![param_column(ColumnType::MYSQL_TYPE_VAR_STRING); statement.num_params()];
+
+        if let Some((schema, _)) = self.federated_server_command_check(statement.query()) {
+            return Ok((unknown_params, convert_schema(&schema)?));
+        }
+
+        let context = self.session.create_query_context().await?;
+        context.attach_query_params(statement.describe_params());
+        let mut planner = Planner::new(context);
+        match planner
+            .describe_stmt(statement.statement(), statement.format())
+            .await
+        {
+            Ok((plan, extras)) => {
+                let starts = PlaceholderRewriter::positional_placeholders(&extras.statement);
+                let params = if starts.len() == statement.num_params() {
+                    let metadata = extras.metadata.read();
+                    starts
+                        .into_iter()
+                        .map(|start| {
+                            param_column(param_column_type(metadata.get_placeholder_type(start)))
+                        })
+                        .collect()
+                } else {
+                    unknown_params
+                };
+                let columns = if plan.has_result_set() {
+                    convert_schema(&plan.schema()).unwrap_or_default()
+                } else {
+                    vec![]
+                };
+                Ok((params, columns))
+            }
+            Err(error) if error.code() == ErrorCode::SYNTAX_EXCEPTION => Err(error),
+            Err(_) => Ok((unknown_params, vec![])),
+        }
+    }
+
     #[async_backtrace::framed]
     async fn do_execute<W: AsyncWrite + Unpin>(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let Some(statement) = self.prepared_statements.get(&id).cloned() else {
+            writer
+                .error(
+                    ErrorKind::ER_UNKNOWN_STMT_HANDLER,
+                    format!("Unknown prepared statement handler ({id}) given to EXECUTE")
+                        .as_bytes(),
+                )
+                .await?;
+            return Ok(());
+        };
+
+        let format = self.session.get_format_settings();
+        let params = statement.bind_params(params, format.timezone);
+
+        let instant = Instant::now();
+        let query_result = match params {
+            Ok(params) => self
+                .do_query_prepared(&statement, params)
+                .await
+                .map_err(|err| err.display_with_sql(statement.query())),
+            Err(error) => Err(error),
+        };
+
+        let mut writer = DFQueryResultWriter::create(writer, true);
+        let write_result = writer.write(query_result, &format).await;
+        observe_mysql_process_request_duration(instant.elapsed());
+
+        write_result
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -322,7 +453,11 @@ impl InteractiveWorkerBase {
 
     #[async_backtrace::framed]
     #[minitrace::trace]
-    async fn do_query(&mut self, query: &str) -> Result<(QueryResult, Option<FormatSettings>)> {
+    async fn do_query(
+        &mut self,
+        query: &str,
+        params: Option<QueryParams>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
         match self.federated_server_command_check(query) {
             Some((schema, data_block)) => {
                 info!("Federated query: {}", query);
@@ -344,37 +479,73 @@ impl InteractiveWorkerBase {
             None => {
                 info!("Normal query: {}", query);
                 let context = self.session.create_query_context().await?;
+                if let Some(params) = params {
+                    context.attach_query_params(params);
+                }
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
+                Self::do_query_plan(context, query, plan, extras).await
+            }
+        }
+    }
 
-                context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = plan.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+    // Executes a prepared statement, it's planned from the statement parsed by prepare.
+    #[async_backtrace::framed]
+    async fn do_query_prepared(
+        &mut self,
+        statement: &PreparedStatement,
+        params: QueryParams,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        if self
+            .federated_server_command_check(statement.query())
+            .is_some()
+        {
+            return self.do_query(statement.query(), Some(params)).await;
+        }
+
+        info!("Prepared query: {}", statement.query());
+        let context = self.session.create_query_context().await?;
+        context.attach_query_params(params);
+
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner
+            .plan_stmt(statement.statement(), statement.format())
+            .await?;
+        Self::do_query_plan(context, statement.query(), plan, extras).await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_query_plan(
+        context: Arc<QueryContext>,
+        query: &str,
+        plan: Plan,
+        extras: PlanExtras,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = plan.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -425,7 +596,7 @@ impl InteractiveWorkerBase {
         }
         let init_query = format!("USE `{}`;", database_name);
 
-        let do_query = self.do_query(&init_query).await;
+        let do_query = self.do_query(&init_query, None).await;
         match do_query {
             Ok((_, _)) => Ok(()),
             Err(error_code) => Err(error_code),
@@ -448,7 +619,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 1,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
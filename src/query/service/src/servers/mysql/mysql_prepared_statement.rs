@@ -0,0 +1,273 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::NaiveDate;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::Dialect;
+use databend_common_catalog::table_context::QueryParamValue;
+use databend_common_catalog::table_context::QueryParams;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::serialize::read_decimal_with_size;
+use databend_common_expression::serialize::uniform_date;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Scalar;
+use databend_common_sql::PlaceholderRewriter;
+use opensrv_mysql::ColumnType;
+use opensrv_mysql::ParamValue;
+use opensrv_mysql::ValueInner;
+
+/// A statement prepared by `COM_STMT_PREPARE`, cached in the session until
+/// `COM_STMT_CLOSE`.
+///
+/// The statement is parsed once, each execution binds the parameters to its
+/// placeholders as typed constants when the statement is planned.
+pub struct PreparedStatement {
+    query: String,
+    statement: Statement,
+    format: Option<String>,
+    num_params: usize,
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str, sql_dialect: Dialect) -> Result<PreparedStatement> {
+        let tokens = tokenize_sql(query)?;
+        let (mut statement, format) = parse_sql(&tokens, sql_dialect)?;
+        PlaceholderRewriter::parse_insert_values(sql_dialect, &mut statement)?;
+        let num_params = PlaceholderRewriter::positional_placeholders(&statement).len();
+        Ok(PreparedStatement {
+            query: query.to_string(),
+            statement,
+            format,
+            num_params,
+        })
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    pub fn format(&self) -> Option<String> {
+        self.format.clone()
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.num_params
+    }
+
+    /// The parameters to plan the statement without values, to describe it.
+    pub fn describe_params(&self) -> QueryParams {
+        QueryParams {
+            describe: true,
+            ..Default::default()
+        }
+    }
+
+    /// Converts the parameters of `COM_STMT_EXECUTE` to the values of the placeholders,
+    /// the date and time values are in the time zone of the session.
+    pub fn bind_params<'a>(
+        &self,
+        params: impl IntoIterator<Item = ParamValue<'a>>,
+        tz: Tz,
+    ) -> Result<QueryParams> {
+        let positional = params
+            .into_iter()
+            .map(|param| param_to_scalar(param, tz).map(QueryParamValue::Scalar))
+            .collect::<Result<Vec<_>>>()?;
+        if positional.len() != self.num_params {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                self.num_params,
+                positional.len()
+            )));
+        }
+
+        Ok(QueryParams {
+            positional,
+            ..Default::default()
+        })
+    }
+}
+
+/// The MySQL type reported for a parameter, strings for the types without a
+/// binary encoding and the unknown ones.
+pub fn param_column_type(data_type: Option<&DataType>) -> ColumnType {
+    match data_type {
+        Some(DataType::Boolean) => ColumnType::MYSQL_TYPE_TINY,
+        Some(DataType::Number(num_ty)) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::UInt8 => ColumnType::MYSQL_TYPE_TINY,
+            NumberDataType::Int16 | NumberDataType::UInt16 => ColumnType::MYSQL_TYPE_SHORT,
+            NumberDataType::Int32 | NumberDataType::UInt32 => ColumnType::MYSQL_TYPE_LONG,
+            NumberDataType::Int64 | NumberDataType::UInt64 => ColumnType::MYSQL_TYPE_LONGLONG,
+            NumberDataType::Float32 => ColumnType::MYSQL_TYPE_FLOAT,
+            NumberDataType::Float64 => ColumnType::MYSQL_TYPE_DOUBLE,
+        },
+        Some(DataType::Decimal(_)) => ColumnType::MYSQL_TYPE_NEWDECIMAL,
+        Some(DataType::Date) => ColumnType::MYSQL_TYPE_DATE,
+        Some(DataType::Timestamp) => ColumnType::MYSQL_TYPE_DATETIME,
+        _ => ColumnType::MYSQL_TYPE_VAR_STRING,
+    }
+}
+
+/// Converts a parameter of `COM_STMT_EXECUTE` to a typed value.
+pub fn param_to_scalar(param: ParamValue, tz: Tz) -> Result<Scalar> {
+    let coltype = param.coltype;
+    match param.value.into_inner() {
+        ValueInner::NULL => Ok(Scalar::Null),
+        ValueInner::Int(v) => Ok(Scalar::Number(NumberScalar::Int64(v))),
+        ValueInner::UInt(v) => Ok(Scalar::Number(NumberScalar::UInt64(v))),
+        ValueInner::Double(v) => Ok(Scalar::Number(NumberScalar::Float64(v.into()))),
+        ValueInner::Bytes(bytes)
+            if matches!(
+                coltype,
+                ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL
+            ) =>
+        {
+            parse_decimal(bytes)
+        }
+        ValueInner::Bytes(bytes) => Ok(Scalar::String(bytes.to_vec())),
+        // The zero date `0000-00-00` is not a valid date, it's bound as NULL like
+        // the `zeroDateTimeBehavior=convertToNull` of the MySQL connectors.
+        ValueInner::Date(bytes) | ValueInner::Datetime(bytes) if is_zero_date(bytes)? => {
+            Ok(Scalar::Null)
+        }
+        ValueInner::Date(bytes) if coltype == ColumnType::MYSQL_TYPE_DATE => {
+            let (year, month, day, ..) = decode_datetime(bytes)?;
+            let date = NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+                .ok_or_else(|| invalid_datetime(bytes))?;
+            Ok(Scalar::Date(uniform_date(date)))
+        }
+        ValueInner::Date(bytes) | ValueInner::Datetime(bytes) => {
+            let (year, month, day, hour, minute, second, micros) = decode_datetime(bytes)?;
+            let datetime = NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+                .and_then(|date| {
+                    date.and_hms_micro_opt(hour as u32, minute as u32, second as u32, micros)
+                })
+                .and_then(|datetime| tz.from_local_datetime(&datetime).earliest())
+                .ok_or_else(|| invalid_datetime(bytes))?;
+            Ok(Scalar::Timestamp(datetime.timestamp_micros()))
+        }
+        ValueInner::Time(bytes) => {
+            // <is_negative 1><days 4><hour 1><minute 1><second 1>[<micros 4>]
+            let (negative, days, hour, minute, second, micros) = match bytes.len() {
+                0 => (false, 0, 0, 0, 0, 0),
+                8 | 12 => (
+                    bytes[0] == 1,
+                    u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]),
+                    bytes[5] as u32,
+                    bytes[6],
+                    bytes[7],
+                    if bytes.len() == 12 {
+                        u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]])
+                    } else {
+                        0
+                    },
+                ),
+                len => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Invalid length {len} of time parameter"
+                    )));
+                }
+            };
+            let sign = if negative { "-" } else { "" };
+            let time = format!(
+                "{sign}{:02}:{minute:02}:{second:02}.{micros:06}",
+                days * 24 + hour
+            );
+            Ok(Scalar::String(time.into_bytes()))
+        }
+    }
+}
+
+/// Parses the text of a decimal parameter, the precision and scale are the ones of the text.
+fn parse_decimal(bytes: &[u8]) -> Result<Scalar> {
+    let invalid = || {
+        ErrorCode::BadArguments(format!(
+            "Invalid decimal parameter {}",
+            String::from_utf8_lossy(bytes)
+        ))
+    };
+    let digits = bytes.strip_prefix(b"-").unwrap_or(bytes);
+    let (int_part, frac_part) = match digits.iter().position(|b| *b == b'.') {
+        Some(pos) => (&digits[..pos], &digits[pos + 1..]),
+        None => (digits, &digits[digits.len()..]),
+    };
+    if !int_part.iter().chain(frac_part).all(u8::is_ascii_digit) || digits == b"." {
+        return Err(invalid());
+    }
+
+    let int_digits = int_part.iter().skip_while(|b| **b == b'0').count();
+    let scale = frac_part.len();
+    let precision = (int_digits + scale).max(1);
+    if precision > 76 {
+        return Err(invalid());
+    }
+    let size = DecimalSize {
+        precision: precision as u8,
+        scale: scale as u8,
+    };
+    if precision <= 38 {
+        let (value, _) = read_decimal_with_size::<i128>(bytes, size, true, false)?;
+        Ok(Scalar::Decimal(DecimalScalar::Decimal128(value, size)))
+    } else {
+        let (value, _) = read_decimal_with_size(bytes, size, true, false)?;
+        Ok(Scalar::Decimal(DecimalScalar::Decimal256(value, size)))
+    }
+}
+
+fn is_zero_date(bytes: &[u8]) -> Result<bool> {
+    let (year, month, day, ..) = decode_datetime(bytes)?;
+    Ok(year == 0 && month == 0 && day == 0)
+}
+
+fn invalid_datetime(bytes: &[u8]) -> ErrorCode {
+    ErrorCode::BadArguments(format!("Invalid datetime parameter {bytes:?}"))
+}
+
+/// Decodes the binary protocol encoding of `DATE`, `DATETIME` and `TIMESTAMP`:
+/// `<year 2><month 1><day 1>[<hour 1><minute 1><second 1>[<micros 4>]]`.
+fn decode_datetime(bytes: &[u8]) -> Result<(u16, u8, u8, u8, u8, u8, u32)> {
+    match bytes.len() {
+        0 => Ok((0, 0, 0, 0, 0, 0, 0)),
+        4 | 7 | 11 => {
+            let year = u16::from_le_bytes([bytes[0], bytes[1]]);
+            let (hour, minute, second) = if bytes.len() >= 7 {
+                (bytes[4], bytes[5], bytes[6])
+            } else {
+                (0, 0, 0)
+            };
+            let micros = if bytes.len() == 11 {
+                u32::from_le_bytes([bytes[7], bytes[8], bytes[9], bytes[10]])
+            } else {
+                0
+            };
+            Ok((year, bytes[2], bytes[3], hour, minute, second, micros))
+        }
+        len => Err(ErrorCode::BadArguments(format!(
+            "Invalid length {len} of datetime parameter"
+        ))),
+    }
+}
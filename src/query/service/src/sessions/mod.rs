@@ -15,6 +15,7 @@
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
+mod query_queue;
 mod session;
 mod session_ctx;
 mod session_info;
@@ -30,6 +31,7 @@ pub use query_ctx::convert_query_log_timestamp;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::short_sql;
 pub use query_ctx_shared::QueryContextShared;
+pub use query_queue::QueryQueueManager;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
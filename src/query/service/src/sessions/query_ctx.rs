@@ -35,6 +35,7 @@ use dashmap::DashMap;
 use databend_common_base::base::tokio::task::JoinHandle;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::MemStat;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_catalog::plan::DataSourcePlan;
@@ -44,7 +45,9 @@ use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
+use databend_common_catalog::table_context::QueryParams;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -271,6 +274,10 @@ impl QueryContext {
         self.shared.attach_stage(attachment);
     }
 
+    pub fn attach_query_params(&self, params: QueryParams) {
+        self.shared.attach_query_params(params);
+    }
+
     pub fn set_ua(&self, ua: String) {
         *self.shared.user_agent.write() = ua;
     }
@@ -292,6 +299,19 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    #[async_backtrace::framed]
+    pub async fn acquire_workload_group(&self) -> Result<()> {
+        self.shared.acquire_workload_group().await
+    }
+
+    pub fn release_workload_group(&self) {
+        self.shared.release_workload_group()
+    }
+
+    pub fn get_workload_group_mem_stat(&self) -> Option<Arc<MemStat>> {
+        self.shared.get_workload_group_mem_stat()
+    }
+
     pub fn set_finish_time(&self, time: SystemTime) {
         *self.shared.finish_time.write() = Some(time)
     }
@@ -633,6 +653,10 @@ impl TableContext for QueryContext {
         self.shared.get_stage_attachment()
     }
 
+    fn get_query_params(&self) -> Option<QueryParams> {
+        self.shared.get_query_params()
+    }
+
     fn get_last_query_id(&self, index: i32) -> String {
         self.shared.session.session_ctx.get_last_query_id(index)
     }
@@ -935,6 +959,10 @@ impl TableContext for QueryContext {
         // If don't find the runtime filters, return empty vector.
         runtime_filters.get(&id).cloned().unwrap_or_default()
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
 }
 
 impl TrySpawn for QueryContext {
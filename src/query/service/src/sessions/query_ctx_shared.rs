@@ -22,10 +22,12 @@ use std::time::SystemTime;
 
 use dashmap::DashMap;
 use databend_common_base::base::Progress;
+use databend_common_base::runtime::MemStat;
 use databend_common_base::runtime::Runtime;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
+use databend_common_catalog::table_context::QueryParams;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -50,6 +52,8 @@ use uuid::Uuid;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::query_queue::QueryQueueGuard;
+use crate::sessions::query_queue::QueryQueueManager;
 use crate::sessions::Session;
 use crate::storages::Table;
 
@@ -86,6 +90,7 @@ pub struct QueryContextShared {
     pub(in crate::sessions) data_operator: DataOperator,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
+    pub(in crate::sessions) query_params: Arc<RwLock<Option<QueryParams>>>,
     pub(in crate::sessions) created_time: SystemTime,
     // now it is only set in query_log::log_query_finished
     pub(in crate::sessions) finish_time: RwLock<Option<SystemTime>>,
@@ -113,6 +118,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_profiles: Arc<RwLock<HashMap<Option<u32>, PlanProfile>>>,
 
     pub(in crate::sessions) runtime_filters: Arc<RwLock<HashMap<IndexType, Vec<Expr<String>>>>>,
+
+    /// True while the query is waiting in the queue of its workload group.
+    pub(in crate::sessions) queued: Arc<AtomicBool>,
+    pub(in crate::sessions) queue_guard: Arc<Mutex<Option<QueryQueueGuard>>>,
 }
 
 impl QueryContextShared {
@@ -140,6 +149,7 @@ impl QueryContextShared {
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
+            query_params: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
             finish_time: Default::default(),
             on_error_map: Arc::new(RwLock::new(None)),
@@ -158,6 +168,8 @@ impl QueryContextShared {
             group_by_spill_progress: Arc::new(Progress::create()),
             query_profiles: Arc::new(RwLock::new(HashMap::new())),
             runtime_filters: Default::default(),
+            queued: Arc::new(AtomicBool::new(false)),
+            queue_guard: Arc::new(Mutex::new(None)),
         }))
     }
 
@@ -319,7 +331,16 @@ impl QueryContextShared {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // Read the uncommitted changes if the table is mutated in the current transaction.
+        let buffered_table_info = self
+            .session
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(cache_table.get_id());
+        if let Some(table_info) = buffered_table_info {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -421,6 +442,15 @@ impl QueryContextShared {
         *stage_attachment = Some(attachment);
     }
 
+    pub fn get_query_params(&self) -> Option<QueryParams> {
+        self.query_params.read().clone()
+    }
+
+    pub fn attach_query_params(&self, params: QueryParams) {
+        let mut query_params = self.query_params.write();
+        *query_params = Some(params);
+    }
+
     pub fn get_created_time(&self) -> SystemTime {
         self.created_time
     }
@@ -430,6 +460,35 @@ impl QueryContextShared {
         status.clone()
     }
 
+    pub fn set_queued(&self, queued: bool) {
+        self.queued.store(queued, Ordering::Release);
+    }
+
+    pub fn is_queued(&self) -> bool {
+        self.queued.load(Ordering::Acquire)
+    }
+
+    /// Waits until the workload group of the current user admits the query,
+    /// the query holds its place in the group until `release_workload_group`.
+    #[async_backtrace::framed]
+    pub async fn acquire_workload_group(self: &Arc<Self>) -> Result<()> {
+        let guard = QueryQueueManager::instance().acquire(self).await?;
+        *self.queue_guard.lock() = guard;
+        Ok(())
+    }
+
+    pub fn release_workload_group(&self) {
+        self.queue_guard.lock().take();
+    }
+
+    /// The memory stat of the query admitted by a workload group.
+    pub fn get_workload_group_mem_stat(&self) -> Option<Arc<MemStat>> {
+        self.queue_guard
+            .lock()
+            .as_ref()
+            .map(|guard| guard.get_mem_stat())
+    }
+
     pub async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection> {
         let user_mgr = UserApiProvider::instance();
         let tenant = self.get_tenant();
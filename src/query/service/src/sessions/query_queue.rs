@@ -0,0 +1,258 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::tokio::time::timeout;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::MemStat;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_users::UserApiProvider;
+use log::info;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::sessions::QueryContextShared;
+
+/// The waiting queries check the queue at least at this interval,
+/// so an abort or a change of the memory usage is noticed.
+const QUEUE_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The workload groups of a tenant are reloaded from meta after this interval,
+/// so a change of the groups made on other nodes takes effect after it.
+const WORKLOAD_GROUPS_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// Admits the queries of the workload groups on this node.
+///
+/// A query of a workload group waits until the running queries of the group
+/// are below the limits of the group, or it is timed out by the `queue_timeout`.
+/// The memory of the admitted queries is tracked by the group, a query fails
+/// if it allocates more memory than the `max_memory_usage` of its group.
+pub struct QueryQueueManager {
+    queues: Mutex<HashMap<String, Arc<WorkloadGroupQueue>>>,
+    /// The cached workload groups, by tenant.
+    workload_groups: RwLock<HashMap<String, CachedWorkloadGroups>>,
+}
+
+struct CachedWorkloadGroups {
+    workload_groups: Vec<WorkloadGroup>,
+    cached_at: Instant,
+}
+
+struct WorkloadGroupQueue {
+    /// The running queries of the group, by query id.
+    running: Mutex<HashMap<String, Weak<QueryContextShared>>>,
+    notify: Notify,
+    /// The memory of the running queries of the group, limited by the `max_memory_usage`.
+    mem_stat: Arc<MemStat>,
+}
+
+impl WorkloadGroupQueue {
+    fn create(name: String) -> Self {
+        WorkloadGroupQueue {
+            running: Mutex::new(HashMap::new()),
+            notify: Notify::new(),
+            mem_stat: MemStat::create_child(name, None),
+        }
+    }
+
+    fn try_admit(
+        &self,
+        query_id: &str,
+        shared: &Arc<QueryContextShared>,
+        group: &WorkloadGroup,
+    ) -> bool {
+        let mut running = self.running.lock();
+        running.retain(|_, query| query.strong_count() > 0);
+
+        if group.max_concurrency > 0 && running.len() as u64 >= group.max_concurrency {
+            return false;
+        }
+        if group.max_memory_usage > 0
+            && self.mem_stat.get_memory_usage() >= group.max_memory_usage as i64
+        {
+            return false;
+        }
+
+        running.insert(query_id.to_string(), Arc::downgrade(shared));
+        true
+    }
+}
+
+/// Holds the place of a running query in its workload group, the place is
+/// released when the guard is dropped.
+pub struct QueryQueueGuard {
+    queue: Arc<WorkloadGroupQueue>,
+    query_id: String,
+    mem_stat: Arc<MemStat>,
+}
+
+impl QueryQueueGuard {
+    /// The memory stat of the query, it reports to the memory stat of the workload group.
+    pub fn get_mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+}
+
+impl Drop for QueryQueueGuard {
+    fn drop(&mut self) {
+        self.queue.running.lock().remove(&self.query_id);
+        self.queue.notify.notify_waiters();
+    }
+}
+
+impl QueryQueueManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(QueryQueueManager {
+            queues: Mutex::new(HashMap::new()),
+            workload_groups: RwLock::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<QueryQueueManager> {
+        GlobalInstance::get()
+    }
+
+    /// Waits until the workload group of the current user admits the query.
+    ///
+    /// Returns `None` if the query is not limited by any workload group.
+    #[async_backtrace::framed]
+    pub async fn acquire(
+        self: &Arc<Self>,
+        shared: &Arc<QueryContextShared>,
+    ) -> Result<Option<QueryQueueGuard>> {
+        if !matches!(
+            shared.get_query_kind(),
+            QueryKind::Query | QueryKind::Insert | QueryKind::Update | QueryKind::CopyIntoTable
+        ) {
+            return Ok(None);
+        }
+
+        let tenant = shared.get_tenant();
+        let user = shared.get_current_user()?;
+        let role = shared.get_current_role();
+        let workload_groups = self.get_workload_groups(&tenant).await?;
+        let group = match WorkloadGroup::find_of_user(
+            &workload_groups,
+            &user.name,
+            role.as_ref().map(|r| r.name.as_str()),
+        ) {
+            Some(group) => group.clone(),
+            None => return Ok(None),
+        };
+
+        let queue = self
+            .queues
+            .lock()
+            .entry(format!("{}/{}", tenant, group.name))
+            .or_insert_with_key(|key| Arc::new(WorkloadGroupQueue::create(key.clone())))
+            .clone();
+        // The limit may be changed by `ALTER WORKLOAD GROUP`.
+        queue
+            .mem_stat
+            .set_limit(group.max_memory_usage.min(i64::MAX as u64) as i64);
+
+        let query_id = shared.init_query_id.read().clone();
+        let start = Instant::now();
+        let mut queued = false;
+        loop {
+            // Registered before the check, so a release between the check and the wait is not missed.
+            let notified = queue.notify.notified();
+            if queue.try_admit(&query_id, shared, &group) {
+                break;
+            }
+
+            if !queued {
+                queued = true;
+                shared.set_queued(true);
+                *shared.status.write() = format!("queued in workload group {}", group.name);
+                info!(
+                    "query {} is queued in workload group {}",
+                    query_id, group.name
+                );
+            }
+
+            let mut wait = QUEUE_CHECK_INTERVAL;
+            if group.queue_timeout > 0 {
+                let remaining =
+                    Duration::from_secs(group.queue_timeout).saturating_sub(start.elapsed());
+                if remaining.is_zero() {
+                    shared.set_queued(false);
+                    return Err(ErrorCode::WorkloadGroupQueueTimeout(format!(
+                        "query is queued in workload group `{}` for more than {} seconds",
+                        group.name, group.queue_timeout
+                    )));
+                }
+                wait = wait.min(remaining);
+            }
+
+            if let Err(cause) = shared.check_aborting() {
+                shared.set_queued(false);
+                return Err(cause);
+            }
+            let _ = timeout(wait, notified).await;
+        }
+
+        if queued {
+            shared.set_queued(false);
+            info!(
+                "query {} is admitted by workload group {} after {:?}",
+                query_id,
+                group.name,
+                start.elapsed()
+            );
+        }
+        let mem_stat =
+            MemStat::create_child(format!("Query-{}", query_id), Some(queue.mem_stat.clone()));
+        Ok(Some(QueryQueueGuard {
+            queue,
+            query_id,
+            mem_stat,
+        }))
+    }
+
+    /// Drops the cached workload groups of the tenant, e.g. after they are changed.
+    pub fn invalidate_workload_groups(&self, tenant: &str) {
+        self.workload_groups.write().remove(tenant);
+    }
+
+    #[async_backtrace::framed]
+    async fn get_workload_groups(&self, tenant: &str) -> Result<Vec<WorkloadGroup>> {
+        if let Some(cached) = self.workload_groups.read().get(tenant) {
+            if cached.cached_at.elapsed() < WORKLOAD_GROUPS_CACHE_TTL {
+                return Ok(cached.workload_groups.clone());
+            }
+        }
+
+        let workload_groups = UserApiProvider::instance()
+            .get_workload_groups(tenant)
+            .await?;
+        self.workload_groups
+            .write()
+            .insert(tenant.to_string(), CachedWorkloadGroups {
+                workload_groups: workload_groups.clone(),
+                cached_at: Instant::now(),
+            });
+        Ok(workload_groups)
+    }
+}
@@ -15,6 +15,7 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -271,6 +272,10 @@ impl Session {
         self.session_ctx.get_settings()
     }
 
+    pub fn txn_mgr(self: &Arc<Self>) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
+
     pub fn get_memory_usage(self: &Arc<Self>) -> usize {
         // TODO(winter): use thread memory tracker
         0
@@ -19,6 +19,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use databend_common_catalog::txn::TxnManager;
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::RoleInfo;
@@ -63,6 +65,8 @@ pub struct SessionContext {
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
     typ: SessionType,
+    // The explicit transaction of the session.
+    txn_mgr: TxnManagerRef,
 }
 
 impl SessionContext {
@@ -82,6 +86,7 @@ impl SessionContext {
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
             typ,
+            txn_mgr: TxnManager::init(),
         }))
     }
 
@@ -286,4 +291,8 @@ impl SessionContext {
         let lock = self.query_ids_results.read();
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
+
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
 }
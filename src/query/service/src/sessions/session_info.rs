@@ -39,6 +39,10 @@ impl Session {
                 let mem_stat = runtime.get_tracker();
                 memory_usage = mem_stat.get_memory_usage();
             }
+            // The memory of a query admitted by a workload group is reported to the group.
+            if let Some(mem_stat) = shared.get_workload_group_mem_stat() {
+                memory_usage += mem_stat.get_memory_usage();
+            }
         }
 
         ProcessInfo {
@@ -65,6 +69,7 @@ impl Session {
         match status.get_query_context_shared() {
             _ if status.get_abort() => ProcessInfoState::Aborting,
             None => ProcessInfoState::Idle,
+            Some(shared) if shared.is_queued() => ProcessInfoState::Queued,
             Some(_) => ProcessInfoState::Query,
         }
     }
@@ -77,7 +77,10 @@ impl SessionManager {
 
     #[async_backtrace::framed]
     pub async fn create_session(&self, typ: SessionType) -> Result<Arc<Session>> {
-        if !matches!(typ, SessionType::Dummy | SessionType::FlightRPC) {
+        if !matches!(
+            typ,
+            SessionType::Dummy | SessionType::FlightRPC | SessionType::Task
+        ) {
             let sessions = self.active_sessions.read();
             self.validate_max_active_sessions(sessions.len(), "active sessions")?;
         }
@@ -107,7 +110,10 @@ impl SessionManager {
     pub fn try_add_session(&self, session: Arc<Session>, typ: SessionType) -> Result<()> {
         let mut sessions = self.active_sessions.write();
         if !matches!(typ, SessionType::Dummy | SessionType::FlightRPC) {
-            self.validate_max_active_sessions(sessions.len(), "active sessions")?;
+            // The task runs are started by the server, they don't take the place of a client.
+            if typ != SessionType::Task {
+                self.validate_max_active_sessions(sessions.len(), "active sessions")?;
+            }
             sessions.insert(session.get_id(), Arc::downgrade(&session));
             set_session_active_connections(sessions.len());
         }
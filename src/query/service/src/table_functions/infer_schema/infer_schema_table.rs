@@ -45,7 +45,10 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_file_location;
+use databend_common_storage::infer_avro_schema;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::read_avro_schema_async;
+use databend_common_storage::read_orc_schema_async;
 use databend_common_storage::read_parquet_schema_async;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
@@ -255,9 +258,16 @@ impl AsyncSource for InferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Avro => {
+                let avro_schema =
+                    read_avro_schema_async(&operator, &first_file.path, Some(first_file.size))
+                        .await?;
+                infer_avro_schema(&avro_schema)?
+            }
+            StageFileFormatType::Orc => read_orc_schema_async(&operator, &first_file.path).await?,
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, Avro and ORC",
                 ));
             }
         };
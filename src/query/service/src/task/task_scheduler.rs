@@ -0,0 +1,368 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::TaskRunState;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::StreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// How often the due tasks are checked.
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The node that schedules the tasks keeps the lease by renewing it every `SCHEDULE_INTERVAL`,
+/// another node takes over if it is not renewed in time.
+const SCHEDULER_LEASE_TTL: Duration = Duration::from_secs(60);
+
+/// Retries of updating the task state after a run, when it is concurrently altered.
+const UPDATE_TASK_RETRIES: usize = 3;
+
+/// Runs the tasks stored in the meta service, used when cloud control is not configured.
+///
+/// Every query node runs the scheduling loop, but only the one holding the scheduler lease
+/// of the tenant starts the due tasks. Only the root of a task DAG is scheduled, the other
+/// tasks of the DAG run in the same job once all their predecessors succeeded.
+pub struct TaskScheduler {
+    tenant: String,
+    node_id: String,
+    /// The tasks running on this node, a task is not started again before its last run ends.
+    running: Mutex<HashSet<String>>,
+}
+
+impl TaskScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(TaskScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            node_id: conf.query.node_id.clone(),
+            running: Mutex::new(HashSet::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    /// Starts the scheduling loop in background.
+    pub fn start(self: &Arc<Self>) {
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            info!("task scheduler started on node {}", scheduler.node_id);
+            loop {
+                if let Err(cause) = scheduler.schedule_due_tasks().await {
+                    warn!("task scheduler: fail to schedule tasks: {:?}", cause);
+                }
+                tokio::time::sleep(SCHEDULE_INTERVAL).await;
+            }
+        });
+    }
+
+    /// Runs the task and the tasks after it once and waits for them, regardless of the
+    /// schedule and status of the task.
+    ///
+    /// A failure of the task query is recorded in the task history instead of returned.
+    #[async_backtrace::framed]
+    pub async fn execute_task(&self, task: Task) -> Result<()> {
+        let task_name = task.task_name.clone();
+        if !self.running.lock().insert(task_name.clone()) {
+            return Err(ErrorCode::IllegalTask(format!(
+                "task {} is already running",
+                task_name
+            )));
+        }
+        let result = self.run_graph(task, Utc::now()).await;
+        self.running.lock().remove(&task_name);
+        result
+    }
+
+    #[async_backtrace::framed]
+    async fn schedule_due_tasks(self: &Arc<Self>) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        if !user_api
+            .try_acquire_task_scheduler_lease(&self.tenant, &self.node_id, SCHEDULER_LEASE_TTL)
+            .await?
+        {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        for task in user_api.get_tasks(&self.tenant).await? {
+            if task.status != TaskStatus::Started
+                || task.schedule.is_none()
+                || task.next_scheduled_at.is_some_and(|at| at > now)
+            {
+                continue;
+            }
+
+            // Move the next schedule time forward before the task is started, so that a
+            // new scheduler does not start it again after the lease is taken over.
+            let seq_task = match user_api.get_task(&self.tenant, &task.task_name).await {
+                Ok(seq_task) => seq_task,
+                Err(cause) => {
+                    warn!(
+                        "task scheduler: fail to get task {}: {:?}",
+                        task.task_name, cause
+                    );
+                    continue;
+                }
+            };
+            let mut task = seq_task.data;
+            let Some(schedule) = &task.schedule else {
+                continue;
+            };
+            let scheduled_at = task.next_scheduled_at;
+            task.next_scheduled_at =
+                Some(schedule.next_after(now).map_err(ErrorCode::IllegalTask)?);
+            // The task is altered concurrently, it is scheduled again in the next round.
+            if let Err(cause) = user_api
+                .update_task(&self.tenant, task.clone(), MatchSeq::Exact(seq_task.seq))
+                .await
+            {
+                warn!(
+                    "task scheduler: fail to update the schedule of task {}: {:?}",
+                    task.task_name, cause
+                );
+                continue;
+            }
+
+            // A task without next schedule time is just created or resumed, it is due next time.
+            let Some(scheduled_at) = scheduled_at else {
+                continue;
+            };
+            if !self.running.lock().insert(task.task_name.clone()) {
+                info!(
+                    "task scheduler: skip task {}, its last run is not finished",
+                    task.task_name
+                );
+                continue;
+            }
+            self.spawn_run(task, scheduled_at);
+        }
+        Ok(())
+    }
+
+    fn spawn_run(self: &Arc<Self>, task: Task, scheduled_at: DateTime<Utc>) {
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            let task_name = task.task_name.clone();
+            if let Err(cause) = scheduler.run_graph(task, scheduled_at).await {
+                warn!(
+                    "task scheduler: fail to run task {}: {:?}",
+                    task_name, cause
+                );
+            }
+            scheduler.running.lock().remove(&task_name);
+        });
+    }
+
+    /// Runs the task, then each started task after it once all its predecessors succeeded.
+    ///
+    /// Every task runs at most once, so a cycle stored by concurrent `ALTER TASK`s can't
+    /// make the run loop forever.
+    #[async_backtrace::framed]
+    async fn run_graph(&self, root: Task, scheduled_at: DateTime<Utc>) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let mut succeeded = HashSet::new();
+        let mut visited = HashSet::from([root.task_name.clone()]);
+        let mut pending = VecDeque::from([root]);
+        while let Some(task) = pending.pop_front() {
+            let task_name = task.task_name.clone();
+            if !self.run_task(task, scheduled_at).await? {
+                continue;
+            }
+            succeeded.insert(task_name.clone());
+            for successor in user_api.get_tasks(&self.tenant).await? {
+                if successor.status == TaskStatus::Started
+                    && successor.after.contains(&task_name)
+                    && successor.after.iter().all(|name| succeeded.contains(name))
+                    && visited.insert(successor.task_name.clone())
+                {
+                    pending.push_back(successor);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the task and records the run in the task history, returns whether it succeeded.
+    ///
+    /// The task is skipped without a run if its `WHEN` condition is not true.
+    #[async_backtrace::framed]
+    async fn run_task(&self, task: Task, scheduled_at: DateTime<Utc>) -> Result<bool> {
+        if let Some(condition) = &task.when_condition {
+            if !self.check_condition(&task, condition).await? {
+                info!(
+                    "task scheduler: skip task {}, condition {} is not true",
+                    task.task_name, condition
+                );
+                return Ok(false);
+            }
+        }
+
+        let user_api = UserApiProvider::instance();
+        let mut run = TaskRun {
+            task: task.clone(),
+            run_id: user_api.generate_task_run_id(&self.tenant).await?,
+            query_id: "".to_string(),
+            attempt_number: task.consecutive_failures + 1,
+            state: TaskRunState::Executing,
+            error_code: 0,
+            error_message: None,
+            scheduled_at,
+            completed_at: None,
+        };
+        user_api.add_task_run(&self.tenant, run.clone()).await?;
+
+        let result = self
+            .execute_sql(&task, &task.query_text, &mut run.query_id)
+            .await;
+        run.completed_at = Some(Utc::now());
+        match &result {
+            Ok(_) => run.state = TaskRunState::Succeeded,
+            Err(cause) => {
+                run.state = TaskRunState::Failed;
+                run.error_code = cause.code() as i64;
+                run.error_message = Some(cause.message());
+            }
+        }
+        user_api.add_task_run(&self.tenant, run).await?;
+
+        self.record_result(&task.task_name, result.is_ok()).await?;
+        Ok(result.is_ok())
+    }
+
+    #[async_backtrace::framed]
+    async fn check_condition(&self, task: &Task, condition: &str) -> Result<bool> {
+        let mut query_id = String::new();
+        let sql = format!("SELECT {}", condition);
+        let Some(block) = self.execute_sql(task, &sql, &mut query_id).await? else {
+            return Ok(false);
+        };
+        Ok(matches!(
+            block.get_by_offset(0).value.index(0),
+            Some(ScalarRef::Boolean(true))
+        ))
+    }
+
+    /// Executes the SQL as the owner role of the task, returns the first block of the result.
+    #[async_backtrace::framed]
+    async fn execute_sql(
+        &self,
+        task: &Task,
+        sql: &str,
+        query_id: &mut String,
+    ) -> Result<Option<DataBlock>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Task)
+            .await?;
+        let user = UserInfo::new_no_auth(
+            &format!("{}-task-{}", self.tenant, task.task_name),
+            "0.0.0.0",
+        );
+        // The task runs with the privileges of its owner role only.
+        session
+            .set_authed_user(user, Some(task.owner.clone()))
+            .await?;
+
+        let ctx = session.create_query_context().await?;
+        *query_id = ctx.get_id();
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, extras) = planner.plan_sql(sql).await?;
+        ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx.clone()).await?;
+        let mut first_block = None;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if first_block.is_none() && !block.is_empty() {
+                first_block = Some(block);
+            }
+        }
+        Ok(first_block)
+    }
+
+    /// Resets the failure count of the task if the run succeeded, or suspends the task once
+    /// it failed `suspend_task_after_num_failures` times in a row.
+    #[async_backtrace::framed]
+    async fn record_result(&self, task_name: &str, succeeded: bool) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let mut retries = 0;
+        loop {
+            let seq_task = match user_api.get_task(&self.tenant, task_name).await {
+                Ok(seq_task) => seq_task,
+                // The task is dropped while running.
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TASK => return Ok(()),
+                Err(e) => return Err(e),
+            };
+            let mut task = seq_task.data;
+            if succeeded {
+                task.consecutive_failures = 0;
+            } else {
+                task.consecutive_failures += 1;
+                if task.status == TaskStatus::Started
+                    && task.reach_failure_limit(task.consecutive_failures)
+                {
+                    info!(
+                        "task scheduler: suspend task {} after {} failures",
+                        task_name, task.consecutive_failures
+                    );
+                    task.status = TaskStatus::Suspended;
+                    task.next_scheduled_at = None;
+                    task.last_suspended_at = Some(Utc::now());
+                }
+            }
+
+            match user_api
+                .update_task(&self.tenant, task, MatchSeq::Exact(seq_task.seq))
+                .await
+            {
+                Ok(_) => return Ok(()),
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TASK && retries < UPDATE_TASK_RETRIES => {
+                    retries += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
@@ -16,24 +16,49 @@
 
 use std::fs;
 use std::io::Write;
+use std::sync::Arc;
 
+use arrow_array::Array;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
+use arrow_flight::encode::FlightDataEncoderBuilder;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
+use arrow_flight::sql::CommandGetXdbcTypeInfo;
+use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::ProstMessageExt;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
+use arrow_flight::PutResult;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
 use databend_common_base::base::tokio;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::PasswordHashMethod;
+use databend_query::servers::flight_sql::flight_sql_service::CommandStatementIngest;
 use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use databend_query::servers::flight_sql::flight_sql_service::TableDefinitionOptions;
+use databend_query::servers::flight_sql::flight_sql_service::TableExistsOption;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use futures::stream;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use goldenfile::Mint;
 use log::debug;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
@@ -148,3 +173,288 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+async fn fetch_flight_info(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> std::result::Result<Vec<RecordBatch>, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    flight_data_to_batches(&flight_data)
+}
+
+fn string_column(batches: &[RecordBatch], name: &str) -> Vec<String> {
+    let mut values = vec![];
+    for batch in batches {
+        let column = batch.column_by_name(name).unwrap();
+        let column = column.as_any().downcast_ref::<StringArray>().unwrap();
+        values.extend(column.iter().map(|v| v.unwrap_or_default().to_string()));
+    }
+    values
+}
+
+#[tokio::test]
+async fn test_metadata() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path.clone()).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        run_query(&mut client, "create database if not exists flight_db")
+            .await
+            .unwrap();
+        run_query(
+            &mut client,
+            "create table if not exists flight_db.t1(a int)",
+        )
+        .await
+        .unwrap();
+
+        let flight_info = client.get_catalogs().await.unwrap();
+        let batches = fetch_flight_info(&mut client, flight_info).await.unwrap();
+        assert!(string_column(&batches, "catalog_name").contains(&"default".to_string()));
+
+        let flight_info = client
+            .get_db_schemas(CommandGetDbSchemas {
+                catalog: Some("default".to_string()),
+                db_schema_filter_pattern: Some("flight%".to_string()),
+            })
+            .await
+            .unwrap();
+        let batches = fetch_flight_info(&mut client, flight_info).await.unwrap();
+        assert_eq!(string_column(&batches, "db_schema_name"), vec![
+            "flight_db".to_string()
+        ]);
+
+        let flight_info = client
+            .get_tables(CommandGetTables {
+                catalog: Some("default".to_string()),
+                db_schema_filter_pattern: Some("flight_db".to_string()),
+                table_name_filter_pattern: None,
+                table_types: vec![],
+                include_schema: true,
+            })
+            .await
+            .unwrap();
+        let batches = fetch_flight_info(&mut client, flight_info).await.unwrap();
+        assert_eq!(string_column(&batches, "table_name"), vec![
+            "t1".to_string()
+        ]);
+        assert_eq!(string_column(&batches, "table_type"), vec![
+            "TABLE".to_string()
+        ]);
+
+        let flight_info = client.get_table_types().await.unwrap();
+        let batches = fetch_flight_info(&mut client, flight_info).await.unwrap();
+        assert!(string_column(&batches, "table_type").contains(&"VIEW".to_string()));
+
+        let flight_info = client
+            .get_primary_keys(CommandGetPrimaryKeys {
+                catalog: None,
+                db_schema: Some("flight_db".to_string()),
+                table: "t1".to_string(),
+            })
+            .await
+            .unwrap();
+        let batches = fetch_flight_info(&mut client, flight_info).await.unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+        let flight_info = client
+            .get_xdbc_type_info(CommandGetXdbcTypeInfo { data_type: None })
+            .await
+            .unwrap();
+        let batches = fetch_flight_info(&mut client, flight_info).await.unwrap();
+        assert!(string_column(&batches, "type_name").contains(&"VARCHAR".to_string()));
+
+        // A statement query without preparing.
+        let flight_info = client
+            .execute("select a from flight_db.t1".to_string(), None)
+            .await
+            .unwrap();
+        let batches = fetch_flight_info(&mut client, flight_info).await.unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+        // A statement can only be fetched once, by the session that planned it.
+        let flight_info = client
+            .execute("select a from flight_db.t1".to_string(), None)
+            .await
+            .unwrap();
+        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+        let mut other_client = client_with_uds(path).await;
+        other_client
+            .handshake(TEST_USER, TEST_PASSWORD)
+            .await
+            .unwrap();
+        assert!(other_client.do_get(ticket.clone()).await.is_err());
+        assert!(client.do_get(ticket.clone()).await.is_ok());
+        assert!(client.do_get(ticket).await.is_err());
+
+        run_query(&mut client, "drop database flight_db")
+            .await
+            .unwrap();
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ingest() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        run_query(&mut client, "drop table if exists ingest_t")
+            .await
+            .unwrap();
+        run_query(
+            &mut client,
+            "create table ingest_t(a int, b string, c int default 7)",
+        )
+        .await
+        .unwrap();
+
+        // The batch columns are matched by name, `c` is filled with its default value.
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("b", DataType::Utf8, false),
+            Field::new("a", DataType::Int64, false),
+        ]));
+        let batch = RecordBatch::try_new(schema, vec![
+            Arc::new(StringArray::from(vec!["x", "y", "z"])),
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+        ])
+        .unwrap();
+
+        let command = CommandStatementIngest {
+            table: "ingest_t".to_string(),
+            ..Default::default()
+        };
+        let descriptor = FlightDescriptor::new_cmd(command.as_any().encode_to_vec());
+        let flight_data = FlightDataEncoderBuilder::new()
+            .with_flight_descriptor(Some(descriptor))
+            .build(stream::iter(vec![Ok(batch.clone()), Ok(batch)]))
+            .map(|data| data.unwrap());
+        let results: Vec<PutResult> = client
+            .do_put(flight_data)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let result = Any::decode(results[0].app_metadata.clone())
+            .unwrap()
+            .unpack::<DoPutUpdateResult>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.record_count, 6);
+
+        let res = run_query(
+            &mut client,
+            "select a, b, c from ingest_t order by a, b limit 3",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            res,
+            [
+                "+---+---+---+",
+                "| a | b | c |",
+                "+---+---+---+",
+                "| 1 | x | 7 |",
+                "| 1 | x | 7 |",
+                "| 2 | y | 7 |",
+                "+---+---+---+",
+            ]
+            .join("\n")
+        );
+
+        // Unknown columns are rejected.
+        let schema = Arc::new(Schema::new(vec![Field::new("d", DataType::Int64, false)]));
+        let batch =
+            RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(vec![1]))]).unwrap();
+        let descriptor = FlightDescriptor::new_cmd(command.as_any().encode_to_vec());
+        let flight_data = FlightDataEncoderBuilder::new()
+            .with_flight_descriptor(Some(descriptor))
+            .build(stream::iter(vec![Ok(batch)]))
+            .map(|data| data.unwrap());
+        assert!(client.do_put(flight_data).await.is_err());
+
+        // Only appending is supported, replacing the table is rejected.
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let batch =
+            RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(vec![1]))]).unwrap();
+        let replace = CommandStatementIngest {
+            table_definition_options: Some(TableDefinitionOptions {
+                if_exists: TableExistsOption::Replace as i32,
+                ..Default::default()
+            }),
+            table: "ingest_t".to_string(),
+            ..Default::default()
+        };
+        let descriptor = FlightDescriptor::new_cmd(replace.as_any().encode_to_vec());
+        let flight_data = FlightDataEncoderBuilder::new()
+            .with_flight_descriptor(Some(descriptor))
+            .build(stream::iter(vec![Ok(batch)]))
+            .map(|data| data.unwrap());
+        let err = client.do_put(flight_data).await.unwrap_err();
+        assert!(err.to_string().contains("replacing"), "{err}");
+
+        let res = run_query(&mut client, "select count(*) from ingest_t")
+            .await
+            .unwrap();
+        assert!(res.contains("| 6 "), "{res}");
+
+        run_query(&mut client, "drop table ingest_t").await.unwrap();
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
@@ -247,6 +247,87 @@ async fn test_insert_format_ndjson() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_format_row_binary() -> PoemResult<()> {
+    let _fixture = TestFixture::setup().await.unwrap();
+
+    let server = Server::new().await;
+    {
+        let (status, body) = server
+            .post("create table t1(a int not null, b string null)", "")
+            .await;
+        assert_ok!(status, body);
+    }
+
+    // rows (10, 'a') and (11, NULL), the leading '\n' must be kept.
+    let rows = vec![10, 0, 0, 0, 0, 1, b'a', 11, 0, 0, 0, 1];
+    {
+        let (status, body) = server
+            .get_response(
+                QueryBuilder::new("insert into table t1 format RowBinary")
+                    .body(rows.clone())
+                    .build(),
+            )
+            .await;
+        assert_ok!(status, body);
+    }
+
+    {
+        let (status, body) = server.get(r#"select * from t1 order by a"#).await;
+        assert_ok!(status, body);
+        assert_eq!(&body, "10\ta\n11\tNULL\n");
+    }
+
+    {
+        let sql = "select * from t1 order by a format RowBinaryWithNamesAndTypes";
+        let (status, body) = server
+            .get_response_bytes(QueryBuilder::new(sql).build())
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        let header = [
+            &[2, 1, b'a', 1, b'b', 5][..],
+            b"Int32",
+            &[16],
+            b"Nullable(String)",
+        ]
+        .concat();
+        assert_eq!(body, [header, rows].concat());
+    }
+
+    // the column types sent by the client only need to be compatible.
+    {
+        let (status, body) = server
+            .post("create table t2(a string null, b timestamp)", "")
+            .await;
+        assert_ok!(status, body);
+    }
+    let data = [
+        &[2, 1, b'a', 1, b'b', 22][..],
+        b"LowCardinality(String)",
+        &[8],
+        b"DateTime",
+        &[1, b'x', 1, 0, 0, 0],
+    ]
+    .concat();
+    {
+        let (status, body) = server
+            .get_response(
+                QueryBuilder::new("insert into table t2 format RowBinaryWithNamesAndTypes")
+                    .body(data)
+                    .build(),
+            )
+            .await;
+        assert_ok!(status, body);
+    }
+    {
+        let (status, body) = server.get(r#"select * from t2"#).await;
+        assert_ok!(status, body);
+        assert_eq!(&body, "x\t1970-01-01 00:00:01.000000\n");
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_settings() -> PoemResult<()> {
     let _fixture = TestFixture::setup().await.unwrap();
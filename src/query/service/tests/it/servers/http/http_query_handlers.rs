@@ -743,6 +743,195 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_query_params() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let route = create_endpoint().await?;
+
+    let cases = vec![
+        (
+            serde_json::json!({"sql": "create table t(a int, b string) engine=fuse"}),
+            0,
+        ),
+        (
+            serde_json::json!({"sql": "insert into t values (?, ?)", "params": [1, "x'y"]}),
+            0,
+        ),
+        (
+            serde_json::json!({"sql": "insert into t(b, a) values (:b, :a)", "params": {"a": -2, "b": "z"}}),
+            0,
+        ),
+        (
+            serde_json::json!({"sql": "select a, b from t where a > ? and b <> ? order by a", "params": [-5, "?"]}),
+            2,
+        ),
+    ];
+    for (json, data_len) in cases {
+        let (status, result) = post_json_to_endpoint(&route, &json, HeaderMap::default()).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result.error);
+        assert_eq!(result.data.len(), data_len, "{:?}", result);
+        assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+        if data_len > 0 {
+            assert_eq!(result.data[0], vec!["-2", "z"], "{:?}", result);
+            assert_eq!(result.data[1], vec!["1", "x'y"], "{:?}", result);
+        }
+    }
+
+    // missing, unused or mistyped parameters
+    for (json, message) in [
+        (
+            serde_json::json!({"sql": "select ?, ?", "params": [1]}),
+            "#2",
+        ),
+        (
+            serde_json::json!({"sql": "select :a", "params": {"b": 1}}),
+            ":a",
+        ),
+        (
+            serde_json::json!({"sql": "select ?", "params": [{"a": 1}]}),
+            "#1",
+        ),
+        (serde_json::json!({"sql": "select ?"}), "query parameter ?"),
+        (
+            serde_json::json!({"sql": "select ?", "params": [1, 2]}),
+            "#2 is not used",
+        ),
+        (
+            serde_json::json!({"sql": "select :a", "params": {"a": 1, "b": 2}}),
+            ":b is not used",
+        ),
+        (
+            serde_json::json!({"sql": "insert into t values (?, ?)", "params": ["x", "y"]}),
+            "query parameter #1",
+        ),
+    ] {
+        let (_, result) = post_json_to_endpoint(&route, &json, HeaderMap::default()).await?;
+        let error = result.error.as_ref().map(|e| e.message.clone());
+        assert!(
+            error.as_ref().is_some_and(|error| error.contains(message)),
+            "{:?}",
+            result
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_result_format() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "select number, number::string from numbers(10)", "pagination": {"wait_time_secs": 5, "max_rows_per_page": 4}});
+
+    async fn send(
+        ep: &EndpointType,
+        method: Method,
+        uri: &str,
+        json: &Value,
+        accept: &str,
+    ) -> Result<Response> {
+        let req = Request::builder()
+            .uri(uri.parse().unwrap())
+            .method(method)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::ACCEPT, accept)
+            .typed_header(headers::Authorization::basic("root", ""))
+            .body(serde_json::to_vec(json)?);
+        ep.call(req)
+            .await
+            .map_err(|e| ErrorCode::Internal(e.to_string()))
+    }
+
+    // Arrow IPC stream, the pages are served in the negotiated format.
+    let accept = "application/vnd.apache.arrow.stream";
+    let mut response = send(&ep, Method::POST, "/v1/query", &json, accept).await?;
+    let mut num_rows = 0;
+    loop {
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.content_type(), Some(accept));
+        let next_uri = response
+            .headers()
+            .get("X-DATABEND-QUERY-NEXT-URI")
+            .map(|uri| uri.to_str().unwrap().to_string());
+        let page_rows: usize = response.headers()["X-DATABEND-QUERY-PAGE-ROWS"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(page_rows <= 4);
+
+        let body = response.into_body().into_vec().await.unwrap();
+        let reader = arrow_ipc::reader::StreamReader::try_new(body.as_slice(), None)?;
+        assert_eq!(reader.schema().fields().len(), 2);
+        let mut rows = 0;
+        for batch in reader {
+            rows += batch?.num_rows();
+        }
+        assert_eq!(rows, page_rows);
+        num_rows += rows;
+
+        match next_uri {
+            Some(uri) if uri.contains("/page/") => {
+                response = send(&ep, Method::GET, &uri, &json, accept).await?;
+            }
+            _ => break,
+        }
+    }
+    assert_eq!(num_rows, 10);
+
+    // Parquet
+    let accept = "application/vnd.apache.parquet";
+    let response = send(&ep, Method::POST, "/v1/query", &json, accept).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.content_type(), Some(accept));
+    let body = response.into_body().into_vec().await.unwrap();
+    assert!(body.starts_with(b"PAR1") && body.ends_with(b"PAR1"));
+
+    // The session and the stats are carried by the headers of the binary pages.
+    let accept = "application/vnd.apache.arrow.stream";
+    let json = serde_json::json!({"sql": "use system", "pagination": {"wait_time_secs": 5}});
+    let response = send(&ep, Method::POST, "/v1/query", &json, accept).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let session = response.headers()["X-DATABEND-SESSION"].to_str().unwrap();
+    let session = serde_json::from_str::<HttpSessionConf>(session)?;
+    assert_eq!(session.database, Some("system".to_string()));
+    assert!(response.headers().contains_key("X-DATABEND-SESSION-ID"));
+    assert!(response.headers().contains_key("X-DATABEND-QUERY-STATS"));
+
+    let json = serde_json::json!({"sql": "select database()", "session": session, "pagination": {"wait_time_secs": 5}});
+    let response = send(&ep, Method::POST, "/v1/query", &json, accept).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let schema = response.headers()["X-DATABEND-QUERY-SCHEMA"]
+        .to_str()
+        .unwrap();
+    let schema = serde_json::from_str::<Value>(schema)?;
+    assert_eq!(schema.as_array().map(|fields| fields.len()), Some(1));
+    let body = response.into_body().into_vec().await.unwrap();
+    let reader = arrow_ipc::reader::StreamReader::try_new(body.as_slice(), None)?;
+    let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+    let column = arrow_cast::display::array_value_to_string(batches[0].column(0), 0)?;
+    assert_eq!(column, "system");
+
+    // JSON by default, and for errors.
+    let accept = "text/html, application/json;q=0.9";
+    let response = send(&ep, Method::POST, "/v1/query", &json, accept).await?;
+    assert_eq!(
+        response.content_type(),
+        Some("application/json; charset=utf-8")
+    );
+    let json = serde_json::json!({"sql": "select * from not_exists"});
+    let accept = "application/vnd.apache.arrow.stream";
+    let response = send(&ep, Method::POST, "/v1/query", &json, accept).await?;
+    let body = response.into_body().into_string().await.unwrap();
+    let result = serde_json::from_str::<QueryResponse>(&body)?;
+    assert!(result.error.is_some(), "{:?}", result);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_query_log() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -28,11 +28,13 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use mysql_async::consts::ColumnType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
 use mysql_async::Row;
 use mysql_async::SslOpts;
+use mysql_async::Value;
 use tokio::sync::Barrier;
 
 use crate::tests::tls_constants::*;
@@ -73,6 +75,110 @@ async fn test_connect_with_tls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    let result: Option<(i64, String, Option<f64>)> = connection
+        .exec_first("SELECT ?, ?, ?", (1, "a'?b", None::<f64>))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(result, Some((1, "a'?b".to_string(), None)));
+
+    connection
+        .query_drop("CREATE TABLE t_prepared(a INT, b VARCHAR)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table")?;
+    let statement = connection
+        .prep("INSERT INTO t_prepared VALUES (?, ?)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare statement")?;
+    assert_eq!(statement.num_params(), 2);
+    let param_types = statement
+        .params()
+        .iter()
+        .map(|param| param.column_type())
+        .collect::<Vec<_>>();
+    assert_eq!(param_types, vec![
+        ColumnType::MYSQL_TYPE_LONG,
+        ColumnType::MYSQL_TYPE_VAR_STRING
+    ]);
+    for (a, b) in [(1, "x"), (2, "y")] {
+        connection
+            .exec_drop(&statement, (a, b))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    }
+    connection
+        .close(statement)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close prepared statement")?;
+
+    let statement = connection
+        .prep("SELECT a, b FROM t_prepared WHERE a > ? ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare statement")?;
+    assert_eq!(statement.columns().len(), 2);
+    let result: Vec<(i32, String)> = connection
+        .exec(&statement, (0,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(result, vec![(1, "x".to_string()), (2, "y".to_string())]);
+
+    // The parameters are values, they are never parsed as SQL.
+    let result: Vec<i32> = connection
+        .exec("SELECT a FROM t_prepared WHERE b = ?", ("x' OR '1' = '1",))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert!(result.is_empty());
+
+    // The infix `?` json operator is not a parameter.
+    let result: Option<(bool, i64)> = connection
+        .exec_first("SELECT parse_json('{\"a\":1}') ? 'a', ?", (2,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(result, Some((true, 2)));
+
+    // The zero date is bound as NULL.
+    let result: Option<bool> = connection
+        .exec_first("SELECT ? IS NULL", (Value::Date(0, 0, 0, 0, 0, 0, 0),))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(result, Some(true));
+
+    // A statement failed to describe doesn't fail the explicit transaction.
+    connection
+        .query_drop("BEGIN")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Begin transaction")?;
+    connection
+        .prep("SELECT a FROM t_prepared_not_exists WHERE a = ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare statement")?;
+    connection
+        .query_drop("INSERT INTO t_prepared VALUES (3, 'z')")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Insert in transaction")?;
+    connection
+        .query_drop("COMMIT")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Commit transaction")?;
+    let result: Vec<i32> = connection
+        .query("SELECT a FROM t_prepared ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query table")?;
+    assert_eq!(result, vec![1, 2, 3]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     // TestFixture will create a default session, so we should limit the max_active_sessions to 2.
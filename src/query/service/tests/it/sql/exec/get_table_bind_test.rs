@@ -32,8 +32,10 @@ use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
+use databend_common_catalog::table_context::QueryParams;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -86,6 +88,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -246,6 +250,13 @@ impl Catalog for FakedCatalog {
         todo!()
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        _req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        todo!()
+    }
+
     async fn count_tables(&self, _req: CountTablesReq) -> Result<CountTablesReply> {
         todo!()
     }
@@ -592,6 +603,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_query_params(&self) -> Option<QueryParams> {
+        todo!()
+    }
+
     fn get_last_query_id(&self, _index: i32) -> String {
         todo!()
     }
@@ -753,6 +768,10 @@ impl TableContext for CtxDelegation {
     fn get_runtime_filter_with_id(&self, _id: IndexType) -> Vec<Expr<String>> {
         todo!()
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -58,14 +58,22 @@ async fn apply_block_pruning(
     ctx: Arc<QueryContext>,
     op: Operator,
     bloom_index_cols: BloomIndexColumns,
+    ngram_index_cols: BloomIndexColumns,
 ) -> Result<Vec<Arc<BlockMeta>>> {
     let ctx: Arc<dyn TableContext> = ctx;
     let segment_locs = table_snapshot.segments.clone();
     let segment_locs = create_segment_location_vector(segment_locs, None);
-    FusePruner::create(&ctx, op, schema, push_down, bloom_index_cols)?
-        .read_pruning(segment_locs)
-        .await
-        .map(|v| v.into_iter().map(|(_, v)| v).collect())
+    FusePruner::create(
+        &ctx,
+        op,
+        schema,
+        push_down,
+        bloom_index_cols,
+        ngram_index_cols,
+    )?
+    .read_pruning(segment_locs)
+    .await
+    .map(|v| v.into_iter().map(|(_, v)| v).collect())
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -247,6 +255,7 @@ async fn test_block_pruner() -> Result<()> {
             ctx.clone(),
             fuse_table.get_operator(),
             fuse_table.bloom_index_cols(),
+            fuse_table.ngram_index_cols(),
         )
         .await?;
 
@@ -38,8 +38,10 @@ use databend_common_expression::TableSchema;
 use databend_common_functions::aggregates::eval_aggr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::evaluator::BlockOperator;
+use databend_common_storages_fuse::statistics::build_column_histogram;
 use databend_common_storages_fuse::statistics::reducers::reduce_block_metas;
 use databend_common_storages_fuse::statistics::Trim;
+use databend_common_storages_fuse::statistics::HISTOGRAM_BUCKETS;
 use databend_common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
 use databend_common_storages_fuse::statistics::STATS_STRING_PREFIX_LEN;
 use databend_common_storages_fuse::FuseStorageFormat;
@@ -108,6 +110,44 @@ fn test_ft_stats_block_stats_with_column_distinct_count() -> databend_common_exc
     Ok(())
 }
 
+#[test]
+fn test_build_column_histogram() -> databend_common_exception::Result<()> {
+    let int32 = |v: i32| Scalar::Number(NumberScalar::Int32(v));
+    // 1000 distinct values, and 500 occurs 100 times.
+    let values = (0..1000)
+        .chain(std::iter::repeat(500).take(99))
+        .map(int32)
+        .collect::<Vec<_>>();
+
+    // 1 of 11 sampled rows is null, and the table has 10 times the sampled rows.
+    let histogram = build_column_histogram(values, 1100, 11000, Some(2000)).unwrap();
+    assert_eq!(histogram.most_common_values, vec![(int32(500), 1000)]);
+    assert_eq!(histogram.buckets.len(), HISTOGRAM_BUCKETS);
+    assert_eq!(histogram.buckets[0].lower_bound, int32(0));
+    assert_eq!(histogram.buckets[0].num_distinct, 22);
+    assert_eq!(histogram.buckets.last().unwrap().upper_bound, int32(999));
+    let num_values = histogram.buckets.iter().map(|b| b.num_values).sum::<u64>();
+    assert_eq!(num_values, 10990);
+
+    // The common value is in a tall bucket, the other buckets are of similar heights.
+    let bucket = histogram
+        .buckets
+        .iter()
+        .find(|b| b.upper_bound == int32(500))
+        .unwrap();
+    assert_eq!(bucket.num_values, 1050);
+    assert!(
+        histogram
+            .buckets
+            .iter()
+            .filter(|b| b.upper_bound != int32(500))
+            .all(|b| (90..=110).contains(&b.num_values))
+    );
+
+    assert!(build_column_histogram(vec![], 10, 10, None).is_none());
+    Ok(())
+}
+
 #[test]
 fn test_ft_tuple_stats_block_stats() -> databend_common_exception::Result<()> {
     let schema = Arc::new(TableSchema::new(vec![TableField::new(
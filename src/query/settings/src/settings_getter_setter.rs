@@ -229,6 +229,10 @@ impl Settings {
         self.try_get_u64("max_inlist_to_or")
     }
 
+    pub fn get_max_cte_recursive_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_cte_recursive_depth")
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         Ok(self.try_get_u64("unquoted_ident_case_sensitive")? != 0)
     }
@@ -338,6 +342,14 @@ impl Settings {
         Ok(self.try_get_u64("sort_spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_window_partition_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_partition_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn get_window_partition_spilling_memory_ratio(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_partition_spilling_memory_ratio")? as usize)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -427,6 +439,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_vector_index_scan(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_vector_index_scan")? != 0)
+    }
+
+    pub fn get_vector_index_nprobe(&self) -> Result<u64> {
+        self.try_get_u64("vector_index_nprobe")
+    }
+
     // Deprecated in the future, use enable_compact_after_write instead.
     pub fn get_enable_recluster_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_recluster_after_write")? != 0)
@@ -44,6 +44,8 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::RangeJoinType;
 use crate::executor::physical_plans::ReclusterSink;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
 use crate::executor::physical_plans::TableScan;
@@ -223,6 +225,8 @@ fn to_format_tree(
         PhysicalPlan::MaterializedCte(plan) => {
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
+        PhysicalPlan::RecursiveCte(plan) => recursive_cte_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan, metadata),
         PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
     }
 }
@@ -357,6 +361,18 @@ fn table_scan_to_format_tree(
         };
         children.push(FormatTreeNode::new(text));
     }
+    // Vector index
+    if let Some(vector_index) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_index.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "vector index: [{}({}), limit: {}]",
+            vector_index.func_name, vector_index.column_name, vector_index.limit
+        )));
+    }
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -381,6 +397,29 @@ fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
     ]))
 }
 
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &Metadata,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, false)
+        )),
+        FormatTreeNode::new(format!("CTE index: {}", plan.cte_idx)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCTEScan".to_string(),
+        children,
+    ))
+}
+
 fn constant_table_scan_to_format_tree(
     plan: &ConstantTableScan,
     metadata: &Metadata,
@@ -1175,6 +1214,36 @@ fn materialized_cte_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("CTE index: {}", plan.cte_idx)),
+        FormatTreeNode::new(format!("distinct: {}", plan.distinct)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(vec![
+        to_format_tree(&plan.anchor, metadata, prof_span_set)?,
+        to_format_tree(&plan.recursive, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCTE".to_string(),
+        children,
+    ))
+}
+
 fn format_output_columns(
     output_schema: DataSchemaRef,
     metadata: &Metadata,
@@ -44,6 +44,8 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -77,6 +79,8 @@ pub enum PhysicalPlan {
     UnionAll(UnionAll),
     CteScan(CteScan),
     MaterializedCte(MaterializedCte),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
 
@@ -143,6 +147,8 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSink(v) => v.plan_id,
             PhysicalPlan::CteScan(v) => v.plan_id,
             PhysicalPlan::MaterializedCte(v) => v.plan_id,
+            PhysicalPlan::RecursiveCte(v) => v.plan_id,
+            PhysicalPlan::RecursiveCteScan(v) => v.plan_id,
             PhysicalPlan::ConstantTableScan(v) => v.plan_id,
             PhysicalPlan::Udf(v) => v.plan_id,
             PhysicalPlan::DeleteSource(_)
@@ -185,6 +191,8 @@ impl PhysicalPlan {
             PhysicalPlan::CopyIntoTable(plan) => plan.output_schema(),
             PhysicalPlan::CteScan(plan) => plan.output_schema(),
             PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
             PhysicalPlan::ConstantTableScan(plan) => plan.output_schema(),
             PhysicalPlan::Udf(plan) => plan.output_schema(),
             PhysicalPlan::MergeIntoSource(plan) => plan.input.output_schema(),
@@ -237,6 +245,8 @@ impl PhysicalPlan {
             PhysicalPlan::MergeIntoAppendNotMatched(_) => "MergeIntoAppendNotMatched".to_string(),
             PhysicalPlan::CteScan(_) => "PhysicalCteScan".to_string(),
             PhysicalPlan::MaterializedCte(_) => "PhysicalMaterializedCte".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "PhysicalRecursiveCte".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "PhysicalRecursiveCteScan".to_string(),
             PhysicalPlan::ConstantTableScan(_) => "PhysicalConstantTableScan".to_string(),
             PhysicalPlan::MergeIntoAddRowNumber(_) => "AddRowNumber".to_string(),
             PhysicalPlan::ReclusterSource(_) => "ReclusterSource".to_string(),
@@ -250,6 +260,7 @@ impl PhysicalPlan {
         match self {
             PhysicalPlan::TableScan(_)
             | PhysicalPlan::CteScan(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ConstantTableScan(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::CompactSource(_)
@@ -299,6 +310,10 @@ impl PhysicalPlan {
             PhysicalPlan::MaterializedCte(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
             PhysicalPlan::ReclusterSink(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Udf(plan) => Box::new(std::iter::once(plan.input.as_ref())),
         }
@@ -325,6 +340,8 @@ impl PhysicalPlan {
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::RangeJoin(_)
             | PhysicalPlan::MaterializedCte(_)
+            | PhysicalPlan::RecursiveCte(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
             | PhysicalPlan::AggregatePartial(_)
@@ -375,6 +392,8 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSource(plan) => plan.table_index,
             PhysicalPlan::DistributedInsertSelect(plan) => plan.input.get_table_index(),
             PhysicalPlan::MaterializedCte(_) |
+            PhysicalPlan::RecursiveCte(_) |
+            PhysicalPlan::RecursiveCteScan(_) |
             // Todo: support union and range join return valid table index by join probe keys
             PhysicalPlan::UnionAll(_) |
             PhysicalPlan::RangeJoin(_)|
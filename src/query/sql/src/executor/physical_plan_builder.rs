@@ -38,6 +38,8 @@ pub struct PhysicalPlanBuilder {
     pub(crate) dry_run: bool,
     // Record cte_idx and the cte's output columns
     pub(crate) cte_output_columns: HashMap<IndexType, Vec<ColumnBinding>>,
+    // Record the (cte_idx, plan_id) of the recursive ctes being built
+    pub(crate) recursive_ctes: Vec<(IndexType, u32)>,
 }
 
 impl PhysicalPlanBuilder {
@@ -50,6 +52,7 @@ impl PhysicalPlanBuilder {
             func_ctx,
             dry_run,
             cte_output_columns: Default::default(),
+            recursive_ctes: vec![],
         }
     }
 
@@ -107,6 +110,12 @@ impl PhysicalPlanBuilder {
             RelOperator::MaterializedCte(cte) => {
                 self.build_materialized_cte(s_expr, cte, required).await
             }
+            RelOperator::RecursiveCte(cte) => {
+                self.build_recursive_cte(s_expr, cte, stat_info).await
+            }
+            RelOperator::RecursiveCteScan(scan) => {
+                self.build_recursive_cte_scan(scan, stat_info).await
+            }
             RelOperator::ConstantTableScan(scan) => {
                 self.build_constant_table_scan(scan, required).await
             }
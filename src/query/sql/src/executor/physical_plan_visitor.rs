@@ -44,6 +44,8 @@ use crate::executor::physical_plans::QuerySource;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -92,6 +94,8 @@ pub trait PhysicalPlanReplacer {
                 self.replace_merge_into_row_id_apply(plan)
             }
             PhysicalPlan::MaterializedCte(plan) => self.replace_materialized_cte(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
             PhysicalPlan::ConstantTableScan(plan) => self.replace_constant_table_scan(plan),
             PhysicalPlan::ReclusterSource(plan) => self.replace_recluster_source(plan),
             PhysicalPlan::ReclusterSink(plan) => self.replace_recluster_sink(plan),
@@ -124,6 +128,10 @@ pub trait PhysicalPlanReplacer {
         Ok(PhysicalPlan::ConstantTableScan(plan.clone()))
     }
 
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_filter(&mut self, plan: &Filter) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -251,6 +259,17 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let anchor = self.replace(&plan.anchor)?;
+        let recursive = self.replace(&plan.recursive)?;
+
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_range_join(&mut self, plan: &RangeJoin) -> Result<PhysicalPlan> {
         let left = self.replace(&plan.left)?;
         let right = self.replace(&plan.right)?;
@@ -507,6 +526,7 @@ impl PhysicalPlan {
                 PhysicalPlan::TableScan(_)
                 | PhysicalPlan::ReplaceAsyncSourcer(_)
                 | PhysicalPlan::CteScan(_)
+                | PhysicalPlan::RecursiveCteScan(_)
                 | PhysicalPlan::ConstantTableScan(_)
                 | PhysicalPlan::ReclusterSource(_)
                 | PhysicalPlan::ExchangeSource(_)
@@ -601,6 +621,10 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.anchor, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.recursive, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Udf(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
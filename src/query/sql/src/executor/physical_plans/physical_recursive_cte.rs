@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub anchor: Box<PhysicalPlan>,
+    pub recursive: Box<PhysicalPlan>,
+    pub cte_idx: IndexType,
+    pub distinct: bool,
+    // Pairs of (anchor column, recursive column)
+    pub pairs: Vec<(String, String)>,
+    pub schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte(
+        &mut self,
+        s_expr: &SExpr,
+        recursive_cte: &crate::plans::RecursiveCte,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        // Every column may be referenced by the next iteration, so none of them can be pruned.
+        let anchor_required = recursive_cte
+            .pairs
+            .iter()
+            .map(|(left, _)| *left)
+            .collect::<ColumnSet>();
+        let recursive_required = recursive_cte
+            .pairs
+            .iter()
+            .map(|(_, right)| *right)
+            .collect::<ColumnSet>();
+
+        // 2. Build physical plan.
+        // The working table is identified by the plan id of the recursive cte, so the scans
+        // in the recursive term need to know it before the plan is built.
+        let plan_id = self.next_plan_id();
+        let anchor = self.build(s_expr.child(0)?, anchor_required).await?;
+        self.recursive_ctes.push((recursive_cte.cte_idx, plan_id));
+        let recursive = self.build(s_expr.child(1)?, recursive_required).await;
+        self.recursive_ctes.pop();
+        let recursive = recursive?;
+        let anchor_schema = anchor.output_schema()?;
+        let recursive_schema = recursive.output_schema()?;
+
+        let mut fields = Vec::with_capacity(recursive_cte.pairs.len());
+        let mut pairs = Vec::with_capacity(recursive_cte.pairs.len());
+        for (left, right) in recursive_cte.pairs.iter() {
+            let (left, right) = (left.to_string(), right.to_string());
+            let left_field = anchor_schema.field_with_name(&left)?;
+            let right_field = recursive_schema.field_with_name(&right)?;
+            // The binder has coerced both terms to the type of the working table.
+            if left_field.data_type() != right_field.data_type() {
+                return Err(ErrorCode::Internal(format!(
+                    "Recursive cte's types mismatch, anchor column {:?}, type: {:?}, recursive column {:?}, type: {:?}",
+                    left,
+                    left_field.data_type(),
+                    right,
+                    right_field.data_type()
+                )));
+            }
+            fields.push(left_field.clone());
+            pairs.push((left, right));
+        }
+
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id,
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            cte_idx: recursive_cte.cte_idx,
+            distinct: recursive_cte.distinct,
+            pairs,
+            schema: DataSchemaRefExt::create(fields),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub cte_idx: IndexType,
+    // The `plan_id` of the `RecursiveCte` which owns the working table.
+    pub recursive_cte_id: u32,
+    pub output_schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte_scan(
+        &mut self,
+        scan: &crate::plans::RecursiveCteScan,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let recursive_cte_id = self
+            .recursive_ctes
+            .iter()
+            .rev()
+            .find(|(cte_idx, _)| *cte_idx == scan.cte_idx)
+            .map(|(_, plan_id)| *plan_id)
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Recursive cte scan {} is not inside its recursive cte",
+                    scan.cte_idx
+                ))
+            })?;
+        // The working table always holds all the columns of the recursive cte,
+        // so the columns are not pruned here.
+        Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+            plan_id: self.next_plan_id(),
+            cte_idx: scan.cte_idx,
+            recursive_cte_id,
+            output_schema: DataSchemaRefExt::create(scan.fields.clone()),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
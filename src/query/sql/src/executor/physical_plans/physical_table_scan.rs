@@ -25,17 +25,24 @@ use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::plan::PrewhereInfo;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::VectorIndexInfo;
 use databend_common_catalog::plan::VirtualColumnInfo;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
 use databend_common_expression::type_check::check_function;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Expr;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::ROW_ID_COL_NAME;
@@ -473,6 +480,13 @@ impl PhysicalPlanBuilder {
 
         let virtual_columns = self.build_virtual_columns(&scan.columns);
 
+        let vector_index = scan
+            .vector_index
+            .as_ref()
+            .map(|vector_index| self.build_vector_index(&metadata, vector_index))
+            .transpose()?
+            .flatten();
+
         Ok(PushDownInfo {
             projection: Some(projection),
             output_columns,
@@ -484,9 +498,42 @@ impl PhysicalPlanBuilder {
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
+            vector_index,
         })
     }
 
+    // Returns `None` if the target can't be folded to a constant vector.
+    fn build_vector_index(
+        &self,
+        metadata: &Metadata,
+        vector_index: &crate::plans::VectorIndexInfo,
+    ) -> Result<Option<VectorIndexInfo>> {
+        let target_type = DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)));
+        let target = check_cast(
+            None,
+            false,
+            vector_index.target.as_expr()?,
+            &target_type,
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let (target, _) = ConstantFolder::fold(&target, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let target = match target {
+            Expr::Constant {
+                scalar: Scalar::Array(Column::Number(NumberColumn::Float32(target))),
+                ..
+            } => target.to_vec(),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(VectorIndexInfo {
+            index_id: vector_index.index_id,
+            column_name: metadata.column(vector_index.column).name(),
+            func_name: vector_index.func_name.clone(),
+            target,
+            limit: vector_index.limit,
+        }))
+    }
+
     fn build_virtual_columns(&self, indices: &ColumnSet) -> Option<Vec<VirtualColumnInfo>> {
         let mut column_and_indices = Vec::new();
         for index in indices.iter() {
@@ -487,6 +487,29 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::RecursiveCte(plan) => {
+            flatten_plan_node_profile(metadata, &plan.anchor, profs, plan_node_profs)?;
+            flatten_plan_node_profile(metadata, &plan.recursive, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&plan.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: plan.plan_id,
+                operator_type: OperatorType::RecursiveCte,
+                execution_info: proc_prof.into(),
+                children: vec![plan.anchor.get_id(), plan.recursive.get_id()],
+                attribute: OperatorAttribute::Empty,
+            };
+            plan_node_profs.push(prof);
+        }
+        PhysicalPlan::RecursiveCteScan(scan) => {
+            let prof = OperatorProfile {
+                id: scan.plan_id,
+                operator_type: OperatorType::RecursiveCteScan,
+                children: vec![],
+                execution_info: Default::default(),
+                attribute: OperatorAttribute::Empty,
+            };
+            plan_node_profs.push(prof)
+        }
         PhysicalPlan::MaterializedCte(_) => todo!(),
         PhysicalPlan::DeleteSource(_)
         | PhysicalPlan::CommitSink(_)
@@ -41,8 +41,10 @@ use super::Finder;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
+use crate::binder::StreamChangeSide;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
+use crate::optimizer::StatInfo;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::DescConnectionPlan;
@@ -94,6 +96,22 @@ pub struct Binder {
     /// Use `IndexMap` because need to keep the insertion order
     /// Then wrap materialized ctes to main plan.
     pub ctes_map: Box<IndexMap<String, CteInfo>>,
+    /// The working tables of the recursive ctes whose recursive term is being bound,
+    /// the key is the cte name.
+    pub recursive_cte_working_tables: HashMap<String, RecursiveCteWorkingTable>,
+    /// The sides of the changes of the standard stream whose changes query is being bound,
+    /// the key is the name by which the changes query reads the side.
+    pub stream_change_sides: HashMap<String, StreamChangeSide>,
+}
+
+/// The working table of a recursive cte, which holds the rows produced by the last iteration.
+#[derive(Clone, Debug)]
+pub struct RecursiveCteWorkingTable {
+    pub cte_idx: IndexType,
+    pub columns: Vec<(String, DataType)>,
+    pub stat_info: Arc<StatInfo>,
+    // The working table can be referenced only once in the recursive term
+    pub referenced: bool,
 }
 
 impl<'a> Binder {
@@ -114,6 +132,8 @@ impl<'a> Binder {
             eq_scalars: vec![],
             m_cte_bound_s_expr: Default::default(),
             ctes_map: Box::default(),
+            recursive_cte_working_tables: HashMap::new(),
+            stream_change_sides: HashMap::new(),
         }
     }
 
@@ -315,6 +335,7 @@ impl<'a> Binder {
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::RefreshNgramIndex(stmt) => self.bind_refresh_ngram_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -542,6 +563,15 @@ impl<'a> Binder {
             Statement::DescDatamaskPolicy(stmt) => {
                 self.bind_desc_data_mask_policy(stmt).await?
             }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
             Statement::CreateNetworkPolicy(stmt) => {
                 self.bind_create_network_policy(stmt).await?
             }
@@ -570,6 +600,21 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreateWorkloadGroup(stmt) => {
+                self.bind_create_workload_group(stmt).await?
+            }
+            Statement::AlterWorkloadGroup(stmt) => {
+                self.bind_alter_workload_group(stmt).await?
+            }
+            Statement::DropWorkloadGroup(stmt) => {
+                self.bind_drop_workload_group(stmt).await?
+            }
+            Statement::DescWorkloadGroup(stmt) => {
+                self.bind_desc_workload_group(stmt).await?
+            }
+            Statement::ShowWorkloadGroups => {
+                self.bind_show_workload_groups().await?
+            }
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -595,18 +640,16 @@ impl<'a> Binder {
             Statement::ShowStreams(stmt) => self.bind_show_streams(bind_context, stmt).await?,
             Statement::DescribeStream(stmt) => self.bind_describe_stream(bind_context, stmt).await?,
 
-            Statement::CreatePipe(_) => {
-                todo!()
-            }
-            Statement::DescribePipe(_) => {
-                todo!()
-            }
-            Statement::AlterPipe(_) => {
-                todo!()
-            }
-            Statement::DropPipe(_) => {
-                todo!()
-            }
+            // Pipes
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(bind_context, stmt).await?,
+            Statement::DescribePipe(stmt) => self.bind_describe_pipe(stmt).await?,
+            Statement::AlterPipe(stmt) => self.bind_alter_pipe(stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
+
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
         };
         Ok(plan)
     }
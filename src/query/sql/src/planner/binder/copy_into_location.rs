@@ -12,19 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_ast::ast::CopyIntoLocationSource;
 use databend_common_ast::ast::CopyIntoLocationStmt;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
 use databend_common_meta_app::principal::StageInfo;
 
 use crate::binder::copy_into_table::resolve_file_location;
+use crate::binder::wrap_cast;
 use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::optimizer::SExpr;
 use crate::plans::CopyIntoLocationPlan;
+use crate::plans::EvalScalar;
 use crate::plans::Plan;
+use crate::plans::ScalarItem;
 use crate::BindContext;
 
 impl<'a> Binder {
@@ -34,6 +43,12 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         stmt: &CopyIntoLocationStmt,
     ) -> Result<Plan> {
+        if stmt.partition_by.is_some() && stmt.single {
+            return Err(ErrorCode::SemanticError(
+                "COPY INTO <location> with PARTITION BY can not be used with SINGLE = TRUE",
+            ));
+        }
+
         let query = match &stmt.src {
             CopyIntoLocationSource::Table(table) => {
                 let (catalog_name, database_name, table_name) = self
@@ -43,35 +58,27 @@ impl<'a> Binder {
                         &table.table,
                     );
                 let subquery = format!("SELECT * FROM {catalog_name}.{database_name}.{table_name}");
-                let tokens = tokenize_sql(&subquery)?;
-                let sub_stmt_msg = parse_sql(&tokens, self.dialect)?;
-                let sub_stmt = sub_stmt_msg.0;
-                match &sub_stmt {
-                    Statement::Query(query) => {
-                        self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                            .await
-                    }
-                    _ => {
-                        return Err(ErrorCode::SyntaxException(
-                            "COPY INTO <location> FROM <non-query> is invalid",
-                        ));
-                    }
-                }
+                self.bind_copy_into_location_subquery(bind_context, &subquery)
+                    .await
             }
             CopyIntoLocationSource::Query(query) => {
                 let select_plan = self
                     .bind_statement(bind_context, &Statement::Query(query.clone()))
                     .await?;
-                if let Plan::Query { s_expr, .. } = &select_plan {
-                    if !self.check_sexpr_top(s_expr)? {
-                        return Err(ErrorCode::SemanticError(
-                            "copy into location source can't contain udf functions".to_string(),
-                        ));
-                    }
-                }
+                self.check_copy_into_location_source(&select_plan)?;
                 Ok(select_plan)
             }
         }?;
+        let query = match &stmt.partition_by {
+            Some(partition_by) => {
+                let select_plan = self
+                    .bind_copy_into_location_partition(query, partition_by)
+                    .await?;
+                self.check_copy_into_location_source(&select_plan)?;
+                select_plan
+            }
+            None => query,
+        };
 
         let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), &stmt.dst).await?;
         self.apply_copy_into_location_options(stmt, &mut stage_info)
@@ -81,9 +88,102 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             from: Box::new(query),
+            partition_by: stmt.partition_by.as_ref().map(|expr| expr.to_string()),
         }))
     }
 
+    #[async_backtrace::framed]
+    async fn bind_copy_into_location_subquery(
+        &mut self,
+        bind_context: &mut BindContext,
+        subquery: &str,
+    ) -> Result<Plan> {
+        let tokens = tokenize_sql(subquery)?;
+        let sub_stmt_msg = parse_sql(&tokens, self.dialect)?;
+        let sub_stmt = sub_stmt_msg.0;
+        match &sub_stmt {
+            Statement::Query(query) => {
+                self.bind_statement(bind_context, &Statement::Query(query.clone()))
+                    .await
+            }
+            _ => Err(ErrorCode::SyntaxException(
+                "COPY INTO <location> FROM <non-query> is invalid",
+            )),
+        }
+    }
+
+    /// The partition path is evaluated as an extra column appended to the source,
+    /// the stage table splits the rows by it and removes it before writing.
+    #[async_backtrace::framed]
+    async fn bind_copy_into_location_partition(
+        &mut self,
+        query: Plan,
+        partition_by: &Expr,
+    ) -> Result<Plan> {
+        let Plan::Query {
+            s_expr,
+            metadata,
+            mut bind_context,
+            rewrite_kind,
+            ignore_result,
+            formatted_ast,
+        } = query
+        else {
+            return Err(ErrorCode::Internal(
+                "The source of COPY INTO <location> must be a query",
+            ));
+        };
+
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (scalar, _) = scalar_binder.bind(partition_by).await?;
+        let data_type = DataType::Nullable(Box::new(DataType::String));
+        let scalar = wrap_cast(&scalar, &data_type);
+
+        let column_binding =
+            self.create_derived_column_binding(partition_by.to_string(), data_type);
+        let s_expr = SExpr::create_unary(
+            Arc::new(
+                EvalScalar {
+                    items: vec![ScalarItem {
+                        scalar,
+                        index: column_binding.index,
+                    }],
+                }
+                .into(),
+            ),
+            Arc::new(*s_expr),
+        );
+        bind_context.add_column_binding(column_binding);
+
+        Ok(Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata,
+            bind_context,
+            rewrite_kind,
+            ignore_result,
+            formatted_ast,
+        })
+    }
+
+    fn check_copy_into_location_source(&self, plan: &Plan) -> Result<()> {
+        if let Plan::Query { s_expr, .. } = plan {
+            if !self.check_sexpr_top(s_expr)? {
+                return Err(ErrorCode::SemanticError(
+                    "copy into location source can't contain udf functions".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub async fn apply_copy_into_location_options(
         &mut self,
@@ -47,6 +47,7 @@ use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_storage::StageFilesInfo;
 use databend_common_users::UserApiProvider;
@@ -82,6 +83,11 @@ impl<'a> Binder {
                     .await
             }
             CopyIntoTableSource::Query(query) => {
+                if !stmt.validation_mode.is_empty() {
+                    return Err(ErrorCode::SemanticError(
+                        "VALIDATION_MODE is not supported for COPY with transformation",
+                    ));
+                }
                 let mut max_column_position = MaxColumnPosition::new();
                 max_column_position.visit_query(query.as_ref());
                 self.metadata
@@ -122,6 +128,17 @@ impl<'a> Binder {
         let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
         self.apply_copy_into_table_options(stmt, &mut stage_info)
             .await?;
+        match validation_mode {
+            ValidationMode::None => {}
+            // Fails at the first error in the rows.
+            ValidationMode::ReturnNRows(_) => {
+                stage_info.copy_options.on_error = OnErrorMode::AbortNum(1);
+            }
+            // Goes through the whole files to collect all the rejected rows.
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                stage_info.copy_options.on_error = OnErrorMode::Continue;
+            }
+        }
         let files_info = StageFilesInfo {
             path,
             files: stmt.files.clone(),
@@ -154,6 +171,7 @@ impl<'a> Binder {
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                is_partitioned: false,
                 default_values: Some(default_values),
             },
             values_consts: vec![],
@@ -173,10 +191,16 @@ impl<'a> Binder {
         bind_ctx: &BindContext,
         plan: CopyIntoTablePlan,
     ) -> Result<Plan> {
+        // files with schema are copied by querying the columns with the same names
         if matches!(
             plan.stage_table_info.stage_info.file_format_params,
-            FileFormatParams::Parquet(_)
+            FileFormatParams::Parquet(_) | FileFormatParams::Avro(_) | FileFormatParams::Orc(_)
         ) {
+            if plan.validation_mode != ValidationMode::None {
+                return Err(ErrorCode::SemanticError(
+                    "VALIDATION_MODE is not supported for parquet, avro and orc files",
+                ));
+            }
             let select_list = plan
                 .required_source_schema
                 .fields()
@@ -287,6 +311,7 @@ impl<'a> Binder {
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                is_partitioned: false,
                 default_values: Some(default_values),
             },
             write_mode,
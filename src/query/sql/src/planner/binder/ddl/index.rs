@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::CreateIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
 use databend_common_ast::ast::ExplainKind;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
+use databend_common_ast::ast::RefreshNgramIndexStmt;
+use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableIndexType;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
@@ -28,11 +33,14 @@ use databend_common_ast::Visitor;
 use databend_common_ast::VisitorMut;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
 use databend_common_license::license::Feature::AggregateIndex;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::IndexType;
 use databend_storages_common_table_meta::meta::Location;
 
 use crate::binder::Binder;
@@ -42,6 +50,7 @@ use crate::plans::CreateIndexPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshNgramIndexPlan;
 use crate::AggregatingIndexChecker;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
@@ -87,6 +96,45 @@ impl Binder {
 
         for table_entry in tables {
             let table = table_entry.table();
+            if self.ctx.get_settings().get_enable_vector_index_scan()?
+                && !bind_context.planning_agg_index
+                && table.support_index()
+                && !matches!(table.engine(), "VIEW" | "STREAM")
+            {
+                let indexes = self
+                    .resolve_table_indexes(
+                        self.ctx.get_tenant().as_str(),
+                        table_entry.catalog(),
+                        table.get_id(),
+                    )
+                    .await?;
+
+                let mut vector_indexes = vec![];
+                for (index_id, _, index_meta) in indexes {
+                    if index_meta.index_type != IndexType::VECTOR {
+                        continue;
+                    }
+                    let tokens = tokenize_sql(&index_meta.query)?;
+                    let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                    if let Statement::Query(query) = &stmt {
+                        let column = Self::vector_index_column(query)?;
+                        vector_indexes.push((index_id, self.normalize_object_identifier(column)));
+                    }
+                }
+
+                if !vector_indexes.is_empty() {
+                    let full_table_name = format!(
+                        "{}.{}.{}",
+                        table_entry.catalog(),
+                        table_entry.database(),
+                        table_entry.name()
+                    );
+                    metadata
+                        .write()
+                        .add_vector_indexes(full_table_name, vector_indexes);
+                }
+            }
+
             // Avoid death loop
             let mut agg_indexes = vec![];
             if self.ctx.get_can_scan_from_agg_index()
@@ -114,6 +162,9 @@ impl Binder {
 
                     let mut s_exprs = Vec::with_capacity(indexes.len());
                     for (index_id, _, index_meta) in indexes {
+                        if index_meta.index_type != IndexType::AGGREGATING {
+                            continue;
+                        }
                         let tokens = tokenize_sql(&index_meta.query)?;
                         let (stmt, _) = parse_sql(&tokens, self.dialect)?;
                         let mut new_bind_context =
@@ -154,6 +205,10 @@ impl Binder {
             sync_creation,
         } = stmt;
 
+        if *index_type == TableIndexType::Vector {
+            return self.bind_create_vector_index(bind_context, stmt).await;
+        }
+
         // check if query support index
         {
             let mut agg_index_checker = AggregatingIndexChecker::default();
@@ -217,14 +272,122 @@ impl Binder {
         Ok(Plan::CreateIndex(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    async fn bind_create_vector_index(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CreateIndexStmt,
+    ) -> Result<Plan> {
+        let CreateIndexStmt {
+            index_type,
+            if_not_exists,
+            index_name,
+            query,
+            sync_creation,
+        } = stmt;
+
+        let column = Self::vector_index_column(query)?;
+        let column_name = self.normalize_object_identifier(column);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        bind_context.planning_agg_index = true;
+        self.bind_query(bind_context, query).await?;
+        bind_context.planning_agg_index = false;
+
+        let tables = self.metadata.read().tables().to_vec();
+
+        if tables.len() != 1 {
+            return Err(ErrorCode::UnsupportedIndex(
+                "Create Index currently only support single table",
+            ));
+        }
+
+        let table_entry = &tables[0];
+        let table = table_entry.table();
+
+        if !table.support_index() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support create index",
+                table.engine()
+            )));
+        }
+
+        let schema = table.schema();
+        let field = schema.field_with_name(&column_name)?;
+        let vector_type =
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32)));
+        if field.data_type().remove_nullable() != vector_type {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index only support column of type {}, but column {} is {}",
+                vector_type,
+                column_name,
+                field.data_type()
+            )));
+        }
+
+        let mut query = query.clone();
+        Self::rewrite_query_with_database(&mut query, table_entry.database());
+
+        let plan = CreateIndexPlan {
+            if_not_exists: *if_not_exists,
+            index_type: *index_type,
+            index_name,
+            original_query: stmt.query.to_string(),
+            query: query.to_string(),
+            table_id: table.get_id(),
+            sync_creation: *sync_creation,
+        };
+        Ok(Plan::CreateIndex(Box::new(plan)))
+    }
+
+    /// A vector index is defined by `SELECT <column> FROM <table>`, returns the column.
+    fn vector_index_column(query: &Query) -> Result<&Identifier> {
+        if query.with.is_none()
+            && query.order_by.is_empty()
+            && query.limit.is_empty()
+            && query.offset.is_none()
+        {
+            if let SetExpr::Select(stmt) = &query.body {
+                if !stmt.distinct
+                    && stmt.from.len() == 1
+                    && matches!(stmt.from[0], TableReference::Table { .. })
+                    && stmt.selection.is_none()
+                    && stmt.group_by.is_none()
+                    && stmt.having.is_none()
+                    && stmt.window_list.is_none()
+                    && stmt.qualify.is_none()
+                    && stmt.select_list.len() == 1
+                {
+                    if let SelectTarget::AliasedExpr { expr, .. } = &stmt.select_list[0] {
+                        if let Expr::ColumnRef {
+                            column: ColumnID::Name(column),
+                            ..
+                        } = expr.as_ref()
+                        {
+                            return Ok(column);
+                        }
+                    }
+                }
+            }
+        }
+        Err(ErrorCode::UnsupportedIndex(
+            "Currently create vector index just support simple query, like: SELECT <column> FROM <table>",
+        ))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_index(
         &mut self,
         stmt: &DropIndexStmt,
     ) -> Result<Plan> {
-        let DropIndexStmt { if_exists, index } = stmt;
+        let DropIndexStmt {
+            index_type,
+            if_exists,
+            index,
+        } = stmt;
 
         let plan = DropIndexPlan {
+            index_type: *index_type,
             if_exists: *if_exists,
             index: index.to_string(),
         };
@@ -237,7 +400,11 @@ impl Binder {
         bind_context: &mut BindContext,
         stmt: &RefreshIndexStmt,
     ) -> Result<Plan> {
-        let RefreshIndexStmt { index, limit } = stmt;
+        let RefreshIndexStmt {
+            index_type,
+            index,
+            limit,
+        } = stmt;
 
         if limit.is_some() && limit.unwrap() < 1 {
             return Err(ErrorCode::RefreshIndexError(format!(
@@ -263,6 +430,17 @@ impl Binder {
         let index_id = res.index_id;
         let index_meta = res.index_meta;
 
+        let expected_type = match index_type {
+            TableIndexType::Aggregating => IndexType::AGGREGATING,
+            TableIndexType::Vector => IndexType::VECTOR,
+        };
+        if index_meta.index_type != expected_type {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Index {} is not a {} index",
+                index_name, index_type
+            )));
+        }
+
         let plan = self
             .build_refresh_index_plan(bind_context, index_id, index_name, index_meta, *limit, None)
             .await?;
@@ -333,6 +511,34 @@ impl Binder {
         Ok(plan)
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_ngram_index(
+        &mut self,
+        stmt: &RefreshNgramIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshNgramIndexStmt {
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Ngram index only support FUSE engine",
+            ));
+        }
+
+        Ok(Plan::RefreshNgramIndex(Box::new(RefreshNgramIndexPlan {
+            catalog,
+            database,
+            table,
+        })))
+    }
+
     fn rewrite_query_with_database(query: &mut Query, name: &str) {
         if let SetExpr::Select(stmt) = &mut query.body {
             if let TableReference::Table { database, .. } = &mut stmt.from[0] {
@@ -21,7 +21,9 @@ mod database;
 mod index;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod role;
+mod row_access_policy;
 mod share;
 mod stage;
 mod stream;
@@ -29,3 +31,4 @@ mod table;
 mod task;
 mod view;
 mod virtual_column;
+mod workload_group;
@@ -0,0 +1,144 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::AlterPipeStmt;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CreatePipeStmt;
+use databend_common_ast::ast::DescribePipeStmt;
+use databend_common_ast::ast::DropPipeStmt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescribePipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+use crate::BindContext;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        // There is no notification from the storage to trigger a load,
+        // new files are only loaded by `ALTER PIPE ... REFRESH`.
+        if *auto_ingest {
+            return Err(ErrorCode::Unimplemented(
+                "AUTO_INGEST = TRUE is not supported, use `ALTER PIPE <name> REFRESH`".to_string(),
+            ));
+        }
+
+        // A pipe loads new files by listing its source location,
+        // which is not possible with a transforming query as the source.
+        if let CopyIntoTableSource::Query(_) = &copy_stmt.src {
+            return Err(ErrorCode::SemanticError(
+                "pipe only supports `COPY INTO <table> FROM <location>`".to_string(),
+            ));
+        }
+
+        // Bind the copy statement to make sure the table and the stage exist,
+        // the statement itself is stored and planned again on every refresh.
+        self.bind_copy_into_table(bind_context, copy_stmt).await?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            pipe_name: name.to_string(),
+            auto_ingest: *auto_ingest,
+            comment: comments.clone(),
+            copy_stmt: copy_stmt.to_string(),
+        };
+        Ok(Plan::CreatePipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        if let AlterPipeOptions::Set {
+            execution_paused,
+            comments,
+        } = options
+        {
+            if execution_paused.is_none() && comments.is_none() {
+                return Err(ErrorCode::SyntaxException(
+                    "alter pipe must set at least one option".to_string(),
+                ));
+            }
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterPipePlan {
+            if_exists: *if_exists,
+            tenant,
+            pipe_name: name.to_string(),
+            alter_options: options.clone(),
+        };
+        Ok(Plan::AlterPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+
+        let plan = DropPipePlan {
+            if_exists: *if_exists,
+            tenant,
+            pipe_name: name.to_string(),
+        };
+        Ok(Plan::DropPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_describe_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        let DescribePipeStmt { name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+
+        let plan = DescribePipePlan {
+            tenant,
+            pipe_name: name.to_string(),
+        };
+        Ok(Plan::DescribePipe(Box::new(plan)))
+    }
+}
@@ -98,6 +98,7 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
@@ -105,6 +106,7 @@ use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -978,6 +980,28 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => Ok(
+                Plan::AddTableRowAccessPolicy(Box::new(AddTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                    columns: columns
+                        .iter()
+                        .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                        .collect(),
+                })),
+            ),
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
         }
     }
 
@@ -69,10 +69,25 @@ impl Binder {
             schedule_opts,
             suspend_task_after_num_failures,
             comments,
+            after,
+            when_condition,
             sql,
         } = stmt;
 
-        verify_scheduler_option(schedule_opts)?;
+        match (schedule_opts, after.is_empty()) {
+            (Some(schedule_opts), true) => verify_scheduler_option(schedule_opts)?,
+            (Some(_), false) => {
+                return Err(ErrorCode::SemanticError(
+                    "a task running after other tasks can not have a schedule".to_string(),
+                ));
+            }
+            (None, true) => {
+                return Err(ErrorCode::SemanticError(
+                    "a task must have a schedule or run after other tasks".to_string(),
+                ));
+            }
+            (None, false) => {}
+        }
 
         let tenant = self.ctx.get_tenant();
         let plan = CreateTaskPlan {
@@ -83,6 +98,13 @@ impl Binder {
             schedule_opts: schedule_opts.clone(),
             suspend_task_after_num_failures: *suspend_task_after_num_failures,
             comment: comments.clone(),
+            after: after.iter().fold(vec![], |mut tasks, task| {
+                if !tasks.contains(task) {
+                    tasks.push(task.clone());
+                }
+                tasks
+            }),
+            when_condition: when_condition.clone(),
             sql: sql.clone(),
         };
         Ok(Plan::CreateTask(Box::new(plan)))
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::*;
+use databend_common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::AlterWorkloadGroupPlan;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DescWorkloadGroupPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::Plan;
+use crate::plans::ShowWorkloadGroupsPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_workload_group(
+        &mut self,
+        stmt: &CreateWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let CreateWorkloadGroupStmt {
+            if_not_exists,
+            name,
+            set_options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateWorkloadGroupPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            set_options: set_options.clone(),
+        };
+        Ok(Plan::CreateWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_workload_group(
+        &mut self,
+        stmt: &AlterWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let AlterWorkloadGroupStmt {
+            if_exists,
+            name,
+            set_options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            set_options: set_options.clone(),
+        };
+        Ok(Plan::AlterWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_workload_group(
+        &mut self,
+        stmt: &DropWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let DropWorkloadGroupStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_workload_group(
+        &mut self,
+        stmt: &DescWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let DescWorkloadGroupStmt { name } = stmt;
+
+        let plan = DescWorkloadGroupPlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_workload_groups(&mut self) -> Result<Plan> {
+        let plan = ShowWorkloadGroupsPlan {};
+        Ok(Plan::ShowWorkloadGroups(Box::new(plan)))
+    }
+}
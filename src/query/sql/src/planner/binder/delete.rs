@@ -29,6 +29,7 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::DeletePlan;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Operator;
 use crate::plans::Plan;
 use crate::plans::RelOp;
@@ -43,11 +44,25 @@ impl<'a> Binder {
     pub(in crate::planner::binder) async fn process_selection(
         &self,
         filter: &'a Option<Expr>,
+        policy: Option<ScalarExpr>,
         table_expr: SExpr,
         scalar_binder: &mut ScalarBinder<'_>,
     ) -> Result<(Option<ScalarExpr>, Vec<SubqueryDesc>)> {
-        Ok(if let Some(expr) = filter {
-            let (scalar, _) = scalar_binder.bind(expr).await?;
+        let scalar = match filter {
+            Some(expr) => Some(scalar_binder.bind(expr).await?.0),
+            None => None,
+        };
+        // The rows invisible by the row access policy are not mutated.
+        let scalar = match (policy, scalar) {
+            (Some(policy), Some(scalar)) => Some(ScalarExpr::FunctionCall(FunctionCall {
+                span: scalar.span(),
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![policy, scalar],
+            })),
+            (policy, scalar) => policy.or(scalar),
+        };
+        Ok(if let Some(scalar) = scalar {
             let mut subquery_desc = vec![];
             self.subquery_desc(&scalar, table_expr, &mut subquery_desc)
                 .await?;
@@ -83,6 +98,7 @@ impl<'a> Binder {
         };
 
         let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
+        let (table_expr, policy) = Self::split_row_access_policy(table_expr)?;
 
         context.allow_internal_columns(false);
         let mut scalar_binder = ScalarBinder::new(
@@ -96,7 +112,7 @@ impl<'a> Binder {
         );
 
         let (selection, subquery_desc) = self
-            .process_selection(selection, table_expr, &mut scalar_binder)
+            .process_selection(selection, policy, table_expr, &mut scalar_binder)
             .await?;
 
         if let Some(selection) = &selection {
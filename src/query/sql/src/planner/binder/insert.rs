@@ -15,16 +15,21 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::InsertSource;
 use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::OnErrorMode;
+use parking_lot::RwLock;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
@@ -35,6 +40,7 @@ use crate::plans::Insert;
 use crate::plans::InsertInputSource;
 use crate::plans::Plan;
 use crate::BindContext;
+use crate::Metadata;
 impl Binder {
     pub fn schema_project(
         &self,
@@ -70,6 +76,48 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
+    // The placeholders of `INSERT ... VALUES` take the types of the columns they are inserted
+    // into, to describe a prepared statement.
+    fn infer_values_placeholder_types(&self, body: &SetExpr, schema: &TableSchema) {
+        let SetExpr::Values { values, .. } = body else {
+            return;
+        };
+        let mut metadata = self.metadata.write();
+        for row in values {
+            for (value, field) in row.iter().zip(schema.fields()) {
+                if let Expr::Placeholder {
+                    span: Some(span), ..
+                } = value
+                {
+                    let data_type = DataType::from(field.data_type());
+                    metadata.set_placeholder_type(span.start, data_type.remove_nullable());
+                }
+            }
+        }
+    }
+
+    // The values of `INSERT ... VALUES` with query parameters are cast to the column types by the
+    // insert pipeline, evaluate them while binding to report a mistyped parameter with its span.
+    async fn check_values_types(&self, body: &SetExpr, schema: &Arc<TableSchema>) -> Result<()> {
+        let SetExpr::Values { values, .. } = body else {
+            return Ok(());
+        };
+        let schema: DataSchemaRef = Arc::new(schema.into());
+        for row in values {
+            let metadata = Arc::new(RwLock::new(Metadata::default()));
+            BindContext::new()
+                .exprs_to_scalar(
+                    row.clone(),
+                    &schema,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    metadata,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
     pub(in crate::planner::binder) async fn bind_insert(
         &mut self,
         bind_context: &mut BindContext,
@@ -147,6 +195,12 @@ impl Binder {
                 }
             }
             InsertSource::Select { query } => {
+                self.infer_values_placeholder_types(&query.body, &schema);
+                if let Some(params) = self.ctx.get_query_params() {
+                    if !params.describe {
+                        self.check_values_types(&query.body, &schema).await?;
+                    }
+                }
                 let statement = Statement::Query(query);
                 let select_plan = self.bind_statement(bind_context, &statement).await?;
                 let opt_ctx = OptimizerContext::new(self.ctx.clone(), self.metadata.clone())
@@ -38,6 +38,7 @@ mod project;
 mod project_set;
 mod qualify;
 mod replace;
+mod row_access_policy;
 mod scalar;
 mod scalar_common;
 mod select;
@@ -45,6 +46,7 @@ mod setting;
 mod show;
 mod sort;
 mod stage;
+mod stream_changes;
 mod stream_column_factory;
 mod table;
 mod table_args;
@@ -56,6 +58,7 @@ mod window;
 pub use aggregate::AggregateInfo;
 pub use bind_context::*;
 pub use binder::Binder;
+pub use binder::RecursiveCteWorkingTable;
 pub use builders::*;
 pub use column_binding::ColumnBinding;
 pub use column_binding::ColumnBindingBuilder;
@@ -67,6 +70,7 @@ pub use location::parse_uri_location;
 pub use merge_into::MergeIntoType;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
+pub use stream_changes::StreamChangeSide;
 pub use stream_column_factory::STREAM_COLUMN_FACTORY;
 pub use table::parse_result_scan_args;
 pub use values::bind_values;
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
+
+use crate::binder::scalar::ScalarBinder;
+use crate::binder::Binder;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
+use crate::plans::RelOperator;
+use crate::BindContext;
+use crate::ScalarExpr;
+
+const OPT_KEY_STREAM_TABLE_NAME: &str = "table_name";
+const OPT_KEY_STREAM_TABLE_DATABASE: &str = "table_database";
+
+impl Binder {
+    /// Wrap the scan of `table` in a filter of its row access policy, if there is one.
+    ///
+    /// A stream is restricted by the policy of its source table. The policy can't be
+    /// bypassed, so an unlicensed or missing policy fails the query.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_row_access_policy(
+        &mut self,
+        catalog: &str,
+        table: &Arc<dyn Table>,
+        bind_context: &BindContext,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let Some(policy) = self.table_row_access_policy(catalog, table).await? else {
+            return Ok(s_expr);
+        };
+
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let handler = get_row_access_policy_handler();
+        let policy_meta = handler
+            .get_row_access_policy(meta_api, self.ctx.get_tenant(), policy.policy.clone())
+            .await?;
+        if policy_meta.args.len() != policy.columns.len() {
+            return Err(ErrorCode::UnmatchColumnDataType(format!(
+                "Row access policy '{}' of table '{}' expects {} columns, but got {}",
+                policy.policy,
+                table.name(),
+                policy_meta.args.len(),
+                policy.columns.len()
+            )));
+        }
+
+        // The arguments of the policy are bound to the columns of the table.
+        let mut aliases = Vec::with_capacity(policy.columns.len());
+        for (column, (arg_name, _)) in policy.columns.iter().zip(policy_meta.args.iter()) {
+            let Some(column_binding) = bind_context
+                .columns
+                .iter()
+                .find(|c| &c.column_name == column)
+            else {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Cannot find column {} of row access policy '{}' in table '{}'",
+                    column,
+                    policy.policy,
+                    table.name()
+                )));
+            };
+            aliases.push((
+                arg_name.to_lowercase(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: column_binding.clone(),
+                }),
+            ));
+        }
+
+        let tokens = tokenize_sql(&policy_meta.body)?;
+        let ast_expr = parse_expr(&tokens, self.dialect)?;
+        let mut policy_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, data_type) = scalar_binder.bind(&ast_expr).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy '{}' must return BOOLEAN, but got {}",
+                policy.policy, data_type
+            )));
+        }
+
+        // The visible rows depend on the current role and user, which are folded into constants.
+        self.ctx.set_cacheable(false);
+
+        let filter = Filter {
+            predicates: vec![predicate],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// Split the row access policy filter, if there is one, from the scan of
+    /// the table mutated by DELETE or UPDATE.
+    pub(crate) fn split_row_access_policy(s_expr: SExpr) -> Result<(SExpr, Option<ScalarExpr>)> {
+        if let RelOperator::Filter(filter) = &*s_expr.plan {
+            let child = s_expr.child(0)?;
+            if matches!(&*child.plan, RelOperator::Scan(_)) {
+                return Ok((child.clone(), filter.predicates.first().cloned()));
+            }
+        }
+        Ok((s_expr, None))
+    }
+
+    async fn table_row_access_policy(
+        &self,
+        catalog: &str,
+        table: &Arc<dyn Table>,
+    ) -> Result<Option<TableRowAccessPolicy>> {
+        if table.engine() != "STREAM" {
+            return Ok(table.get_table_info().meta.row_access_policy.clone());
+        }
+
+        let options = table.options();
+        let (Some(source_database), Some(source_table)) = (
+            options.get(OPT_KEY_STREAM_TABLE_DATABASE),
+            options.get(OPT_KEY_STREAM_TABLE_NAME),
+        ) else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid stream {}: source table must be set",
+                table.name()
+            )));
+        };
+        let source = self
+            .ctx
+            .get_table(catalog, source_database, source_table)
+            .await?;
+        Ok(source.get_table_info().meta.row_access_policy.clone())
+    }
+}
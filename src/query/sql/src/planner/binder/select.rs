@@ -35,6 +35,7 @@ use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::SetOperator;
 use databend_common_ast::ast::TableReference;
+use databend_common_ast::walk_table_reference;
 use databend_common_ast::Visitor;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -53,8 +54,10 @@ use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
+use crate::binder::RecursiveCteWorkingTable;
 use crate::binder::INTERNAL_COLUMN_FACTORY;
 use crate::normalize_identifier;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::BindContext;
@@ -63,14 +66,22 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::JoinType;
+use crate::plans::RecursiveCte;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
 use crate::plans::Visitor as _;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::IndexType;
+use crate::NameResolutionContext;
 use crate::UdfRewriter;
 use crate::VirtualColumnRewriter;
 use crate::Visibility;
@@ -360,6 +371,11 @@ impl Binder {
                         "duplicate cte {table_name}"
                     )));
                 }
+                let recursive = with.recursive && {
+                    let mut finder = TableNameFinder::new(&table_name, &self.name_resolution_ctx);
+                    finder.visit_query(&cte.query);
+                    finder.found
+                };
                 let cte_info = CteInfo {
                     columns_alias: cte
                         .alias
@@ -369,6 +385,7 @@ impl Binder {
                         .collect(),
                     query: *cte.query.clone(),
                     materialized: cte.materialized,
+                    recursive,
                     cte_idx: idx,
                     used_count: 0,
                     stat_info: None,
@@ -527,12 +544,178 @@ impl Binder {
                 right_expr,
                 true,
             ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
+            (SetOperator::Intersect, true) => self.bind_intersect_or_except_all(
+                left.span(),
+                right.span(),
+                left_bind_context,
+                right_bind_context,
+                left_expr,
+                right_expr,
+                JoinType::LeftSemi,
+            ),
+            (SetOperator::Except, true) => self.bind_intersect_or_except_all(
+                left.span(),
+                right.span(),
+                left_bind_context,
+                right_bind_context,
+                left_expr,
+                right_expr,
+                JoinType::LeftAnti,
+            ),
         }
     }
 
+    /// Bind the body of a recursive cte, which must be `anchor UNION [ALL] recursive`.
+    ///
+    /// The recursive term reads the working table, whose types come from the anchor term.
+    /// If the recursive term produces wider types, e.g. `n + 1` turns `UInt8` into `UInt16`,
+    /// it is bound again with the wider types until the types are stable.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        const MAX_BIND_ROUNDS: usize = 8;
+
+        let query = &cte_info.query;
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation) if set_operation.op == SetOperator::Union => {
+                set_operation
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive cte '{table_name}' must be of the form `anchor UNION [ALL] recursive`"
+                ))
+                .set_span(span));
+            }
+        };
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "WITH, ORDER BY, LIMIT and OFFSET are not supported in recursive cte '{table_name}'"
+            ))
+            .set_span(span));
+        }
+
+        let (anchor_expr, anchor_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[], 0)
+            .await?;
+        let stat_info = RelExpr::with_s_expr(&anchor_expr).derive_cardinality()?;
+        let column_names = anchor_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                cte_info
+                    .columns_alias
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone())
+            })
+            .collect::<Vec<_>>();
+
+        let mut data_types = anchor_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+        let mut round = 0;
+        let (recursive_expr, recursive_context) = loop {
+            self.recursive_cte_working_tables.insert(
+                table_name.to_string(),
+                RecursiveCteWorkingTable {
+                    cte_idx: cte_info.cte_idx,
+                    columns: column_names
+                        .iter()
+                        .cloned()
+                        .zip(data_types.iter().cloned())
+                        .collect(),
+                    stat_info: stat_info.clone(),
+                    referenced: false,
+                },
+            );
+            let res = self
+                .bind_set_expr(bind_context, &set_operation.right, &[], 0)
+                .await;
+            let working_table = self.recursive_cte_working_tables.remove(table_name);
+            let (recursive_expr, recursive_context) = res?;
+
+            if !working_table.map_or(false, |working_table| working_table.referenced) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the recursive term of cte '{table_name}' must reference '{table_name}'"
+                ))
+                .set_span(set_operation.right.span()));
+            }
+            if recursive_context.columns.len() != data_types.len() {
+                return Err(ErrorCode::SemanticError(
+                    "SetOperation must have the same number of columns",
+                ));
+            }
+
+            let mut coercion_types = Vec::with_capacity(data_types.len());
+            for ((name, data_type), column) in column_names
+                .iter()
+                .zip(data_types.iter())
+                .zip(recursive_context.columns.iter())
+            {
+                match common_super_type(
+                    data_type.clone(),
+                    *column.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                ) {
+                    Some(data_type) => coercion_types.push(data_type),
+                    None => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "recursive cte's types cannot be matched, column {:?}, anchor type: {:?}, recursive type: {:?}",
+                            name, data_type, column.data_type
+                        )));
+                    }
+                }
+            }
+            if coercion_types == data_types {
+                break (recursive_expr, recursive_context);
+            }
+
+            round += 1;
+            if round >= MAX_BIND_ROUNDS {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the column types of recursive cte '{table_name}' keep widening, \
+                     please cast the columns of the anchor term explicitly"
+                ))
+                .set_span(span));
+            }
+            data_types = coercion_types;
+        };
+
+        let (new_bind_context, pairs, anchor_expr, recursive_expr) = self.coercion_union_type(
+            set_operation.left.span(),
+            set_operation.right.span(),
+            anchor_context,
+            recursive_context,
+            anchor_expr,
+            recursive_expr,
+            data_types,
+        )?;
+
+        let recursive_cte = RecursiveCte {
+            cte_idx: cte_info.cte_idx,
+            distinct: !set_operation.all,
+            pairs,
+        };
+        let s_expr = SExpr::create_binary(
+            Arc::new(recursive_cte.into()),
+            Arc::new(anchor_expr),
+            Arc::new(recursive_expr),
+        );
+        Ok((s_expr, new_bind_context))
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn bind_union(
         &mut self,
@@ -658,37 +841,156 @@ impl Binder {
             &mut HashMap::new(),
             left_expr,
         )?;
-        let mut left_conditions = Vec::with_capacity(left_context.columns.len());
-        let mut right_conditions = Vec::with_capacity(right_context.columns.len());
-        assert_eq!(left_context.columns.len(), right_context.columns.len());
-        for (left_column, right_column) in left_context
+        let join_conditions = self.set_operation_join_conditions(
+            left_span,
+            right_span,
+            &left_context.columns,
+            &right_context.columns,
+        );
+        let s_expr = self.bind_join_with_type(join_type, join_conditions, left_expr, right_expr)?;
+        Ok((s_expr, left_context))
+    }
+
+    /// `INTERSECT ALL` and `EXCEPT ALL` keep duplicates: a row which appears `m` times in the left
+    /// and `n` times in the right appears `min(m, n)` and `max(m - n, 0)` times respectively.
+    ///
+    /// Every row is numbered by its occurrence among the equal rows, then the left is semi/anti
+    /// joined with the right on all the columns and the occurrence number, e.g. the 2nd `(1, 'a')`
+    /// of the left only matches the 2nd `(1, 'a')` of the right.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bind_intersect_or_except_all(
+        &mut self,
+        left_span: Span,
+        right_span: Span,
+        left_context: BindContext,
+        right_context: BindContext,
+        left_expr: SExpr,
+        right_expr: SExpr,
+        join_type: JoinType,
+    ) -> Result<(SExpr, BindContext)> {
+        let (left_expr, left_occurrence) =
+            self.bind_occurrence_number(left_span, &left_context, left_expr)?;
+        let (right_expr, right_occurrence) =
+            self.bind_occurrence_number(right_span, &right_context, right_expr)?;
+
+        let left_columns = left_context
             .columns
             .iter()
-            .zip(right_context.columns.iter())
-        {
-            left_conditions.push(
-                BoundColumnRef {
-                    span: left_span,
-                    column: left_column.clone(),
+            .cloned()
+            .chain(std::iter::once(left_occurrence))
+            .collect::<Vec<_>>();
+        let right_columns = right_context
+            .columns
+            .iter()
+            .cloned()
+            .chain(std::iter::once(right_occurrence))
+            .collect::<Vec<_>>();
+        let join_conditions = self.set_operation_join_conditions(
+            left_span,
+            right_span,
+            &left_columns,
+            &right_columns,
+        );
+        let s_expr = self.bind_join_with_type(join_type, join_conditions, left_expr, right_expr)?;
+        Ok((s_expr, left_context))
+    }
+
+    /// Build the join conditions which match the rows equal on all the columns.
+    ///
+    /// Set operations treat NULLs as equal, i.e. the columns are compared with
+    /// `IS NOT DISTINCT FROM`. The hash join never matches NULL keys, so a nullable column
+    /// is compared as a single element tuple instead: the tuple itself is never NULL and
+    /// two tuples holding NULL are equal.
+    fn set_operation_join_conditions(
+        &self,
+        left_span: Span,
+        right_span: Span,
+        left_columns: &[ColumnBinding],
+        right_columns: &[ColumnBinding],
+    ) -> JoinConditions {
+        assert_eq!(left_columns.len(), right_columns.len());
+        let mut left_conditions = Vec::with_capacity(left_columns.len());
+        let mut right_conditions = Vec::with_capacity(right_columns.len());
+        for (left_column, right_column) in left_columns.iter().zip(right_columns.iter()) {
+            let nullable = left_column.data_type.is_nullable_or_null()
+                || right_column.data_type.is_nullable_or_null();
+            let condition = |span: Span, column: &ColumnBinding| -> ScalarExpr {
+                let column: ScalarExpr = BoundColumnRef {
+                    span,
+                    column: column.clone(),
                 }
-                .into(),
-            );
-            right_conditions.push(
-                BoundColumnRef {
-                    span: right_span,
-                    column: right_column.clone(),
+                .into();
+                if nullable {
+                    FunctionCall {
+                        span,
+                        func_name: "tuple".to_string(),
+                        params: vec![],
+                        arguments: vec![column],
+                    }
+                    .into()
+                } else {
+                    column
                 }
-                .into(),
-            );
+            };
+            left_conditions.push(condition(left_span, left_column));
+            right_conditions.push(condition(right_span, right_column));
         }
-        let join_conditions = JoinConditions {
+        JoinConditions {
             left_conditions,
             right_conditions,
             non_equi_conditions: vec![],
             other_conditions: vec![],
+        }
+    }
+
+    /// Number the rows by their occurrence among the equal rows with `row_number()`,
+    /// returns the new `SExpr` and the binding of the occurrence number.
+    fn bind_occurrence_number(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        child: SExpr,
+    ) -> Result<(SExpr, ColumnBinding)> {
+        let function = WindowFuncType::RowNumber;
+        let data_type = function.return_type();
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column("_occurrence".to_string(), data_type.clone());
+        let partition_by = bind_context
+            .columns
+            .iter()
+            .map(|column| ScalarItem {
+                scalar: BoundColumnRef {
+                    span,
+                    column: column.clone(),
+                }
+                .into(),
+                index: column.index,
+            })
+            .collect();
+        let window = Window {
+            span,
+            index,
+            function,
+            arguments: vec![],
+            partition_by,
+            order_by: vec![],
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Range,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::Following(None),
+            },
         };
-        let s_expr = self.bind_join_with_type(join_type, join_conditions, left_expr, right_expr)?;
-        Ok((s_expr, left_context))
+        let column_binding = ColumnBindingBuilder::new(
+            "_occurrence".to_string(),
+            index,
+            Box::new(data_type),
+            Visibility::Visible,
+        )
+        .build();
+        let s_expr = SExpr::create_unary(Arc::new(window.into()), Arc::new(child));
+        Ok((s_expr, column_binding))
     }
 
     #[allow(clippy::type_complexity)]
@@ -1165,3 +1467,38 @@ impl<'a> Visitor<'a> for MaxColumnPosition {
         }
     }
 }
+
+/// Find whether a query references the table with the given name,
+/// which is used to decide if a cte in `WITH RECURSIVE` is actually recursive.
+pub struct TableNameFinder<'a> {
+    table_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    pub found: bool,
+}
+
+impl<'a> TableNameFinder<'a> {
+    pub fn new(table_name: &'a str, name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        Self {
+            table_name,
+            name_resolution_ctx,
+            found: false,
+        }
+    }
+}
+
+impl<'a, 'ast> Visitor<'ast> for TableNameFinder<'a> {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table: name,
+            ..
+        } = table
+        {
+            if normalize_identifier(name, self.name_resolution_ctx).name == self.table_name {
+                self.found = true;
+            }
+        }
+        walk_table_reference(self, table);
+    }
+}
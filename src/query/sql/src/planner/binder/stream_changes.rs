@@ -0,0 +1,357 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Join;
+use databend_common_ast::ast::JoinCondition;
+use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperation;
+use databend_common_ast::ast::SetOperator;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_catalog::plan::ChangeType;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::CHANGE_ACTION_COL_NAME;
+use databend_common_expression::CHANGE_IS_UPDATE_COL_NAME;
+use databend_common_expression::CHANGE_ROW_ID_COL_NAME;
+
+use crate::binder::Binder;
+use crate::binder::Visibility;
+use crate::optimizer::SExpr;
+use crate::BindContext;
+
+const OPT_KEY_STREAM_MODE: &str = "mode";
+const STREAM_MODE_STANDARD: &str = "standard";
+
+/// The column of the changes query that matches the rows of both sides.
+const SIDE_ROW_ID_COL_NAME: &str = "_change_row_id";
+
+/// Whether the table is a stream in standard mode, which exposes updates and deletes.
+pub(crate) fn is_standard_stream(table: &dyn Table) -> bool {
+    table.engine() == "STREAM"
+        && table
+            .options()
+            .get(OPT_KEY_STREAM_MODE)
+            .is_some_and(|mode| mode == STREAM_MODE_STANDARD)
+}
+
+/// A side of the changes of a standard stream, see [`ChangeType`].
+#[derive(Clone)]
+pub struct StreamChangeSide {
+    pub catalog: String,
+    pub database: String,
+    /// The stream that only reads this side of the changes.
+    pub table: Arc<dyn Table>,
+}
+
+impl Binder {
+    /// Bind a stream in standard mode to the query of its changes.
+    ///
+    /// The rows of the blocks added and removed since the stream offset are matched
+    /// by `change$row_id`: a row on both sides with different values is an update,
+    /// a row on both sides with the same values is untouched (e.g. moved by compaction)
+    /// and is left out.
+    #[allow(clippy::too_many_arguments)]
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_stream_changes(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        catalog: &str,
+        database: &str,
+        stream: &Arc<dyn Table>,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        // The sides are registered in the binder rather than resolved from the catalog,
+        // so they are only visible while the changes query is being bound.
+        for change_type in [ChangeType::Insert, ChangeType::Delete] {
+            self.stream_change_sides
+                .insert(side_name(change_type).to_string(), StreamChangeSide {
+                    catalog: catalog.to_string(),
+                    database: database.to_string(),
+                    table: stream.with_change_type(change_type)?,
+                });
+        }
+        let query = changes_query(*span, stream.as_ref());
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let res = self.bind_query(&mut new_bind_context, &query).await;
+        for change_type in [ChangeType::Insert, ChangeType::Delete] {
+            self.stream_change_sides.remove(side_name(change_type));
+        }
+
+        let (s_expr, mut new_bind_context) = res?;
+        for column in new_bind_context.columns.iter_mut() {
+            // Like the append-only stream, the change columns are only visible by name.
+            if matches!(
+                column.column_name.as_str(),
+                CHANGE_ACTION_COL_NAME | CHANGE_IS_UPDATE_COL_NAME | CHANGE_ROW_ID_COL_NAME
+            ) {
+                column.visibility = Visibility::InVisible;
+            }
+        }
+        if let Some(alias) = alias {
+            new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        } else {
+            for column in new_bind_context.columns.iter_mut() {
+                column.database_name = None;
+                column.table_name = Some(stream.name().to_string());
+            }
+        }
+        new_bind_context.parent = Some(Box::new(bind_context.clone()));
+        Ok((s_expr, new_bind_context))
+    }
+}
+
+/// The name by which the changes query reads a side of the changes.
+fn side_name(change_type: ChangeType) -> &'static str {
+    match change_type {
+        ChangeType::Insert => "_change_insert",
+        ChangeType::Delete => "_change_delete",
+    }
+}
+
+fn ident(name: &str) -> Identifier {
+    Identifier::from_name_with_quoted(name, Some('`'))
+}
+
+fn column_ref(span: Span, table: &str, column: &str) -> Expr {
+    Expr::ColumnRef {
+        span,
+        database: None,
+        table: Some(ident(table)),
+        column: ColumnID::Name(ident(column)),
+    }
+}
+
+fn binary_op(span: Span, op: BinaryOperator, left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOp {
+        span,
+        op,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+fn select_target(expr: Expr, alias: Option<&str>) -> SelectTarget {
+    SelectTarget::AliasedExpr {
+        expr: Box::new(expr),
+        alias: alias.map(ident),
+    }
+}
+
+fn select_stmt(
+    span: Span,
+    select_list: Vec<SelectTarget>,
+    from: TableReference,
+    selection: Option<Expr>,
+) -> SelectStmt {
+    SelectStmt {
+        span,
+        hints: None,
+        distinct: false,
+        select_list,
+        from: vec![from],
+        selection,
+        group_by: None,
+        having: None,
+        window_list: None,
+        qualify: None,
+    }
+}
+
+fn query(span: Span, body: SetExpr) -> Query {
+    Query {
+        span,
+        with: None,
+        body,
+        order_by: vec![],
+        limit: vec![],
+        offset: None,
+        ignore_result: false,
+    }
+}
+
+/// Build the query of the changes of a standard stream, which is:
+///
+/// ```sql
+/// SELECT i.<columns>, 'INSERT' AS change$action, d._change_row_id IS NOT NULL AS change$is_update,
+///     i._change_row_id AS change$row_id
+/// FROM <insert side> AS i LEFT JOIN <delete side> AS d ON i._change_row_id = d._change_row_id
+/// WHERE d._change_row_id IS NULL OR NOT (i.<columns> IS NOT DISTINCT FROM d.<columns>)
+/// UNION ALL
+/// SELECT d.<columns>, 'DELETE' AS change$action, ... -- the same with the sides swapped
+/// ```
+fn changes_query(span: Span, stream: &dyn Table) -> Query {
+    let schema = stream.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<Vec<_>>();
+
+    // `SELECT <columns>, change$row_id AS _change_row_id FROM <side> AS <alias>`
+    let side = |change_type: ChangeType, alias: &str| {
+        let mut select_list = columns
+            .iter()
+            .map(|column| {
+                select_target(
+                    Expr::ColumnRef {
+                        span,
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(ident(column)),
+                    },
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+        select_list.push(select_target(
+            Expr::ColumnRef {
+                span,
+                database: None,
+                table: None,
+                column: ColumnID::Name(ident(CHANGE_ROW_ID_COL_NAME)),
+            },
+            Some(SIDE_ROW_ID_COL_NAME),
+        ));
+        let from = TableReference::Table {
+            span,
+            catalog: None,
+            database: None,
+            table: ident(side_name(change_type)),
+            alias: None,
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        };
+        TableReference::Subquery {
+            span,
+            lateral: false,
+            subquery: Box::new(query(
+                span,
+                SetExpr::Select(Box::new(select_stmt(span, select_list, from, None))),
+            )),
+            alias: Some(TableAlias {
+                name: ident(alias),
+                columns: vec![],
+            }),
+        }
+    };
+
+    let changes = |change_type: ChangeType| {
+        let (this, other, other_type, action) = match change_type {
+            ChangeType::Insert => ("i", "d", ChangeType::Delete, "INSERT"),
+            ChangeType::Delete => ("d", "i", ChangeType::Insert, "DELETE"),
+        };
+
+        let mut select_list = columns
+            .iter()
+            .map(|column| select_target(column_ref(span, this, column), None))
+            .collect::<Vec<_>>();
+        select_list.push(select_target(
+            Expr::Literal {
+                span,
+                lit: Literal::String(action.to_string()),
+            },
+            Some(CHANGE_ACTION_COL_NAME),
+        ));
+        select_list.push(select_target(
+            Expr::IsNull {
+                span,
+                expr: Box::new(column_ref(span, other, SIDE_ROW_ID_COL_NAME)),
+                not: true,
+            },
+            Some(CHANGE_IS_UPDATE_COL_NAME),
+        ));
+        select_list.push(select_target(
+            column_ref(span, this, SIDE_ROW_ID_COL_NAME),
+            Some(CHANGE_ROW_ID_COL_NAME),
+        ));
+
+        let from = TableReference::Join {
+            span,
+            join: Join {
+                op: JoinOperator::LeftOuter,
+                condition: JoinCondition::On(Box::new(binary_op(
+                    span,
+                    BinaryOperator::Eq,
+                    column_ref(span, this, SIDE_ROW_ID_COL_NAME),
+                    column_ref(span, other, SIDE_ROW_ID_COL_NAME),
+                ))),
+                left: Box::new(side(change_type, this)),
+                right: Box::new(side(other_type, other)),
+            },
+        };
+
+        // The rows only on this side, or on both sides with different values.
+        let unchanged = columns
+            .iter()
+            .map(|column| Expr::IsDistinctFrom {
+                span,
+                left: Box::new(column_ref(span, this, column)),
+                right: Box::new(column_ref(span, other, column)),
+                not: true,
+            })
+            .reduce(|left, right| binary_op(span, BinaryOperator::And, left, right))
+            .unwrap_or(Expr::Literal {
+                span,
+                lit: Literal::Boolean(true),
+            });
+        let selection = binary_op(
+            span,
+            BinaryOperator::Or,
+            Expr::IsNull {
+                span,
+                expr: Box::new(column_ref(span, other, SIDE_ROW_ID_COL_NAME)),
+                not: false,
+            },
+            Expr::UnaryOp {
+                span,
+                op: UnaryOperator::Not,
+                expr: Box::new(unchanged),
+            },
+        );
+
+        SetExpr::Select(Box::new(select_stmt(
+            span,
+            select_list,
+            from,
+            Some(selection),
+        )))
+    };
+
+    query(
+        span,
+        SetExpr::SetOperation(Box::new(SetOperation {
+            span,
+            op: SetOperator::Union,
+            all: true,
+            left: Box::new(changes(ChangeType::Insert)),
+            right: Box::new(changes(ChangeType::Delete)),
+        })),
+    )
+}
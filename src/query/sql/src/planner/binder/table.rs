@@ -74,6 +74,10 @@ use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_types::MetaId;
+use databend_common_storage::infer_avro_schema;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::read_avro_schema_async;
+use databend_common_storage::read_orc_schema_async;
 use databend_common_storage::DataOperator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
@@ -92,6 +96,7 @@ use super::InternalColumnBinding;
 use super::INTERNAL_COLUMN_FACTORY;
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::stream_changes::is_standard_stream;
 use crate::binder::table_args::bind_table_args;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
@@ -108,6 +113,7 @@ use crate::plans::CteScan;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::RecursiveCteScan;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
@@ -193,13 +199,25 @@ impl Binder {
         alias: &Option<TableAlias>,
         travel_point: &Option<TimeTravelPoint>,
     ) -> Result<(SExpr, BindContext)> {
-        let (catalog, database, table_name) =
+        let qualified = catalog.is_some() || database.is_some();
+        let (mut catalog, mut database, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table);
         let table_alias_name = if let Some(table_alias) = alias {
             Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
         } else {
             None
         };
+        // Check and bind the working table of a recursive cte
+        if !qualified && self.recursive_cte_working_tables.contains_key(&table_name) {
+            return self.bind_recursive_cte_scan(*span, bind_context, &table_name, alias);
+        }
+        // Check the side of the changes of a standard stream, which is only visible
+        // to the changes query of the stream
+        let change_side = match qualified {
+            true => None,
+            false => self.stream_change_sides.get(&table_name).cloned(),
+        };
+        let is_change_side = change_side.is_some();
         let mut bind_cte = true;
         if let Some(cte_name) = &bind_context.cte_name {
             // If table name equals to cte name, then skip bind cte and find table from catalog
@@ -210,7 +228,7 @@ impl Binder {
         }
         // Check and bind common table expression
         let ctes_map = self.ctes_map.clone();
-        if let Some(cte_info) = ctes_map.get(&table_name) {
+        if let Some(cte_info) = ctes_map.get(&table_name).filter(|_| !is_change_side) {
             if bind_cte {
                 return if !cte_info.materialized {
                     self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
@@ -219,6 +237,11 @@ impl Binder {
                     self.bind_m_cte(bind_context, cte_info, &table_name, alias, span)
                         .await
                 };
+            } else if cte_info.recursive {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive reference to cte '{table_name}' is only allowed in the recursive term, not within a subquery"
+                ))
+                .set_span(*span));
             }
         }
 
@@ -230,16 +253,24 @@ impl Binder {
         };
 
         // Resolve table with catalog
-        let table_meta = match self
-            .resolve_data_source(
-                tenant.as_str(),
-                catalog.as_str(),
-                database.as_str(),
-                table_name.as_str(),
-                &navigation_point,
-            )
-            .await
-        {
+        let table_meta = match change_side {
+            Some(change_side) => {
+                catalog = change_side.catalog;
+                database = change_side.database;
+                Ok(change_side.table)
+            }
+            None => {
+                self.resolve_data_source(
+                    tenant.as_str(),
+                    catalog.as_str(),
+                    database.as_str(),
+                    table_name.as_str(),
+                    &navigation_point,
+                )
+                .await
+            }
+        };
+        let table_meta = match table_meta {
             Ok(table) => table,
             Err(e) => {
                 let mut parent = bind_context.parent.as_mut();
@@ -270,6 +301,14 @@ impl Binder {
             }
         };
 
+        // A stream in standard mode is bound to the query of its changes,
+        // which reads the sides of the changes.
+        if !is_change_side && is_standard_stream(table_meta.as_ref()) {
+            return self
+                .bind_stream_changes(bind_context, span, &catalog, &database, &table_meta, alias)
+                .await;
+        }
+
         match table_meta.engine() {
             "VIEW" => {
                 // TODO(leiysky): this check is error-prone,
@@ -318,9 +357,9 @@ impl Binder {
             }
             _ => {
                 let table_index = self.metadata.write().add_table(
-                    catalog,
+                    catalog.clone(),
                     database.clone(),
-                    table_meta,
+                    table_meta.clone(),
                     table_alias_name,
                     bind_context.view_info.is_some(),
                     bind_context.planning_agg_index,
@@ -330,6 +369,9 @@ impl Binder {
                 let (s_expr, mut bind_context) = self
                     .bind_base_table(bind_context, database.as_str(), table_index)
                     .await?;
+                let s_expr = self
+                    .bind_row_access_policy(&catalog, &table_meta, &bind_context, s_expr)
+                    .await?;
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
@@ -902,6 +944,7 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    is_partitioned: false,
                     default_values: None,
                 };
                 StageTable::try_create(info)?
@@ -929,13 +972,40 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    is_partitioned: false,
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
+            FileFormatParams::Avro(..) | FileFormatParams::Orc(..) => {
+                // the schema of the first file is used for all files.
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match files_to_copy.as_ref().and_then(|files| files.first()) {
+                    Some(file) => file.clone(),
+                    None => files_info.first_file(&operator).await?,
+                };
+                let schema = if let FileFormatParams::Avro(..) = stage_info.file_format_params {
+                    let avro_schema =
+                        read_avro_schema_async(&operator, &first_file.path, Some(first_file.size))
+                            .await?;
+                    infer_avro_schema(&avro_schema)?
+                } else {
+                    read_orc_schema_async(&operator, &first_file.path).await?
+                };
+                let info = StageTableInfo {
+                    schema: Arc::new(schema),
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    is_partitioned: false,
                     default_values: None,
                 };
                 StageTable::try_create(info)?
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(
-                    "query stage files only support parquet/NDJson/CSV/TSV format for now",
+                    "query stage files only support parquet/NDJson/CSV/TSV/Avro/ORC format for now",
                 ));
             }
         };
@@ -1106,9 +1176,13 @@ impl Binder {
             window_definitions: DashMap::new(),
         };
 
-        let (s_expr, mut res_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut res_bind_context) = if cte_info.recursive {
+            self.bind_recursive_cte(span, &mut new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_query(&mut new_bind_context, &cte_info.query)
+                .await?
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -1142,6 +1216,64 @@ impl Binder {
         Ok((s_expr, res_bind_context))
     }
 
+    /// Bind the working table of the recursive cte whose recursive term is being bound.
+    fn bind_recursive_cte_scan(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        // It's safe to unwrap here because the caller has checked the working table exists.
+        let working_table = self
+            .recursive_cte_working_tables
+            .get_mut(table_name)
+            .unwrap();
+        if working_table.referenced {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference to cte '{table_name}' must not appear more than once"
+            ))
+            .set_span(span));
+        }
+        working_table.referenced = true;
+        let working_table = working_table.clone();
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut fields = Vec::with_capacity(working_table.columns.len());
+        for (column_name, data_type) in working_table.columns.iter() {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            let column_binding = ColumnBindingBuilder::new(
+                column_name.clone(),
+                index,
+                Box::new(data_type.clone()),
+                Visibility::Visible,
+            )
+            .table_name(Some(table_name.to_string()))
+            .build();
+            new_bind_context.add_column_binding(column_binding);
+            fields.push(DataField::new(
+                index.to_string().as_str(),
+                data_type.clone(),
+            ));
+        }
+        if let Some(alias) = alias {
+            new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let s_expr = SExpr::create_leaf(Arc::new(
+            RecursiveCteScan {
+                cte_idx: working_table.cte_idx,
+                fields,
+                stat: working_table.stat_info,
+            }
+            .into(),
+        ));
+        Ok((s_expr, new_bind_context))
+    }
+
     // Bind materialized cte
     #[async_backtrace::framed]
     pub(crate) async fn bind_m_cte(
@@ -1207,7 +1339,8 @@ impl Binder {
         let table_name = table.name();
         let table = table.table();
         let statistics_provider = table.column_statistics_provider().await?;
-        let table_version = if table.engine() == "STREAM" {
+        // The stream in standard mode reads the changes without the filter of append-only.
+        let table_version = if table.engine() == "STREAM" && !is_standard_stream(table.as_ref()) {
             let options = table.options();
             let table_version = options
                 .get("table_version")
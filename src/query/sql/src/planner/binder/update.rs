@@ -64,6 +64,7 @@ impl Binder {
         };
 
         let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
+        let (table_expr, policy) = Self::split_row_access_policy(table_expr)?;
 
         let table = self
             .ctx
@@ -112,7 +113,7 @@ impl Binder {
         }
 
         let (selection, subquery_desc) = self
-            .process_selection(selection, table_expr, &mut scalar_binder)
+            .process_selection(selection, policy, table_expr, &mut scalar_binder)
             .await?;
 
         if let Some(selection) = &selection {
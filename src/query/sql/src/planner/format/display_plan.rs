@@ -76,6 +76,8 @@ impl Plan {
             Plan::DescribeTable(_) => Ok("DescribeTable".to_string()),
             Plan::RenameTable(_) => Ok("RenameTable".to_string()),
             Plan::SetOptions(_) => Ok("SetOptions".to_string()),
+            Plan::AddTableRowAccessPolicy(_) => Ok("AddTableRowAccessPolicy".to_string()),
+            Plan::DropTableRowAccessPolicy(_) => Ok("DropTableRowAccessPolicy".to_string()),
             Plan::RenameTableColumn(_) => Ok("RenameTableColumn".to_string()),
             Plan::AddTableColumn(_) => Ok("AddTableColumn".to_string()),
             Plan::ModifyTableColumn(_) => Ok("ModifyTableColumn".to_string()),
@@ -103,6 +105,7 @@ impl Plan {
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
             Plan::DropIndex(_) => Ok("DropIndex".to_string()),
             Plan::RefreshIndex(_) => Ok("RefreshIndex".to_string()),
+            Plan::RefreshNgramIndex(_) => Ok("RefreshNgramIndex".to_string()),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(_) => Ok("CreateVirtualColumn".to_string()),
@@ -170,6 +173,11 @@ impl Plan {
             Plan::DropDatamaskPolicy(_) => Ok("DropDatamaskPolicy".to_string()),
             Plan::DescDatamaskPolicy(_) => Ok("DescDatamaskPolicy".to_string()),
 
+            // Row access policy
+            Plan::CreateRowAccessPolicy(_) => Ok("CreateRowAccessPolicy".to_string()),
+            Plan::DropRowAccessPolicy(_) => Ok("DropRowAccessPolicy".to_string()),
+            Plan::DescRowAccessPolicy(_) => Ok("DescRowAccessPolicy".to_string()),
+
             // network policy
             Plan::CreateNetworkPolicy(_) => Ok("CreateNetworkPolicy".to_string()),
             Plan::AlterNetworkPolicy(_) => Ok("AlterNetworkPolicy".to_string()),
@@ -183,6 +191,13 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // workload group
+            Plan::CreateWorkloadGroup(_) => Ok("CreateWorkloadGroup".to_string()),
+            Plan::AlterWorkloadGroup(_) => Ok("AlterWorkloadGroup".to_string()),
+            Plan::DropWorkloadGroup(_) => Ok("DropWorkloadGroup".to_string()),
+            Plan::DescWorkloadGroup(_) => Ok("DescWorkloadGroup".to_string()),
+            Plan::ShowWorkloadGroups(_) => Ok("ShowWorkloadGroups".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -191,6 +206,17 @@ impl Plan {
             Plan::ExecuteTask(_) => Ok("ExecuteTask".to_string()),
             Plan::ShowTasks(_) => Ok("ShowTasks".to_string()),
 
+            // pipe
+            Plan::CreatePipe(_) => Ok("CreatePipe".to_string()),
+            Plan::AlterPipe(_) => Ok("AlterPipe".to_string()),
+            Plan::DropPipe(_) => Ok("DropPipe".to_string()),
+            Plan::DescribePipe(_) => Ok("DescribePipe".to_string()),
+
+            // transaction
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
+
             // task
             Plan::CreateConnection(_) => Ok("CreateConnection".to_string()),
             Plan::DescConnection(_) => Ok("DescConnection".to_string()),
@@ -241,6 +267,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             order_by: None,
             prewhere: None,
             agg_index: None,
+            vector_index: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
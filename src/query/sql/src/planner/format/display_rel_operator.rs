@@ -77,6 +77,8 @@ impl Display for FormatContext {
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
                 RelOperator::CteScan(_) => write!(f, "CteScan"),
                 RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
                 RelOperator::AddRowNumber(_) => write!(f, "AddRowNumber"),
                 RelOperator::Udf(_) => write!(f, "Udf"),
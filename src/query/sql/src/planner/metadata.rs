@@ -61,7 +61,11 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Mappings from table name to its vector indexes, (index id, column name).
+    vector_indexes: HashMap<String, Vec<(u64, String)>>,
     max_column_position: usize, // for CSV
+    /// The inferred types of the unbound placeholders, keyed by their start offsets.
+    placeholder_types: HashMap<u32, DataType>,
 }
 
 impl Metadata {
@@ -274,6 +278,32 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_vector_indexes(&mut self, table: String, vector_indexes: Vec<(u64, String)>) {
+        self.vector_indexes
+            .entry(table)
+            .and_modify(|indexes| indexes.extend_from_slice(&vector_indexes))
+            .or_insert(vector_indexes);
+    }
+
+    pub fn get_vector_indexes(&self, table: &str) -> Option<&[(u64, String)]> {
+        self.vector_indexes.get(table).map(|v| v.as_slice())
+    }
+
+    /// Records the type of a placeholder, the first non-null type inferred is kept.
+    pub fn set_placeholder_type(&mut self, start: u32, data_type: DataType) {
+        let entry = self
+            .placeholder_types
+            .entry(start)
+            .or_insert(DataType::Null);
+        if *entry == DataType::Null {
+            *entry = data_type;
+        }
+    }
+
+    pub fn get_placeholder_type(&self, start: u32) -> Option<&DataType> {
+        self.placeholder_types.get(&start)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -50,6 +50,8 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::Window(_) => "WindowFunc".to_string(),
         RelOperator::CteScan(_) => "CteScan".to_string(),
         RelOperator::MaterializedCte(_) => "MaterializedCte".to_string(),
+        RelOperator::RecursiveCte(_) => "RecursiveCte".to_string(),
+        RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
         RelOperator::ConstantTableScan(_) => "ConstantTableScan".to_string(),
         RelOperator::AddRowNumber(_) => "AddRowNumber".to_string(),
         RelOperator::Udf(_) => "Udf".to_string(),
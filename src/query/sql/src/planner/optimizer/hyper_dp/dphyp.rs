@@ -258,7 +258,9 @@ impl DPhpy {
             RelOperator::DummyTableScan(_)
             | RelOperator::ConstantTableScan(_)
             | RelOperator::CteScan(_)
-            | RelOperator::MaterializedCte(_) => Ok((s_expr, true)),
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::RecursiveCteScan(_) => Ok((s_expr, true)),
         }
     }
 
@@ -184,13 +184,17 @@ pub fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
         Plan::ExplainAnalyze { plan } => Ok(Plan::ExplainAnalyze {
             plan: Box::new(optimize(opt_ctx, *plan)?),
         }),
-        Plan::CopyIntoLocation(CopyIntoLocationPlan { stage, path, from }) => {
-            Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
-                stage,
-                path,
-                from: Box::new(optimize(opt_ctx, *from)?),
-            }))
-        }
+        Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage,
+            path,
+            from,
+            partition_by,
+        }) => Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage,
+            path,
+            from: Box::new(optimize(opt_ctx, *from)?),
+            partition_by,
+        })),
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
             plan.enable_distributed = opt_ctx.enable_distributed_optimization
                 && opt_ctx
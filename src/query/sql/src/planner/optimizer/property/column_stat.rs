@@ -38,6 +38,9 @@ pub struct ColumnStat {
 
     /// Histogram of column
     pub histogram: Option<Histogram>,
+
+    /// The most common values with their fractions of rows, collected by `ANALYZE TABLE`
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 #[derive(Debug, Clone)]
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use databend_common_exception::Result;
 use databend_common_expression::arithmetics_type::ResultTypeOfUnary;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 
@@ -33,10 +34,9 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
+/// The histogram is collected by `ANALYZE TABLE` if possible, otherwise it is
 /// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// of rows, which brings the assumption that the data is uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
@@ -153,6 +153,29 @@ pub fn histogram_from_ndv(
     Ok(Histogram { buckets })
 }
 
+/// Construct a histogram from the one collected by `ANALYZE TABLE`.
+///
+/// Only numeric bounds are supported, because the estimation of joins
+/// needs to convert the bounds to `f64`.
+pub fn histogram_from_column_histogram(histogram: &ColumnHistogram) -> Option<Histogram> {
+    let min = Datum::from_scalar(histogram.buckets.first()?.lower_bound.clone())?;
+    if !matches!(min, Datum::Int(_) | Datum::UInt(_) | Datum::Float(_)) {
+        return None;
+    }
+
+    let mut buckets = Vec::with_capacity(histogram.buckets.len() + 1);
+    // The first bucket is a dummy bucket which records the min value, as in `histogram_from_ndv`.
+    buckets.push(HistogramBucket::new(min, 0.0, 0.0));
+    for bucket in histogram.buckets.iter() {
+        buckets.push(HistogramBucket::new(
+            Datum::from_scalar(bucket.upper_bound.clone())?,
+            bucket.num_values as f64,
+            bucket.num_distinct as f64,
+        ));
+    }
+    Some(Histogram::new(buckets))
+}
+
 #[derive(Debug, Clone)]
 pub struct HistogramBucket {
     /// Upper bound value of the bucket.
@@ -354,6 +354,25 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
                 return 0.0;
             }
         }
+
+        // The most common values have known fractions of rows,
+        // and the other values share the rest uniformly.
+        if !column_stat.most_common_values.is_empty() {
+            if let Some((_, fraction)) = column_stat
+                .most_common_values
+                .iter()
+                .find(|(value, _)| value.compare(constant_datum).ok() == Some(Ordering::Equal))
+            {
+                return *fraction;
+            }
+            let common_fraction: f64 = column_stat
+                .most_common_values
+                .iter()
+                .map(|(_, fraction)| fraction)
+                .sum();
+            let rest_ndv = (column_stat.ndv - column_stat.most_common_values.len() as f64).max(1.0);
+            return ((1.0 - common_fraction) / rest_ndv).max(0.0);
+        }
     }
 
     if column_stat.ndv == 0.0 {
@@ -371,6 +390,7 @@ fn update_statistic(
 ) -> Result<()> {
     let new_ndv = (column_stat.ndv * selectivity).ceil();
     column_stat.ndv = new_ndv;
+    column_stat.most_common_values.clear();
     if matches!(
         new_min,
         Datum::Bool(_) | Datum::Int(_) | Datum::UInt(_) | Datum::Float(_)
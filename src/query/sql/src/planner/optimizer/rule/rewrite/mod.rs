@@ -42,6 +42,7 @@ mod rule_push_down_prewhere;
 mod rule_push_down_sort_scan;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
+mod rule_try_apply_vector_index;
 
 pub use rule_commute_join::RuleCommuteJoin;
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
@@ -72,3 +73,4 @@ pub use rule_push_down_prewhere::RulePushDownPrewhere;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
 pub use rule_split_aggregate::RuleSplitAggregate;
 pub use rule_try_apply_agg_index::RuleTryApplyAggIndex;
+pub use rule_try_apply_vector_index::RuleTryApplyVectorIndex;
@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::plans::VectorIndexInfo;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///              Scan
+///
+/// Output: Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///              Scan(vector index)
+///
+/// Applies if the query is `ORDER BY <distance>(<column>, <constant>) LIMIT <n>`
+/// and the column has a vector index, so the storage can prune the blocks by the index.
+pub struct RuleTryApplyVectorIndex {
+    id: RuleID,
+    metadata: MetadataRef,
+    patterns: Vec<SExpr>,
+}
+
+impl RuleTryApplyVectorIndex {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyVectorIndex,
+            metadata,
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Sort,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
+                )),
+            )],
+        }
+    }
+
+    fn try_build_vector_index(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+    ) -> Option<VectorIndexInfo> {
+        let limit = sort.limit?;
+        if sort.items.len() != 1 || !sort.items[0].asc {
+            return None;
+        }
+        if scan.vector_index.is_some()
+            || scan.prewhere.is_some()
+            || scan
+                .push_down_predicates
+                .as_ref()
+                .is_some_and(|p| !p.is_empty())
+        {
+            return None;
+        }
+
+        let item = eval_scalar
+            .items
+            .iter()
+            .find(|item| item.index == sort.items[0].index)?;
+        let ScalarExpr::FunctionCall(FunctionCall {
+            func_name,
+            arguments,
+            ..
+        }) = &item.scalar
+        else {
+            return None;
+        };
+        if !matches!(func_name.as_str(), "cosine_distance" | "l2_distance") || arguments.len() != 2
+        {
+            return None;
+        }
+        // Both distances are symmetric.
+        let (column, target) = match (&arguments[0], &arguments[1]) {
+            (ScalarExpr::BoundColumnRef(column), target) if target.used_columns().is_empty() => {
+                (column, target)
+            }
+            (target, ScalarExpr::BoundColumnRef(column)) if target.used_columns().is_empty() => {
+                (column, target)
+            }
+            _ => return None,
+        };
+
+        let metadata = self.metadata.read();
+        let column_name = match metadata.column(column.column.index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                column_name,
+                path_indices: None,
+                ..
+            }) if *table_index == scan.table_index => column_name,
+            _ => return None,
+        };
+        let table = metadata.table(scan.table_index);
+        let table_name = format!("{}.{}.{}", table.catalog(), table.database(), table.name());
+        let (index_id, _) = metadata
+            .get_vector_indexes(&table_name)?
+            .iter()
+            .find(|(_, name)| name == column_name)?;
+
+        Some(VectorIndexInfo {
+            index_id: *index_id,
+            column: column.column.index,
+            func_name: func_name.clone(),
+            target: target.clone(),
+            limit,
+        })
+    }
+}
+
+impl Rule for RuleTryApplyVectorIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let scan_expr = eval_scalar_expr.child(0)?;
+        let mut scan: Scan = scan_expr.plan().clone().try_into()?;
+
+        if let Some(vector_index) = self.try_build_vector_index(&sort, &eval_scalar, &scan) {
+            scan.vector_index = Some(vector_index);
+            let eval_scalar_expr = eval_scalar_expr.replace_children(vec![Arc::new(
+                scan_expr.replace_plan(Arc::new(RelOperator::Scan(scan))),
+            )]);
+            let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar_expr)]);
+            result.set_applied_rule(&self.id);
+            state.add_result(result);
+        }
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
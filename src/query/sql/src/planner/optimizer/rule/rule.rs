@@ -47,6 +47,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownFilterProjectSet,
         RuleID::FoldCountAggregate,
         RuleID::TryApplyAggIndex,
+        RuleID::TryApplyVectorIndex,
         RuleID::SplitAggregate,
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
@@ -103,6 +104,7 @@ pub enum RuleID {
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
+    TryApplyVectorIndex,
     CommuteJoin,
 
     // Exploration rules
@@ -146,6 +148,7 @@ impl Display for RuleID {
             RuleID::LeftExchangeJoin => write!(f, "LeftExchangeJoin"),
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
+            RuleID::TryApplyVectorIndex => write!(f, "TryApplyVectorIndex"),
         }
     }
 }
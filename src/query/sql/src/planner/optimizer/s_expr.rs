@@ -364,6 +364,8 @@ impl SExpr {
             | RelOperator::AddRowNumber(_)
             | RelOperator::Pattern(_)
             | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::ConstantTableScan(_) => {}
         };
         for child in &self.children {
@@ -430,6 +432,8 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::AddRowNumber(_)
         | RelOperator::Pattern(_)
         | RelOperator::MaterializedCte(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::ConstantTableScan(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
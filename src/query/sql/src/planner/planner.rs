@@ -30,7 +30,9 @@ use databend_common_exception::Result;
 use parking_lot::RwLock;
 
 use super::semantic::AggregateRewriter;
+use super::semantic::BoundParams;
 use super::semantic::DistinctToGroupBy;
+use super::semantic::PlaceholderRewriter;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::Insert;
@@ -63,6 +65,41 @@ impl Planner {
     #[async_backtrace::framed]
     #[minitrace::trace]
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
+        let res = self.plan_sql_inner(sql).await;
+        if res.is_err() {
+            // A statement failed to plan also fails the explicit transaction.
+            self.ctx.txn_mgr().lock().set_fail();
+        }
+        res
+    }
+
+    /// Plan a statement that is parsed already, e.g. a prepared statement.
+    #[async_backtrace::framed]
+    pub async fn plan_stmt(
+        &mut self,
+        stmt: &Statement,
+        format: Option<String>,
+    ) -> Result<(Plan, PlanExtras)> {
+        let res = self.plan_stmt_inner(stmt.clone(), format).await;
+        if res.is_err() {
+            self.ctx.txn_mgr().lock().set_fail();
+        }
+        res
+    }
+
+    /// Plan a statement to describe it without executing it, a failure doesn't
+    /// fail the explicit transaction.
+    #[async_backtrace::framed]
+    pub async fn describe_stmt(
+        &mut self,
+        stmt: &Statement,
+        format: Option<String>,
+    ) -> Result<(Plan, PlanExtras)> {
+        self.plan_stmt_inner(stmt.clone(), format).await
+    }
+
+    #[async_backtrace::framed]
+    async fn plan_sql_inner(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
         let settings = self.ctx.get_settings();
         let sql_dialect = settings.get_sql_dialect()?;
         // Step 1: Tokenize the SQL.
@@ -91,41 +128,8 @@ impl Planner {
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
-                let (mut stmt, format) = parse_sql(&tokens, sql_dialect)?;
-
-                if matches!(stmt, Statement::CopyIntoLocation(_)) {
-                    // Indicate binder there is no need to collect column statistics for the binding table.
-                    self.ctx
-                        .attach_query_str(QueryKind::CopyIntoTable, String::new());
-                }
-
-                self.replace_stmt(&mut stmt, sql_dialect);
-
-                // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
-                let plan = binder.bind(&stmt).await?;
-
-                // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-                let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
-                    .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
-                    .with_enable_join_reorder(unsafe {
-                        !self.ctx.get_settings().get_disable_join_reorder()?
-                    })
-                    .with_enable_dphyp(self.ctx.get_settings().get_enable_dphyp()?);
-
-                let optimized_plan = optimize(opt_ctx, plan)?;
-                Ok((optimized_plan, PlanExtras {
-                    metadata,
-                    format,
-                    statement: stmt,
-                }))
+                let (stmt, format) = parse_sql(&tokens, sql_dialect)?;
+                self.plan_stmt_inner(stmt, format).await
             }
             .await;
 
@@ -169,6 +173,56 @@ impl Planner {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn plan_stmt_inner(
+        &mut self,
+        mut stmt: Statement,
+        format: Option<String>,
+    ) -> Result<(Plan, PlanExtras)> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+
+        if matches!(stmt, Statement::CopyIntoLocation(_)) {
+            // Indicate binder there is no need to collect column statistics for the binding table.
+            self.ctx
+                .attach_query_str(QueryKind::CopyIntoTable, String::new());
+        }
+
+        self.replace_stmt(&mut stmt, sql_dialect);
+        let bound_params = match self.ctx.get_query_params() {
+            Some(params) => PlaceholderRewriter::rewrite(&params, sql_dialect, &mut stmt)?,
+            None => BoundParams::default(),
+        };
+
+        // Step 3: Bind AST with catalog, and generate a pure logical SExpr
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+        let plan = binder
+            .bind(&stmt)
+            .await
+            .map_err(|error| bound_params.annotate_error(error))?;
+
+        // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
+        let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
+            .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
+            .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
+            .with_enable_dphyp(settings.get_enable_dphyp()?);
+
+        let optimized_plan =
+            optimize(opt_ctx, plan).map_err(|error| bound_params.annotate_error(error))?;
+        Ok((optimized_plan, PlanExtras {
+            metadata,
+            format,
+            statement: stmt,
+        }))
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
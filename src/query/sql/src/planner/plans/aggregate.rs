@@ -210,6 +210,8 @@ impl Operator for Aggregate {
             });
             for item in self.group_items.iter() {
                 let item_stat = statistics.column_stats.get_mut(&item.index).unwrap();
+                // Every group value occurs once after aggregation.
+                item_stat.most_common_values.clear();
                 if let Some(histogram) = &mut item_stat.histogram {
                     let mut num_values = 0.0;
                     let mut num_distinct = 0.0;
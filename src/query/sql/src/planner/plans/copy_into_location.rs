@@ -24,6 +24,9 @@ pub struct CopyIntoLocationPlan {
     pub stage: Box<StageInfo>,
     pub path: String,
     pub from: Box<Plan>,
+    /// The `PARTITION BY` expression. If set, the partition path of each row is
+    /// appended as the last column of `from`.
+    pub partition_by: Option<String>,
 }
 
 impl Debug for CopyIntoLocationPlan {
@@ -33,6 +36,9 @@ impl Debug for CopyIntoLocationPlan {
             "Copy into {:?}/{} from {:?}",
             self.stage, self.path, self.from
         )?;
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " partition by {partition_by}")?;
+        }
         Ok(())
     }
 }
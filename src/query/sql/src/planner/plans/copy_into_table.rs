@@ -33,6 +33,7 @@ use databend_common_meta_app::principal::COPY_MAX_FILES_COMMIT_MSG;
 use databend_common_meta_app::principal::COPY_MAX_FILES_PER_COMMIT;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_metrics::storage::*;
+use databend_common_pipeline_sources::input_formats::rejected_rows_schema;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::StageFileInfo;
 use log::info;
@@ -114,6 +115,8 @@ impl CopyIntoTablePlan {
         let start = Instant::now();
 
         let stage_table_info = &self.stage_table_info;
+        // RETURN_ALL_ERRORS also validates the files which have been loaded before.
+        let force = self.force || self.validation_mode == ValidationMode::ReturnAllErrors;
         let max_files = stage_table_info.stage_info.copy_options.max_files;
         let max_files = if max_files == 0 {
             None
@@ -123,7 +126,7 @@ impl CopyIntoTablePlan {
 
         let operator = init_stage_operator(&stage_table_info.stage_info)?;
         let all_source_file_infos = if operator.info().native_capability().blocking {
-            if self.force {
+            if force {
                 stage_table_info
                     .files_info
                     .blocking_list(&operator, false, max_files)
@@ -132,7 +135,7 @@ impl CopyIntoTablePlan {
                     .files_info
                     .blocking_list(&operator, false, None)
             }
-        } else if self.force {
+        } else if force {
             stage_table_info
                 .files_info
                 .list(&operator, false, max_files)
@@ -152,8 +155,9 @@ impl CopyIntoTablePlan {
 
         ctx.set_status_info(&format!("end list files: got {} files", num_all_files));
 
-        let need_copy_file_infos = if self.force {
-            if !self.stage_table_info.stage_info.copy_options.purge
+        let need_copy_file_infos = if force {
+            if self.validation_mode == ValidationMode::None
+                && !self.stage_table_info.stage_info.copy_options.purge
                 && all_source_file_infos.len() > COPY_MAX_FILES_PER_COMMIT
             {
                 return Err(ErrorCode::Internal(COPY_MAX_FILES_COMMIT_MSG));
@@ -251,9 +255,14 @@ impl CopyIntoTablePlan {
 
     pub fn schema(&self) -> DataSchemaRef {
         if self.from_attachment {
-            Arc::new(DataSchema::empty())
-        } else {
-            Self::copy_into_table_schema()
+            return Arc::new(DataSchema::empty());
+        }
+        match self.validation_mode {
+            ValidationMode::None => Self::copy_into_table_schema(),
+            ValidationMode::ReturnNRows(_) => self.required_source_schema.clone(),
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                rejected_rows_schema()
+            }
         }
     }
 }
@@ -14,6 +14,7 @@
 
 use databend_common_ast::ast::AlterPasswordAction;
 use databend_common_ast::ast::PasswordSetOptions;
+use databend_common_ast::ast::WorkloadGroupSetOptions;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
@@ -254,3 +255,80 @@ impl DescPasswordPolicyPlan {
         ])
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateWorkloadGroupPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub set_options: WorkloadGroupSetOptions,
+}
+
+impl CreateWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub set_options: WorkloadGroupSetOptions,
+}
+
+impl AlterWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescWorkloadGroupPlan {
+    pub name: String,
+}
+
+impl DescWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Name", DataType::String),
+            DataField::new("Max Concurrency", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Max Memory Usage", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Queue Timeout", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Users", DataType::String),
+            DataField::new("Roles", DataType::String),
+            DataField::new("Comment", DataType::String),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowWorkloadGroupsPlan {}
+
+impl ShowWorkloadGroupsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Name", DataType::String),
+            DataField::new("Max Concurrency", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Max Memory Usage", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Queue Timeout", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Users", DataType::String),
+            DataField::new("Roles", DataType::String),
+            DataField::new("Comment", DataType::String),
+        ])
+    }
+}
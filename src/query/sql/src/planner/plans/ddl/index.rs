@@ -34,10 +34,19 @@ pub struct CreateIndexPlan {
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropIndexPlan {
+    pub index_type: TableIndexType,
     pub if_exists: bool,
     pub index: String,
 }
 
+/// Build the n-gram filters of the blocks written before `ngram_index_columns` was set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshNgramIndexPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct RefreshIndexPlan {
     pub index_id: u64,
@@ -45,6 +45,8 @@ pub fn task_schema() -> DataSchemaRef {
         DataField::new("next_schedule_time", DataType::Timestamp.wrap_nullable()),
         DataField::new("last_committed_on", DataType::Timestamp),
         DataField::new("last_suspended_on", DataType::Timestamp.wrap_nullable()),
+        DataField::new("after", DataType::String),
+        DataField::new("condition_text", DataType::String.wrap_nullable()),
     ]))
 }
 
@@ -74,10 +76,12 @@ pub struct CreateTaskPlan {
     pub tenant: String,
     pub task_name: String,
     pub warehouse_opts: WarehouseOptions,
-    pub schedule_opts: ScheduleOptions,
+    pub schedule_opts: Option<ScheduleOptions>,
     pub suspend_task_after_num_failures: Option<u64>,
     pub sql: String,
     pub comment: String,
+    pub after: Vec<String>,
+    pub when_condition: Option<String>,
 }
 
 impl CreateTaskPlan {
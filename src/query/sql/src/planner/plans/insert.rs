@@ -16,11 +16,13 @@ use std::sync::Arc;
 
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::SendableDataBlockStream;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_types::MetaId;
 use databend_common_pipeline_sources::input_formats::InputContext;
+use parking_lot::Mutex;
 
 use super::Plan;
 
@@ -44,6 +46,9 @@ pub enum InsertInputSource {
     },
     // From stage
     Stage(Box<Plan>),
+    // From outside stream of data blocks, used in flight sql ingest only;
+    // the stream is taken by the interpreter when building the pipeline.
+    StreamingWithBlocks(Arc<Mutex<Option<SendableDataBlockStream>>>),
 }
 
 #[derive(Clone)]
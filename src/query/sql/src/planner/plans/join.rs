@@ -298,6 +298,7 @@ impl Join {
 
         if join_card_updated {
             for (idx, left) in left_statistics.column_stats.iter_mut() {
+                left.most_common_values.clear();
                 if *idx == left_column_index {
                     if left.histogram.is_some() {
                         left.histogram = if left.ndv as u64 <= 2 {
@@ -322,6 +323,7 @@ impl Join {
                 left.histogram = None;
             }
             for (idx, right) in right_statistics.column_stats.iter_mut() {
+                right.most_common_values.clear();
                 if *idx == right_column_index {
                     if right.histogram.is_some() {
                         right.histogram = if right.ndv as u64 <= 2 {
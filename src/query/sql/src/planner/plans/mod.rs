@@ -40,8 +40,11 @@ mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte;
+mod recursive_cte_scan;
 mod replace;
 mod revert_table;
+pub mod row_access_policy;
 mod scalar_expr;
 mod scan;
 mod setting;
@@ -84,8 +87,11 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::RecursiveCte;
+pub use recursive_cte_scan::RecursiveCteScan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use scalar_expr::*;
 pub use scan::*;
 pub use setting::*;
@@ -39,6 +39,8 @@ use crate::plans::ConstantTableScan;
 use crate::plans::CteScan;
 use crate::plans::Exchange;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
 use crate::plans::Udf;
 use crate::plans::Window;
 
@@ -95,6 +97,8 @@ pub enum RelOp {
     Window,
     ProjectSet,
     MaterializedCte,
+    RecursiveCte,
+    RecursiveCteScan,
     ConstantTableScan,
     AddRowNumber,
     Udf,
@@ -121,6 +125,8 @@ pub enum RelOperator {
     Window(Window),
     ProjectSet(ProjectSet),
     MaterializedCte(MaterializedCte),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
     Pattern(PatternPlan),
@@ -144,6 +150,8 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => rel_op.rel_op(),
             RelOperator::CteScan(rel_op) => rel_op.rel_op(),
             RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::AddRowNumber(rel_op) => rel_op.rel_op(),
             RelOperator::Udf(rel_op) => rel_op.rel_op(),
@@ -167,6 +175,8 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
@@ -190,6 +200,8 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
@@ -213,6 +225,8 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_stats(rel_expr),
@@ -272,6 +286,12 @@ impl Operator for RelOperator {
             RelOperator::MaterializedCte(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::ConstantTableScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -336,6 +356,12 @@ impl Operator for RelOperator {
             RelOperator::MaterializedCte(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::ConstantTableScan(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -409,6 +435,46 @@ impl TryFrom<RelOperator> for MaterializedCte {
     }
 }
 
+impl From<RecursiveCte> for RelOperator {
+    fn from(value: RecursiveCte) -> Self {
+        Self::RecursiveCte(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCte {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCte",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
+
 impl From<Join> for RelOperator {
     fn from(v: Join) -> Self {
         Self::Join(v)
@@ -28,8 +28,10 @@ use super::SetSecondaryRolesPlan;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterPipePlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
@@ -37,6 +39,7 @@ use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
@@ -48,7 +51,9 @@ use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::CreatePipePlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
@@ -59,12 +64,16 @@ use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DescSharePlan;
+use crate::plans::DescWorkloadGroupPlan;
+use crate::plans::DescribePipePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
 use crate::plans::DropCatalogPlan;
@@ -75,7 +84,9 @@ use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::DropPipePlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
 use crate::plans::DropStagePlan;
@@ -83,11 +94,13 @@ use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
@@ -101,6 +114,7 @@ use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshNgramIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
@@ -127,6 +141,7 @@ use crate::plans::ShowRolesPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::ShowSharesPlan;
 use crate::plans::ShowTasksPlan;
+use crate::plans::ShowWorkloadGroupsPlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UnSettingPlan;
 use crate::plans::UndropDatabasePlan;
@@ -206,6 +221,8 @@ pub enum Plan {
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
 
     // Insert
     Insert(Box<Insert>),
@@ -226,6 +243,7 @@ pub enum Plan {
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    RefreshNgramIndex(Box<RefreshNgramIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumn(Box<CreateVirtualColumnPlan>),
@@ -298,6 +316,11 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
@@ -311,6 +334,13 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Workload group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+    DescWorkloadGroup(Box<DescWorkloadGroupPlan>),
+    ShowWorkloadGroups(Box<ShowWorkloadGroupsPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -318,6 +348,17 @@ pub enum Plan {
     DescribeTask(Box<DescribeTaskPlan>),
     ShowTasks(Box<ShowTasksPlan>),
     ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Pipe
+    CreatePipe(Box<CreatePipePlan>),
+    AlterPipe(Box<AlterPipePlan>),
+    DropPipe(Box<DropPipePlan>),
+    DescribePipe(Box<DescribePipePlan>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
 }
 
 #[derive(Clone, Debug)]
@@ -375,6 +416,95 @@ impl Plan {
     }
 }
 
+impl Plan {
+    /// Only the queries, the DML on Fuse tables and the statements changing the
+    /// session state can be executed in an explicit transaction. The others change
+    /// the meta directly and can't be rolled back, so new plans are rejected by default.
+    pub fn is_allowed_in_transaction(&self) -> bool {
+        matches!(
+            self,
+            Plan::Query { .. }
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::ExplainAnalyze { .. }
+                | Plan::CopyIntoTable(_)
+                | Plan::CopyIntoLocation(_)
+                | Plan::Insert(_)
+                | Plan::Replace(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+                | Plan::MergeInto(_)
+                | Plan::ShowCreateCatalog(_)
+                | Plan::ShowCreateDatabase(_)
+                | Plan::ShowCreateTable(_)
+                | Plan::DescribeTable(_)
+                | Plan::ExistsTable(_)
+                | Plan::UseDatabase(_)
+                | Plan::ShowRoles(_)
+                | Plan::ShowGrants(_)
+                | Plan::SetRole(_)
+                | Plan::SetSecondaryRoles(_)
+                | Plan::ShowFileFormats(_)
+                | Plan::DescConnection(_)
+                | Plan::ShowConnections(_)
+                | Plan::Presign(_)
+                | Plan::SetVariable(_)
+                | Plan::UnSetVariable(_)
+                | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
+                | Plan::DescNetworkPolicy(_)
+                | Plan::ShowNetworkPolicies(_)
+                | Plan::DescPasswordPolicy(_)
+                | Plan::DescWorkloadGroup(_)
+                | Plan::ShowWorkloadGroups(_)
+                | Plan::DescribeTask(_)
+                | Plan::ShowTasks(_)
+                | Plan::DescribePipe(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort
+        )
+    }
+
+    /// The `(catalog, database, table)` written by the DML plans.
+    pub fn write_target(&self) -> Option<(&str, &str, &str)> {
+        match self {
+            Plan::Insert(plan) => Some((
+                plan.catalog.as_str(),
+                plan.database.as_str(),
+                plan.table.as_str(),
+            )),
+            Plan::Replace(plan) => Some((
+                plan.catalog.as_str(),
+                plan.database.as_str(),
+                plan.table.as_str(),
+            )),
+            Plan::Update(plan) => Some((
+                plan.catalog.as_str(),
+                plan.database.as_str(),
+                plan.table.as_str(),
+            )),
+            Plan::MergeInto(plan) => Some((
+                plan.catalog.as_str(),
+                plan.database.as_str(),
+                plan.table.as_str(),
+            )),
+            Plan::Delete(plan) => Some((
+                plan.catalog_name.as_str(),
+                plan.database_name.as_str(),
+                plan.table_name.as_str(),
+            )),
+            Plan::CopyIntoTable(plan) => Some((
+                plan.catalog_info.catalog_name(),
+                plan.database_name.as_str(),
+                plan.table_name.as_str(),
+            )),
+            _ => None,
+        }
+    }
+}
+
 impl Display for Plan {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.kind())
@@ -418,15 +548,21 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
             Plan::DescPasswordPolicy(plan) => plan.schema(),
+            Plan::DescWorkloadGroup(plan) => plan.schema(),
+            Plan::ShowWorkloadGroups(plan) => plan.schema(),
             Plan::CopyIntoTable(plan) => plan.schema(),
             Plan::MergeInto(plan) => plan.schema(),
             Plan::CreateTask(plan) => plan.schema(),
             Plan::DescribeTask(plan) => plan.schema(),
             Plan::ShowTasks(plan) => plan.schema(),
             Plan::ExecuteTask(plan) => plan.schema(),
+            Plan::DescribePipe(plan) => plan.schema(),
 
             Plan::DescConnection(plan) => plan.schema(),
             Plan::ShowConnections(plan) => plan.schema(),
@@ -462,12 +598,16 @@ impl Plan {
                 | Plan::VacuumTable(_)
                 | Plan::VacuumDropTable(_)
                 | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
                 | Plan::DescPasswordPolicy(_)
+                | Plan::DescWorkloadGroup(_)
+                | Plan::ShowWorkloadGroups(_)
                 | Plan::CopyIntoTable(_)
                 | Plan::ShowTasks(_)
                 | Plan::DescribeTask(_)
+                | Plan::DescribePipe(_)
                 | Plan::DescConnection(_)
                 | Plan::ShowConnections(_)
                 | Plan::MergeInto(_)
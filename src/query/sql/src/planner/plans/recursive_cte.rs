@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `RecursiveCte` evaluates `anchor UNION [ALL] recursive` of a `WITH RECURSIVE` cte.
+///
+/// The left child is the anchor term, the right child is the recursive term,
+/// which reads the rows produced by the previous iteration through a `RecursiveCteScan`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCte {
+    pub cte_idx: IndexType,
+    // `UNION` discards the rows that have been produced before, `UNION ALL` keeps them.
+    pub distinct: bool,
+    // Pairs of (anchor column, recursive column)
+    pub pairs: Vec<(IndexType, IndexType)>,
+}
+
+impl RecursiveCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        for (left, right) in &self.pairs {
+            used_columns.insert(*left);
+            used_columns.insert(*right);
+        }
+        Ok(used_columns)
+    }
+}
+
+impl Operator for RecursiveCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCte
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        // Only the anchor columns are visible to the parent
+        let output_columns = self.pairs.iter().map(|(left, _)| *left).collect();
+
+        let mut outer_columns = left_prop.outer_columns.clone();
+        outer_columns = outer_columns
+            .union(&right_prop.outer_columns)
+            .cloned()
+            .collect();
+
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(left_prop.used_columns.clone());
+        used_columns.extend(right_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            orderings: vec![],
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        // The number of iterations is unknown before execution,
+        // so the anchor is the best estimation we have.
+        let left_stat_info = rel_expr.derive_cardinality_child(0)?;
+        Ok(Arc::new(StatInfo {
+            cardinality: left_stat_info.cardinality,
+            statistics: left_stat_info.statistics.clone(),
+        }))
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        _required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // The working table lives in the local query context, so it can't be distributed.
+        Ok(RequiredProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_children(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _required: &RequiredProperty,
+    ) -> Result<Vec<Vec<RequiredProperty>>> {
+        Ok(vec![vec![
+            RequiredProperty {
+                distribution: Distribution::Serial,
+            },
+            RequiredProperty {
+                distribution: Distribution::Serial,
+            },
+        ]])
+    }
+}
@@ -22,9 +22,11 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
+use databend_common_storage::Datum;
 use itertools::Itertools;
 
 use super::ScalarItem;
+use crate::optimizer::histogram_from_column_histogram;
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
@@ -78,6 +80,18 @@ impl AggIndexInfo {
     }
 }
 
+/// A top-k distance query on a column that has a vector index,
+/// it is `ORDER BY <func_name>(<column>, <target>) LIMIT <limit>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    pub index_id: u64,
+    pub column: IndexType,
+    pub func_name: String,
+    /// The constant vector to compute the distances with.
+    pub target: ScalarExpr,
+    pub limit: usize,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Statistics {
     // statistics will be ignored in comparison and hashing
@@ -95,6 +109,7 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    pub vector_index: Option<VectorIndexInfo>,
 
     pub statistics: Statistics,
 }
@@ -121,6 +136,7 @@ impl Scan {
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            vector_index: self.vector_index.clone(),
         }
     }
 
@@ -201,19 +217,38 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
-                .ok();
+                // Prefer the histogram collected by `ANALYZE TABLE` to the uniform one.
+                let histogram = col_stat
+                    .histogram
+                    .as_ref()
+                    .and_then(histogram_from_column_histogram)
+                    .or_else(|| {
+                        histogram_from_ndv(
+                            ndv,
+                            num_rows,
+                            Some((min.clone(), max.clone())),
+                            DEFAULT_HISTOGRAM_BUCKETS,
+                        )
+                        .ok()
+                    });
+                let most_common_values = match &col_stat.histogram {
+                    Some(histogram) if num_rows > 0 => histogram
+                        .most_common_values
+                        .iter()
+                        .filter_map(|(value, count)| {
+                            let fraction = (*count as f64 / num_rows as f64).min(1.0);
+                            Some((Datum::from_scalar(value.clone())?, fraction))
+                        })
+                        .collect(),
+                    _ => vec![],
+                };
                 let column_stat = ColumnStat {
                     min,
                     max,
                     ndv: ndv as f64,
                     null_count: col_stat.null_count,
                     histogram,
+                    most_common_values,
                 };
                 column_stats.insert(*k as IndexType, column_stat);
             }
@@ -18,6 +18,7 @@ mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
+mod placeholder_rewriter;
 mod type_check;
 mod udf_rewriter;
 mod view_rewriter;
@@ -35,6 +36,8 @@ pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
+pub use placeholder_rewriter::BoundParams;
+pub use placeholder_rewriter::PlaceholderRewriter;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
@@ -0,0 +1,397 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use chrono_tz::Tz;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::Dialect;
+use databend_common_ast::Visitor;
+use databend_common_ast::VisitorMut;
+use databend_common_catalog::table_context::QueryParamValue;
+use databend_common_catalog::table_context::QueryParams;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::types::date::date_to_string;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::Scalar;
+use serde_json::Value;
+
+/// Binds the query parameters to the placeholders of a statement as constants.
+///
+/// Positional placeholders `?` are numbered by their position in the SQL text,
+/// named placeholders `:name` are looked up by name. To describe a statement, the
+/// placeholders are left unbound for the type checker.
+pub struct PlaceholderRewriter<'a> {
+    params: &'a QueryParams,
+    // The start offset of each positional placeholder to its index.
+    positions: HashMap<u32, usize>,
+    used_names: HashSet<String>,
+    bound: BoundParams,
+    error: Option<ErrorCode>,
+}
+
+/// The spans of the placeholders bound to the query parameters.
+#[derive(Debug, Clone, Default)]
+pub struct BoundParams {
+    params: Vec<(Span, String)>,
+}
+
+impl BoundParams {
+    /// Names the query parameters whose values the error is raised on, e.g. a value
+    /// which can not be cast to the type it is used as.
+    pub fn annotate_error(&self, error: ErrorCode) -> ErrorCode {
+        let Some(error_span) = error.span() else {
+            return error;
+        };
+        let params = self
+            .params
+            .iter()
+            .filter(|(span, _)| {
+                span.is_some_and(|span| span.start < error_span.end && error_span.start < span.end)
+            })
+            .map(|(_, param)| param.as_str())
+            .collect::<Vec<_>>();
+        if params.is_empty() {
+            return error;
+        }
+        error.add_message(format!(
+            "Invalid value of the query parameter {}",
+            params.join(", ")
+        ))
+    }
+}
+
+impl<'a> PlaceholderRewriter<'a> {
+    pub fn rewrite(
+        params: &'a QueryParams,
+        sql_dialect: Dialect,
+        stmt: &mut Statement,
+    ) -> Result<BoundParams> {
+        let mut rewriter = PlaceholderRewriter {
+            params,
+            positions: HashMap::new(),
+            used_names: HashSet::new(),
+            bound: BoundParams::default(),
+            error: None,
+        };
+
+        Self::parse_insert_values(sql_dialect, stmt)?;
+        if params.describe {
+            return Ok(BoundParams::default());
+        }
+
+        rewriter.positions = Self::positional_placeholders(stmt)
+            .into_iter()
+            .enumerate()
+            .map(|(index, start)| (start, index))
+            .collect();
+
+        rewriter.visit_statement(stmt);
+        if let Some(error) = rewriter.error {
+            return Err(error);
+        }
+        rewriter.check_unused()?;
+        Ok(rewriter.bound)
+    }
+
+    /// Every query parameter given must be bound to a placeholder of the statement.
+    fn check_unused(&self) -> Result<()> {
+        let unused = (self.positions.len()..self.params.positional.len())
+            .map(|index| format!("#{}", index + 1))
+            .chain(
+                self.params
+                    .named
+                    .keys()
+                    .filter(|name| !self.used_names.contains(*name))
+                    .map(|name| format!(":{name}")),
+            )
+            .collect::<Vec<_>>();
+        if unused.is_empty() {
+            return Ok(());
+        }
+        Err(ErrorCode::BadArguments(format!(
+            "The query parameter {} is not used by the statement",
+            unused.join(", ")
+        )))
+    }
+
+    /// The start offsets of the positional placeholders of the statement, in order.
+    pub fn positional_placeholders(stmt: &Statement) -> Vec<u32> {
+        let mut collector = PositionalPlaceholderCollector::default();
+        collector.visit_statement(stmt);
+        collector.starts.sort_unstable();
+        collector.starts
+    }
+
+    /// The values of `INSERT ... VALUES` are not parsed with the statement,
+    /// parse them as a `VALUES` query to reach their placeholders.
+    pub fn parse_insert_values(sql_dialect: Dialect, stmt: &mut Statement) -> Result<()> {
+        let Statement::Insert(insert) = stmt else {
+            return Ok(());
+        };
+        if let InsertSource::Values { rest_str, start } = &insert.source {
+            // The values are padded to their offset in the statement,
+            // so the spans of the tokens point into the original SQL.
+            let keyword = "VALUES ";
+            let values = format!(
+                "{}{keyword}{}",
+                " ".repeat(start.saturating_sub(keyword.len())),
+                rest_str.trim_end_matches(';').trim_end()
+            );
+            // The values may not be valid SQL tokens, e.g. when the data of
+            // a stage attachment follows.
+            let Ok(tokens) = tokenize_sql(&values) else {
+                return Ok(());
+            };
+            let has_placeholder = tokens
+                .iter()
+                .any(|token| matches!(token.kind, TokenKind::Placeholder | TokenKind::Colon));
+            if !has_placeholder {
+                return Ok(());
+            }
+            if let (Statement::Query(query), _) = parse_sql(&tokens, sql_dialect)? {
+                insert.source = InsertSource::Select { query };
+            }
+        }
+        Ok(())
+    }
+
+    fn bind(&mut self, span: Span, name: &Option<Identifier>) -> Result<Expr> {
+        let (param, value) = match name {
+            Some(name) => {
+                self.used_names.insert(name.name.clone());
+                (format!(":{}", name.name), self.params.named.get(&name.name))
+            }
+            None => {
+                let index = span.and_then(|span| self.positions.get(&span.start).copied());
+                (
+                    format!("#{}", index.map_or(0, |index| index + 1)),
+                    index.and_then(|index| self.params.positional.get(index)),
+                )
+            }
+        };
+        let expr = match value {
+            Some(value) => Self::value_to_expr(span, &param, value)?,
+            None => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "No value is given for the query parameter {param}"
+                ))
+                .set_span(span));
+            }
+        };
+        self.bound.params.push((span, param));
+        Ok(expr)
+    }
+
+    fn value_to_expr(span: Span, param: &str, value: &QueryParamValue) -> Result<Expr> {
+        match value {
+            QueryParamValue::Json(value) => Self::json_to_expr(span, param, value),
+            QueryParamValue::Scalar(scalar) => Self::scalar_to_expr(span, param, scalar),
+        }
+    }
+
+    fn json_to_expr(span: Span, param: &str, value: &Value) -> Result<Expr> {
+        let lit = match value {
+            Value::Null => Literal::Null,
+            Value::Bool(v) => Literal::Boolean(*v),
+            Value::String(v) => Literal::String(v.clone()),
+            Value::Number(v) => {
+                return Ok(if let Some(v) = v.as_u64() {
+                    Self::uint_expr(span, v)
+                } else if let Some(v) = v.as_i64() {
+                    Self::int_expr(span, v)
+                } else {
+                    Self::float_expr(span, v.as_f64().unwrap_or_default())
+                });
+            }
+            Value::Array(values) => {
+                let exprs = values
+                    .iter()
+                    .map(|value| Self::json_to_expr(span, param, value))
+                    .collect::<Result<Vec<_>>>()?;
+                return Ok(Expr::Array { span, exprs });
+            }
+            Value::Object(_) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Invalid value {value} of the query parameter {param}, expected null, boolean, number, string or array"
+                ))
+                .set_span(span));
+            }
+        };
+        Ok(Expr::Literal { span, lit })
+    }
+
+    /// The typed values keep their types, those without a literal form are cast from strings.
+    fn scalar_to_expr(span: Span, param: &str, scalar: &Scalar) -> Result<Expr> {
+        let cast = |value: String, target_type: TypeName| Expr::Cast {
+            span,
+            expr: Box::new(Expr::Literal {
+                span,
+                lit: Literal::String(value),
+            }),
+            target_type,
+            pg_style: false,
+        };
+        let lit = match scalar {
+            Scalar::Null => Literal::Null,
+            Scalar::Boolean(v) => Literal::Boolean(*v),
+            Scalar::String(v) => match std::str::from_utf8(v) {
+                Ok(v) => Literal::String(v.to_string()),
+                Err(_) => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Invalid value of the query parameter {param}, expected UTF-8 string"
+                    ))
+                    .set_span(span));
+                }
+            },
+            Scalar::Number(v) => {
+                return Ok(match v {
+                    NumberScalar::UInt8(v) => Self::uint_expr(span, *v as u64),
+                    NumberScalar::UInt16(v) => Self::uint_expr(span, *v as u64),
+                    NumberScalar::UInt32(v) => Self::uint_expr(span, *v as u64),
+                    NumberScalar::UInt64(v) => Self::uint_expr(span, *v),
+                    NumberScalar::Int8(v) => Self::int_expr(span, *v as i64),
+                    NumberScalar::Int16(v) => Self::int_expr(span, *v as i64),
+                    NumberScalar::Int32(v) => Self::int_expr(span, *v as i64),
+                    NumberScalar::Int64(v) => Self::int_expr(span, *v),
+                    NumberScalar::Float32(v) => Self::float_expr(span, v.0 as f64),
+                    NumberScalar::Float64(v) => Self::float_expr(span, v.0),
+                });
+            }
+            Scalar::Decimal(v) => {
+                let (DecimalScalar::Decimal128(_, size) | DecimalScalar::Decimal256(_, size)) = v;
+                return Ok(cast(v.to_string(), TypeName::Decimal {
+                    precision: size.precision,
+                    scale: size.scale,
+                }));
+            }
+            Scalar::Date(v) => {
+                return Ok(cast(
+                    date_to_string(*v, Tz::UTC).to_string(),
+                    TypeName::Date,
+                ));
+            }
+            Scalar::Timestamp(v) => {
+                // The trailing `Z` keeps the instant regardless of the session time zone.
+                let value = format!("{}Z", timestamp_to_string(*v, Tz::UTC));
+                return Ok(cast(value, TypeName::Timestamp));
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Invalid value {scalar} of the query parameter {param}, expected a scalar value"
+                ))
+                .set_span(span));
+            }
+        };
+        Ok(Expr::Literal { span, lit })
+    }
+
+    fn uint_expr(span: Span, v: u64) -> Expr {
+        Expr::Literal {
+            span,
+            lit: Literal::UInt64(v),
+        }
+    }
+
+    // Negative numbers are parsed as the negation of the literal.
+    fn int_expr(span: Span, v: i64) -> Expr {
+        let expr = Self::uint_expr(span, v.unsigned_abs());
+        if v < 0 {
+            Expr::UnaryOp {
+                span,
+                op: UnaryOperator::Minus,
+                expr: Box::new(expr),
+            }
+        } else {
+            expr
+        }
+    }
+
+    fn float_expr(span: Span, v: f64) -> Expr {
+        if !v.is_finite() {
+            let value = if v.is_nan() {
+                "nan"
+            } else if v > 0.0 {
+                "inf"
+            } else {
+                "-inf"
+            };
+            return Expr::Cast {
+                span,
+                expr: Box::new(Expr::Literal {
+                    span,
+                    lit: Literal::String(value.to_string()),
+                }),
+                target_type: TypeName::Float64,
+                pg_style: false,
+            };
+        }
+        let expr = Expr::Literal {
+            span,
+            lit: Literal::Float64(v.abs()),
+        };
+        if v.is_sign_negative() {
+            Expr::UnaryOp {
+                span,
+                op: UnaryOperator::Minus,
+                expr: Box::new(expr),
+            }
+        } else {
+            expr
+        }
+    }
+}
+
+impl<'a> VisitorMut for PlaceholderRewriter<'a> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Placeholder { span, name } = expr {
+            match self.bind(*span, name) {
+                Ok(new_expr) => *expr = new_expr,
+                Err(error) => {
+                    self.error.get_or_insert(error);
+                }
+            }
+            return;
+        }
+        walk_expr_mut(self, expr);
+    }
+}
+
+#[derive(Default)]
+struct PositionalPlaceholderCollector {
+    starts: Vec<u32>,
+}
+
+impl<'ast> Visitor<'ast> for PositionalPlaceholderCollector {
+    fn visit_placeholder(&mut self, span: Span, name: &'ast Option<Identifier>) {
+        if let (Some(span), None) = (span, name) {
+            self.starts.push(span.start);
+        }
+    }
+}
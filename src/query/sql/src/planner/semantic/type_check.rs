@@ -1043,6 +1043,27 @@ impl<'a> TypeChecker<'a> {
             Expr::Map { span, kvs, .. } => self.resolve_map(*span, kvs).await?,
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs).await?,
+
+            Expr::Placeholder { span, .. } => {
+                let describe = self.ctx.get_query_params().is_some_and(|p| p.describe);
+                if !describe {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "No value is bound to the query parameter {expr}"
+                    ))
+                    .set_span(*span));
+                }
+                // Describing the statement, the placeholder is typed by where it is used.
+                if let Some(span) = span {
+                    self.metadata
+                        .write()
+                        .set_placeholder_type(span.start, DataType::Null);
+                }
+                let scalar = ScalarExpr::ConstantExpr(ConstantExpr {
+                    span: *span,
+                    value: Scalar::Null,
+                });
+                (scalar, DataType::Null)
+            }
         };
 
         Ok(Box::new((scalar, data_type)))
@@ -1966,11 +1987,31 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// Infers the type of an unbound placeholder from the column it is combined with,
+    /// e.g. `a > ?`, to describe a prepared statement.
+    #[async_backtrace::framed]
+    async fn infer_placeholder_type(&mut self, placeholder: &Expr, other: &Expr) -> Result<()> {
+        if let (
+            Expr::Placeholder {
+                span: Some(span), ..
+            },
+            Expr::ColumnRef { .. },
+        ) = (placeholder, other)
+        {
+            let box (_, data_type) = self.resolve(other).await?;
+            self.metadata
+                .write()
+                .set_placeholder_type(span.start, data_type.remove_nullable());
+        }
+        Ok(())
+    }
+
     /// Resolve binary expressions. Most of the binary expressions
     /// would be transformed into `FunctionCall`, except comparison
     /// expressions, conjunction(`AND`) and disjunction(`OR`).
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
+    #[async_backtrace::framed]
     pub async fn resolve_binary_op(
         &mut self,
         span: Span,
@@ -1978,6 +2019,9 @@ impl<'a> TypeChecker<'a> {
         left: &Expr,
         right: &Expr,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
+        self.infer_placeholder_type(left, right).await?;
+        self.infer_placeholder_type(right, left).await?;
+
         match op {
             BinaryOperator::NotLike | BinaryOperator::NotRegexp | BinaryOperator::NotRLike => {
                 let positive_op = match op {
@@ -2605,6 +2649,25 @@ impl<'a> TypeChecker<'a> {
                     .ok()?;
                 Some(self.resolve_scalar_function_call(span, "array_min", vec![], vec![array]))
             }
+            ("stream_has_data", &[arg]) => {
+                if !matches!(arg, Expr::Literal {
+                    lit: Literal::String(_),
+                    ..
+                }) {
+                    return Some(Err(ErrorCode::SemanticError(
+                        "stream_has_data only accepts a constant stream name",
+                    )
+                    .set_span(span)));
+                }
+                // Rewrite to `(SELECT has_data FROM stream_status('<stream>'))`.
+                let sql = format!("(SELECT has_data FROM stream_status({}))", arg);
+                let expr =
+                    match tokenize_sql(&sql).and_then(|tokens| parse_expr(&tokens, self.dialect)) {
+                        Ok(expr) => expr,
+                        Err(e) => return Some(Err(e.set_span(span))),
+                    };
+                Some(self.resolve(&expr).await)
+            }
             _ => None,
         }
     }
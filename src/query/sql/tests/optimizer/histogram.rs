@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_sql::optimizer::histogram_from_column_histogram;
 use databend_common_sql::optimizer::Histogram;
 use databend_common_sql::optimizer::HistogramBucket;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
+use databend_storages_common_table_meta::meta::ColumnHistogramBucket;
 
 #[test]
 fn test_histogram() {
@@ -28,3 +33,38 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_from_column_histogram() {
+    let bucket =
+        |lower: u64, upper: u64, num_values: u64, num_distinct: u64| ColumnHistogramBucket {
+            lower_bound: Scalar::Number(NumberScalar::UInt64(lower)),
+            upper_bound: Scalar::Number(NumberScalar::UInt64(upper)),
+            num_values,
+            num_distinct,
+        };
+    let column_histogram = ColumnHistogram {
+        buckets: vec![bucket(1, 1, 90, 1), bucket(2, 10, 10, 9)],
+        most_common_values: vec![(Scalar::Number(NumberScalar::UInt64(1)), 90)],
+    };
+
+    let histogram = histogram_from_column_histogram(&column_histogram).unwrap();
+    // The first bucket records the min value.
+    assert_eq!(histogram.num_buckets(), 3);
+    assert_eq!(histogram.buckets[0].upper_bound(), &Datum::UInt(1));
+    assert_eq!(histogram.buckets[2].upper_bound(), &Datum::UInt(10));
+    assert_eq!(histogram.num_values(), 100.0);
+    assert_eq!(histogram.num_distinct_values(), 10.0);
+
+    // Only numeric bounds are supported.
+    let column_histogram = ColumnHistogram {
+        buckets: vec![ColumnHistogramBucket {
+            lower_bound: Scalar::String(b"a".to_vec()),
+            upper_bound: Scalar::String(b"b".to_vec()),
+            num_values: 2,
+            num_distinct: 2,
+        }],
+        most_common_values: vec![],
+    };
+    assert!(histogram_from_column_histogram(&column_histogram).is_none());
+}
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -25,12 +26,14 @@ use databend_common_exception::Result;
 use databend_common_exception::Span;
 use databend_common_expression::converts::scalar_to_datavalue;
 use databend_common_expression::eval_function;
+use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::MapType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::BlockEntry;
@@ -123,6 +126,10 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
 ///         |  123456789abcd |  ac2345bcd   |
 ///         +----------------+--------------+
 /// ```
+///
+/// String columns can also be indexed by an n-gram filter, which holds the digests of all the
+/// n-grams of the column values. It is stored as column 'Ngram(column_id)' of the same filter
+/// block, and is used to prune blocks for `LIKE` and `match` predicates.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -152,6 +159,9 @@ pub enum FilterEvalResult {
     Uncertain,
 }
 
+/// The number of characters of a gram in the n-gram filter.
+pub const NGRAM_SIZE: usize = 3;
+
 impl BloomIndex {
     /// Load a filter directly from the source table's schema and the corresponding filter parquet file.
     #[minitrace::trace]
@@ -178,6 +188,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -267,6 +278,57 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for (index, field) in ngram_columns_map.into_iter() {
+            let Some(filter_name) = Self::build_ngram_column_name(version, &field) else {
+                break;
+            };
+            let field_type = &data_blocks_tobe_indexed[0].get_by_offset(index).data_type;
+            if !Self::ngram_supported_data_type(field_type) {
+                continue;
+            }
+            let source_columns_iter = data_blocks_tobe_indexed.iter().map(|block| {
+                let value = &block.get_by_offset(index).value;
+                value.convert_to_full_column(field_type, block.num_rows())
+            });
+            let column = Column::concat_columns(source_columns_iter)?;
+            let column = match column {
+                Column::Nullable(box nullable_column) => nullable_column.column,
+                column => column,
+            };
+            let column = StringType::try_downcast_column(&column).unwrap();
+
+            // Values of null rows are empty, which have no grams.
+            let mut grams = HashSet::new();
+            for value in column.iter() {
+                if let Ok(value) = std::str::from_utf8(value) {
+                    grams.extend(Self::ngrams(value));
+                }
+            }
+            if grams.is_empty() {
+                continue;
+            }
+            let mut builder = StringColumnBuilder::with_capacity(grams.len(), grams.len() * 4);
+            for gram in grams {
+                builder.put_str(gram);
+                builder.commit_row();
+            }
+            let grams_column = Column::String(builder.build());
+            let digests = Self::calculate_column_digest(
+                &func_ctx,
+                &grams_column,
+                &DataType::String,
+                &DataType::Number(NumberDataType::UInt64),
+            )?;
+            let digests = UInt64Type::try_downcast_column(&digests).unwrap();
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(digests.deref());
+            let filter = filter_builder.build()?;
+
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -327,6 +389,26 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_column_ngram_predicate(&mut expr, &mut |span, col_name, grams, return_type| {
+            let Some(filter_column) = &Self::build_ngram_column_name(
+                self.version,
+                data_schema.field_with_name(col_name)?,
+            ) else {
+                return Ok(None);
+            };
+
+            // If the column doesn't contain some grams of the predicate, no row can match.
+            if self.find_ngrams(filter_column, grams, scalar_map)? == FilterEvalResult::MustFalse {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -406,7 +488,8 @@ impl BloomIndex {
         Ok(digest)
     }
 
-    /// Find all columns that match the pattern of `col = <constant>` in the expression.
+    /// Find all columns that match the pattern of `col = <constant>` in the expression,
+    /// including the values of the `col IN (<constant>, ...)` lists.
     pub fn find_eq_columns(
         expr: &Expr<String>,
         fields: Vec<TableField>,
@@ -423,6 +506,23 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns that match the pattern of `col LIKE <constant>` or
+    /// `match(col, <constant>)` in the expression,
+    /// returns the grams that a matching value must contain.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<(TableField, Vec<String>)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_ngram_predicate(&mut expr.clone(), &mut |_, col_name, grams, _| {
+            if let Some(v) = fields.iter().find(|f: &&TableField| f.name() == col_name) {
+                cols.push((v.clone(), grams.to_vec()));
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -438,6 +538,109 @@ impl BloomIndex {
         }
     }
 
+    /// The n-gram filter is only available since the V4 index format.
+    /// It will be stored with field name 'Ngram(column_id)'
+    pub fn build_ngram_column_name(version: u64, field: &TableField) -> Option<String> {
+        match BlockBloomFilterIndexVersion::try_from(version) {
+            Ok(BlockBloomFilterIndexVersion::V4(_)) => {
+                Some(format!("Ngram({})", field.column_id()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Split a string into grams of [`NGRAM_SIZE`] characters.
+    pub fn ngrams(value: &str) -> Vec<&str> {
+        let boundaries = value
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(value.len()))
+            .collect::<Vec<_>>();
+        boundaries
+            .windows(NGRAM_SIZE + 1)
+            .map(|w| &value[w[0]..w[NGRAM_SIZE]])
+            .collect()
+    }
+
+    /// Collect the grams of the literal parts of a `LIKE` pattern.
+    ///
+    /// Wildcards split the pattern into literal parts. An escaped character is skipped
+    /// together with the backslash, as the fast paths of `LIKE` may match it verbatim.
+    pub fn like_pattern_ngrams(pattern: &[u8]) -> Vec<String> {
+        let mut literals = Vec::new();
+        let mut start = 0;
+        let mut i = 0;
+        while i < pattern.len() {
+            match pattern[i] {
+                b'%' | b'_' | b'\\' => {
+                    literals.push(&pattern[start..i]);
+                    if pattern[i] == b'\\' {
+                        i += 1;
+                    }
+                    start = i + 1;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        if start < pattern.len() {
+            literals.push(&pattern[start..]);
+        }
+
+        let mut grams = Vec::new();
+        for literal in literals {
+            if let Ok(literal) = std::str::from_utf8(literal) {
+                for gram in Self::ngrams(literal) {
+                    if !grams.iter().any(|g| g == gram) {
+                        grams.push(gram.to_string());
+                    }
+                }
+            }
+        }
+        grams
+    }
+
+    /// Collect the grams of the terms of a `match` query.
+    ///
+    /// The terms are separated by whitespaces, a matching value contains all of them.
+    pub fn match_query_ngrams(query: &[u8]) -> Vec<String> {
+        let mut grams = Vec::new();
+        for term in query.split(|c| c.is_ascii_whitespace()) {
+            if let Ok(term) = std::str::from_utf8(term) {
+                for gram in Self::ngrams(term) {
+                    if !grams.iter().any(|g| g == gram) {
+                        grams.push(gram.to_string());
+                    }
+                }
+            }
+        }
+        grams
+    }
+
+    fn find_ngrams(
+        &self,
+        filter_column: &str,
+        grams: &[String],
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+        for gram in grams {
+            let contains = scalar_map
+                .get(&Scalar::String(gram.as_bytes().to_vec()))
+                .map_or(true, |digest| filter.contains_digest(*digest));
+            if !contains {
+                return Ok(FilterEvalResult::MustFalse);
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
+
     fn find(
         &self,
         filter_column: &str,
@@ -488,6 +691,15 @@ impl BloomIndex {
         Xor8Filter::supported_type(data_type)
     }
 
+    pub fn ngram_supported_type(data_type: &TableDataType) -> bool {
+        let data_type = DataType::from(data_type);
+        Self::ngram_supported_data_type(&data_type)
+    }
+
+    pub fn ngram_supported_data_type(data_type: &DataType) -> bool {
+        data_type.remove_nullable() == DataType::String
+    }
+
     /// Checks if the average length of a string column exceeds 256 bytes.
     /// If it does, the bloom index for the column will not be established.
     fn check_large_string(column: &Column) -> bool {
@@ -573,6 +785,57 @@ fn visit_expr_column_eq_constant(
             }
             _ => (),
         },
+        // `Column IN (<constant>, ...)` is resolved as `contains(<constant array>, Column)`
+        // when the list is long, it is false only if none of the values exist.
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } if function.signature.name == "contains" => {
+            if let [
+                Expr::Constant {
+                    scalar: Scalar::Array(values),
+                    data_type: DataType::Array(box value_type),
+                    ..
+                },
+                Expr::ColumnRef {
+                    id,
+                    data_type: column_type,
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                if value_type.remove_nullable() == column_type.remove_nullable()
+                    && !values.is_empty()
+                {
+                    let mut all_absent = true;
+                    for value in values.iter() {
+                        let new_expr =
+                            visitor(*span, id, &value.to_owned(), column_type, return_type)?;
+                        if !matches!(
+                            new_expr,
+                            Some(Expr::Constant {
+                                scalar: Scalar::Boolean(false),
+                                ..
+                            })
+                        ) {
+                            all_absent = false;
+                        }
+                    }
+                    if all_absent {
+                        let new_expr = Expr::Constant {
+                            span: *span,
+                            scalar: Scalar::Boolean(false),
+                            data_type: return_type.clone(),
+                        };
+                        *expr = new_expr;
+                        return Ok(());
+                    }
+                }
+            }
+        }
         _ => (),
     }
 
@@ -592,6 +855,60 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+fn visit_expr_column_ngram_predicate(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[String], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>` or `match(Column, <constant>)`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let name = function.signature.name.as_str();
+        if name == "like" || name == "match" {
+            if let [
+                Expr::ColumnRef { id, data_type, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(pattern),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                let grams = if name == "like" {
+                    BloomIndex::like_pattern_ngrams(pattern)
+                } else {
+                    BloomIndex::match_query_ngrams(pattern)
+                };
+                if BloomIndex::ngram_supported_data_type(data_type) && !grams.is_empty() {
+                    if let Some(new_expr) = visitor(*span, id, &grams, return_type)? {
+                        *expr = new_expr;
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_ngram_predicate(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_ngram_predicate(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
@@ -118,6 +118,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -251,6 +252,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -291,6 +293,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -309,6 +312,222 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_bloom_filter_in_list() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec!["a", "b", "c"]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let bloom_columns = bloom_columns_map(schema.clone(), vec![0, 1]);
+    let fields = bloom_columns.values().cloned().collect::<Vec<_>>();
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        bloom_columns,
+        BTreeMap::new(),
+    )?
+    .unwrap();
+
+    let column = |name: &str, data_type: DataType| Expr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type,
+        display_name: name.to_string(),
+    };
+    let eq = |name: &str, val: u8| {
+        check_function(
+            None,
+            "eq",
+            &[],
+            &[
+                column(name, DataType::Number(NumberDataType::UInt8)),
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Number(NumberScalar::UInt8(val)),
+                    data_type: DataType::Number(NumberDataType::UInt8),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap()
+    };
+    let or = |lhs: Expr<String>, rhs: Expr<String>| {
+        check_function(None, "or", &[], &[lhs, rhs], &BUILTIN_FUNCTIONS).unwrap()
+    };
+    let in_list = |name: &str, values: Column| {
+        let data_type = values.data_type();
+        check_function(
+            None,
+            "contains",
+            &[],
+            &[
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Array(values),
+                    data_type: DataType::Array(Box::new(data_type.clone())),
+                },
+                column(name, data_type),
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap()
+    };
+
+    for (expr, expected) in [
+        (or(eq("0", 5), eq("0", 6)), FilterEvalResult::MustFalse),
+        (or(eq("0", 5), eq("0", 3)), FilterEvalResult::Uncertain),
+        (
+            or(or(eq("0", 4), eq("0", 5)), eq("0", 6)),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            in_list("0", UInt8Type::from_data(vec![4, 5, 6, 7])),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            in_list("0", UInt8Type::from_data(vec![4, 5, 6, 1])),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            in_list("1", StringType::from_data(vec!["x", "y", "z", "w"])),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            in_list("1", StringType::from_data(vec!["x", "y", "z", "c"])),
+            FilterEvalResult::Uncertain,
+        ),
+    ] {
+        let point_query_cols = BloomIndex::find_eq_columns(&expr, fields.clone())?;
+        let mut scalar_map = HashMap::<Scalar, u64>::new();
+        let func_ctx = FunctionContext::default();
+        for (_, scalar, ty) in point_query_cols.iter() {
+            let digest = BloomIndex::calculate_scalar_digest(&func_ctx, scalar, ty)?;
+            scalar_map.insert(scalar.clone(), digest);
+        }
+        assert_eq!(
+            expected,
+            index.apply(expr.clone(), &scalar_map, schema.clone())?,
+            "{expr}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_ngram_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    // Long strings are skipped by the bloom filter, but not by the ngram filter.
+    let val: String = (0..512).map(|_| 'a').collect::<String>() + " connection timeout";
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec![&val, "数据库 error"]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let mut ngram_columns = BTreeMap::new();
+    ngram_columns.insert(1, schema.field(1).clone());
+    let fields = ngram_columns.values().cloned().collect::<Vec<_>>();
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        BTreeMap::new(),
+        ngram_columns,
+    )?
+    .unwrap();
+
+    for (func, pattern, expected) in [
+        ("like", "%timeout%", FilterEvalResult::Uncertain),
+        ("like", "%connection_timeout", FilterEvalResult::Uncertain),
+        ("like", "%数据库%", FilterEvalResult::Uncertain),
+        ("like", "%deadlock%", FilterEvalResult::MustFalse),
+        ("like", "%time%lock%", FilterEvalResult::MustFalse),
+        ("like", "%数据表%", FilterEvalResult::MustFalse),
+        // Too short to have a gram.
+        ("like", "%zz%", FilterEvalResult::Uncertain),
+        // The escaped characters are not used.
+        ("like", "%out\\%", FilterEvalResult::Uncertain),
+        ("match", "timeout connection", FilterEvalResult::Uncertain),
+        ("match", "数据库  error", FilterEvalResult::Uncertain),
+        ("match", "timeout deadlock", FilterEvalResult::MustFalse),
+        ("match", "zz", FilterEvalResult::Uncertain),
+    ] {
+        assert_eq!(
+            expected,
+            eval_ngram_index(&index, func, "1", fields.clone(), schema.clone(), pattern),
+            "{func}({pattern})"
+        );
+    }
+
+    assert_eq!(BloomIndex::ngrams("abcd"), vec!["abc", "bcd"]);
+    assert_eq!(BloomIndex::like_pattern_ngrams(b"ab%cde_f\\ghij"), vec![
+        "cde", "hij"
+    ]);
+    assert_eq!(BloomIndex::match_query_ngrams(b"abcd  ab cde"), vec![
+        "abc", "bcd", "cde"
+    ]);
+
+    Ok(())
+}
+
+fn eval_ngram_index(
+    index: &BloomIndex,
+    func: &str,
+    col_name: &str,
+    fields: Vec<TableField>,
+    schema: Arc<TableSchema>,
+    pattern: &str,
+) -> FilterEvalResult {
+    let expr = check_function(
+        None,
+        func,
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(pattern.as_bytes().to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let ngram_query_cols = BloomIndex::find_ngram_columns(&expr, fields).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, grams) in ngram_query_cols.iter() {
+        for gram in grams {
+            let scalar = Scalar::String(gram.as_bytes().to_vec());
+            let digest =
+                BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &DataType::String).unwrap();
+            scalar_map.insert(scalar, digest);
+        }
+    }
+
+    index.apply(expr, &scalar_map, schema).unwrap()
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
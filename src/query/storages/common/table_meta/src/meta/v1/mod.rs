@@ -20,4 +20,6 @@ pub use segment::BlockMeta;
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
 pub use snapshot::TableSnapshotLite;
+pub use table_snapshot_statistics::ColumnHistogram;
+pub use table_snapshot_statistics::ColumnHistogramBucket;
 pub use table_snapshot_statistics::TableSnapshotStatistics;
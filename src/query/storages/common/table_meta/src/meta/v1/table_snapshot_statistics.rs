@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -31,14 +32,41 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// Histograms of the columns, collected by sampling the blocks.
+    #[serde(default)]
+    pub column_histograms: HashMap<ColumnId, ColumnHistogram>,
+}
+
+/// An equi-height histogram with the most common values of a column.
+///
+/// The numbers of values are estimated for the whole table, null values are not counted.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ColumnHistogram {
+    /// Buckets ordered by bounds, a value never spans two buckets.
+    pub buckets: Vec<ColumnHistogramBucket>,
+    /// The most common values with their number of rows, in descending order of the number.
+    pub most_common_values: Vec<(Scalar, u64)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnHistogramBucket {
+    pub lower_bound: Scalar,
+    pub upper_bound: Scalar,
+    pub num_values: u64,
+    pub num_distinct: u64,
 }
 
 impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        column_histograms: HashMap<ColumnId, ColumnHistogram>,
+    ) -> Self {
         Self {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            column_histograms,
         }
     }
 
@@ -49,4 +77,8 @@ impl TableSnapshotStatistics {
     pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
         &self.column_distinct_values
     }
+
+    pub fn get_column_histograms(&self) -> &HashMap<ColumnId, ColumnHistogram> {
+        &self.column_histograms
+    }
 }
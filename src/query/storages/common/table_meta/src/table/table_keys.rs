@@ -22,6 +22,7 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 
 // Attached table options.
@@ -0,0 +1,193 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::io::Cursor;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use deltalake::kernel::Add;
+use opendal::Operator;
+use roaring::RoaringTreemap;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The magic number at the beginning of a serialized deletion vector.
+const DELETION_VECTOR_MAGIC: u32 = 1681511377;
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The descriptor of a deletion vector, which marks the deleted rows of a data file.
+///
+/// See: https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vectors
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    /// `u`: relative to the table root, `i`: inline, `p`: absolute path.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    /// The number of deleted rows.
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    pub fn try_from_add(add: &Add) -> Result<Option<Self>> {
+        let Some(dv) = &add.deletion_vector else {
+            return Ok(None);
+        };
+        let value = serde_json::to_value(dv).and_then(serde_json::from_value);
+        value.map(Some).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Invalid deletion vector of {}: {e:?}", add.path))
+        })
+    }
+
+    /// The location of the deletion vector file relative to the table root.
+    fn relative_path(&self) -> Result<String> {
+        match self.storage_type.as_str() {
+            "u" => {
+                // `<random prefix><base85 encoded uuid>`, the encoded uuid is 20 characters.
+                let dv = &self.path_or_inline_dv;
+                if dv.len() < 20 {
+                    return Err(ErrorCode::ReadTableDataError(format!(
+                        "Invalid deletion vector path {dv}"
+                    )));
+                }
+                let (prefix, encoded) = dv.split_at(dv.len() - 20);
+                let uuid = z85_decode(encoded)?;
+                let uuid = format!(
+                    "{}-{}-{}-{}-{}",
+                    hex(&uuid[0..4]),
+                    hex(&uuid[4..6]),
+                    hex(&uuid[6..8]),
+                    hex(&uuid[8..10]),
+                    hex(&uuid[10..16])
+                );
+                if prefix.is_empty() {
+                    Ok(format!("deletion_vector_{uuid}.bin"))
+                } else {
+                    Ok(format!("{prefix}/deletion_vector_{uuid}.bin"))
+                }
+            }
+            other => Err(ErrorCode::Unimplemented(format!(
+                "Deletion vector of storage type '{other}' is not supported"
+            ))),
+        }
+    }
+
+    /// Read the positions of the deleted rows in the data file.
+    #[async_backtrace::framed]
+    pub async fn read(&self, op: &Operator) -> Result<RoaringTreemap> {
+        let size = self.size_in_bytes as usize;
+        let data = if self.storage_type == "i" {
+            let mut data = z85_decode(&self.path_or_inline_dv)?;
+            data.truncate(size);
+            data
+        } else {
+            // The file starts with a version byte, each deletion vector in the file
+            // is stored as `<size: u32 big endian><data><checksum: u32>`.
+            let path = self.relative_path()?;
+            let offset = self.offset.unwrap_or(1) as u64;
+            let chunk = op
+                .read_with(&path)
+                .range(offset..offset + 4 + size as u64)
+                .await?;
+            let stored_size = u32::from_be_bytes(chunk_prefix(&chunk)?) as usize;
+            if stored_size != size {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "Deletion vector size mismatch in {path}, expected {size}, got {stored_size}"
+                )));
+            }
+            chunk[4..].to_vec()
+        };
+
+        let magic = u32::from_le_bytes(chunk_prefix(&data)?);
+        if magic != DELETION_VECTOR_MAGIC {
+            return Err(ErrorCode::ReadTableDataError(format!(
+                "Invalid deletion vector magic number {magic}"
+            )));
+        }
+        RoaringTreemap::deserialize_from(Cursor::new(&data[4..])).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot deserialize deletion vector: {e:?}"))
+        })
+    }
+}
+
+fn chunk_prefix(data: &[u8]) -> Result<[u8; 4]> {
+    data.get(0..4)
+        .and_then(|v| v.try_into().ok())
+        .ok_or_else(|| ErrorCode::ReadTableDataError("Deletion vector is truncated"))
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Decode the Z85 (a base85 variant) encoded string.
+fn z85_decode(encoded: &str) -> Result<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    if encoded.len() % 5 != 0 {
+        return Err(ErrorCode::ReadTableDataError(format!(
+            "Invalid z85 encoded length {}",
+            encoded.len()
+        )));
+    }
+
+    let mut decoded = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks(5) {
+        let mut value: u64 = 0;
+        for c in chunk {
+            let digit = Z85_ALPHABET.iter().position(|a| a == c).ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!("Invalid z85 character {}", *c as char))
+            })?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value)
+            .map_err(|_| ErrorCode::ReadTableDataError("Invalid z85 encoded value"))?;
+        decoded.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z85_decode() {
+        // The example of the Z85 specification.
+        let decoded = z85_decode("HelloWorld").unwrap();
+        assert_eq!(decoded, vec![
+            0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B
+        ]);
+        assert!(z85_decode("Hello").is_ok());
+        assert!(z85_decode("Hell").is_err());
+        assert!(z85_decode("Hell~").is_err());
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let dv = DeletionVectorDescriptor {
+            storage_type: "u".to_string(),
+            path_or_inline_dv: "ab^-aqEH.-t@S}K{vb[*k^".to_string(),
+            offset: Some(4),
+            size_in_bytes: 40,
+            cardinality: 6,
+        };
+        assert_eq!(
+            dv.relative_path().unwrap(),
+            "ab/deletion_vector_d2c639aa-8816-431a-aaf6-d3fe2512ff61.bin"
+        );
+    }
+}
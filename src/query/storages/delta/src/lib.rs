@@ -17,6 +17,7 @@
 #![allow(clippy::diverging_sub_expression)]
 
 mod dal;
+mod deletion_vector;
 mod partition;
 mod partition_columns;
 mod table;
@@ -14,6 +14,8 @@
 
 // TODO: support other data types
 
+use std::collections::BTreeMap;
+
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -104,7 +106,12 @@ pub fn str_to_scalar(value: &str, data_type: &DataType) -> Result<Scalar> {
     }
 }
 
-pub fn get_partition_values(add: &Add, fields: &[&TableField]) -> Result<Vec<Scalar>> {
+/// The partition values of `add` are keyed by the physical names if column mapping is enabled.
+pub fn get_partition_values(
+    add: &Add,
+    fields: &[&TableField],
+    physical_names: &BTreeMap<String, String>,
+) -> Result<Vec<Scalar>> {
     match &add.partition_values_parsed {
         Some(row) => row
             .get_column_iter()
@@ -113,7 +120,8 @@ pub fn get_partition_values(add: &Add, fields: &[&TableField]) -> Result<Vec<Sca
         None => {
             let mut values = Vec::with_capacity(fields.len());
             for f in fields {
-                match add.partition_values.get(&f.name) {
+                let name = physical_names.get(&f.name).unwrap_or(&f.name);
+                match add.partition_values.get(name) {
                     Some(Some(v)) => values.push(str_to_scalar(v, &f.data_type().into())?),
                     Some(None) => values.push(Scalar::Null),
                     None => {
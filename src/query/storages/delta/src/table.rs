@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -26,6 +27,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -55,6 +57,7 @@ use url::Url;
 
 // use object_store_opendal::OpendalStore;
 use crate::dal::OpendalStore;
+use crate::deletion_vector::DeletionVectorDescriptor;
 use crate::partition::DeltaPartInfo;
 use crate::partition_columns::get_partition_values;
 use crate::partition_columns::get_pushdown_without_partition_columns;
@@ -68,9 +71,15 @@ pub struct DeltaTable {
     meta: DeltaTableMeta,
 }
 
+/// The physical name of a column, used when column mapping is enabled.
+const COLUMN_MAPPING_PHYSICAL_NAME: &str = "delta.columnMapping.physicalName";
+
 #[derive(Serialize, Deserialize)]
 pub struct DeltaTableMeta {
     partition_columns: Vec<String>,
+    /// Logical name to physical name of the columns, empty if column mapping is disabled.
+    #[serde(default)]
+    physical_names: BTreeMap<String, String>,
 }
 
 /// In a delta table, partition columns are not stored in parquet file.
@@ -84,6 +93,14 @@ pub struct DeltaTableMeta {
 ///   - filter pass to parquet reader: all partition columns are appended to the filter input columns.
 ///   - pruner: ColumnRef of partition columns in filter expr are replace with const scalars.
 /// Type of partition columns can only be simple primitive types.
+///
+/// With column mapping (`delta.columnMapping.mode` is `name` or `id`), the columns are stored
+/// in the parquet files and partition values with their physical names:
+/// - the parquet reader uses the physical names, the output keeps the logical names.
+/// - filters are not pushed down to the parquet reader, since they refer to the logical names.
+///
+/// Rows marked in deletion vectors are removed after reading, which needs the position of each
+/// row in the file, so row group pruning and prewhere are disabled for tables with deletion vectors.
 impl DeltaTable {
     #[async_backtrace::framed]
     pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
@@ -146,6 +163,7 @@ impl DeltaTable {
         })?;
         let meta = DeltaTableMeta {
             partition_columns: state.partition_columns.clone(),
+            physical_names: Self::get_physical_names(&arrow_schema),
         };
         let meta = serde_json::to_string(&meta).map_err(|e| {
             ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
@@ -155,8 +173,21 @@ impl DeltaTable {
         Ok((schema, meta))
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    fn get_physical_names(arrow_schema: &ArrowSchema) -> BTreeMap<String, String> {
+        arrow_schema
+            .fields()
+            .iter()
+            .filter_map(|field| {
+                let physical_name = field.metadata().get(COLUMN_MAPPING_PHYSICAL_NAME)?;
+                // The metadata values of the delta schema are serialized as json.
+                let physical_name = serde_json::from_str::<String>(physical_name)
+                    .unwrap_or_else(|_| physical_name.clone());
+                Some((field.name().clone(), physical_name))
+            })
+            .collect()
+    }
+
+    fn new_delta_table(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op));
         let config = DeltaTableConfig::default();
@@ -164,13 +195,42 @@ impl DeltaTable {
             location: Url::from_directory_path("/").unwrap(),
             options: HashMap::new().into(),
         }));
-        let mut table = deltalake::table::DeltaTable::new(log_store, config);
+        Ok(deltalake::table::DeltaTable::new(log_store, config))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::new_delta_table(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
         Ok(table)
     }
 
+    /// Load the table of the given version, the version is the snapshot id of delta tables.
+    #[async_backtrace::framed]
+    async fn load_at(
+        sp: &StorageParams,
+        point: &NavigationPoint,
+    ) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::new_delta_table(sp)?;
+        let res = match point {
+            NavigationPoint::SnapshotID(version) => {
+                let version = version.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid delta table version {version}, expect an integer"
+                    ))
+                })?;
+                table.load_version(version).await
+            }
+            NavigationPoint::TimePoint(time_point) => table.load_with_datetime(*time_point).await,
+        };
+        res.map_err(|err| {
+            ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
+        })?;
+        Ok(table)
+    }
+
     #[async_backtrace::framed]
     async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
         self.table
@@ -196,10 +256,17 @@ impl DeltaTable {
             .fields()
             .iter()
             .filter(|field| !self.meta.partition_columns.contains(&field.name))
-            .cloned()
+            .map(|field| match self.meta.physical_names.get(&field.name) {
+                Some(physical_name) => TableField::new(physical_name, field.data_type().clone()),
+                None => field.clone(),
+            })
             .collect();
         let table_schema = Arc::new(TableSchema::new(non_partition_fields));
 
+        let has_deletion_vectors = plan.parts.partitions.iter().any(|part| {
+            DeltaPartInfo::from_part(part).is_ok_and(|part| part.deletion_vector.is_some())
+        });
+
         let arrow_schema = table_schema.to_arrow();
         let arrow_fields = arrow_schema
             .fields
@@ -223,11 +290,28 @@ impl DeltaTable {
             read_options = read_options.with_do_prewhere(false);
         }
 
+        if has_deletion_vectors {
+            read_options = read_options
+                .with_prune_pages(false)
+                .with_prune_row_groups(false)
+                .with_do_prewhere(false);
+        }
+
+        let plan_push_downs = if has_deletion_vectors || !self.meta.physical_names.is_empty() {
+            plan.push_downs.clone().map(|p| PushDownInfo {
+                filters: None,
+                prewhere: None,
+                ..p
+            })
+        } else {
+            plan.push_downs.clone()
+        };
+
         let pruner = ParquetRSPruner::try_create(
             ctx.get_function_context()?,
             table_schema.clone(),
             leaf_fields,
-            &plan.push_downs,
+            &plan_push_downs,
             read_options,
             self.meta.partition_columns.clone(),
         )?;
@@ -241,7 +325,7 @@ impl DeltaTable {
             .map(|name| self.info.meta.schema.index_of(name))
             .collect();
         let partition_field_indexes = partition_field_indexes?;
-        let push_downs = if let Some(ref p) = plan.push_downs {
+        let push_downs = if let Some(ref p) = plan_push_downs {
             Some(get_pushdown_without_partition_columns(
                 p.clone(),
                 &partition_field_indexes[..],
@@ -250,7 +334,7 @@ impl DeltaTable {
             None
         };
         let mut builder =
-            ParquetRSReaderBuilder::create(ctx.clone(), op, table_schema, &arrow_schema)?
+            ParquetRSReaderBuilder::create(ctx.clone(), op.clone(), table_schema, &arrow_schema)?
                 .with_options(read_options)
                 .with_push_downs(push_downs.as_ref())
                 .with_pruner(Some(pruner))
@@ -267,6 +351,7 @@ impl DeltaTable {
                     output_schema.clone(),
                     parquet_reader.clone(),
                     self.get_partition_fields()?.into_iter().cloned().collect(),
+                    op.clone(),
                 )
             },
             max_threads.max(1),
@@ -299,12 +384,19 @@ impl DeltaTable {
                             add.path
                         ))
                     })?;
-                read_rows += stats.num_records as usize;
+                let deletion_vector = DeletionVectorDescriptor::try_from_add(add)?;
+                let deleted_rows = deletion_vector.as_ref().map_or(0, |dv| dv.cardinality);
+                read_rows += (stats.num_records - deleted_rows) as usize;
                 read_bytes += add.size as usize;
-                let partition_values = get_partition_values(add, &partition_fields[..])?;
+                let partition_values = get_partition_values(
+                    add,
+                    &partition_fields[..],
+                    &self.meta.physical_names,
+                )?;
                 Ok(Arc::new(
                     Box::new(DeltaPartInfo{
                         partition_values,
+                        deletion_vector,
                         data: ParquetPart::ParquetFiles(
                             ParquetFilesPart {
                             files: vec![(add.path.clone(), add.size as u64)],
@@ -361,6 +453,29 @@ impl Table for DeltaTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let table = Self::load_at(self.get_storage_params()?, point).await?;
+        let (table_schema, meta_string) = Self::get_meta(&table).await?;
+        let meta: DeltaTableMeta = serde_json::from_str(&meta_string).map_err(|e| {
+            ErrorCode::Internal(format!(
+                "fail to deserialize DeltaTableMeta({meta_string}): {e:?}"
+            ))
+        })?;
+
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(table_schema);
+        info.meta
+            .engine_options
+            .insert(OPT_KEY_ENGINE_META.to_lowercase(), meta_string);
+
+        Ok(Arc::new(DeltaTable {
+            info,
+            table: OnceCell::new_with(Some(table)),
+            meta,
+        }))
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -15,6 +15,8 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::table_context::TableContext;
@@ -33,8 +35,10 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSFullReader;
+use opendal::Operator;
 use opendal::Reader;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
+use roaring::RoaringTreemap;
 
 use crate::partition::DeltaPartInfo;
 
@@ -61,9 +65,15 @@ pub struct DeltaTableSource {
     // Used to check schema
     output_schema: DataSchemaRef,
 
+    // Used to read deletion vectors.
+    op: Operator,
+
     // Per partition
     stream: Option<ParquetRecordBatchStream<Reader>>,
     partition_block_entries: Vec<BlockEntry>,
+    // The positions of deleted rows in the file, and the position of the next row to read.
+    deleted_rows: Option<RoaringTreemap>,
+    row_offset: u64,
 }
 
 impl DeltaTableSource {
@@ -73,6 +83,7 @@ impl DeltaTableSource {
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSFullReader>,
         partition_fields: Vec<TableField>,
+        op: Operator,
     ) -> Result<ProcessorPtr> {
         let output_partition_columns = output_schema
             .fields()
@@ -94,12 +105,35 @@ impl DeltaTableSource {
             output_schema,
             partition_fields,
             output_partition_columns,
+            op,
             stream: None,
             generated_data: None,
             is_finished: false,
             partition_block_entries: vec![],
+            deleted_rows: None,
+            row_offset: 0,
         })))
     }
+
+    /// Remove the rows marked in the deletion vector of current file.
+    fn apply_deletion_vector(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows() as u64;
+        let start = self.row_offset;
+        self.row_offset += num_rows;
+
+        let Some(deleted_rows) = &self.deleted_rows else {
+            return Ok(block);
+        };
+
+        let bitmap: Bitmap = (start..start + num_rows)
+            .map(|row| !deleted_rows.contains(row))
+            .collect::<MutableBitmap>()
+            .into();
+        if bitmap.unset_bits() == 0 {
+            return Ok(block);
+        }
+        block.filter_with_bitmap(&bitmap)
+    }
 }
 
 #[async_trait::async_trait]
@@ -147,6 +181,8 @@ impl Processor for DeltaTableSource {
                 .parquet_reader
                 .read_block_from_stream(&mut stream)
                 .await?
+                .map(|b| self.apply_deletion_vector(b))
+                .transpose()?
                 .map(|b| {
                     let mut columns = b.columns().to_vec();
                     for (fi, pi) in self.output_partition_columns.iter() {
@@ -180,6 +216,11 @@ impl Processor for DeltaTableSource {
                             BlockEntry::new(f.data_type().into(), Value::Scalar(v.clone()))
                         })
                         .collect::<Vec<_>>();
+                    self.deleted_rows = match &part.deletion_vector {
+                        Some(dv) => Some(dv.read(&self.op).await?),
+                        None => None,
+                    };
+                    self.row_offset = 0;
                     let stream = self
                         .parquet_reader
                         .prepare_data_stream(&files.files[0].0, Some(&partition_fields))
@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -65,6 +66,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -104,6 +106,7 @@ pub struct FuseTable {
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) ngram_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -179,6 +182,13 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        // Unlike bloom index, the ngram index is only built for the specified columns.
+        let ngram_index_cols = table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -189,6 +199,7 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            ngram_index_cols,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -403,6 +414,10 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn ngram_index_cols(&self) -> BloomIndexColumns {
+        self.ngram_index_cols.clone()
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -725,12 +740,14 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     Some(table_statistics.column_distinct_values.clone()),
+                    table_statistics.column_histograms.clone(),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     None,
+                    HashMap::new(),
                     snapshot.summary.row_count,
                 )
             }
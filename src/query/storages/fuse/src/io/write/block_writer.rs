@@ -118,6 +118,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -125,6 +126,7 @@ impl BloomIndexState {
             location.1,
             &[block],
             bloom_columns_map,
+            ngram_columns_map,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -165,6 +167,7 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
 }
 
 impl BlockBuilder {
@@ -180,6 +183,7 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -16,20 +16,34 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ColumnId;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use log::warn;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::statistics::build_column_histogram;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
 use crate::FuseTable;
 
+/// The maximum number of blocks sampled to build the histograms.
+const HISTOGRAM_SAMPLE_BLOCKS: usize = 100;
+/// The maximum number of rows sampled to build the histograms.
+const HISTOGRAM_SAMPLE_ROWS: usize = 100_000;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -59,6 +73,11 @@ impl FuseTable {
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
+            // Evenly spaced blocks sampled for the histograms.
+            let mut block_index = 0;
+            let mut sampled_blocks = Vec::new();
+            let sample_step =
+                (snapshot.summary.block_count as usize / HISTOGRAM_SAMPLE_BLOCKS).max(1);
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -80,6 +99,12 @@ impl FuseTable {
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     blocks_cluster_stats.push(segment.summary.cluster_stats.clone());
                     segment.blocks.iter().for_each(|block| {
+                        if block_index % sample_step == 0
+                            && sampled_blocks.len() < HISTOGRAM_SAMPLE_BLOCKS
+                        {
+                            sampled_blocks.push(block.clone());
+                        }
+                        block_index += 1;
                         let block = block.as_ref();
                         let row_count = block.row_count;
                         if row_count != 0 {
@@ -127,8 +152,12 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
+            let column_histograms = self
+                .collect_column_histograms(ctx, &sampled_blocks, &ndv_map, row_count_sum)
+                .await?;
+
             // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, column_histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -155,4 +184,84 @@ impl FuseTable {
 
         Ok(())
     }
+    // Build the histograms of the columns from the rows sampled in `blocks`.
+    #[async_backtrace::framed]
+    async fn collect_column_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        blocks: &[Arc<BlockMeta>],
+        ndv_map: &HashMap<ColumnId, u64>,
+        num_rows: u64,
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let schema = self.schema();
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| is_histogram_supported(field.data_type()))
+            .collect::<Vec<_>>();
+        if fields.is_empty() || blocks.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let projection = Projection::Columns(fields.iter().map(|(i, _)| *i).collect());
+        let block_reader =
+            self.create_block_reader(ctx.clone(), projection, false, false, false)?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let rows_per_block = HISTOGRAM_SAMPLE_ROWS / blocks.len();
+
+        let mut values = vec![Vec::new(); fields.len()];
+        let mut num_sampled_rows = 0;
+        for (i, block_meta) in blocks.iter().enumerate() {
+            let block = block_reader
+                .read_by_meta(&settings, block_meta, &self.storage_format)
+                .await?;
+            let block_rows = block.num_rows();
+            let step = (block_rows / rows_per_block).max(1);
+            num_sampled_rows += (0..block_rows).step_by(step).take(rows_per_block).len();
+            for (entry, column_values) in block.columns().iter().zip(values.iter_mut()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block_rows);
+                for row in (0..block_rows).step_by(step).take(rows_per_block) {
+                    // Null and non-finite values are not counted.
+                    match column.index(row).unwrap() {
+                        ScalarRef::Null => {}
+                        ScalarRef::Number(NumberScalar::Float32(v)) if !v.is_finite() => {}
+                        ScalarRef::Number(NumberScalar::Float64(v)) if !v.is_finite() => {}
+                        value => column_values.push(value.to_owned()),
+                    }
+                }
+            }
+
+            let status = format!(
+                "analyze: sample blocks for histograms:{}/{}",
+                i + 1,
+                blocks.len()
+            );
+            ctx.set_status_info(&status);
+        }
+
+        let mut histograms = HashMap::new();
+        for ((_, field), column_values) in fields.iter().zip(values) {
+            let ndv = ndv_map.get(&field.column_id).copied();
+            if let Some(histogram) =
+                build_column_histogram(column_values, num_sampled_rows, num_rows, ndv)
+            {
+                histograms.insert(field.column_id, histogram);
+            }
+        }
+        Ok(histograms)
+    }
+}
+
+fn is_histogram_supported(data_type: &TableDataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        TableDataType::Boolean
+            | TableDataType::Number(_)
+            | TableDataType::String
+            | TableDataType::Date
+            | TableDataType::Timestamp
+    )
 }
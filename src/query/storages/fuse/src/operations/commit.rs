@@ -219,7 +219,16 @@ impl FuseTable {
             update_stream_meta: update_stream_meta.to_vec(),
         };
 
-        // 3. let's roll
+        // 3. in an explicit transaction, keep the new table meta in the session,
+        // it will be committed by `COMMIT` together with the other changes of the transaction.
+        let txn_mgr = ctx.txn_mgr();
+        if txn_mgr.lock().is_active() {
+            txn_mgr.lock().update_table_meta(req, table_info);
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
+        // 4. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
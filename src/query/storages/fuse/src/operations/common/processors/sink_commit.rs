@@ -338,7 +338,9 @@ where F: SnapshotGenerator + Send + 'static
                 .await
                 {
                     Ok(_) => {
-                        if self.transient {
+                        // In an explicit transaction, the new snapshot is not committed yet,
+                        // so the historical data can't be purged.
+                        if self.transient && !self.ctx.txn_mgr().lock().is_active() {
                             // Removes historical data, if table is transient
                             let latest = self.table.refresh(self.ctx.as_ref()).await?;
                             let tbl = FuseTable::try_from_table(latest.as_ref())?;
@@ -101,6 +101,9 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table
+            .ngram_index_cols
+            .bloom_index_fields(source_schema.clone(), BloomIndex::ngram_supported_type)?;
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -108,6 +111,7 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -315,6 +315,7 @@ impl FuseTable {
             self.schema_with_stream(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
         )?;
 
         if let Some(inverse) = filters.map(|f| f.inverted_filter) {
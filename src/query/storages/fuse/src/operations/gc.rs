@@ -22,6 +22,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::schema::IndexType;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::LoadParams;
@@ -81,12 +82,15 @@ impl FuseTable {
         let mut purged_snapshot_count = 0;
 
         let catalog = ctx.get_catalog(&ctx.get_current_catalog()).await?;
-        let table_agg_index_ids = catalog
-            .list_index_ids_by_table_id(ListIndexesByIdReq {
+        let table_indexes = catalog
+            .list_indexes_by_table_id(ListIndexesByIdReq {
                 tenant: ctx.get_tenant(),
                 table_id: self.get_id(),
             })
-            .await?;
+            .await?
+            .into_iter()
+            .map(|(index_id, _, index_meta)| (index_id, index_meta.index_type))
+            .collect::<Vec<_>>();
 
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -169,7 +173,7 @@ impl FuseTable {
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
-                        &table_agg_index_ids,
+                        &table_indexes,
                     )
                     .await?;
 
@@ -184,7 +188,7 @@ impl FuseTable {
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
-                        &table_agg_index_ids,
+                        &table_indexes,
                     )
                     .await?;
 
@@ -224,7 +228,7 @@ impl FuseTable {
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
-                    &table_agg_index_ids,
+                    &table_indexes,
                 )
                 .await?;
             } else {
@@ -235,7 +239,7 @@ impl FuseTable {
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
-                    &table_agg_index_ids,
+                    &table_indexes,
                 )
                 .await?;
             }
@@ -253,7 +257,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
-                &table_agg_index_ids,
+                &table_indexes,
             )
             .await?;
         }
@@ -319,7 +323,7 @@ impl FuseTable {
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
-        table_agg_index_ids: &[u64],
+        table_indexes: &[(u64, IndexType)],
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -335,13 +339,7 @@ impl FuseTable {
                     continue;
                 }
                 purge_files.push(loc.to_string());
-                for index_id in table_agg_index_ids {
-                    purge_files.push(
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            loc, *index_id,
-                        ),
-                    )
-                }
+                purge_files.extend(gen_index_locations(loc, table_indexes));
             }
 
             for loc in &locations.bloom_location {
@@ -368,7 +366,7 @@ impl FuseTable {
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
-        table_agg_index_ids: &[u64],
+        table_indexes: &[(u64, IndexType)],
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -381,19 +379,13 @@ impl FuseTable {
                 .await?;
 
             let mut blocks_to_be_purged = HashSet::new();
-            let mut agg_indexes_to_be_purged = HashSet::new();
+            let mut indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
                 if locations_referenced_by_root.block_location.contains(loc) {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
-                for index_id in table_agg_index_ids {
-                    agg_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            loc, *index_id,
-                        ),
-                    );
-                }
+                indexes_to_be_purged.extend(gen_index_locations(loc, table_indexes));
             }
 
             let mut blooms_to_be_purged = HashSet::new();
@@ -427,7 +419,7 @@ impl FuseTable {
                 ctx,
                 counter,
                 blocks_to_be_purged,
-                agg_indexes_to_be_purged,
+                indexes_to_be_purged,
                 blooms_to_be_purged,
                 segment_locations_to_be_purged,
             )
@@ -445,7 +437,7 @@ impl FuseTable {
         root_snapshot: Arc<SnapshotLiteExtended>,
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
-        table_agg_index_ids: &[u64],
+        table_indexes: &[(u64, IndexType)],
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
@@ -455,20 +447,16 @@ impl FuseTable {
                 .collect::<Vec<_>>(),
         );
 
-        let mut agg_indexes_to_be_purged = HashSet::new();
-        for index_id in table_agg_index_ids {
-            agg_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(|loc| {
-                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                    loc, *index_id,
-                )
-            }));
+        let mut indexes_to_be_purged = HashSet::new();
+        for loc in root_location_tuple.block_location.iter() {
+            indexes_to_be_purged.extend(gen_index_locations(loc, table_indexes));
         }
 
         self.purge_block_segments(
             ctx,
             counter,
             root_location_tuple.block_location,
-            agg_indexes_to_be_purged,
+            indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
         )
@@ -492,7 +480,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         blocks_to_be_purged: HashSet<String>,
-        agg_indexes_to_be_purged: HashSet<String>,
+        indexes_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
@@ -504,10 +492,10 @@ impl FuseTable {
                 .await?;
         }
 
-        let agg_index_count = agg_indexes_to_be_purged.len();
-        if agg_index_count > 0 {
-            counter.agg_indexes += agg_index_count;
-            self.try_purge_location_files(ctx.clone(), agg_indexes_to_be_purged)
+        let index_count = indexes_to_be_purged.len();
+        if index_count > 0 {
+            counter.indexes += index_count;
+            self.try_purge_location_files(ctx.clone(), indexes_to_be_purged)
                 .await?;
         }
 
@@ -696,10 +684,34 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     }
 }
 
+/// The aggregating and vector index files of a block.
+fn gen_index_locations<'a>(
+    block_location: &'a str,
+    table_indexes: &'a [(u64, IndexType)],
+) -> impl Iterator<Item = String> + 'a {
+    table_indexes
+        .iter()
+        .filter_map(move |(index_id, index_type)| match index_type {
+            IndexType::AGGREGATING => Some(
+                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                    block_location,
+                    *index_id,
+                ),
+            ),
+            IndexType::VECTOR => Some(
+                TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                    block_location,
+                    *index_id,
+                ),
+            ),
+            IndexType::JOIN => None,
+        })
+}
+
 struct PurgeCounter {
     start: Instant,
     blocks: usize,
-    agg_indexes: usize,
+    indexes: usize,
     blooms: usize,
     segments: usize,
     table_statistics: usize,
@@ -711,7 +723,7 @@ impl PurgeCounter {
         Self {
             start: Instant::now(),
             blocks: 0,
-            agg_indexes: 0,
+            indexes: 0,
             blooms: 0,
             segments: 0,
             table_statistics: 0,
@@ -24,6 +24,7 @@ mod merge;
 mod merge_into;
 mod mutation;
 mod navigate;
+mod ngram_index;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -34,6 +35,7 @@ mod revert;
 mod truncate;
 mod update;
 pub mod util;
+mod vector_index_sink;
 pub use agg_index_sink::AggIndexSink;
 pub use common::*;
 pub use compact::CompactOptions;
@@ -46,3 +48,4 @@ pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
 pub use util::set_backoff;
+pub use vector_index_sink::VectorIndexSink;
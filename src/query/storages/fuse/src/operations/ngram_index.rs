@@ -0,0 +1,215 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::lock::Lock;
+use databend_common_catalog::plan::Projection;
+use databend_common_exception::Result;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::TableField;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use log::info;
+use uuid::Uuid;
+
+use crate::index::filters::BlockFilter;
+use crate::io::write_data;
+use crate::io::BloomIndexState;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::SegmentWriter;
+use crate::operations::common::AbortOperation;
+use crate::FuseTable;
+use crate::TableContext;
+
+impl FuseTable {
+    /// Build the n-gram filters of the blocks that don't have them yet,
+    /// e.g. the blocks written before `ngram_index_columns` was set.
+    ///
+    /// The bloom index file of such a block is rebuilt with the n-gram filters
+    /// and written to a new location, the data files are left untouched.
+    /// Like a segment compaction, the segments that contain the refreshed blocks
+    /// are rewritten and committed as a new snapshot.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_ngram_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        lock: Arc<dyn Lock>,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let schema = self.schema();
+        let ngram_columns_map = self
+            .ngram_index_cols
+            .bloom_index_fields(schema.clone(), BloomIndex::ngram_supported_type)?;
+        if ngram_columns_map.is_empty() {
+            return Ok(());
+        }
+        let bloom_columns_map = self
+            .bloom_index_cols
+            .bloom_index_fields(schema.clone(), BloomIndex::supported_type)?;
+        let ngram_fields = ngram_columns_map.values().cloned().collect::<Vec<_>>();
+
+        // Only the indexed columns are read, so the columns are located by their
+        // offsets in the projected block rather than in the table schema.
+        let table_schema = self.schema_with_stream();
+        let mut field_indices = bloom_columns_map
+            .values()
+            .chain(ngram_columns_map.values())
+            .map(|f| table_schema.index_of(f.name()))
+            .collect::<Result<Vec<_>>>()?;
+        field_indices.sort();
+        field_indices.dedup();
+        let offsets = field_indices
+            .iter()
+            .enumerate()
+            .map(|(offset, i)| (table_schema.field(*i).name().clone(), offset))
+            .collect::<HashMap<_, _>>();
+        let to_offsets = |columns_map: BTreeMap<FieldIndex, TableField>| {
+            columns_map
+                .into_values()
+                .map(|f| (offsets[f.name()], f))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let bloom_columns_map = to_offsets(bloom_columns_map);
+        let ngram_columns_map = to_offsets(ngram_columns_map);
+
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(field_indices),
+            false,
+            false,
+            false,
+        )?;
+        let read_settings = ReadSettings::from_ctx(&ctx)?;
+        let segment_reader = MetaReaders::segment_info_reader(self.operator.clone(), schema);
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+
+        let mut abort_operation = AbortOperation::default();
+        let mut summary = snapshot.summary.clone();
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        let mut num_refreshed_blocks = 0;
+        for (location, ver) in snapshot.segments.iter() {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: location.clone(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            let mut segment_summary = segment_info.summary.clone();
+            let mut block_metas = segment_info.block_metas()?;
+            let mut refreshed = false;
+            for block_meta in block_metas.iter_mut() {
+                if self.has_ngram_filters(block_meta, &ngram_fields).await? {
+                    continue;
+                }
+
+                let block = block_reader
+                    .read_by_meta(&read_settings, block_meta, &self.storage_format)
+                    .await?;
+                let index_location = self
+                    .meta_location_generator
+                    .block_bloom_index_location(&Uuid::new_v4());
+                let Some(index_state) = BloomIndexState::try_create(
+                    ctx.clone(),
+                    &block,
+                    index_location,
+                    bloom_columns_map.clone(),
+                    ngram_columns_map.clone(),
+                )?
+                else {
+                    continue;
+                };
+                write_data(index_state.data, &self.operator, &index_state.location.0).await?;
+                abort_operation
+                    .bloom_filter_indexes
+                    .push(index_state.location.0.clone());
+
+                segment_summary.index_size = segment_summary.index_size
+                    - block_meta.bloom_filter_index_size
+                    + index_state.size;
+                summary.index_size =
+                    summary.index_size - block_meta.bloom_filter_index_size + index_state.size;
+
+                let mut new_block_meta = block_meta.as_ref().clone();
+                new_block_meta.bloom_filter_index_location = Some(index_state.location);
+                new_block_meta.bloom_filter_index_size = index_state.size;
+                *block_meta = Arc::new(new_block_meta);
+                refreshed = true;
+                num_refreshed_blocks += 1;
+            }
+
+            if refreshed {
+                let new_segment = SegmentInfo::new(block_metas, segment_summary);
+                let new_location = segment_writer.write_segment(new_segment).await?;
+                abort_operation.add_segment(new_location.0.clone());
+                segments.push(new_location);
+            } else {
+                segments.push((location.clone(), *ver));
+            }
+        }
+
+        info!(
+            "refresh ngram index of table {}: {} blocks refreshed",
+            self.table_info.desc, num_refreshed_blocks
+        );
+        if num_refreshed_blocks == 0 {
+            return Ok(());
+        }
+
+        let _guard = lock.try_lock(ctx.clone()).await?;
+        self.commit_mutation(&ctx, snapshot, &segments, summary, abort_operation, None)
+            .await
+    }
+
+    /// Check if the bloom index of the block has the n-gram filters of all the given fields.
+    async fn has_ngram_filters(
+        &self,
+        block_meta: &BlockMeta,
+        ngram_fields: &[TableField],
+    ) -> Result<bool> {
+        let Some((location, version)) = &block_meta.bloom_filter_index_location else {
+            return Ok(false);
+        };
+        // The n-gram filters are absent from index of older versions.
+        if *version != BlockFilter::VERSION {
+            return Ok(false);
+        }
+
+        let reader = MetaReaders::bloom_index_meta_reader(self.operator.clone());
+        let index_meta = reader
+            .read(&LoadParams {
+                location: location.clone(),
+                len_hint: Some(block_meta.bloom_filter_index_size),
+                ver: 0,
+                put_cache: false,
+            })
+            .await?;
+
+        Ok(ngram_fields.iter().all(|field| {
+            BloomIndex::build_ngram_column_name(*version, field)
+                .is_some_and(|name| index_meta.columns.iter().any(|(n, _)| n == &name))
+        }))
+    }
+}
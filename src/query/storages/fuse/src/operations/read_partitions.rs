@@ -47,6 +47,7 @@ use sha2::Sha256;
 use crate::fuse_part::FusePartInfo;
 use crate::pruning::FusePruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 use crate::FuseLazyPartInfo;
 use crate::FuseTable;
 
@@ -172,6 +173,7 @@ impl FuseTable {
                 table_schema.clone(),
                 &push_downs,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
             )?
         } else {
             let cluster_keys = self.cluster_keys(ctx.clone());
@@ -184,12 +186,18 @@ impl FuseTable {
                 self.cluster_key_meta.clone(),
                 cluster_keys,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
             )?
         };
 
-        let block_metas = pruner.read_pruning(segments_location).await?;
+        let mut block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
 
+        if let Some(vector_index) = push_downs.as_ref().and_then(|p| p.vector_index.clone()) {
+            let vector_pruner = VectorIndexPruner::create(ctx.clone(), dal.clone(), vector_index);
+            block_metas = vector_pruner.prune(block_metas).await?;
+        }
+
         info!(
             "prune snapshot block end, final block numbers:{}, cost:{}",
             block_metas.len(),
@@ -192,6 +192,7 @@ impl FuseTable {
             None,
             vec![],
             BloomIndexColumns::None,
+            BloomIndexColumns::None,
             max_concurrency,
         )?;
 
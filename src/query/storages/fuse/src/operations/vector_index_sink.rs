@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_vector::IvfIndex;
+use log::warn;
+use opendal::Operator;
+
+use crate::io::TableMetaLocationGenerator;
+
+#[derive(Default)]
+struct BlockVectors {
+    num_rows: usize,
+    dimension: Option<usize>,
+    values: Vec<f32>,
+    // False if the vectors have different dimensions.
+    valid: bool,
+}
+
+/// Builds an [`IvfIndex`] for each block from the vector column and `_block_name`,
+/// the index file is written beside the block.
+///
+/// The index of a block is written as soon as all the rows of the block are consumed,
+/// so only the vectors of the blocks being read are kept in memory.
+pub struct VectorIndexSink {
+    data_accessor: Operator,
+    index_id: u64,
+    vector_offset: usize,
+    block_name_offset: usize,
+    // The number of rows of each block to be indexed.
+    block_rows: HashMap<String, usize>,
+    location_vectors: HashMap<String, BlockVectors>,
+}
+
+impl VectorIndexSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        data_accessor: Operator,
+        index_id: u64,
+        vector_offset: usize,
+        block_name_offset: usize,
+        block_rows: HashMap<String, usize>,
+    ) -> Result<ProcessorPtr> {
+        let sinker = AsyncSinker::create(input, ctx, VectorIndexSink {
+            data_accessor,
+            index_id,
+            vector_offset,
+            block_name_offset,
+            block_rows,
+            location_vectors: HashMap::new(),
+        });
+
+        Ok(ProcessorPtr::create(sinker))
+    }
+
+    /// Collect the vectors of the block,
+    /// return the locations of the blocks whose rows are all collected.
+    fn process_block(&mut self, block: &DataBlock) -> Vec<String> {
+        let num_rows = block.num_rows();
+        let col = block.get_by_offset(self.block_name_offset);
+        let block_name_col = col.value.try_downcast::<StringType>().unwrap();
+
+        let entry = block.get_by_offset(self.vector_offset);
+        let (column, validity) = match entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows)
+        {
+            Column::Nullable(nullable) => (nullable.column, Some(nullable.validity)),
+            column => (column, None),
+        };
+        let vector_col = ArrayType::<Float32Type>::try_downcast_column(&column).unwrap();
+
+        let mut completed = vec![];
+        for (i, vector) in vector_col.iter().enumerate() {
+            let location = unsafe {
+                String::from_utf8_unchecked(StringType::to_owned_scalar(
+                    block_name_col.index(i).unwrap(),
+                ))
+            };
+            let expected_rows = self.block_rows.get(&location).copied();
+            let vectors = self
+                .location_vectors
+                .entry(location.clone())
+                .or_insert_with(|| BlockVectors {
+                    valid: true,
+                    ..Default::default()
+                });
+            vectors.num_rows += 1;
+            if expected_rows == Some(vectors.num_rows) {
+                completed.push(location);
+            }
+
+            // Null and empty vectors are not indexed.
+            if validity.as_ref().is_some_and(|v| !v.get_bit(i)) || vector.is_empty() {
+                continue;
+            }
+            match vectors.dimension {
+                Some(dimension) if dimension != vector.len() => vectors.valid = false,
+                _ => vectors.dimension = Some(vector.len()),
+            }
+            if vectors.valid {
+                vectors.values.extend(vector.iter().map(|v| v.0));
+            }
+        }
+        completed
+    }
+
+    async fn write_index(&self, loc: &str, vectors: BlockVectors) -> Result<()> {
+        let dimension = match vectors.dimension {
+            Some(dimension) if vectors.valid => dimension,
+            // The block is left unindexed, so it can't be pruned by the index.
+            _ => {
+                if !vectors.valid {
+                    warn!(
+                        "skip building vector index for {}: vectors have different dimensions",
+                        loc
+                    );
+                }
+                return Ok(());
+            }
+        };
+
+        let index = IvfIndex::build(dimension, &vectors.values)?;
+        let loc = TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+            loc,
+            self.index_id,
+        );
+        self.data_accessor.write(&loc, index.to_bytes()).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for VectorIndexSink {
+    const NAME: &'static str = "VectorIndexSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        for (loc, vectors) in std::mem::take(&mut self.location_vectors) {
+            self.write_index(&loc, vectors).await?;
+        }
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        for loc in self.process_block(&data_block) {
+            if let Some(vectors) = self.location_vectors.remove(&loc) {
+                self.write_index(&loc, vectors).await?;
+            }
+        }
+
+        Ok(false)
+    }
+}
@@ -77,6 +77,7 @@ impl PruningContext {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
         max_concurrency: usize,
     ) -> Result<Arc<PruningContext>> {
         let func_ctx = ctx.get_function_context()?;
@@ -129,6 +130,7 @@ impl PruningContext {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_index_cols,
         )?;
 
         // Page pruner, used in native format
@@ -189,6 +191,7 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         Self::create_with_pages(
             ctx,
@@ -198,10 +201,12 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            ngram_index_cols,
         )
     }
 
     // Create fuse pruner with pages.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_with_pages(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -210,6 +215,7 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         let max_concurrency = {
             let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
@@ -232,6 +238,7 @@ impl FusePruner {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
             max_concurrency,
         )?;
 
@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_vector::Distance;
+use databend_common_vector::IvfIndex;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use log::debug;
+use log::info;
+use opendal::Operator;
+
+use crate::io::TableMetaLocationGenerator;
+
+/// Prunes the blocks that can't contain the top `limit` rows of
+/// `ORDER BY <distance>(<column>, <target>) LIMIT <limit>`.
+///
+/// Each indexed block is searched for its nearest `limit` distances, then only the blocks
+/// holding one of the nearest `limit` distances overall are kept.
+/// The search is approximate, so the result may miss some of the exact nearest rows.
+/// Blocks without a readable index are always kept.
+pub struct VectorIndexPruner {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    info: VectorIndexInfo,
+}
+
+impl VectorIndexPruner {
+    pub fn create(ctx: Arc<dyn TableContext>, dal: Operator, info: VectorIndexInfo) -> Self {
+        Self { ctx, dal, info }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let distance = match Distance::from_func_name(&self.info.func_name) {
+            Some(distance) => distance,
+            None => return Ok(metas),
+        };
+        let limit = self.info.limit;
+        if limit == 0 || metas.len() <= 1 {
+            return Ok(metas);
+        }
+
+        let settings = self.ctx.get_settings();
+        let nprobe = settings.get_vector_index_nprobe()? as usize;
+        let target = Arc::new(self.info.target.iter().map(|v| v.0).collect::<Vec<_>>());
+
+        let tasks = metas
+            .iter()
+            .map(|(_, meta)| {
+                let dal = self.dal.clone();
+                let target = target.clone();
+                let loc = TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                    &meta.location.0,
+                    self.info.index_id,
+                );
+                async move { search_block(&dal, &loc, distance, &target, nprobe, limit).await }
+            })
+            .collect::<Vec<_>>();
+
+        let threads_nums = settings.get_max_threads()? as usize;
+        let results = execute_futures_in_parallel(
+            tasks,
+            threads_nums,
+            threads_nums * 2,
+            "vector-index-pruning-worker".to_owned(),
+        )
+        .await?;
+
+        let mut candidates = results
+            .iter()
+            .flatten()
+            .flat_map(|distances| distances.iter().copied())
+            .collect::<Vec<_>>();
+        // Too few candidates, every block may hold some of the result.
+        if candidates.len() < limit {
+            return Ok(metas);
+        }
+        candidates.select_nth_unstable_by(limit - 1, |a, b| a.total_cmp(b));
+        let threshold = candidates[limit - 1];
+
+        let num_blocks = metas.len();
+        let pruned = metas
+            .into_iter()
+            .zip(results)
+            .filter(|(_, distances)| match distances {
+                Some(distances) => distances.first().is_some_and(|d| *d <= threshold),
+                None => true,
+            })
+            .map(|(meta, _)| meta)
+            .collect::<Vec<_>>();
+
+        info!(
+            "vector index pruning, index id: {}, blocks before: {}, after: {}",
+            self.info.index_id,
+            num_blocks,
+            pruned.len()
+        );
+        Ok(pruned)
+    }
+}
+
+/// Returns the nearest distances in the block, or `None` if its index can't be used.
+async fn search_block(
+    dal: &Operator,
+    loc: &str,
+    distance: Distance,
+    target: &[f32],
+    nprobe: usize,
+    limit: usize,
+) -> Option<Vec<f32>> {
+    let data = match dal.read(loc).await {
+        Ok(data) => data,
+        Err(e) => {
+            if e.kind() == opendal::ErrorKind::NotFound {
+                debug!("Vector index `{loc}` not found.")
+            } else {
+                debug!("Read vector index `{loc}` failed: {e}");
+            }
+            return None;
+        }
+    };
+    IvfIndex::from_bytes(&data)
+        .and_then(|index| index.search(distance, target, nprobe, limit))
+        .inspect_err(|e| debug!("Search vector index `{loc}` failed: {e}"))
+        .ok()
+}
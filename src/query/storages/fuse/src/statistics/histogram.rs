@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::Scalar;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
+use databend_storages_common_table_meta::meta::ColumnHistogramBucket;
+
+pub const HISTOGRAM_BUCKETS: usize = 100;
+pub const HISTOGRAM_MOST_COMMON_VALUES: usize = 20;
+
+/// Build an equi-height histogram from the sampled non-null values of a column.
+///
+/// `num_sampled_rows` counts the null values too. The numbers of values are scaled
+/// from the sample to `num_rows`, and the numbers of distinct values to `ndv` if given.
+pub fn build_column_histogram(
+    mut values: Vec<Scalar>,
+    num_sampled_rows: usize,
+    num_rows: u64,
+    ndv: Option<u64>,
+) -> Option<ColumnHistogram> {
+    if values.is_empty() || num_sampled_rows == 0 {
+        return None;
+    }
+    values.sort();
+
+    // Distinct values with their number of occurrences, in ascending order.
+    let mut distinct_values: Vec<(Scalar, u64)> = Vec::new();
+    for value in values {
+        match distinct_values.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => distinct_values.push((value, 1)),
+        }
+    }
+
+    let num_values: u64 = distinct_values.iter().map(|(_, count)| count).sum();
+    let value_scale = num_rows as f64 / num_sampled_rows as f64;
+    let distinct_scale = ndv.map_or(1.0, |ndv| {
+        (ndv as f64 / distinct_values.len() as f64).max(1.0)
+    });
+    let scale_values = |count: u64| ((count as f64 * value_scale).round() as u64).max(1);
+
+    // A value is common if it occurs more often than the average.
+    let average = num_values as f64 / distinct_values.len() as f64;
+    let mut most_common_values = distinct_values
+        .iter()
+        .filter(|(_, count)| *count > 1 && *count as f64 > average)
+        .collect::<Vec<_>>();
+    most_common_values.sort_by(|a, b| b.1.cmp(&a.1));
+    most_common_values.truncate(HISTOGRAM_MOST_COMMON_VALUES);
+    let most_common_values = most_common_values
+        .into_iter()
+        .map(|(value, count)| (value.clone(), scale_values(*count)))
+        .collect();
+
+    let num_buckets = HISTOGRAM_BUCKETS.min(distinct_values.len());
+    let mut buckets = Vec::with_capacity(num_buckets);
+    let mut start = 0;
+    let mut bucket_values = 0;
+    let mut remaining_values = num_values;
+    for (i, (_, count)) in distinct_values.iter().enumerate() {
+        bucket_values += count;
+        // The height is recomputed from the remaining values, so that
+        // a common value taking a whole bucket doesn't shrink the next ones.
+        let height = remaining_values as f64 / (num_buckets - buckets.len()) as f64;
+        if bucket_values as f64 >= height || i == distinct_values.len() - 1 {
+            let num_distinct = (i + 1 - start) as f64 * distinct_scale;
+            buckets.push(ColumnHistogramBucket {
+                lower_bound: distinct_values[start].0.clone(),
+                upper_bound: distinct_values[i].0.clone(),
+                num_values: scale_values(bucket_values),
+                num_distinct: num_distinct.round() as u64,
+            });
+            start = i + 1;
+            remaining_values -= bucket_values;
+            bucket_values = 0;
+        }
+    }
+
+    Some(ColumnHistogram {
+        buckets,
+        most_common_values,
+    })
+}
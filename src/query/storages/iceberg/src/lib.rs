@@ -96,6 +96,7 @@ mod database;
 mod partition;
 mod stats;
 mod table;
+mod table_sink;
 mod table_source;
 
 pub use catalog::IcebergCatalog;
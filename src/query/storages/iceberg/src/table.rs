@@ -28,6 +28,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -39,6 +40,8 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::init_operator;
@@ -48,12 +51,14 @@ use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use icelake::catalog::Catalog;
 use opendal::Operator;
 use tokio::sync::OnceCell;
 
 use crate::partition::IcebergPartInfo;
 use crate::stats::get_stats_of_data_file;
+use crate::table_sink::IcebergTableSink;
 use crate::table_source::IcebergTableSource;
 
 pub const ICEBERG_ENGINE: &str = "ICEBERG";
@@ -97,7 +102,7 @@ impl IcebergTable {
         // FIXME: we should implement catalog for icelake.
         let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
             "databend",
-            OperatorCreatorWrapper(dop.clone()),
+            OperatorCreatorWrapper(dop),
         ));
 
         let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
@@ -106,12 +111,11 @@ impl IcebergTable {
         })
     }
 
-    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
+    /// Build arrow schema from iceberg metadata.
+    pub fn get_arrow_schema(table: &icelake::Table) -> Result<ArrowSchema> {
         let meta = table.current_table_metadata();
 
-        // Build arrow schema from iceberg metadata.
-        let arrow_schema: ArrowSchema = meta
-            .schemas
+        meta.schemas
             .last()
             .ok_or_else(|| {
                 ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
@@ -120,7 +124,11 @@ impl IcebergTable {
             .try_into()
             .map_err(|e| {
                 ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
-            })?;
+            })
+    }
+
+    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
+        let arrow_schema = Self::get_arrow_schema(table)?;
 
         // Build arrow2 schema from arrow schema.
         let fields: Vec<Arrow2Field> = arrow_schema
@@ -171,18 +179,7 @@ impl IcebergTable {
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
                 let op = DataOperator::try_new(sp)?;
-                // FIXME: we should implement catalog for icelake.
-                let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
-                    "databend",
-                    OperatorCreatorWrapper(op),
-                ));
-
-                let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
-                let table = icelake_catalog.load_table(&table_id).await.map_err(|err| {
-                    ErrorCode::ReadTableDataError(format!("Iceberg catalog load failed: {err:?}"))
-                })?;
-
-                Ok(table)
+                Self::load_iceberg_table(op).await
             })
             .await
     }
@@ -365,6 +362,50 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        // The blocks are written and committed by the sink added in `commit_insertion`.
+        Ok(())
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+    ) -> Result<()> {
+        // The snapshots of icelake can only extend the current one, and the copied files
+        // can't be recorded, so a later COPY would load the same files again.
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Overwrite iceberg table {} is not supported yet",
+                self.name()
+            )));
+        }
+        if copied_files.is_some() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "COPY INTO iceberg table {} is not supported yet",
+                self.name()
+            )));
+        }
+
+        let dop = DataOperator::try_new(self.get_storage_params()?)?;
+        let data_schema = DataSchema::from(self.schema());
+
+        // All the data files must be committed in one snapshot.
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            IcebergTableSink::try_create(input, ctx.clone(), dop.clone(), data_schema.clone())
+        })
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_storage::DataOperator;
+use icelake::io::task_writer::TaskWriter;
+use icelake::transaction::Transaction;
+
+use crate::IcebergTable;
+
+/// Writes the incoming blocks as parquet data files of the iceberg table,
+/// and commits them as a new snapshot once all the blocks are written.
+pub struct IcebergTableSink {
+    dop: DataOperator,
+    data_schema: DataSchema,
+    write_progress: Arc<Progress>,
+
+    table: Option<icelake::Table>,
+    arrow_schema: Option<ArrowSchemaRef>,
+    writer: Option<TaskWriter>,
+}
+
+impl IcebergTableSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        dop: DataOperator,
+        data_schema: DataSchema,
+    ) -> Result<ProcessorPtr> {
+        let write_progress = ctx.get_write_progress();
+        let sinker = AsyncSinker::create(input, ctx, IcebergTableSink {
+            dop,
+            data_schema,
+            write_progress,
+            table: None,
+            arrow_schema: None,
+            writer: None,
+        });
+        Ok(ProcessorPtr::create(sinker))
+    }
+
+    /// Convert the block into a record batch of the iceberg schema.
+    ///
+    /// The columns are matched with the iceberg fields by name. The arrow types of databend may
+    /// differ from the iceberg ones (e.g. `LargeUtf8` and `Utf8`), so the columns are cast to the
+    /// types of the iceberg schema.
+    fn to_record_batch(&self, block: DataBlock) -> Result<RecordBatch> {
+        let arrow_schema = self
+            .arrow_schema
+            .clone()
+            .ok_or_else(|| ErrorCode::Internal("Iceberg table sink is not started"))?;
+        let batch = block
+            .to_record_batch(&self.data_schema)
+            .map_err(|e| ErrorCode::Internal(format!("Cannot convert block: {e:?}")))?;
+        let columns = arrow_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let column = batch.column_by_name(field.name()).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Column {} of the iceberg table is missing",
+                        field.name()
+                    ))
+                })?;
+                arrow_cast::cast(column, field.data_type()).map_err(|e| {
+                    ErrorCode::Internal(format!("Cannot cast column {}: {e:?}", field.name()))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(arrow_schema, columns)
+            .map_err(|e| ErrorCode::Internal(format!("Cannot build record batch: {e:?}")))
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergTableSink {
+    const NAME: &'static str = "IcebergTableSink";
+
+    #[async_backtrace::framed]
+    async fn on_start(&mut self) -> Result<()> {
+        // Always load the latest metadata, the new snapshot is based on it.
+        let table = IcebergTable::load_iceberg_table(self.dop.clone()).await?;
+        let arrow_schema = IcebergTable::get_arrow_schema(&table)?;
+        let writer = table
+            .task_writer()
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("Cannot create iceberg writer: {e:?}")))?;
+
+        self.table = Some(table);
+        self.arrow_schema = Some(Arc::new(arrow_schema));
+        self.writer = Some(writer);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let (Some(mut table), Some(writer)) = (self.table.take(), self.writer.take()) else {
+            return Ok(());
+        };
+
+        let data_files = writer
+            .close()
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("Cannot close iceberg writer: {e:?}")))?;
+        if data_files.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = Transaction::new(&mut table);
+        tx.append_data_file(data_files);
+        tx.commit().await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot commit iceberg transaction: {e:?}"))
+        })
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if data_block.is_empty() {
+            return Ok(false);
+        }
+
+        let progress_values = ProgressValues {
+            rows: data_block.num_rows(),
+            bytes: data_block.memory_size(),
+        };
+        let batch = self.to_record_batch(data_block)?;
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| ErrorCode::Internal("Iceberg table sink is not started"))?;
+        writer
+            .write(&batch)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("Cannot write iceberg data: {e:?}")))?;
+        self.write_progress.incr(&progress_values);
+
+        Ok(false)
+    }
+}
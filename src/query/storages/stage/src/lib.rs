@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod parquet_file;
+mod partition_by;
 mod row_based_file;
 mod stage_table;
 
@@ -18,11 +18,13 @@ use databend_common_expression::DataBlock;
 #[derive(Debug)]
 pub struct BlockBatch {
     pub blocks: Vec<DataBlock>,
+    /// See [`crate::partition_by::PartitionMeta`].
+    pub partition: Option<String>,
 }
 
 impl BlockBatch {
-    pub fn create_block(blocks: Vec<DataBlock>) -> DataBlock {
-        DataBlock::empty_with_meta(Box::new(BlockBatch { blocks }))
+    pub fn create_block(blocks: Vec<DataBlock>, partition: Option<String>) -> DataBlock {
+        DataBlock::empty_with_meta(Box::new(BlockBatch { blocks, partition }))
     }
 }
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
@@ -24,6 +25,7 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 
 use super::block_batch::BlockBatch;
+use crate::partition_by::PartitionMeta;
 
 pub(super) struct LimitFileSizeProcessor {
     input: Arc<InputPort>,
@@ -34,9 +36,9 @@ pub(super) struct LimitFileSizeProcessor {
     input_data: Option<DataBlock>,
     output_data: Option<DataBlock>,
 
-    // since we only output one BlockBatch each time, the remaining blocks is kept here.
-    // remember to flush it when input is finished
-    blocks: Vec<DataBlock>,
+    // since we only output one BlockBatch each time, the remaining blocks is kept here,
+    // grouped by partition. remember to flush it when input is finished
+    blocks: BTreeMap<Option<String>, Vec<DataBlock>>,
 }
 
 impl LimitFileSizeProcessor {
@@ -51,7 +53,7 @@ impl LimitFileSizeProcessor {
             threshold,
             input_data: None,
             output_data: None,
-            blocks: Vec::new(),
+            blocks: BTreeMap::new(),
         };
         Ok(ProcessorPtr::create(Box::new(p)))
     }
@@ -90,9 +92,10 @@ impl Processor for LimitFileSizeProcessor {
                             self.output.finish();
                             Ok(Event::Finished)
                         } else {
-                            // flush the remaining blocks
-                            let blocks = std::mem::take(&mut self.blocks);
-                            self.output.push_data(Ok(BlockBatch::create_block(blocks)));
+                            // flush the remaining blocks, one partition each time
+                            let (partition, blocks) = self.blocks.pop_first().unwrap();
+                            self.output
+                                .push_data(Ok(BlockBatch::create_block(blocks, partition)));
                             Ok(Event::NeedConsume)
                         }
                     } else {
@@ -109,7 +112,8 @@ impl Processor for LimitFileSizeProcessor {
         assert!(self.output_data.is_none());
         // slicing has overhead, we do not do it for now.
         let block = self.input_data.take().unwrap();
-        let mut blocks = std::mem::take(&mut self.blocks);
+        let partition = PartitionMeta::partition_of(&block);
+        let mut blocks = self.blocks.remove(&partition).unwrap_or_default();
 
         blocks.push(block);
         let mut break_point = blocks.len();
@@ -122,11 +126,13 @@ impl Processor for LimitFileSizeProcessor {
             }
         }
         if break_point == blocks.len() {
-            self.blocks = blocks;
+            self.blocks.insert(partition, blocks);
         } else {
             let remain = blocks.split_off(break_point + 1);
-            self.output_data = Some(BlockBatch::create_block(blocks));
-            self.blocks = remain;
+            if !remain.is_empty() {
+                self.blocks.insert(partition.clone(), remain);
+            }
+            self.output_data = Some(BlockBatch::create_block(blocks, partition));
         }
         Ok(())
     }
@@ -18,12 +18,16 @@ use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_formats::FileFormatOptionsExt;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
 use opendal::Operator;
 
 use crate::parquet_file::limit_file_size_processor::LimitFileSizeProcessor;
 use crate::parquet_file::sink_processor::ParquetFileSink;
+use crate::partition_by::PartitionByTransform;
 
+// PartitionByTransform * N: split each data block by partition, if PARTITION BY is specified.
 // LimitFileSizeProcessor * 1:  slice/group block to batches (as a block meta) that are suitable as a file.
 // ParquetFileSink * N: simply serialize blocks in each meta to a whole file and write out.
 #[allow(clippy::too_many_arguments)]
@@ -37,6 +41,15 @@ pub(crate) fn append_data_to_parquet_files(
     uuid: String,
     group_id: &std::sync::atomic::AtomicUsize,
 ) -> Result<()> {
+    if table_info.is_partitioned {
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                PartitionByTransform,
+            )))
+        })?;
+    }
     pipeline.try_resize(1)?;
     pipeline.add_transform(|input, output| {
         LimitFileSizeProcessor::try_create(input, output, max_file_size)
@@ -49,7 +62,7 @@ pub(crate) fn append_data_to_parquet_files(
         let mut options_ext =
             FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
         let output_format = options_ext.get_output_format(
-            table_info.schema(),
+            table_info.file_schema(),
             table_info.stage_info.file_format_params.clone(),
         )?;
         ParquetFileSink::try_create(
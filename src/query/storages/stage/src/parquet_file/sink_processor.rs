@@ -44,6 +44,8 @@ pub struct ParquetFileSink {
     uuid: String,
     group_id: usize,
     batch_id: usize,
+    // the partition of output_data
+    partition: Option<String>,
 }
 
 impl ParquetFileSink {
@@ -65,6 +67,7 @@ impl ParquetFileSink {
             uuid,
             group_id,
             batch_id: 0,
+            partition: None,
         })))
     }
 }
@@ -107,6 +110,7 @@ impl Processor for ParquetFileSink {
             self.output_format.serialize_block(&b)?;
         }
         self.output_data = self.output_format.finalize()?;
+        self.partition = blocks.partition;
         Ok(())
     }
 
@@ -115,6 +119,7 @@ impl Processor for ParquetFileSink {
         assert!(!self.output_data.is_empty());
         let path = unload_path(
             &self.table_info,
+            self.partition.as_deref(),
             &self.uuid,
             self.group_id,
             self.batch_id,
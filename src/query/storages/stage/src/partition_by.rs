@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+
+/// The partition the rows are unloaded to, if the `PARTITION BY` expression is null or empty.
+pub const NULL_PARTITION: &str = "_NULL_";
+
+/// The partition of the rows in a block, they are unloaded under `<path>/<partition>/`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PartitionMeta {
+    pub partition: String,
+}
+
+impl PartitionMeta {
+    /// Returns the partition of the block, or None if the unloading is not partitioned.
+    pub fn partition_of(block: &DataBlock) -> Option<String> {
+        block
+            .get_meta()
+            .and_then(PartitionMeta::downcast_ref_from)
+            .map(|meta| meta.partition.clone())
+    }
+}
+
+#[typetag::serde(name = "unload_partition")]
+impl BlockMetaInfo for PartitionMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        PartitionMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Splits each block by the partition in its last column, which is evaluated from the
+/// `PARTITION BY` expression. The column is removed and the partition is kept in the meta
+/// of the output blocks.
+pub(crate) struct PartitionByTransform;
+
+impl AccumulatingTransform for PartitionByTransform {
+    const NAME: &'static str = "PartitionByTransform";
+
+    fn transform(&mut self, mut block: DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = block.num_rows();
+        let entry = block.get_by_offset(block.num_columns() - 1);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+
+        let mut partitions: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+        for row in 0..num_rows {
+            let partition = match column.index(row) {
+                Some(ScalarRef::String(s)) => normalize_partition(&String::from_utf8_lossy(s))?,
+                _ => NULL_PARTITION.to_string(),
+            };
+            partitions.entry(partition).or_default().push(row as u32);
+        }
+        block.pop_columns(1);
+
+        if partitions.len() == 1 {
+            let (partition, _) = partitions.pop_first().unwrap();
+            return Ok(vec![
+                block.add_meta(Some(Box::new(PartitionMeta { partition })))?,
+            ]);
+        }
+        partitions
+            .into_iter()
+            .map(|(partition, rows)| {
+                block
+                    .take(&rows, &mut None)?
+                    .add_meta(Some(Box::new(PartitionMeta { partition })))
+            })
+            .collect()
+    }
+}
+
+/// Trims the slashes around the partition, an empty partition is taken as null.
+///
+/// The partition is a relative path under the unload path, so it must not contain
+/// empty, `.` or `..` segments, which could write the files outside of the path.
+fn normalize_partition(partition: &str) -> Result<String> {
+    let partition = partition.trim_matches('/');
+    if partition.is_empty() {
+        return Ok(NULL_PARTITION.to_string());
+    }
+    if partition
+        .split('/')
+        .any(|segment| segment.is_empty() || segment == "." || segment == "..")
+    {
+        return Err(ErrorCode::BadArguments(format!(
+            "Invalid partition '{partition}', it must not contain empty, '.' or '..' path segments"
+        )));
+    }
+    Ok(partition.to_string())
+}
@@ -18,11 +18,13 @@ use databend_common_expression::DataBlock;
 #[derive(Debug)]
 pub struct FileOutputBuffers {
     pub buffers: Vec<Vec<u8>>,
+    /// See [`crate::partition_by::PartitionMeta`].
+    pub partition: Option<String>,
 }
 
 impl FileOutputBuffers {
-    pub fn create_block(buffers: Vec<Vec<u8>>) -> DataBlock {
-        DataBlock::empty_with_meta(Box::new(FileOutputBuffers { buffers }))
+    pub fn create_block(buffers: Vec<Vec<u8>>, partition: Option<String>) -> DataBlock {
+        DataBlock::empty_with_meta(Box::new(FileOutputBuffers { buffers, partition }))
     }
 }
 
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
-use std::mem;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
@@ -34,7 +34,8 @@ pub(super) struct LimitFileSizeProcessor {
 
     input_data: Option<DataBlock>,
     output_data: Option<DataBlock>,
-    buffers: Vec<Vec<u8>>,
+    // the buffers not large enough as a file yet, grouped by partition.
+    buffers: BTreeMap<Option<String>, Vec<Vec<u8>>>,
 }
 
 impl LimitFileSizeProcessor {
@@ -49,7 +50,7 @@ impl LimitFileSizeProcessor {
             threshold,
             input_data: None,
             output_data: None,
-            buffers: Vec::new(),
+            buffers: BTreeMap::new(),
         };
         Ok(ProcessorPtr::create(Box::new(p)))
     }
@@ -88,9 +89,10 @@ impl Processor for LimitFileSizeProcessor {
                             self.output.finish();
                             Ok(Event::Finished)
                         } else {
-                            let buffers = std::mem::take(&mut self.buffers);
+                            // flush the remaining buffers, one partition each time
+                            let (partition, buffers) = self.buffers.pop_first().unwrap();
                             self.output
-                                .push_data(Ok(FileOutputBuffers::create_block(buffers)));
+                                .push_data(Ok(FileOutputBuffers::create_block(buffers, partition)));
                             Ok(Event::NeedConsume)
                         }
                     } else {
@@ -108,13 +110,13 @@ impl Processor for LimitFileSizeProcessor {
 
         let block = self.input_data.take().unwrap();
         let block_meta = block.get_owned_meta().unwrap();
-        let buffers = FileOutputBuffers::downcast_from(block_meta).unwrap();
-        let buffers = buffers.buffers;
+        let input = FileOutputBuffers::downcast_from(block_meta).unwrap();
+        let partition = input.partition;
 
-        self.buffers.extend(buffers);
+        let mut buffers = self.buffers.remove(&partition).unwrap_or_default();
+        buffers.extend(input.buffers);
 
         let mut size = 0;
-        let mut buffers = mem::take(&mut self.buffers);
         let break_idx = buffers
             .iter()
             .enumerate()
@@ -128,12 +130,16 @@ impl Processor for LimitFileSizeProcessor {
             })
             .unwrap_or(buffers.len());
         if break_idx == buffers.len() {
-            self.buffers = buffers;
+            if !buffers.is_empty() {
+                self.buffers.insert(partition, buffers);
+            }
             Ok(())
         } else {
             let remain = buffers.split_off(break_idx + 1);
-            self.output_data = Some(FileOutputBuffers::create_block(buffers));
-            self.buffers = remain;
+            if !remain.is_empty() {
+                self.buffers.insert(partition.clone(), remain);
+            }
+            self.output_data = Some(FileOutputBuffers::create_block(buffers, partition));
             Ok(())
         }
     }
@@ -18,14 +18,18 @@ use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_formats::FileFormatOptionsExt;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::input_formats::InputContext;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
 use opendal::Operator;
 
+use crate::partition_by::PartitionByTransform;
 use crate::row_based_file::limit_file_size_processor::LimitFileSizeProcessor;
 use crate::row_based_file::serialize_processor::SerializeProcessor;
 use crate::row_based_file::sink_processor::RowBasedFileSink;
 
+// PartitionByTransform * N: split each data block by partition, if PARTITION BY is specified.
 // SerializeProcessor * N: serialize each data block to many small byte buffers.
 // LimitFileSizeProcessor * 1:  group small byte buffers to batches (as a block meta) that are large enough as a file.
 // RowBasedFileSink * N: simply concat small byte buffers to a whole and write out.
@@ -42,7 +46,7 @@ pub(crate) fn append_data_to_row_based_files(
 ) -> Result<()> {
     let mut options_ext = FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
     let output_format = options_ext.get_output_format(
-        table_info.schema(),
+        table_info.file_schema(),
         table_info.stage_info.file_format_params.clone(),
     )?;
     let compression = table_info
@@ -52,11 +56,20 @@ pub(crate) fn append_data_to_row_based_files(
         .compression();
     let prefix = output_format.serialize_prefix()?;
 
+    if table_info.is_partitioned {
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                PartitionByTransform,
+            )))
+        })?;
+    }
     pipeline.add_transform(|input, output| {
         let mut options_ext =
             FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
         let output_format = options_ext.get_output_format(
-            table_info.schema(),
+            table_info.file_schema(),
             table_info.stage_info.file_format_params.clone(),
         )?;
         SerializeProcessor::try_create(ctx.clone(), input, output, output_format)
@@ -25,6 +25,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_transforms::processors::Transform;
 use databend_common_pipeline_transforms::processors::Transformer;
 
+use crate::partition_by::PartitionMeta;
 use crate::row_based_file::buffers::FileOutputBuffers;
 
 pub(super) struct SerializeProcessor {
@@ -67,6 +68,9 @@ impl Transform for SerializeProcessor {
             bytes,
         };
         self.ctx.get_write_progress().incr(&progress_values);
-        Ok(FileOutputBuffers::create_block(buffers))
+        Ok(FileOutputBuffers::create_block(
+            buffers,
+            PartitionMeta::partition_of(&block),
+        ))
     }
 }
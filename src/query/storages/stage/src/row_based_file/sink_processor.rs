@@ -47,6 +47,8 @@ pub struct RowBasedFileSink {
     uuid: String,
     group_id: usize,
     batch_id: usize,
+    // the partition of output_data
+    partition: Option<String>,
 
     compression: Option<CompressAlgorithm>,
 }
@@ -70,6 +72,7 @@ impl RowBasedFileSink {
             uuid,
             group_id,
             batch_id: 0,
+            partition: None,
             output_data: vec![],
             compression,
         })))
@@ -113,13 +116,14 @@ impl Processor for RowBasedFileSink {
         let size = buffers.buffers.iter().map(|b| b.len()).sum::<usize>();
         let mut output = Vec::with_capacity(self.prefix.len() + size);
         output.extend_from_slice(self.prefix.as_slice());
-        for b in buffers.buffers {
+        for b in &buffers.buffers {
             output.extend_from_slice(b.as_slice());
         }
         if let Some(compression) = self.compression {
             output = CompressCodec::from(compression).compress_all(&output)?;
         }
         self.output_data = output;
+        self.partition = buffers.partition;
         Ok(())
     }
 
@@ -127,6 +131,7 @@ impl Processor for RowBasedFileSink {
     async fn async_process(&mut self) -> Result<()> {
         let path = unload_path(
             &self.table_info,
+            self.partition.as_deref(),
             &self.uuid,
             self.group_id,
             self.batch_id,
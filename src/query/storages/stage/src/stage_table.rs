@@ -305,6 +305,7 @@ impl Table for StageTable {
 
 pub fn unload_path(
     stage_table_info: &StageTableInfo,
+    partition: Option<&str>,
     uuid: &str,
     group_id: usize,
     batch_id: usize,
@@ -321,6 +322,15 @@ pub fn unload_path(
         .unwrap_or_default();
 
     let path = &stage_table_info.files_info.path;
+    // the files of a partition are put in the directory named by the partition.
+    let path = &match partition {
+        None => path.clone(),
+        Some(partition) if path.ends_with("data_") => match path.rsplit_once('/') {
+            Some((dir, prefix)) => format!("{}/{}/{}", dir, partition, prefix),
+            None => format!("{}/{}", partition, path),
+        },
+        Some(partition) => format!("{}/{}", path.trim_end_matches('/'), partition),
+    };
 
     if path.ends_with("data_") {
         format!(
@@ -46,6 +46,7 @@ pub struct StreamPruner {
 }
 
 impl StreamPruner {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -54,6 +55,7 @@ impl StreamPruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Arc<Self>> {
         let max_concurrency = {
             let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
@@ -76,6 +78,7 @@ impl StreamPruner {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
             max_concurrency,
         )?;
 
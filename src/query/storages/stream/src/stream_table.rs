@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt::Display;
 use std::str::FromStr;
@@ -23,6 +24,7 @@ use databend_common_base::base::tokio::runtime::Handle;
 use databend_common_base::base::tokio::task::block_in_place;
 use databend_common_catalog::catalog::StorageDescription;
 use databend_common_catalog::plan::block_id_from_location;
+use databend_common_catalog::plan::ChangeType;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
@@ -62,10 +64,15 @@ pub const OPT_KEY_TABLE_VER: &str = "table_version";
 pub const OPT_KEY_MODE: &str = "mode";
 
 pub const MODE_APPEND_ONLY: &str = "append_only";
+pub const MODE_STANDARD: &str = "standard";
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum StreamMode {
+    /// Only the rows inserted into the table are visible.
     AppendOnly,
+    /// The rows inserted, updated and deleted are visible, with `change$action`
+    /// and `change$is_update` telling the kind of change.
+    Standard,
 }
 
 pub enum StreamStatus {
@@ -78,6 +85,7 @@ impl FromStr for StreamMode {
     fn from_str(s: &str) -> Result<Self> {
         match s {
             MODE_APPEND_ONLY => Ok(StreamMode::AppendOnly),
+            MODE_STANDARD => Ok(StreamMode::Standard),
             _ => Err(ErrorCode::IllegalStream(format!(
                 "invalid stream mode: {}",
                 s
@@ -90,6 +98,7 @@ impl Display for StreamMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
             StreamMode::AppendOnly => MODE_APPEND_ONLY.to_string(),
+            StreamMode::Standard => MODE_STANDARD.to_string(),
         })
     }
 }
@@ -103,6 +112,8 @@ pub struct StreamTable {
     table_version: u64,
     mode: StreamMode,
     snapshot_location: Option<String>,
+    /// Which side of the changes is read, only set for the stream in standard mode.
+    change_type: Option<ChangeType>,
 }
 
 impl StreamTable {
@@ -137,6 +148,7 @@ impl StreamTable {
             table_version,
             mode,
             snapshot_location,
+            change_type: None,
         }))
     }
 
@@ -217,26 +229,30 @@ impl StreamTable {
         let start = Instant::now();
         let table = self.source_table(ctx.clone()).await?;
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
-        let latest_snapshot = fuse_table.read_table_snapshot().await?;
-        if latest_snapshot.is_none() {
-            return Ok((PartStatistics::default(), Partitions::default()));
-        }
-
-        let latest_snapshot = latest_snapshot.unwrap();
-        let latest_segments = HashSet::from_iter(latest_snapshot.segments.clone());
+        let (latest_segments, latest_block_count) = match fuse_table.read_table_snapshot().await? {
+            Some(latest_snapshot) => (
+                HashSet::from_iter(latest_snapshot.segments.clone()),
+                latest_snapshot.summary.block_count as usize,
+            ),
+            None => (HashSet::new(), 0),
+        };
 
-        let summary = latest_snapshot.summary.block_count as usize;
-        drop(latest_snapshot);
         let operator = fuse_table.get_operator();
-        let base_segments = if let Some(snapshot_location) = &self.snapshot_location {
-            let (base_snapshot, _) =
-                SnapshotsIO::read_snapshot(snapshot_location.clone(), operator.clone()).await?;
-            HashSet::from_iter(base_snapshot.segments.clone())
-        } else {
-            HashSet::new()
-        };
+        let (base_segments, base_block_count) =
+            if let Some(snapshot_location) = &self.snapshot_location {
+                let (base_snapshot, _) =
+                    SnapshotsIO::read_snapshot(snapshot_location.clone(), operator.clone()).await?;
+                (
+                    HashSet::from_iter(base_snapshot.segments.clone()),
+                    base_snapshot.summary.block_count as usize,
+                )
+            } else {
+                (HashSet::new(), 0)
+            };
 
-        let mut base_blocks = HashSet::new();
+        // The blocks only in the base snapshot are removed since the stream offset,
+        // and the blocks only in the latest snapshot are added.
+        let mut base_blocks = HashMap::new();
         let mut latest_blocks = Vec::new();
         {
             let fuse_segment_io =
@@ -254,7 +270,7 @@ impl StreamTable {
                 for segment in segments {
                     let segment = segment?;
                     segment.blocks.into_iter().for_each(|block| {
-                        base_blocks.insert(block.location.clone());
+                        base_blocks.insert(block.location.clone(), block);
                     })
                 }
             }
@@ -271,21 +287,27 @@ impl StreamTable {
                 for segment in segments {
                     let segment = segment?;
                     segment.blocks.into_iter().for_each(|block| {
-                        if base_blocks.contains(&block.location) {
-                            base_blocks.remove(&block.location);
-                        } else {
+                        if base_blocks.remove(&block.location).is_none() {
                             latest_blocks.push(block);
                         }
                     });
                 }
             }
         }
-        if latest_blocks.is_empty() {
+
+        let (blocks, summary) = match self.change_type {
+            Some(ChangeType::Delete) => (
+                base_blocks.values().cloned().collect::<Vec<_>>(),
+                base_block_count,
+            ),
+            _ => (latest_blocks, latest_block_count),
+        };
+        if blocks.is_empty() {
             return Ok((PartStatistics::default(), Partitions::default()));
         }
 
         let mut base_block_ids = Vec::with_capacity(base_blocks.len());
-        for base_block in base_blocks {
+        for base_block in base_blocks.keys() {
             let block_id = block_id_from_location(&base_block.0)?;
             base_block_ids.push(block_id);
         }
@@ -294,6 +316,7 @@ impl StreamTable {
 
         let table_schema = fuse_table.schema_with_stream();
         let bloom_index_cols = fuse_table.bloom_index_cols();
+        let ngram_index_cols = fuse_table.ngram_index_cols();
         let (cluster_keys, cluster_key_meta) =
             if !fuse_table.is_native() || fuse_table.cluster_key_meta().is_none() {
                 (vec![], None)
@@ -311,9 +334,10 @@ impl StreamTable {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
         )?;
 
-        let block_metas = stream_pruner.pruning(latest_blocks).await?;
+        let block_metas = stream_pruner.pruning(blocks).await?;
         let pruning_stats = stream_pruner.pruning_stats();
 
         log::info!(
@@ -374,6 +398,26 @@ impl Table for StreamTable {
         true
     }
 
+    fn with_change_type(&self, change_type: ChangeType) -> Result<Arc<dyn Table>> {
+        if self.mode != StreamMode::Standard {
+            return Err(ErrorCode::IllegalStream(format!(
+                "Changes can only be read from the stream in standard mode, but stream '{}' is {}",
+                self.stream_info.name, self.mode
+            )));
+        }
+
+        Ok(Arc::new(StreamTable {
+            stream_info: self.stream_info.clone(),
+            table_id: self.table_id,
+            table_name: self.table_name.clone(),
+            table_database: self.table_database.clone(),
+            table_version: self.table_version,
+            mode: self.mode.clone(),
+            snapshot_location: self.snapshot_location.clone(),
+            change_type: Some(change_type),
+        }))
+    }
+
     fn stream_columns(&self) -> Vec<StreamColumn> {
         vec![
             STREAM_COLUMN_FACTORY
@@ -108,8 +108,10 @@ pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::parse_local_task_runs_to_datablock;
 pub use task_history_table::parse_task_runs_to_datablock;
 pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::parse_local_tasks_to_datablock;
 pub use tasks_table::parse_tasks_to_datablock;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PushDownInfo;
@@ -22,8 +23,8 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTaskRunsRequest;
 use databend_common_cloud_control::pb::TaskRun;
 use databend_common_cloud_control::task_client::make_request;
+use databend_common_cloud_control::task_utils;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::Int32Type;
@@ -33,15 +34,59 @@ use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal;
+use databend_common_meta_app::principal::TaskRunState;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_run_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
+use crate::tasks_table::from_local_task;
 
 pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock> {
+    let task_runs = task_runs
+        .into_iter()
+        .map(|task_run| task_run.try_into())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(task_runs_to_datablock(task_runs))
+}
+
+/// Builds the block of the task runs stored in the meta service, without cloud control.
+pub fn parse_local_task_runs_to_datablock(task_runs: Vec<principal::TaskRun>) -> DataBlock {
+    task_runs_to_datablock(task_runs.into_iter().map(from_local_task_run).collect())
+}
+
+fn from_local_task_run(task_run: principal::TaskRun) -> task_utils::TaskRun {
+    let task = from_local_task(task_run.task);
+    task_utils::TaskRun {
+        task_id: task.task_id,
+        task_name: task.task_name,
+        query_text: task.query_text,
+        comment: task.comment,
+        owner: task.owner,
+        run_id: task_run.run_id.to_string(),
+        query_id: task_run.query_id,
+        schedule_options: task.schedule_options,
+        warehouse_options: task.warehouse_options,
+        attempt_number: task_run.attempt_number as i32,
+        state: match task_run.state {
+            TaskRunState::Scheduled => task_utils::State::SCHEDULED,
+            TaskRunState::Executing => task_utils::State::EXECUTING,
+            TaskRunState::Succeeded => task_utils::State::SUCCEEDED,
+            TaskRunState::Failed => task_utils::State::FAILED,
+            TaskRunState::Cancelled => task_utils::State::CANCELLED,
+        },
+        scheduled_at: task_run.scheduled_at,
+        completed_at: task_run.completed_at,
+        error_code: task_run.error_code,
+        error_message: task_run.error_message,
+    }
+}
+
+fn task_runs_to_datablock(task_runs: Vec<task_utils::TaskRun>) -> DataBlock {
     let mut name: Vec<Vec<u8>> = Vec::with_capacity(task_runs.len());
     let mut id: Vec<u64> = Vec::with_capacity(task_runs.len());
     let mut owner: Vec<Vec<u8>> = Vec::with_capacity(task_runs.len());
@@ -58,8 +103,7 @@ pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock
     let mut scheduled_time: Vec<i64> = Vec::with_capacity(task_runs.len());
     let mut completed_time: Vec<Option<i64>> = Vec::with_capacity(task_runs.len());
 
-    for task_run in task_runs {
-        let tr: databend_common_cloud_control::task_utils::TaskRun = task_run.try_into()?;
+    for tr in task_runs {
         name.push(tr.task_name.into_bytes());
         id.push(tr.task_id);
         owner.push(tr.owner.into_bytes());
@@ -79,7 +123,7 @@ pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock
         completed_time.push(tr.completed_at.map(|t| t.timestamp_micros()));
         scheduled_time.push(tr.scheduled_at.timestamp_micros());
     }
-    Ok(DataBlock::new_from_columns(vec![
+    DataBlock::new_from_columns(vec![
         StringType::from_data(name),
         UInt64Type::from_data(id),
         StringType::from_data(owner),
@@ -95,7 +139,7 @@ pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock
         Int32Type::from_data(attempt_number),
         TimestampType::from_opt_data(completed_time),
         TimestampType::from_data(scheduled_time),
-    ]))
+    ])
 }
 
 pub struct TaskHistoryTable {
@@ -116,17 +160,24 @@ impl AsyncSystemTable for TaskHistoryTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
+
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let roles = available_roles
+                .into_iter()
+                .map(|role| role.name)
+                .collect::<HashSet<_>>();
+            let mut task_runs = UserApiProvider::instance()
+                .get_task_runs(&tenant, None)
+                .await?;
+            task_runs.retain(|task_run| roles.contains(&task_run.task.owner));
+            return Ok(parse_local_task_runs_to_datablock(task_runs));
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTaskRunsRequest {
             tenant_id: tenant.clone(),
             scheduled_time_start: "".to_string(),
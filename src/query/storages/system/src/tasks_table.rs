@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PushDownInfo;
@@ -21,9 +22,10 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_cloud_control::pb::Task;
+use databend_common_cloud_control::pb::WarehouseOptions;
 use databend_common_cloud_control::task_client::make_request;
+use databend_common_cloud_control::task_utils;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::StringType;
@@ -31,15 +33,57 @@ use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal;
+use databend_common_meta_app::principal::TaskStatus;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 
 pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
+    let tasks = tasks
+        .into_iter()
+        .map(|task| task.try_into())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(tasks_to_datablock(tasks))
+}
+
+/// Builds the block of the tasks stored in the meta service, when cloud control is not configured.
+pub fn parse_local_tasks_to_datablock(tasks: Vec<principal::Task>) -> DataBlock {
+    tasks_to_datablock(tasks.into_iter().map(from_local_task).collect())
+}
+
+pub(crate) fn from_local_task(task: principal::Task) -> task_utils::Task {
+    task_utils::Task {
+        task_id: task.task_id,
+        task_name: task.task_name,
+        query_text: task.query_text,
+        comment: Some(task.comment),
+        owner: task.owner,
+        schedule_options: task.schedule.map(|schedule| schedule.to_string()),
+        warehouse_options: task.warehouse.map(|warehouse| WarehouseOptions {
+            warehouse: Some(warehouse),
+            using_warehouse_size: None,
+        }),
+        next_scheduled_at: task.next_scheduled_at,
+        suspend_task_after_num_failures: task.suspend_task_after_num_failures.map(|v| v as i32),
+        status: match task.status {
+            TaskStatus::Suspended => task_utils::Status::Suspended,
+            TaskStatus::Started => task_utils::Status::Started,
+        },
+        created_at: task.created_at,
+        updated_at: task.updated_at,
+        last_suspended_at: task.last_suspended_at,
+        after: task.after,
+        when_condition: task.when_condition,
+    }
+}
+
+fn tasks_to_datablock(tasks: Vec<task_utils::Task>) -> DataBlock {
     let mut created_on: Vec<i64> = Vec::with_capacity(tasks.len());
     let mut name: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
     let mut id: Vec<u64> = Vec::with_capacity(tasks.len());
@@ -53,9 +97,10 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
     let mut last_committed_on: Vec<i64> = Vec::with_capacity(tasks.len());
     let mut next_schedule_time: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
     let mut last_suspended_on: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
+    let mut after: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
+    let mut condition_text: Vec<Option<Vec<u8>>> = Vec::with_capacity(tasks.len());
 
-    for task in tasks {
-        let tsk: databend_common_cloud_control::task_utils::Task = task.try_into()?;
+    for tsk in tasks {
         created_on.push(tsk.created_at.timestamp_micros());
         name.push(tsk.task_name.into_bytes());
         id.push(tsk.task_id);
@@ -72,8 +117,10 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
         next_schedule_time.push(tsk.next_scheduled_at.map(|t| t.timestamp_micros()));
         last_committed_on.push(tsk.updated_at.timestamp_micros());
         last_suspended_on.push(tsk.last_suspended_at.map(|t| t.timestamp_micros()));
+        after.push(tsk.after.join(", ").into_bytes());
+        condition_text.push(tsk.when_condition.map(|s| s.into_bytes()));
     }
-    Ok(DataBlock::new_from_columns(vec![
+    DataBlock::new_from_columns(vec![
         TimestampType::from_data(created_on),
         StringType::from_data(name),
         UInt64Type::from_data(id),
@@ -87,7 +134,9 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
         TimestampType::from_opt_data(next_schedule_time),
         TimestampType::from_data(last_committed_on),
         TimestampType::from_opt_data(last_suspended_on),
-    ]))
+        StringType::from_data(after),
+        StringType::from_opt_data(condition_text),
+    ])
 }
 
 pub struct TasksTable {
@@ -108,17 +157,22 @@ impl AsyncSystemTable for TasksTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
+
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.tasks table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let roles = available_roles
+                .into_iter()
+                .map(|role| role.name)
+                .collect::<HashSet<_>>();
+            let mut tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+            tasks.retain(|task| roles.contains(&task.owner));
+            return Ok(parse_local_tasks_to_datablock(tasks));
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTasksRequest {
             tenant_id: tenant.clone(),
             name_like: "".to_string(),
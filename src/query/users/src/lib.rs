@@ -22,6 +22,7 @@ mod jwt;
 mod network_policy;
 mod password_policy;
 mod role_mgr;
+mod task;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -29,6 +30,7 @@ mod user_setting;
 mod user_stage;
 mod user_udf;
 mod visibility_checker;
+mod workload_group;
 
 pub mod connection;
 pub mod file_format;
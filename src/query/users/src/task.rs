@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::TaskApi;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// How long the runs of a task are kept in `system.task_history`.
+const TASK_RUN_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+impl UserApiProvider {
+    // Add a new task, a task id is assigned to it.
+    #[async_backtrace::framed]
+    pub async fn add_task(&self, tenant: &str, mut task: Task, if_not_exists: bool) -> Result<u64> {
+        if if_not_exists && self.exists_task(tenant, task.task_name.as_str()).await? {
+            return Ok(0);
+        }
+
+        let client = self.get_task_api_client(tenant)?;
+        task.task_id = client.generate_id().await?;
+        match client.add_task(task).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::TASK_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while add task)"))
+                }
+            }
+        }
+    }
+
+    // Update a task, fails if the seq of it is not matched.
+    #[async_backtrace::framed]
+    pub async fn update_task(&self, tenant: &str, task: Task, seq: MatchSeq) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .update_task(task, seq)
+            .await
+            .map_err(|e| e.add_message_back(" (while update task)"))
+    }
+
+    // Drop a task by name.
+    #[async_backtrace::framed]
+    pub async fn drop_task(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_task_api_client(tenant)?;
+        match client.drop_task(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_TASK {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop task)"))
+                }
+            }
+        }
+    }
+
+    // Check whether a task exists.
+    #[async_backtrace::framed]
+    pub async fn exists_task(&self, tenant: &str, name: &str) -> Result<bool> {
+        match self.get_task(tenant, name).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_TASK {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a task by name, together with its seq.
+    #[async_backtrace::framed]
+    pub async fn get_task(&self, tenant: &str, name: &str) -> Result<SeqV<Task>> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .get_task(name, MatchSeq::GE(0))
+            .await
+            .map_err(|e| e.add_message_back(" (while get task)"))
+    }
+
+    // Get all tasks of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tasks(&self, tenant: &str) -> Result<Vec<Task>> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .get_tasks()
+            .await
+            .map_err(|e| e.add_message_back(" (while get tasks)"))
+    }
+
+    // Record a run of a task.
+    #[async_backtrace::framed]
+    pub async fn add_task_run(&self, tenant: &str, run: TaskRun) -> Result<()> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .add_task_run(run, TASK_RUN_RETENTION)
+            .await
+            .map_err(|e| e.add_message_back(" (while add task run)"))
+    }
+
+    // Get the runs of a task, or of all the tasks if no name is given.
+    #[async_backtrace::framed]
+    pub async fn get_task_runs(&self, tenant: &str, name: Option<&str>) -> Result<Vec<TaskRun>> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .get_task_runs(name)
+            .await
+            .map_err(|e| e.add_message_back(" (while get task runs)"))
+    }
+
+    // Generate an id for a task run.
+    #[async_backtrace::framed]
+    pub async fn generate_task_run_id(&self, tenant: &str) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        client.generate_id().await
+    }
+
+    // Try to become the node that schedules the tasks of the tenant.
+    #[async_backtrace::framed]
+    pub async fn try_acquire_task_scheduler_lease(
+        &self,
+        tenant: &str,
+        node_id: &str,
+        ttl: Duration,
+    ) -> Result<bool> {
+        let client = self.get_task_api_client(tenant)?;
+        client.try_acquire_scheduler_lease(node_id, ttl).await
+    }
+}
@@ -34,10 +34,14 @@ use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TaskApi;
+use databend_common_management::TaskMgr;
 use databend_common_management::UdfApi;
 use databend_common_management::UdfMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
+use databend_common_management::WorkloadGroupApi;
+use databend_common_management::WorkloadGroupMgr;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::tenant::TenantQuota;
 use databend_common_meta_kvapi::kvapi;
@@ -151,6 +155,20 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_workload_group_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl WorkloadGroupApi>> {
+        Ok(Arc::new(WorkloadGroupMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_task_api_client(&self, tenant: &str) -> Result<Arc<impl TaskApi>> {
+        Ok(Arc::new(TaskMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -0,0 +1,18 @@
+set window_partition_spilling_bytes_threshold_per_proc = 8;
+drop table if exists t;
+CREATE TABLE t (user_id INT NULL, ts INT, v INT);
+INSERT INTO t VALUES (1, 3, 10), (2, 1, 20), (1, 1, 30), (3, 2, 40), (2, 2, 50), (NULL, 1, 60);
+INSERT INTO t VALUES (1, 2, 70), (3, 1, 80), (NULL, 2, 90);
+
+SELECT user_id, ts, row_number() OVER (PARTITION BY user_id ORDER BY ts) FROM t ORDER BY user_id, ts;
+
+SELECT '===================';
+
+SELECT user_id, ts, sum(v) OVER (PARTITION BY user_id ORDER BY ts ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) FROM t ORDER BY user_id, ts;
+
+SELECT '===================';
+
+SELECT user_id, ts, rank() OVER (PARTITION BY user_id % 2 ORDER BY v DESC) FROM t ORDER BY user_id, ts;
+
+set window_partition_spilling_bytes_threshold_per_proc = 0;
+drop table t;
@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# Write into a copy of the table, the test data must be kept untouched.
+WORKDIR=$(mktemp -d)
+cp -r "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl "$WORKDIR"/
+ROOT="$WORKDIR"/iceberg_tbl
+
+stmt "drop table if exists test_iceberg_write;"
+
+echo ">>>> create table test_iceberg_write engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg_write engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_iceberg_write values (7, 'f'), (8, 'g');"
+stmt "insert into test_iceberg_write select id + 10, data from test_iceberg_write where id < 3;"
+query "select * from test_iceberg_write order by id, data;"
+query "select count(*) from test_iceberg_write where id > 6;"
+
+echo "insert overwrite test_iceberg_write values (1, 'a');" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "not supported"
+
+stmt "drop table test_iceberg_write;"
+
+rm -rf "$WORKDIR"
@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/column_mapping/)
+
+stmt "drop table if exists test_delta;"
+
+echo ">>>> create table test_delta engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+query "select * from test_delta order by user_id;"
+query "select count(*) from test_delta;"
+query "select user_id from test_delta where user_id > 0 order by user_id;"
+
+# time travel to the version before the rows are deleted
+query "select * from test_delta at (snapshot => '0') order by user_id;"
+query "select count(*) from test_delta at (snapshot => '1');"
+
+stmt "drop table test_delta;"